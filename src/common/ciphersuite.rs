@@ -9,14 +9,27 @@ pub(crate) trait CipherSuiteParameter: Debug + Clone {
 pub(crate) enum CipherSuiteId {
     BbsBls12381G1XmdSha256,
     BbsBls12381G1XofShake256,
+    BbsBls12381G1XmdSha256Draft06,
+    BbsBls12381G1XofShake256Draft06,
     BlsSigBls12381G2XmdSha256Nul,
     BlsSigBls12381G2XmdSha256Aug,
     BlsSigBls12381G2XmdSha256Pop,
+    BlsSigBls12381G1XmdSha256Nul,
+    BlsSigBls12381G1XmdSha256Aug,
+    BlsSigBls12381G1XmdSha256Pop,
+    BlsVrfBls12381G2XmdSha256,
+    IbeBfBls12381G2XmdSha256,
+    PsBls12381G1XmdSha256,
+    GroupSigBbs04Bls12381XmdSha256,
+    PreAfghBls12381XmdSha256,
+    /// An application defined ciphersuite, see
+    /// `bbs::ciphersuites::custom::BbsCustomCiphersuite`.
+    Custom(&'static [u8]),
 }
 
 impl CipherSuiteId {
     /// Convert to a String represenation.
-    pub(crate) fn as_octets(&self) -> &[u8] {
+    pub(crate) fn as_octets(&self) -> &'static [u8] {
         match &self {
             CipherSuiteId::BbsBls12381G1XmdSha256 => {
                 b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_"
@@ -24,6 +37,12 @@ impl CipherSuiteId {
             CipherSuiteId::BbsBls12381G1XofShake256 => {
                 b"BBS_BLS12381G1_XOF:SHAKE-256_SSWU_RO_H2G_HM2S_"
             }
+            CipherSuiteId::BbsBls12381G1XmdSha256Draft06 => {
+                b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_"
+            }
+            CipherSuiteId::BbsBls12381G1XofShake256Draft06 => {
+                b"BBS_BLS12381G1_XOF:SHAKE-256_SSWU_RO_"
+            }
             CipherSuiteId::BlsSigBls12381G2XmdSha256Nul => {
                 b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_"
             }
@@ -33,6 +52,45 @@ impl CipherSuiteId {
             CipherSuiteId::BlsSigBls12381G2XmdSha256Pop => {
                 b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_"
             }
+            CipherSuiteId::BlsSigBls12381G1XmdSha256Nul => {
+                b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_"
+            }
+            CipherSuiteId::BlsSigBls12381G1XmdSha256Aug => {
+                b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_AUG_"
+            }
+            CipherSuiteId::BlsSigBls12381G1XmdSha256Pop => {
+                b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_"
+            }
+            CipherSuiteId::BlsVrfBls12381G2XmdSha256 => {
+                b"BLS_VRF_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_"
+            }
+            CipherSuiteId::IbeBfBls12381G2XmdSha256 => {
+                b"IBE_BF_BLS12381G2_XMD:SHA-256_SSWU_RO_"
+            }
+            CipherSuiteId::PsBls12381G1XmdSha256 => {
+                b"PS_BLS12381G1_XMD:SHA-256_SSWU_RO_"
+            }
+            CipherSuiteId::GroupSigBbs04Bls12381XmdSha256 => {
+                b"GROUP_SIG_BBS04_BLS12381_XMD:SHA-256_"
+            }
+            CipherSuiteId::PreAfghBls12381XmdSha256 => {
+                b"PRE_AFGH_BLS12381_XMD:SHA-256_"
+            }
+            CipherSuiteId::Custom(id) => *id,
+        }
+    }
+
+    /// The `api_id` prefixing the domain separation tags of the ciphersuite.
+    /// The BBS `draft-irtf-cfrg-bbs-signatures-06` ciphersuites define it as
+    /// `ciphersuite_id || "H2G_HM2S_"`, for the other ones this is the
+    /// ciphersuite ID.
+    pub(crate) fn api_id(&self) -> Vec<u8> {
+        match &self {
+            CipherSuiteId::BbsBls12381G1XmdSha256Draft06
+            | CipherSuiteId::BbsBls12381G1XofShake256Draft06 => {
+                [self.as_octets(), b"H2G_HM2S_"].concat()
+            }
+            _ => self.as_octets().to_vec(),
         }
     }
 }
@@ -7,7 +7,7 @@ use crate::{
 pub(crate) trait HashToCurveParameter: ExpandMessageParameter {
     /// Default domain separation tag for `hash_to_point` operation.
     fn default_hash_to_point_dst() -> Vec<u8> {
-        Self::ID.as_octets().to_vec()
+        Self::ID.api_id()
     }
 
     fn hash_to_g1(message: &[u8], dst: &[u8]) -> Result<G1Projective, Error> {
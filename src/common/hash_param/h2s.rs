@@ -19,13 +19,17 @@ use super::{
 pub(crate) trait HashToScalarParameter: ExpandMessageParameter {
     /// Default domain separation tag for `hash_to_scalar` operation.
     fn default_hash_to_scalar_dst() -> Vec<u8> {
-        [Self::ID.as_octets(), DEFAULT_DST_SUFFIX_H2S.as_bytes()].concat()
+        [
+            Self::ID.api_id().as_slice(),
+            DEFAULT_DST_SUFFIX_H2S.as_bytes(),
+        ]
+        .concat()
     }
 
     /// Default domain separation tag to be used in [MapMessageToScalarAsHash](https://identity.foundation/bbs-signature/draft-bbs-signatures.html#name-mapmessagetoscalarashash).
     fn default_map_message_to_scalar_as_hash_dst() -> Vec<u8> {
         [
-            Self::ID.as_octets(),
+            Self::ID.api_id().as_slice(),
             DEFAULT_DST_SUFFIX_MESSAGE_TO_SCALAR.as_bytes(),
         ]
         .concat()
@@ -1,3 +1,71 @@
+use crate::{
+    curves::bls12_381::{Scalar, OCTET_SCALAR_LENGTH},
+    Error,
+};
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+// Number of bytes of a Lamport secret key chunk, `K` in EIP-2333.
+const LAMPORT_CHUNK_LENGTH: usize = 32;
+
+// Number of chunks of a Lamport secret key, `L` in EIP-2333.
+const LAMPORT_CHUNK_COUNT: usize = 255;
+
+// `IKM_to_lamport_SK` of EIP-2333, hashing every chunk of the Lamport secret
+// key into `lamport_pk` as they are computed.
+fn ikm_to_lamport_pk(ikm: &[u8], salt: &[u8], lamport_pk: &mut Sha256) {
+    let mut okm = vec![0u8; LAMPORT_CHUNK_LENGTH * LAMPORT_CHUNK_COUNT];
+    // expand() only fails when `okm` is longer than 255 hash outputs
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(b"", &mut okm)
+        .expect("Lamport secret key length is valid");
+    for chunk in okm.chunks(LAMPORT_CHUNK_LENGTH) {
+        lamport_pk.update(Sha256::digest(chunk));
+    }
+    okm.zeroize();
+}
+
+// `parent_SK_to_lamport_PK` of EIP-2333.
+pub(crate) fn parent_sk_to_lamport_pk(
+    parent_sk: &Scalar,
+    index: u32,
+) -> [u8; 32] {
+    let salt = index.to_be_bytes();
+    let mut ikm: [u8; OCTET_SCALAR_LENGTH] = parent_sk.to_bytes_be();
+    let mut not_ikm = ikm.map(|b| !b);
+
+    let mut lamport_pk = Sha256::new();
+    ikm_to_lamport_pk(&ikm, &salt, &mut lamport_pk);
+    ikm_to_lamport_pk(&not_ikm, &salt, &mut lamport_pk);
+    ikm.zeroize();
+    not_ikm.zeroize();
+
+    let mut compressed_lamport_pk = [0u8; 32];
+    compressed_lamport_pk.copy_from_slice(&lamport_pk.finalize());
+    compressed_lamport_pk
+}
+
+// Parse a derivation path as defined by EIP-2334, i.e. `m` followed by
+// `/<index>` for every level of the tree.
+pub(crate) fn parse_derivation_path(path: &str) -> Result<Vec<u32>, Error> {
+    let invalid_path = || Error::BadParams {
+        cause: format!("invalid derivation path `{path}`"),
+    };
+    let mut nodes = path.split('/');
+    if nodes.next() != Some("m") {
+        return Err(invalid_path());
+    }
+    nodes
+        .map(|node| {
+            if node.is_empty() || !node.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid_path());
+            }
+            node.parse::<u32>().map_err(|_| invalid_path())
+        })
+        .collect()
+}
+
 // Key-pair implementation.
 macro_rules! bbs_bls_key_pair_impl {
     (
@@ -56,7 +124,9 @@ macro_rules! bbs_bls_key_pair_impl {
             /// Computes a secret key from an IKM, as defined by
             /// <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-04#section-2.3>
             /// Note this procedure does not follow
-            /// <https://identity.foundation/bbs-signature/draft-bbs-signatures.html#name-keygen>
+            /// <https://identity.foundation/bbs-signature/draft-bbs-signatures.html#name-keygen>,
+            /// BBS keys interoperable with other implementations are derived
+            /// with the `key_gen` function of the BBS ciphersuites.
             pub fn new(ikm_in: &[u8], key_info: &[u8]) -> Option<Self> {
                 let mut random_ikm = [0u8; $min_key_gen_ikm_length];
 
@@ -93,6 +163,54 @@ macro_rules! bbs_bls_key_pair_impl {
                 None
             }
 
+            /// Derive a master secret key from `seed`, as `derive_master_SK`
+            /// of <https://eips.ethereum.org/EIPS/eip-2333>.
+            pub fn derive_master(seed: &[u8]) -> Result<Self, Error> {
+                if seed.len() < $min_key_gen_ikm_length {
+                    return Err(Error::BadParams {
+                        cause: format!(
+                            "seed is too short, needs to be at least {} bytes \
+                             long",
+                            $min_key_gen_ikm_length
+                        ),
+                    });
+                }
+                Self::hkdf_mod_r(seed)
+            }
+
+            /// Derive the child secret key at `index`, as `derive_child_SK`
+            /// of <https://eips.ethereum.org/EIPS/eip-2333>.
+            pub fn derive_child(&self, index: u32) -> Result<Self, Error> {
+                let compressed_lamport_pk =
+                    crate::common::key_pair::parent_sk_to_lamport_pk(
+                        &self.0, index,
+                    );
+                Self::hkdf_mod_r(&compressed_lamport_pk)
+            }
+
+            /// Derive the secret key at `path` from `seed`, where `path` is
+            /// a derivation path as defined by
+            /// <https://eips.ethereum.org/EIPS/eip-2334>, e.g.
+            /// `m/12381/3600/0/0`.
+            pub fn derive_path(seed: &[u8], path: &str) -> Result<Self, Error> {
+                crate::common::key_pair::parse_derivation_path(path)?
+                    .into_iter()
+                    .try_fold(Self::derive_master(seed)?, |sk, index| {
+                        sk.derive_child(index)
+                    })
+            }
+
+            // `HKDF_mod_r` of EIP-2333, which is the `KeyGen` of
+            // draft-irtf-cfrg-bls-signature-04 with an empty `key_info`.
+            fn hkdf_mod_r(ikm: &[u8]) -> Result<Self, Error> {
+                match $generate_sk(ikm, b"") {
+                    Some(sk) if sk.is_zero().unwrap_u8() == 0u8 => {
+                        Ok(SecretKey(Box::new(sk)))
+                    }
+                    _ => Err(Error::InvalidSecretKey),
+                }
+            }
+
             #[allow(dead_code)]
             pub(super) fn as_scalar(&self) -> Scalar {
                 *self.0
@@ -1,4 +1,13 @@
-use crate::error::Error;
+use crate::{
+    error::Error,
+    key_encoding::{base64url_decode, base64url_encode},
+};
+use core::fmt;
+use serde::{
+    de::{Error as DError, SeqAccess, Unexpected, Visitor},
+    Deserializer,
+    Serializer,
+};
 
 /// Implementation of I2OSP() function defined in RFC8017.
 // Messages can have size of (2^64-1) bytes, so support direct encoding of u64
@@ -29,3 +38,247 @@ pub(crate) fn i2osp_with_data(
 ) -> Result<Vec<u8>, Error> {
     Ok([&i2osp(input.len() as u64, octent_length)?, input].concat())
 }
+
+// Serialize `octets` as an unpadded base64url string for human-readable
+// formats, and as bytes otherwise.
+pub(crate) fn serialize_octets<S>(
+    octets: &[u8],
+    s: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if s.is_human_readable() {
+        s.serialize_str(&base64url_encode(octets))
+    } else {
+        s.serialize_bytes(octets)
+    }
+}
+
+// Deserialize octets serialized by `serialize_octets`.
+pub(crate) fn deserialize_octets<'de, D>(d: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_octets_with(
+        d,
+        "base64url string or byte array",
+        base64url_decode,
+    )
+}
+
+// Deserialize octets encoded with `decode` as a string for human-readable
+// formats, and as bytes otherwise.
+pub(crate) fn deserialize_octets_with<'de, D>(
+    d: D,
+    expecting: &'static str,
+    decode: fn(&str) -> Result<Vec<u8>, Error>,
+) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct OctetsVisitor {
+        expecting: &'static str,
+        decode: fn(&str) -> Result<Vec<u8>, Error>,
+    }
+
+    impl<'de> Visitor<'de> for OctetsVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.expecting)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Vec<u8>, E>
+        where
+            E: DError,
+        {
+            (self.decode)(v)
+                .map_err(|_| DError::invalid_value(Unexpected::Str(v), &self))
+        }
+
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Vec<u8>, E>
+        where
+            E: DError,
+        {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Vec<u8>, E>
+        where
+            E: DError,
+        {
+            Ok(v)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Vec<u8>, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut octets = Vec::new();
+            while let Some(b) = seq.next_element()? {
+                octets.push(b);
+            }
+            Ok(octets)
+        }
+    }
+
+    let visitor = OctetsVisitor { expecting, decode };
+    if d.is_human_readable() {
+        d.deserialize_str(visitor)
+    } else {
+        d.deserialize_bytes(visitor)
+    }
+}
+
+// Define a public opaque wrapper `$name` around the validated octets of a
+// value of `$len` bytes. `$validate` checks the octets once on parsing.
+macro_rules! fixed_octets_type {
+    ($(#[$attr:meta])* $name:ident, $len:expr, $validate:expr) => {
+        $(#[$attr])*
+        ///
+        /// Serialized with serde as an unpadded base64url string in
+        /// human-readable formats, or as hex with [`crate::serde_hex`], and as
+        /// bytes otherwise.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name([u8; $len]);
+
+        impl $name {
+            /// The number of bytes in the octets representation.
+            pub const SIZE_BYTES: usize = $len;
+
+            /// Parse and validate the octets representation.
+            pub fn from_octets(
+                octets: &[u8; $len],
+            ) -> Result<Self, $crate::Error> {
+                let validate: fn(&[u8; $len]) -> Result<(), $crate::Error> =
+                    $validate;
+                validate(octets)?;
+                Ok(Self(*octets))
+            }
+
+            /// Get the octets representation.
+            pub fn to_octets(&self) -> [u8; $len] {
+                self.0
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = [u8; $len];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = $crate::Error;
+
+            fn try_from(octets: &[u8]) -> Result<Self, Self::Error> {
+                Self::from_octets(octets.try_into()?)
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                $crate::common::serialization::serialize_octets(&self.0, s)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let octets =
+                    $crate::common::serialization::deserialize_octets(d)?;
+                Self::try_from(octets.as_slice())
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use fixed_octets_type;
+
+// Define a public opaque wrapper `$name` around the validated octets of a
+// value of variable length. `$validate` checks the octets once on parsing.
+macro_rules! octets_type {
+    ($(#[$attr:meta])* $name:ident, $validate:expr) => {
+        $(#[$attr])*
+        ///
+        /// Serialized with serde as an unpadded base64url string in
+        /// human-readable formats, or as hex with [`crate::serde_hex`], and as
+        /// bytes otherwise.
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $name(Vec<u8>);
+
+        impl $name {
+            /// Parse and validate the octets representation.
+            pub fn from_octets(octets: &[u8]) -> Result<Self, $crate::Error> {
+                let validate: fn(&[u8]) -> Result<(), $crate::Error> =
+                    $validate;
+                validate(octets)?;
+                Ok(Self(octets.to_vec()))
+            }
+
+            /// Get the octets representation.
+            pub fn to_octets(&self) -> Vec<u8> {
+                self.0.clone()
+            }
+        }
+
+        impl core::ops::Deref for $name {
+            type Target = [u8];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = $crate::Error;
+
+            fn try_from(octets: &[u8]) -> Result<Self, Self::Error> {
+                Self::from_octets(octets)
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                $crate::common::serialization::serialize_octets(&self.0, s)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let octets =
+                    $crate::common::serialization::deserialize_octets(d)?;
+                Self::from_octets(&octets).map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use octets_type;
@@ -8,3 +8,6 @@ pub(crate) const OCTET_POINT_G1_LENGTH: usize = 48;
 
 /// Number of bytes to store an element of G2 in affine and compressed form.
 pub(crate) const OCTET_POINT_G2_LENGTH: usize = 96;
+
+/// Number of bytes to store an element of GT in compressed form.
+pub(crate) const OCTET_POINT_GT_LENGTH: usize = 288;
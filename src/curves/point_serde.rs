@@ -2,12 +2,15 @@ use group::Curve;
 
 use crate::{
     curves::bls12_381::{
+        Compress,
         G1Affine,
         G1Projective,
         G2Affine,
         G2Projective,
+        Gt,
         OCTET_POINT_G1_LENGTH,
         OCTET_POINT_G2_LENGTH,
+        OCTET_POINT_GT_LENGTH,
     },
     error::Error,
 };
@@ -53,3 +56,22 @@ pub(crate) fn octets_to_point_g2(
         Err(Error::BadEncoding)
     }
 }
+
+/// Get the representation of an element of GT to compressed octets form.
+pub(crate) fn point_to_octets_gt(
+    p: &Gt,
+) -> Result<[u8; OCTET_POINT_GT_LENGTH], Error> {
+    let mut octets = [0u8; OCTET_POINT_GT_LENGTH];
+    p.write_compressed(&mut octets[..])
+        .map_err(|_| Error::CryptoOps {
+            cause: "failed to compress GT element".to_owned(),
+        })?;
+    Ok(octets)
+}
+
+/// Convert from octets in compressed form to an element of GT.
+pub(crate) fn octets_to_point_gt(
+    octets: &[u8; OCTET_POINT_GT_LENGTH],
+) -> Result<Gt, Error> {
+    Gt::read_compressed(&octets[..]).map_err(|_| Error::BadEncoding)
+}
@@ -0,0 +1,376 @@
+use crate::{
+    bbs::{
+        ciphersuites::{
+            bls12_381::{
+                BbsProof,
+                BbsProofDraft06,
+                BbsSignature,
+                BBS_BLS12381G1_PUBLIC_KEY_LENGTH,
+            },
+            BbsCiphersuite,
+        },
+        BbsProofVerifyRequest,
+        BbsVerifyRequest,
+    },
+    bbs_bound::{
+        ciphersuites::BbsBoundCiphersuite,
+        BbsBoundProofVerifyRequest,
+        BbsBoundVerifyRequest,
+    },
+    bls::ciphersuites::{
+        bls12_381::{
+            BlsSignature as BlsG2Signature,
+            BLS_SIG_BLS12381G2_SECRET_KEY_LENGTH,
+        },
+        bls12_381_g1::BlsSignature as BlsG1Signature,
+        BlsCiphersuite,
+    },
+    Error,
+};
+
+/// Version of the envelope encoding produced by this crate.
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// The scheme and ciphersuite which produced an enveloped value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnvelopeCiphersuite {
+    /// A BBS ciphersuite.
+    Bbs(BbsCiphersuite),
+    /// A BBS bound ciphersuite.
+    BbsBound(BbsBoundCiphersuite),
+    /// A BLS signature ciphersuite.
+    Bls(BlsCiphersuite),
+}
+
+impl EnvelopeCiphersuite {
+    // Tag of the scheme of the ciphersuite.
+    fn scheme_tag(&self) -> u8 {
+        match self {
+            Self::Bbs(_) => 1,
+            Self::BbsBound(_) => 2,
+            Self::Bls(_) => 3,
+        }
+    }
+
+    /// The ciphersuite identifier as octets.
+    pub fn as_octets(&self) -> &'static [u8] {
+        match self {
+            Self::Bbs(c) => c.as_octets(),
+            Self::BbsBound(c) => c.as_octets(),
+            Self::Bls(c) => c.as_octets(),
+        }
+    }
+
+    // Get the ciphersuite with scheme tag `scheme_tag` and identifier
+    // `octets`.
+    fn from_octets(scheme_tag: u8, octets: &[u8]) -> Result<Self, Error> {
+        match scheme_tag {
+            1 => Ok(Self::Bbs(BbsCiphersuite::from_octets(octets)?)),
+            2 => Ok(Self::BbsBound(BbsBoundCiphersuite::from_octets(octets)?)),
+            3 => Ok(Self::Bls(BlsCiphersuite::from_octets(octets)?)),
+            _ => Err(Error::BadParams {
+                cause: format!("unknown envelope scheme tag {scheme_tag}"),
+            }),
+        }
+    }
+}
+
+/// The kind of an enveloped value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnvelopeKind {
+    /// A signature.
+    Signature,
+    /// A signature proof of knowledge.
+    Proof,
+}
+
+impl EnvelopeKind {
+    fn tag(&self) -> u8 {
+        match self {
+            Self::Signature => 1,
+            Self::Proof => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self, Error> {
+        match tag {
+            1 => Ok(Self::Signature),
+            2 => Ok(Self::Proof),
+            _ => Err(Error::BadParams {
+                cause: format!("unknown envelope kind tag {tag}"),
+            }),
+        }
+    }
+}
+
+/// A signature or a proof along with the scheme and ciphersuite which
+/// produced it, for values crossing a system boundary.
+/// The octets representation is
+/// `version || scheme_tag || kind_tag || I2OSP(len(ciphersuite_id), 1) ||
+/// ciphersuite_id || payload`, where `payload` is the raw encoding of the
+/// value. The raw encodings remain the ones returned by the signing and
+/// proving APIs; envelopes are opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Envelope {
+    ciphersuite: EnvelopeCiphersuite,
+    kind: EnvelopeKind,
+    payload: Vec<u8>,
+}
+
+impl Envelope {
+    /// Wrap the raw encoding `payload` of a value of kind `kind` produced by
+    /// `ciphersuite`, e.g. a `BbsSignature` or the octets returned by a
+    /// signing API. The payload is validated as such a value.
+    pub fn new<P>(
+        ciphersuite: EnvelopeCiphersuite,
+        kind: EnvelopeKind,
+        payload: P,
+    ) -> Result<Self, Error>
+    where
+        P: AsRef<[u8]>,
+    {
+        let payload = payload.as_ref();
+        match (ciphersuite, kind) {
+            (
+                EnvelopeCiphersuite::Bbs(_) | EnvelopeCiphersuite::BbsBound(_),
+                EnvelopeKind::Signature,
+            ) => {
+                BbsSignature::try_from(payload)?;
+            }
+            (
+                EnvelopeCiphersuite::Bbs(
+                    BbsCiphersuite::Bls12381G1Sha256Draft06
+                    | BbsCiphersuite::Bls12381G1Shake256Draft06,
+                ),
+                EnvelopeKind::Proof,
+            ) => {
+                BbsProofDraft06::from_octets(payload)?;
+            }
+            (
+                EnvelopeCiphersuite::Bbs(_) | EnvelopeCiphersuite::BbsBound(_),
+                EnvelopeKind::Proof,
+            ) => {
+                BbsProof::from_octets(payload)?;
+            }
+            (
+                EnvelopeCiphersuite::Bls(
+                    BlsCiphersuite::Bls12381G2Sha256Nul
+                    | BlsCiphersuite::Bls12381G2Sha256Aug
+                    | BlsCiphersuite::Bls12381G2Sha256Pop,
+                ),
+                EnvelopeKind::Signature,
+            ) => {
+                BlsG2Signature::try_from(payload)?;
+            }
+            (
+                EnvelopeCiphersuite::Bls(
+                    BlsCiphersuite::Bls12381G1Sha256Nul
+                    | BlsCiphersuite::Bls12381G1Sha256Aug
+                    | BlsCiphersuite::Bls12381G1Sha256Pop,
+                ),
+                EnvelopeKind::Signature,
+            ) => {
+                BlsG1Signature::try_from(payload)?;
+            }
+            (EnvelopeCiphersuite::Bls(_), EnvelopeKind::Proof) => {
+                return Err(Error::BadParams {
+                    cause: "BLS ciphersuites have no proofs".to_owned(),
+                });
+            }
+        }
+        Ok(Self {
+            ciphersuite,
+            kind,
+            payload: payload.to_vec(),
+        })
+    }
+
+    /// The scheme and ciphersuite which produced the value.
+    pub fn ciphersuite(&self) -> EnvelopeCiphersuite {
+        self.ciphersuite
+    }
+
+    /// The kind of the value.
+    pub fn kind(&self) -> EnvelopeKind {
+        self.kind
+    }
+
+    /// The raw encoding of the value.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Get the octets representation of the envelope.
+    pub fn to_octets(&self) -> Vec<u8> {
+        let ciphersuite_id = self.ciphersuite.as_octets();
+        let mut octets =
+            Vec::with_capacity(4 + ciphersuite_id.len() + self.payload.len());
+        octets.push(ENVELOPE_VERSION);
+        octets.push(self.ciphersuite.scheme_tag());
+        octets.push(self.kind.tag());
+        // Ciphersuite identifiers are shorter than 256 bytes
+        octets.push(ciphersuite_id.len() as u8);
+        octets.extend_from_slice(ciphersuite_id);
+        octets.extend_from_slice(&self.payload);
+        octets
+    }
+
+    /// Parse an envelope from its octets representation, and validate the
+    /// value it holds.
+    pub fn from_octets(octets: &[u8]) -> Result<Self, Error> {
+        let (version, scheme_tag, kind_tag, id_len, rest) = match octets {
+            [version, scheme_tag, kind_tag, id_len, rest @ ..] => {
+                (*version, *scheme_tag, *kind_tag, *id_len as usize, rest)
+            }
+            _ => return Err(Error::BadEncoding),
+        };
+        if version != ENVELOPE_VERSION {
+            return Err(Error::BadParams {
+                cause: format!("unsupported envelope version {version}"),
+            });
+        }
+        if rest.len() < id_len {
+            return Err(Error::BadEncoding);
+        }
+        let (ciphersuite_id, payload) = rest.split_at(id_len);
+
+        Self::new(
+            EnvelopeCiphersuite::from_octets(scheme_tag, ciphersuite_id)?,
+            EnvelopeKind::from_tag(kind_tag)?,
+            payload,
+        )
+    }
+
+    /// Verify an enveloped BBS signature with the ciphersuite of the
+    /// envelope.
+    pub fn verify_bbs_signature<T>(
+        &self,
+        public_key: &[u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH],
+        header: Option<T>,
+        messages: Option<&[T]>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match (self.ciphersuite, self.kind) {
+            (
+                EnvelopeCiphersuite::Bbs(ciphersuite),
+                EnvelopeKind::Signature,
+            ) => ciphersuite.verify(&BbsVerifyRequest {
+                public_key,
+                header,
+                messages,
+                signature: self.payload.as_slice().try_into()?,
+            }),
+            _ => Err(self.mismatch("a BBS signature")),
+        }
+    }
+
+    /// Verify an enveloped BBS signature proof of knowledge with the
+    /// ciphersuite of the envelope.
+    pub fn verify_bbs_proof<T>(
+        &self,
+        public_key: &[u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH],
+        header: Option<T>,
+        presentation_header: Option<T>,
+        messages: Option<&[(usize, T)]>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match (self.ciphersuite, self.kind) {
+            (EnvelopeCiphersuite::Bbs(ciphersuite), EnvelopeKind::Proof) => {
+                ciphersuite.proof_verify(&BbsProofVerifyRequest {
+                    public_key,
+                    header,
+                    presentation_header,
+                    proof: &self.payload,
+                    messages,
+                })
+            }
+            _ => Err(self.mismatch("a BBS proof")),
+        }
+    }
+
+    /// Verify an enveloped BBS bound signature with the ciphersuite of the
+    /// envelope.
+    pub fn verify_bbs_bound_signature<T>(
+        &self,
+        public_key: &[u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH],
+        bls_secret_key: &[u8; BLS_SIG_BLS12381G2_SECRET_KEY_LENGTH],
+        header: Option<T>,
+        messages: Option<&[T]>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match (self.ciphersuite, self.kind) {
+            (
+                EnvelopeCiphersuite::BbsBound(ciphersuite),
+                EnvelopeKind::Signature,
+            ) => ciphersuite.verify(&BbsBoundVerifyRequest {
+                public_key,
+                bls_secret_key,
+                header,
+                messages,
+                signature: self.payload.as_slice().try_into()?,
+            }),
+            _ => Err(self.mismatch("a BBS bound signature")),
+        }
+    }
+
+    /// Verify an enveloped BBS bound signature proof of knowledge with the
+    /// ciphersuite of the envelope.
+    pub fn verify_bbs_bound_proof<T>(
+        &self,
+        public_key: &[u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH],
+        header: Option<T>,
+        presentation_header: Option<T>,
+        messages: Option<&[(usize, T)]>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match (self.ciphersuite, self.kind) {
+            (
+                EnvelopeCiphersuite::BbsBound(ciphersuite),
+                EnvelopeKind::Proof,
+            ) => ciphersuite.proof_verify(&BbsBoundProofVerifyRequest {
+                public_key,
+                header,
+                presentation_header,
+                proof: &self.payload,
+                messages,
+            }),
+            _ => Err(self.mismatch("a BBS bound proof")),
+        }
+    }
+
+    /// Verify an enveloped BLS signature on `message` with the ciphersuite of
+    /// the envelope, for the compressed `public_key`.
+    pub fn verify_bls_signature<T>(
+        &self,
+        public_key: &[u8],
+        message: T,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match (self.ciphersuite, self.kind) {
+            (
+                EnvelopeCiphersuite::Bls(ciphersuite),
+                EnvelopeKind::Signature,
+            ) => ciphersuite.verify(public_key, message, &self.payload),
+            _ => Err(self.mismatch("a BLS signature")),
+        }
+    }
+
+    fn mismatch(&self, expected: &str) -> Error {
+        Error::BadParams {
+            cause: format!(
+                "envelope holds a {:?} of {:?}, not {expected}",
+                self.kind, self.ciphersuite
+            ),
+        }
+    }
+}
@@ -60,6 +60,16 @@ pub enum Error {
         cause: String,
     },
 
+    /// A component of a proof is malformed.
+    MalformedProofComponent {
+        /// Name of the malformed component.
+        component: String,
+        /// Byte offset of the malformed component in the proof.
+        offset: usize,
+        /// Detailed cause.
+        cause: String,
+    },
+
     /// Not enough message generators.
     MessageGeneratorsLengthMismatch {
         /// Number of message generators.
@@ -125,6 +135,17 @@ impl core::fmt::Debug for Error {
             Error::MalformedProof { ref cause } => {
                 write!(f, "proof is malformed: cause: {cause}")
             }
+            Error::MalformedProofComponent {
+                ref component,
+                offset,
+                ref cause,
+            } => {
+                write!(
+                    f,
+                    "proof component `{component}` at byte offset {offset} is \
+                     malformed: cause: {cause}"
+                )
+            }
             Error::MessageGeneratorsLengthMismatch {
                 generators,
                 messages,
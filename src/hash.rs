@@ -0,0 +1,245 @@
+use crate::{
+    common::hash_param::constant::{MAX_DST_SIZE, XOF_NO_OF_BYTES},
+    curves::{
+        bls12_381::{
+            hash_to_curve::{
+                ExpandMessageState,
+                ExpandMsgXmd,
+                ExpandMsgXof,
+                InitExpandMessage,
+            },
+            G1Projective,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{point_to_octets_g1, point_to_octets_g2},
+    },
+    Error,
+};
+use core::fmt::Debug;
+use digest::{Digest, ExtendableOutput, Update, XofReader};
+use sha2::{Sha256, Sha512};
+use sha3::{Shake128, Shake256};
+
+// Prefix of the hashed DST replacing a DST longer than 255 bytes.
+const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+
+pub(crate) mod sealed {
+    use crate::curves::bls12_381::hash_to_curve::ExpandMessage;
+
+    pub trait Sealed {
+        type Expander: ExpandMessage;
+
+        // Maximum `len_in_bytes` accepted by `expand_message`.
+        const MAX_LENGTH: usize;
+
+        // Reduce a DST longer than 255 bytes as per RFC 9380 section 5.3.3.
+        fn oversize_dst(dst: &[u8]) -> Vec<u8>;
+    }
+}
+
+/// An `expand_message` variant as defined in RFC 9380
+/// <https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message>.
+/// This trait is sealed, only the variants provided by this module are
+/// supported.
+pub trait ExpandMessage: sealed::Sealed + Debug + Clone {}
+
+/// `expand_message_xmd` with SHA-256.
+#[derive(Debug, Clone, Copy)]
+pub struct XmdSha256;
+
+/// `expand_message_xmd` with SHA-512.
+#[derive(Debug, Clone, Copy)]
+pub struct XmdSha512;
+
+/// `expand_message_xof` with SHAKE-128.
+#[derive(Debug, Clone, Copy)]
+pub struct XofShake128;
+
+/// `expand_message_xof` with SHAKE-256.
+#[derive(Debug, Clone, Copy)]
+pub struct XofShake256;
+
+impl sealed::Sealed for XmdSha256 {
+    type Expander = ExpandMsgXmd<Sha256>;
+    const MAX_LENGTH: usize = 255 * 32;
+
+    fn oversize_dst(dst: &[u8]) -> Vec<u8> {
+        Sha256::digest(&[OVERSIZE_DST_SALT, dst].concat()).to_vec()
+    }
+}
+
+impl sealed::Sealed for XmdSha512 {
+    type Expander = ExpandMsgXmd<Sha512>;
+    const MAX_LENGTH: usize = 255 * 64;
+
+    fn oversize_dst(dst: &[u8]) -> Vec<u8> {
+        Sha512::digest(&[OVERSIZE_DST_SALT, dst].concat()).to_vec()
+    }
+}
+
+impl sealed::Sealed for XofShake128 {
+    type Expander = ExpandMsgXof<Shake128>;
+    const MAX_LENGTH: usize = u16::MAX as usize;
+
+    fn oversize_dst(dst: &[u8]) -> Vec<u8> {
+        xof_oversize_dst::<Shake128>(dst)
+    }
+}
+
+impl sealed::Sealed for XofShake256 {
+    type Expander = ExpandMsgXof<Shake256>;
+    const MAX_LENGTH: usize = u16::MAX as usize;
+
+    fn oversize_dst(dst: &[u8]) -> Vec<u8> {
+        xof_oversize_dst::<Shake256>(dst)
+    }
+}
+
+impl ExpandMessage for XmdSha256 {}
+
+impl ExpandMessage for XmdSha512 {}
+
+impl ExpandMessage for XofShake128 {}
+
+impl ExpandMessage for XofShake256 {}
+
+/// Expand `msg` to `len_in_bytes` uniformly random bytes with the
+/// `expand_message` variant `E`.
+/// `dst` must not be empty, a `dst` longer than 255 bytes is reduced as
+/// defined in RFC 9380 section 5.3.3.
+pub fn expand_message<E>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, Error>
+where
+    E: ExpandMessage,
+{
+    let dst = process_dst::<E>(dst)?;
+    if len_in_bytes == 0 || len_in_bytes > E::MAX_LENGTH {
+        return Err(Error::BadParams {
+            cause: format!(
+                "`len_in_bytes` must be in [1, {}], given {len_in_bytes}",
+                E::MAX_LENGTH
+            ),
+        });
+    }
+
+    let mut expander = E::Expander::init_expand(msg, &dst, len_in_bytes);
+    let mut uniform_bytes = vec![0u8; len_in_bytes];
+    expander.read_into(&mut uniform_bytes);
+    Ok(uniform_bytes)
+}
+
+/// `expand_message_xmd` with SHA-256.
+pub fn expand_message_xmd(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, Error> {
+    expand_message::<XmdSha256>(msg, dst, len_in_bytes)
+}
+
+/// `expand_message_xof` with SHAKE-256.
+pub fn expand_message_xof(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, Error> {
+    expand_message::<XofShake256>(msg, dst, len_in_bytes)
+}
+
+/// Hash `msg` to a point of G1 with the `BLS12381G1_XMD:SHA-256_SSWU_RO_`
+/// suite of RFC 9380, and return the point in compressed form.
+/// `dst` must not be empty, a `dst` longer than 255 bytes is reduced as
+/// defined in RFC 9380 section 5.3.3.
+pub fn hash_to_g1(
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<[u8; OCTET_POINT_G1_LENGTH], Error> {
+    let dst = process_dst::<XmdSha256>(dst)?;
+    Ok(point_to_octets_g1(&G1Projective::hash_to::<
+        ExpandMsgXmd<Sha256>,
+    >(msg, &dst)))
+}
+
+/// Hash `msg` to a point of G2 with the `BLS12381G2_XMD:SHA-256_SSWU_RO_`
+/// suite of RFC 9380, and return the point in compressed form.
+/// `dst` must not be empty, a `dst` longer than 255 bytes is reduced as
+/// defined in RFC 9380 section 5.3.3.
+pub fn hash_to_g2(
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<[u8; OCTET_POINT_G2_LENGTH], Error> {
+    let dst = process_dst::<XmdSha256>(dst)?;
+    Ok(point_to_octets_g2(&G2Projective::hash_to::<
+        ExpandMsgXmd<Sha256>,
+    >(msg, &dst)))
+}
+
+/// Hash `msg` to `count` scalars of the BLS12-381 scalar field, encoded in
+/// big-endian, as `hash_to_field` of RFC 9380 with `L = 48` and the
+/// `expand_message` variant `E`.
+/// `dst` must not be empty, a `dst` longer than 255 bytes is reduced as
+/// defined in RFC 9380 section 5.3.3.
+pub fn hash_to_scalars<E>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<[u8; OCTET_SCALAR_LENGTH]>, Error>
+where
+    E: ExpandMessage,
+{
+    let len_in_bytes =
+        count
+            .checked_mul(XOF_NO_OF_BYTES)
+            .ok_or_else(|| Error::BadParams {
+                cause: "too many scalars requested".to_owned(),
+            })?;
+    let uniform_bytes = expand_message::<E>(msg, dst, len_in_bytes)?;
+
+    Ok(uniform_bytes
+        .chunks(XOF_NO_OF_BYTES)
+        .map(|tv| {
+            let mut buf = [0u8; 64];
+            buf[64 - XOF_NO_OF_BYTES..].copy_from_slice(tv);
+            Scalar::from_wide_bytes_be_mod_r(&buf).to_bytes_be()
+        })
+        .collect())
+}
+
+// Check a `dst` is valid for RFC 9380 hashing, and reduce it with the
+// `expand_message` variant `E` if it is longer than 255 bytes.
+fn process_dst<E>(dst: &[u8]) -> Result<Vec<u8>, Error>
+where
+    E: ExpandMessage,
+{
+    if dst.is_empty() {
+        return Err(Error::BadParams {
+            cause: "empty dst".to_owned(),
+        });
+    }
+    if dst.len() > MAX_DST_SIZE as usize {
+        return Ok(E::oversize_dst(dst));
+    }
+    Ok(dst.to_vec())
+}
+
+// `H("H2C-OVERSIZE-DST-" || dst, ceil(2 * k / 8))` for an extendable-output
+// function `H`, with the `k = 128` security level of BLS12-381.
+fn xof_oversize_dst<H>(dst: &[u8]) -> Vec<u8>
+where
+    H: Default + Update + ExtendableOutput,
+{
+    let mut reduced = vec![0u8; 32];
+    H::default()
+        .chain(OVERSIZE_DST_SALT)
+        .chain(dst)
+        .finalize_xof()
+        .read(&mut reduced);
+    reduced
+}
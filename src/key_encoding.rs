@@ -0,0 +1,549 @@
+use crate::Error;
+#[cfg(feature = "jwk")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "jwk")]
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+/// JWK curve name of keys with public keys in G1.
+#[cfg(feature = "jwk")]
+pub(crate) const JWK_CRV_BLS12381G1: &str = "BLS12381G1";
+
+/// JWK curve name of keys with public keys in G2.
+#[cfg(feature = "jwk")]
+pub(crate) const JWK_CRV_BLS12381G2: &str = "BLS12381G2";
+
+/// Multicodec code of `bls12_381-g1-pub`.
+pub(crate) const MULTICODEC_BLS12381G1_PUB: u64 = 0xea;
+
+/// Multicodec code of `bls12_381-g2-pub`.
+pub(crate) const MULTICODEC_BLS12381G2_PUB: u64 = 0xeb;
+
+/// Multicodec code of `bls12_381-g1-priv`.
+pub(crate) const MULTICODEC_BLS12381G1_PRIV: u64 = 0x1309;
+
+/// Multicodec code of `bls12_381-g2-priv`.
+pub(crate) const MULTICODEC_BLS12381G2_PRIV: u64 = 0x130a;
+
+// Multibase prefix of base58-btc.
+const MULTIBASE_BASE58_BTC: char = 'z';
+
+const DID_KEY_PREFIX: &str = "did:key:";
+
+// SubjectPublicKeyInfo and PKCS#8 encodings, with named curve OIDs chosen by
+// this crate.
+#[cfg(feature = "nonstandard_der")]
+pub(crate) mod der;
+
+/// JWK key type of a BLS12-381 key.
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+#[cfg(feature = "jwk")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwkKeyType {
+    /// `OKP`, the point is encoded in compressed form in `x`.
+    Okp,
+    /// `EC`, the point is encoded in uncompressed form, split in `x` and `y`.
+    Ec,
+}
+
+#[cfg(feature = "jwk")]
+impl JwkKeyType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Okp => "OKP",
+            Self::Ec => "EC",
+        }
+    }
+}
+
+/// A JSON Web Key of a BLS12-381 public or secret key, as defined in
+/// <https://datatracker.ietf.org/doc/draft-ietf-cose-bls-key-representations/>.
+/// All the members are base64url encoded without padding.
+#[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+#[cfg(feature = "jwk")]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    /// Key type, `OKP` or `EC`.
+    pub kty: String,
+    /// Curve, `BLS12381G1` or `BLS12381G2`.
+    pub crv: String,
+    /// Compressed point for `OKP`, first half of the uncompressed point for
+    /// `EC`.
+    pub x: String,
+    /// Second half of the uncompressed point for `EC`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// Big-endian secret key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+}
+
+#[cfg(feature = "jwk")]
+impl core::fmt::Debug for Jwk {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("d", &self.d.as_ref().map(|_| "****"))
+            .finish()
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        if let Some(d) = self.d.as_mut() {
+            d.zeroize();
+        }
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl Jwk {
+    /// Serialize the JWK as JSON.
+    pub fn to_json(&self) -> String {
+        // unwrap() is safe here since all the members are strings
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Deserialize a JWK from JSON.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|_| Error::Serde)
+    }
+
+    /// Compute the JWK thumbprint as defined in
+    /// <https://www.rfc-editor.org/rfc/rfc7638>, with SHA-256, base64url
+    /// encoded.
+    pub fn thumbprint(&self) -> Result<String, Error> {
+        if self.crv != JWK_CRV_BLS12381G1 && self.crv != JWK_CRV_BLS12381G2 {
+            return Err(Error::BadParams {
+                cause: format!("unsupported JWK curve `{}`", self.crv),
+            });
+        }
+        // The required members in lexicographic order, all the values are
+        // either fixed names or base64url strings which need no escaping.
+        let canonical = match (self.key_type()?, &self.y) {
+            (JwkKeyType::Okp, None) => format!(
+                r#"{{"crv":"{}","kty":"OKP","x":"{}"}}"#,
+                self.crv, self.x
+            ),
+            (JwkKeyType::Ec, Some(y)) => format!(
+                r#"{{"crv":"{}","kty":"EC","x":"{}","y":"{}"}}"#,
+                self.crv, self.x, y
+            ),
+            _ => return Err(Error::BadEncoding),
+        };
+        if !self.x.bytes().all(is_base64url) {
+            return Err(Error::BadEncoding);
+        }
+        if !self.y.iter().all(|y| y.bytes().all(is_base64url)) {
+            return Err(Error::BadEncoding);
+        }
+        Ok(base64url_encode(&Sha256::digest(canonical.as_bytes())))
+    }
+
+    pub(crate) fn key_type(&self) -> Result<JwkKeyType, Error> {
+        [JwkKeyType::Okp, JwkKeyType::Ec]
+            .into_iter()
+            .find(|kty| kty.as_str() == self.kty)
+            .ok_or_else(|| Error::BadParams {
+                cause: format!("unsupported JWK key type `{}`", self.kty),
+            })
+    }
+
+    // Encode a point given in compressed and uncompressed forms.
+    pub(crate) fn from_point(
+        kty: JwkKeyType,
+        crv: &str,
+        compressed: &[u8],
+        uncompressed: &[u8],
+    ) -> Self {
+        let (x, y) = match kty {
+            JwkKeyType::Okp => (base64url_encode(compressed), None),
+            JwkKeyType::Ec => {
+                let (x, y) = uncompressed.split_at(uncompressed.len() / 2);
+                (base64url_encode(x), Some(base64url_encode(y)))
+            }
+        };
+        Self {
+            kty: kty.as_str().to_owned(),
+            crv: crv.to_owned(),
+            x,
+            y,
+            d: None,
+        }
+    }
+
+    // Decode the point of a JWK of curve `crv`, in compressed form for `OKP`
+    // and in uncompressed form for `EC`.
+    pub(crate) fn to_point(
+        &self,
+        crv: &str,
+    ) -> Result<(JwkKeyType, Vec<u8>), Error> {
+        if self.crv != crv {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "unexpected JWK curve `{}`, expected `{crv}`",
+                    self.crv
+                ),
+            });
+        }
+        let kty = self.key_type()?;
+        let point = match (kty, &self.y) {
+            (JwkKeyType::Okp, None) => base64url_decode(&self.x)?,
+            (JwkKeyType::Ec, Some(y)) => {
+                [base64url_decode(&self.x)?, base64url_decode(y)?].concat()
+            }
+            _ => return Err(Error::BadEncoding),
+        };
+        Ok((kty, point))
+    }
+}
+
+#[cfg(feature = "jwk")]
+fn is_base64url(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'_'
+}
+
+pub(crate) fn base64url_encode(bytes: &[u8]) -> String {
+    base64::encode_config(bytes, base64::URL_SAFE_NO_PAD)
+}
+
+pub(crate) fn base64url_decode(value: &str) -> Result<Vec<u8>, Error> {
+    base64::decode_config(value, base64::URL_SAFE_NO_PAD)
+        .map_err(|_| Error::BadEncoding)
+}
+
+/// Encode `key` as a Multikey, i.e. the base58-btc multibase encoding of the
+/// unsigned varint of `codec` followed by `key`.
+pub(crate) fn multikey_encode(codec: u64, key: &[u8]) -> String {
+    let mut bytes = Vec::with_capacity(key.len() + 10);
+    let mut value = codec;
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+    bytes.extend_from_slice(key);
+
+    let multikey = format!(
+        "{MULTIBASE_BASE58_BTC}{}",
+        bs58::encode(&bytes).into_string()
+    );
+    bytes.zeroize();
+    multikey
+}
+
+/// Decode a Multikey of multicodec `codec`.
+pub(crate) fn multikey_decode(
+    codec: u64,
+    multikey: &str,
+) -> Result<Vec<u8>, Error> {
+    let encoded = multikey
+        .strip_prefix(MULTIBASE_BASE58_BTC)
+        .ok_or(Error::BadEncoding)?;
+    let mut bytes = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|_| Error::BadEncoding)?;
+
+    // Unsigned varint, multicodec codes fit in 9 bytes. The encoding must be
+    // minimal, i.e. a varint of more than one byte can't end with 0x00.
+    let mut value = 0u64;
+    let mut varint_len = 0;
+    for (i, b) in bytes.iter().take(9).enumerate() {
+        value |= u64::from(b & 0x7f) << (7 * i);
+        if b & 0x80 == 0 {
+            varint_len = i + 1;
+            break;
+        }
+    }
+    if varint_len == 0 || (varint_len > 1 && bytes[varint_len - 1] == 0) {
+        bytes.zeroize();
+        return Err(Error::BadEncoding);
+    }
+    if value != codec {
+        bytes.zeroize();
+        return Err(Error::BadParams {
+            cause: format!(
+                "unexpected multicodec {value:#x}, expected {codec:#x}"
+            ),
+        });
+    }
+    let key = bytes[varint_len..].to_vec();
+    bytes.zeroize();
+    Ok(key)
+}
+
+/// Extract the Multikey of a `did:key` DID or DID URL.
+pub(crate) fn did_key_to_multikey(did: &str) -> Result<&str, Error> {
+    let multikey =
+        did.strip_prefix(DID_KEY_PREFIX)
+            .ok_or_else(|| Error::BadParams {
+                cause: "not a `did:key` DID".to_owned(),
+            })?;
+    // Drop the fragment of a verification method DID URL,
+    // `did:key:<multikey>#<multikey>`.
+    Ok(multikey.split('#').next().unwrap_or(multikey))
+}
+
+/// Build the `did:key` DID of a public key Multikey.
+pub(crate) fn multikey_to_did_key(multikey: &str) -> String {
+    format!("{DID_KEY_PREFIX}{multikey}")
+}
+
+// Key encodings implementation, for the types generated by
+// `bbs_bls_key_pair_impl`.
+macro_rules! key_encoding_impl {
+    (
+        $octet_point_length:ident,
+        $point_projective_type:ident,
+        $point_affine_type:ident,
+        $jwk_crv:ident,
+        $multicodec_pub:ident,
+        $multicodec_priv:ident,
+        $der_curve_oid:ident
+    ) => {
+        impl PublicKey {
+            /// Encode the public key as a JWK of key type `kty`.
+            #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+            #[cfg(feature = "jwk")]
+            pub fn to_jwk(&self, kty: JwkKeyType) -> Jwk {
+                let point = self.0.to_affine();
+                Jwk::from_point(
+                    kty,
+                    $jwk_crv,
+                    &point.to_compressed(),
+                    &point.to_uncompressed(),
+                )
+            }
+
+            /// Decode a public key from a JWK, the point must be a valid
+            /// point of the prime order subgroup other than the identity.
+            #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+            #[cfg(feature = "jwk")]
+            pub fn from_jwk(jwk: &Jwk) -> Result<Self, Error> {
+                let point = match jwk.to_point($jwk_crv)? {
+                    (JwkKeyType::Okp, point) => {
+                        let bytes =
+                            vec_to_byte_array::<$octet_point_length>(&point)
+                                .map_err(|_| Error::BadEncoding)?;
+                        $point_affine_type::from_compressed(&bytes)
+                    }
+                    (JwkKeyType::Ec, point) => {
+                        let bytes = vec_to_byte_array::<
+                            { 2 * $octet_point_length },
+                        >(&point)
+                        .map_err(|_| Error::BadEncoding)?;
+                        $point_affine_type::from_uncompressed(&bytes)
+                    }
+                };
+                // `from_compressed` and `from_uncompressed` check the point
+                // is in the prime order subgroup
+                if point.is_none().unwrap_u8() == 1u8 {
+                    return Err(Error::BadEncoding);
+                }
+                Self::validated(Self($point_projective_type::from(
+                    &point.unwrap(),
+                )))
+            }
+
+            /// Encode the public key as a Multikey.
+            pub fn to_multikey(&self) -> String {
+                crate::key_encoding::multikey_encode(
+                    $multicodec_pub,
+                    &self.to_octets(),
+                )
+            }
+
+            /// Decode a public key from a Multikey, the point must be a
+            /// valid point of the prime order subgroup other than the
+            /// identity.
+            pub fn from_multikey(multikey: &str) -> Result<Self, Error> {
+                let bytes = crate::key_encoding::multikey_decode(
+                    $multicodec_pub,
+                    multikey,
+                )?;
+                Self::from_point_octets(&bytes)
+            }
+
+            /// Encode the public key as a `did:key` DID.
+            pub fn to_did_key(&self) -> String {
+                crate::key_encoding::multikey_to_did_key(&self.to_multikey())
+            }
+
+            /// Decode a public key from a `did:key` DID or DID URL.
+            pub fn from_did_key(did: &str) -> Result<Self, Error> {
+                Self::from_multikey(crate::key_encoding::did_key_to_multikey(
+                    did,
+                )?)
+            }
+
+            /// Encode the public key as a DER `SubjectPublicKeyInfo`, with
+            /// the point in compressed form.
+            ///
+            /// The named curve OID is not registered and is specific to this
+            /// crate: the encoding only round-trips with this crate.
+            #[cfg_attr(docsrs, doc(cfg(feature = "nonstandard_der")))]
+            #[cfg(feature = "nonstandard_der")]
+            pub fn to_spki_der(&self) -> Vec<u8> {
+                crate::key_encoding::der::spki_encode(
+                    $der_curve_oid,
+                    &self.to_octets(),
+                )
+            }
+
+            /// Decode a public key from a DER `SubjectPublicKeyInfo`, the
+            /// point must be a valid point of the prime order subgroup other
+            /// than the identity.
+            ///
+            /// The named curve OID is not registered and is specific to this
+            /// crate: the encoding only round-trips with this crate.
+            #[cfg_attr(docsrs, doc(cfg(feature = "nonstandard_der")))]
+            #[cfg(feature = "nonstandard_der")]
+            pub fn from_spki_der(der: &[u8]) -> Result<Self, Error> {
+                let bytes =
+                    crate::key_encoding::der::spki_decode($der_curve_oid, der)?;
+                Self::from_point_octets(&bytes)
+            }
+
+            // Decode a compressed point, which must be a valid point of the
+            // prime order subgroup other than the identity.
+            fn from_point_octets(bytes: &[u8]) -> Result<Self, Error> {
+                let bytes: [u8; $octet_point_length] =
+                    bytes.try_into().map_err(|_| Error::BadEncoding)?;
+                Self::validated(Self::from_octets(&bytes)?)
+            }
+
+            fn validated(self) -> Result<Self, Error> {
+                if self.is_valid().unwrap_u8() == 0u8 {
+                    return Err(Error::InvalidPublicKey);
+                }
+                Ok(self)
+            }
+        }
+
+        impl SecretKey {
+            /// Encode the secret key, and its public key, as a JWK of key
+            /// type `kty`.
+            #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+            #[cfg(feature = "jwk")]
+            pub fn to_jwk(&self, kty: JwkKeyType) -> Jwk {
+                let mut jwk = PublicKey::from(self).to_jwk(kty);
+                let mut bytes = self.to_bytes();
+                jwk.d = Some(crate::key_encoding::base64url_encode(&bytes));
+                bytes.zeroize();
+                jwk
+            }
+
+            /// Decode a secret key from a JWK, the public key members of the
+            /// JWK must match the secret key.
+            #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+            #[cfg(feature = "jwk")]
+            pub fn from_jwk(jwk: &Jwk) -> Result<Self, Error> {
+                let mut bytes = crate::key_encoding::base64url_decode(
+                    jwk.d.as_ref().ok_or(Error::BadEncoding)?,
+                )?;
+                let secret_key =
+                    vec_to_byte_array::<{ Self::SIZE_BYTES }>(&bytes)
+                        .map_err(|_| Error::BadEncoding)
+                        .and_then(|mut result| {
+                            let secret_key = Self::from_bytes(&result);
+                            result.zeroize();
+                            secret_key
+                        });
+                bytes.zeroize();
+                let secret_key = secret_key?;
+
+                if PublicKey::from_jwk(jwk)? != PublicKey::from(&secret_key) {
+                    return Err(Error::InvalidSecretKey);
+                }
+                Ok(secret_key)
+            }
+
+            /// Encode the secret key as a Multikey.
+            pub fn to_multikey(&self) -> String {
+                let mut bytes = self.to_bytes();
+                let multikey = crate::key_encoding::multikey_encode(
+                    $multicodec_priv,
+                    &bytes,
+                );
+                bytes.zeroize();
+                multikey
+            }
+
+            /// Decode a secret key from a Multikey.
+            pub fn from_multikey(multikey: &str) -> Result<Self, Error> {
+                let mut bytes = crate::key_encoding::multikey_decode(
+                    $multicodec_priv,
+                    multikey,
+                )?;
+                let secret_key =
+                    vec_to_byte_array::<{ Self::SIZE_BYTES }>(&bytes)
+                        .map_err(|_| Error::BadEncoding)
+                        .and_then(|mut result| {
+                            let secret_key = Self::from_bytes(&result);
+                            result.zeroize();
+                            secret_key
+                        });
+                bytes.zeroize();
+                secret_key
+            }
+
+            /// Encode the secret key, and its public key, as a DER PKCS#8
+            /// `PrivateKeyInfo`.
+            ///
+            /// The named curve OID is not registered and is specific to this
+            /// crate: the encoding only round-trips with this crate.
+            #[cfg_attr(docsrs, doc(cfg(feature = "nonstandard_der")))]
+            #[cfg(feature = "nonstandard_der")]
+            pub fn to_pkcs8_der(&self) -> Vec<u8> {
+                let mut bytes = self.to_bytes();
+                let der = crate::key_encoding::der::pkcs8_encode(
+                    $der_curve_oid,
+                    &bytes,
+                    &PublicKey::from(self).to_octets(),
+                );
+                bytes.zeroize();
+                der
+            }
+
+            /// Decode a secret key from a DER PKCS#8 `PrivateKeyInfo`, the
+            /// public key, if present, must match the secret key.
+            ///
+            /// The named curve OID is not registered and is specific to this
+            /// crate: the encoding only round-trips with this crate.
+            #[cfg_attr(docsrs, doc(cfg(feature = "nonstandard_der")))]
+            #[cfg(feature = "nonstandard_der")]
+            pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, Error> {
+                let (mut bytes, point) =
+                    crate::key_encoding::der::pkcs8_decode(
+                        $der_curve_oid,
+                        der,
+                    )?;
+                let secret_key =
+                    vec_to_byte_array::<{ Self::SIZE_BYTES }>(&bytes)
+                        .map_err(|_| Error::BadEncoding)
+                        .and_then(|mut result| {
+                            let secret_key = Self::from_bytes(&result);
+                            result.zeroize();
+                            secret_key
+                        });
+                bytes.zeroize();
+                let secret_key = secret_key?;
+
+                if let Some(point) = point {
+                    if PublicKey::from_point_octets(&point)?
+                        != PublicKey::from(&secret_key)
+                    {
+                        return Err(Error::InvalidSecretKey);
+                    }
+                }
+                Ok(secret_key)
+            }
+        }
+    };
+}
+
+pub(crate) use key_encoding_impl;
@@ -0,0 +1,207 @@
+use crate::Error;
+use zeroize::Zeroize;
+
+/// DER encoded OID of `id-ecPublicKey`, `1.2.840.10045.2.1`, the algorithm
+/// of the SubjectPublicKeyInfo and PKCS#8 encodings.
+const DER_OID_EC_PUBLIC_KEY: &[u8] =
+    &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+/// DER encoded named curve OID of keys with public keys in G1,
+/// `2.25.50989516091352353630457819521503935986`. No OID is registered for
+/// BLS12-381 keys, this is a UUID based OID as defined in ITU-T X.667 from a
+/// UUID generated for this crate, which other implementations do not know.
+pub(crate) const DER_OID_BLS12381G1: &[u8] = &[
+    0x69, 0xcc, 0xdc, 0x9c, 0xcf, 0xa4, 0x93, 0x9a, 0xb4, 0xc5, 0xaf, 0xae,
+    0xda, 0xf6, 0xaa, 0x89, 0xcc, 0xfb, 0x72,
+];
+
+/// DER encoded named curve OID of keys with public keys in G2,
+/// `2.25.114815132368137896659891972159464246289`, a UUID based OID as
+/// defined in ITU-T X.667 from a UUID generated for this crate.
+pub(crate) const DER_OID_BLS12381G2: &[u8] = &[
+    0x69, 0x81, 0xac, 0xe0, 0xcb, 0xc5, 0x81, 0xc4, 0xca, 0x8a, 0xcd, 0x80,
+    0xd1, 0xf2, 0xcd, 0xec, 0x8e, 0xce, 0x98, 0x11,
+];
+
+// DER tags.
+const DER_INTEGER: u8 = 0x02;
+const DER_BIT_STRING: u8 = 0x03;
+const DER_OCTET_STRING: u8 = 0x04;
+const DER_OID: u8 = 0x06;
+const DER_SEQUENCE: u8 = 0x30;
+const DER_CONTEXT_1: u8 = 0xa1;
+
+// Version of the `PrivateKeyInfo` of RFC 5208.
+const PKCS8_VERSION: u8 = 0;
+
+// Version of the `ECPrivateKey` of RFC 5915.
+const EC_PRIVATE_KEY_VERSION: u8 = 1;
+
+// Encode a DER TLV, the content is at most 65535 bytes long.
+fn der_encode(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(content.len() + 4);
+    bytes.push(tag);
+    match content.len() {
+        len @ 0..=0x7f => bytes.push(len as u8),
+        len @ 0x80..=0xff => bytes.extend_from_slice(&[0x81, len as u8]),
+        len => bytes.extend_from_slice(&[0x82, (len >> 8) as u8, len as u8]),
+    }
+    bytes.extend_from_slice(content);
+    bytes
+}
+
+// Decode the DER TLV of tag `tag` at the start of `bytes`, and return its
+// content and the remaining bytes. Only the minimal definite length forms
+// of DER are accepted.
+fn der_decode(tag: u8, bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (len, header_len) = match bytes {
+        [t, len, ..] if *t == tag && *len < 0x80 => (usize::from(*len), 2),
+        [t, 0x81, len, ..] if *t == tag && *len >= 0x80 => {
+            (usize::from(*len), 3)
+        }
+        [t, 0x82, len_hi, len_lo, ..] if *t == tag && *len_hi != 0 => {
+            (usize::from(*len_hi) << 8 | usize::from(*len_lo), 4)
+        }
+        _ => return Err(Error::BadEncoding),
+    };
+    let remaining = &bytes[header_len..];
+    if remaining.len() < len {
+        return Err(Error::BadEncoding);
+    }
+    Ok(remaining.split_at(len))
+}
+
+// Decode a DER TLV of tag `tag` which must span all of `bytes`.
+fn der_decode_all(tag: u8, bytes: &[u8]) -> Result<&[u8], Error> {
+    match der_decode(tag, bytes)? {
+        (content, []) => Ok(content),
+        _ => Err(Error::BadEncoding),
+    }
+}
+
+// The `AlgorithmIdentifier` of an EC key of named curve `curve_oid`.
+fn der_algorithm_identifier(curve_oid: &[u8]) -> Vec<u8> {
+    der_encode(
+        DER_SEQUENCE,
+        &[
+            der_encode(DER_OID, DER_OID_EC_PUBLIC_KEY),
+            der_encode(DER_OID, curve_oid),
+        ]
+        .concat(),
+    )
+}
+
+// Decode an `AlgorithmIdentifier`, which must be the one of an EC key of
+// named curve `curve_oid`, and return the remaining bytes.
+fn der_decode_algorithm_identifier<'a>(
+    curve_oid: &[u8],
+    bytes: &'a [u8],
+) -> Result<&'a [u8], Error> {
+    let (algorithm, remaining) = der_decode(DER_SEQUENCE, bytes)?;
+    if algorithm != &der_algorithm_identifier(curve_oid)[2..] {
+        return Err(Error::BadParams {
+            cause: "unexpected key algorithm or curve".to_owned(),
+        });
+    }
+    Ok(remaining)
+}
+
+/// Encode `point` as a DER `SubjectPublicKeyInfo` as defined in
+/// <https://www.rfc-editor.org/rfc/rfc5480>, of named curve `curve_oid`.
+pub(crate) fn spki_encode(curve_oid: &[u8], point: &[u8]) -> Vec<u8> {
+    der_encode(
+        DER_SEQUENCE,
+        &[
+            der_algorithm_identifier(curve_oid),
+            der_encode(DER_BIT_STRING, &[&[0u8], point].concat()),
+        ]
+        .concat(),
+    )
+}
+
+/// Decode the point of a DER `SubjectPublicKeyInfo` of named curve
+/// `curve_oid`.
+pub(crate) fn spki_decode(
+    curve_oid: &[u8],
+    der: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let spki = der_decode_all(DER_SEQUENCE, der)?;
+    let remaining = der_decode_algorithm_identifier(curve_oid, spki)?;
+    match der_decode_all(DER_BIT_STRING, remaining)? {
+        [0u8, point @ ..] => Ok(point.to_vec()),
+        _ => Err(Error::BadEncoding),
+    }
+}
+
+/// Encode the big-endian secret key `key` and its public key `point` as a
+/// DER PKCS#8 `PrivateKeyInfo` as defined in
+/// <https://www.rfc-editor.org/rfc/rfc5208>, of named curve `curve_oid`, with
+/// the `ECPrivateKey` of <https://www.rfc-editor.org/rfc/rfc5915> as private
+/// key.
+pub(crate) fn pkcs8_encode(
+    curve_oid: &[u8],
+    key: &[u8],
+    point: &[u8],
+) -> Vec<u8> {
+    let mut octet_string = der_encode(DER_OCTET_STRING, key);
+    let mut ec_private_key = der_encode(
+        DER_SEQUENCE,
+        &[
+            der_encode(DER_INTEGER, &[EC_PRIVATE_KEY_VERSION]),
+            octet_string.clone(),
+            der_encode(
+                DER_CONTEXT_1,
+                &der_encode(DER_BIT_STRING, &[&[0u8], point].concat()),
+            ),
+        ]
+        .concat(),
+    );
+    let mut private_key = der_encode(DER_OCTET_STRING, &ec_private_key);
+    let mut content = [
+        der_encode(DER_INTEGER, &[PKCS8_VERSION]),
+        der_algorithm_identifier(curve_oid),
+        private_key.clone(),
+    ]
+    .concat();
+    let der = der_encode(DER_SEQUENCE, &content);
+    octet_string.zeroize();
+    ec_private_key.zeroize();
+    private_key.zeroize();
+    content.zeroize();
+    der
+}
+
+/// Decode the big-endian secret key, and the public key point if present,
+/// of a DER PKCS#8 `PrivateKeyInfo` of named curve `curve_oid`.
+pub(crate) fn pkcs8_decode(
+    curve_oid: &[u8],
+    der: &[u8],
+) -> Result<(Vec<u8>, Option<Vec<u8>>), Error> {
+    let private_key_info = der_decode_all(DER_SEQUENCE, der)?;
+    let (version, remaining) = der_decode(DER_INTEGER, private_key_info)?;
+    if version != [PKCS8_VERSION] {
+        return Err(Error::BadEncoding);
+    }
+    let remaining = der_decode_algorithm_identifier(curve_oid, remaining)?;
+    let ec_private_key = der_decode_all(
+        DER_SEQUENCE,
+        der_decode_all(DER_OCTET_STRING, remaining)?,
+    )?;
+
+    let (version, remaining) = der_decode(DER_INTEGER, ec_private_key)?;
+    if version != [EC_PRIVATE_KEY_VERSION] {
+        return Err(Error::BadEncoding);
+    }
+    let (key, remaining) = der_decode(DER_OCTET_STRING, remaining)?;
+    let point = match remaining {
+        [] => None,
+        _ => match der_decode_all(
+            DER_BIT_STRING,
+            der_decode_all(DER_CONTEXT_1, remaining)?,
+        )? {
+            [0u8, point @ ..] => Some(point.to_vec()),
+            _ => return Err(Error::BadEncoding),
+        },
+    };
+    Ok((key.to_vec(), point))
+}
@@ -0,0 +1,442 @@
+use crate::{curves::bls12_381::OCTET_SCALAR_LENGTH, Error};
+use aes::Aes128;
+use ctr::{
+    cipher::{generic_array::GenericArray, NewCipher, StreamCipher},
+    Ctr128BE,
+};
+use hmac::Hmac;
+use rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+
+// Version of the keystore format defined by EIP-2335.
+const KEYSTORE_VERSION: u32 = 4;
+
+// Length of the key derived from the password.
+const DECRYPTION_KEY_LENGTH: usize = 32;
+
+// Length of the AES-128 key, the first half of the decryption key.
+const CIPHER_KEY_LENGTH: usize = 16;
+
+const SALT_LENGTH: usize = 32;
+
+const IV_LENGTH: usize = 16;
+
+const KDF_SCRYPT: &str = "scrypt";
+
+const KDF_PBKDF2: &str = "pbkdf2";
+
+const PBKDF2_PRF: &str = "hmac-sha256";
+
+const CHECKSUM_SHA_256: &str = "sha256";
+
+const CIPHER_AES_128_CTR: &str = "aes-128-ctr";
+
+/// Password based key derivation function of a keystore.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeystoreKdf {
+    /// scrypt with cost parameter `n`, block size `r` and parallelization
+    /// `p`.
+    Scrypt {
+        /// CPU/memory cost, a power of 2.
+        n: u32,
+        /// Block size.
+        r: u32,
+        /// Parallelization.
+        p: u32,
+    },
+    /// PBKDF2 with HMAC-SHA256 and `c` iterations.
+    Pbkdf2 {
+        /// Number of iterations.
+        c: u32,
+    },
+}
+
+impl KeystoreKdf {
+    /// scrypt with the parameters recommended by EIP-2335.
+    pub const SCRYPT: Self = Self::Scrypt {
+        n: 262144,
+        r: 8,
+        p: 1,
+    };
+
+    /// PBKDF2 with the parameters recommended by EIP-2335.
+    pub const PBKDF2: Self = Self::Pbkdf2 { c: 262144 };
+}
+
+impl Default for KeystoreKdf {
+    fn default() -> Self {
+        Self::SCRYPT
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreModule<P> {
+    function: String,
+    params: P,
+    message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum KdfParams {
+    Scrypt {
+        dklen: usize,
+        n: u32,
+        p: u32,
+        r: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        dklen: usize,
+        c: u32,
+        prf: String,
+        salt: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct ChecksumParams {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    kdf: KeystoreModule<KdfParams>,
+    checksum: KeystoreModule<ChecksumParams>,
+    cipher: KeystoreModule<CipherParams>,
+}
+
+/// A password encrypted secret key, in the JSON keystore format defined by
+/// <https://eips.ethereum.org/EIPS/eip-2335>.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    crypto: KeystoreCrypto,
+    /// Free text description of the key.
+    #[serde(default)]
+    pub description: String,
+    /// Hex encoded public key of the secret key.
+    pub pubkey: String,
+    /// EIP-2334 derivation path of the secret key, empty if the key was not
+    /// derived from a tree.
+    pub path: String,
+    /// UUID of the keystore.
+    pub uuid: String,
+    /// Version of the keystore format, always 4.
+    pub version: u32,
+}
+
+impl Keystore {
+    /// Serialize the keystore as JSON.
+    pub fn to_json(&self) -> String {
+        // unwrap() is safe here since all the members are strings and
+        // integers
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// Deserialize a keystore from JSON.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(|_| Error::Serde)
+    }
+
+    // Encrypt `secret` with `password`.
+    pub(crate) fn encrypt<R>(
+        secret: &[u8; OCTET_SCALAR_LENGTH],
+        pubkey: &[u8],
+        password: &str,
+        kdf: KeystoreKdf,
+        path: &str,
+        rng: &mut R,
+    ) -> Result<Self, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut iv = [0u8; IV_LENGTH];
+        let mut uuid = [0u8; 16];
+        rng.try_fill_bytes(&mut salt)
+            .and_then(|_| rng.try_fill_bytes(&mut iv))
+            .and_then(|_| rng.try_fill_bytes(&mut uuid))
+            .map_err(|_| Error::CryptoOps {
+                cause: "random number generation failed".to_owned(),
+            })?;
+
+        let kdf_params = match kdf {
+            KeystoreKdf::Scrypt { n, r, p } => KdfParams::Scrypt {
+                dklen: DECRYPTION_KEY_LENGTH,
+                n,
+                p,
+                r,
+                salt: hex::encode(salt),
+            },
+            KeystoreKdf::Pbkdf2 { c } => KdfParams::Pbkdf2 {
+                dklen: DECRYPTION_KEY_LENGTH,
+                c,
+                prf: PBKDF2_PRF.to_owned(),
+                salt: hex::encode(salt),
+            },
+        };
+        let mut decryption_key = derive_decryption_key(password, &kdf_params)?;
+
+        let mut cipher_message = *secret;
+        apply_aes_128_ctr(&decryption_key, &iv, &mut cipher_message);
+        let checksum = compute_checksum(&decryption_key, &cipher_message);
+        decryption_key.zeroize();
+
+        Ok(Self {
+            crypto: KeystoreCrypto {
+                kdf: KeystoreModule {
+                    function: match kdf {
+                        KeystoreKdf::Scrypt { .. } => KDF_SCRYPT,
+                        KeystoreKdf::Pbkdf2 { .. } => KDF_PBKDF2,
+                    }
+                    .to_owned(),
+                    params: kdf_params,
+                    message: String::new(),
+                },
+                checksum: KeystoreModule {
+                    function: CHECKSUM_SHA_256.to_owned(),
+                    params: ChecksumParams {},
+                    message: hex::encode(checksum),
+                },
+                cipher: KeystoreModule {
+                    function: CIPHER_AES_128_CTR.to_owned(),
+                    params: CipherParams {
+                        iv: hex::encode(iv),
+                    },
+                    message: hex::encode(cipher_message),
+                },
+            },
+            description: String::new(),
+            pubkey: hex::encode(pubkey),
+            path: path.to_owned(),
+            uuid: format_uuid_v4(uuid),
+            version: KEYSTORE_VERSION,
+        })
+    }
+
+    // Decrypt the secret with `password`, fails if the password is not the
+    // one the keystore was encrypted with.
+    pub(crate) fn decrypt(
+        &self,
+        password: &str,
+    ) -> Result<[u8; OCTET_SCALAR_LENGTH], Error> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(bad_keystore("unsupported version"));
+        }
+        let crypto = &self.crypto;
+        let expected_kdf = match crypto.kdf.params {
+            KdfParams::Scrypt { .. } => KDF_SCRYPT,
+            KdfParams::Pbkdf2 { .. } => KDF_PBKDF2,
+        };
+        if crypto.kdf.function != expected_kdf {
+            return Err(bad_keystore("unsupported KDF"));
+        }
+        if crypto.checksum.function != CHECKSUM_SHA_256 {
+            return Err(bad_keystore("unsupported checksum function"));
+        }
+        if crypto.cipher.function != CIPHER_AES_128_CTR {
+            return Err(bad_keystore("unsupported cipher"));
+        }
+        let checksum: [u8; 32] = decode_hex(&crypto.checksum.message)?;
+        let iv: [u8; IV_LENGTH] = decode_hex(&crypto.cipher.params.iv)?;
+        let cipher_message: [u8; OCTET_SCALAR_LENGTH] =
+            decode_hex(&crypto.cipher.message)?;
+
+        let mut decryption_key =
+            derive_decryption_key(password, &crypto.kdf.params)?;
+
+        // Not constant time, the checksum does not depend on the secret.
+        if compute_checksum(&decryption_key, &cipher_message) != checksum {
+            decryption_key.zeroize();
+            return Err(Error::CryptoOps {
+                cause: "keystore checksum mismatch, invalid password"
+                    .to_owned(),
+            });
+        }
+
+        let mut secret = cipher_message;
+        apply_aes_128_ctr(&decryption_key, &iv, &mut secret);
+        decryption_key.zeroize();
+        Ok(secret)
+    }
+}
+
+fn bad_keystore(cause: &str) -> Error {
+    Error::BadParams {
+        cause: format!("invalid keystore: {cause}"),
+    }
+}
+
+fn decode_hex<const N: usize>(value: &str) -> Result<[u8; N], Error> {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(value, &mut bytes).map_err(|_| Error::BadEncoding)?;
+    Ok(bytes)
+}
+
+// Process the password as specified by EIP-2335: NFKD normalization, then
+// removal of the C0, C1 and `Delete` control codes.
+fn process_password(password: &str) -> Vec<u8> {
+    password
+        .nfkd()
+        .filter(|c| !c.is_control())
+        .collect::<String>()
+        .into_bytes()
+}
+
+fn derive_decryption_key(
+    password: &str,
+    kdf_params: &KdfParams,
+) -> Result<[u8; DECRYPTION_KEY_LENGTH], Error> {
+    let mut password = process_password(password);
+    let mut decryption_key = [0u8; DECRYPTION_KEY_LENGTH];
+
+    let result = match kdf_params {
+        KdfParams::Scrypt {
+            dklen,
+            n,
+            p,
+            r,
+            salt,
+        } => {
+            let salt = hex::decode(salt).map_err(|_| Error::BadEncoding)?;
+            if *dklen != DECRYPTION_KEY_LENGTH || !n.is_power_of_two() || *n < 2
+            {
+                return Err(bad_keystore("invalid scrypt parameters"));
+            }
+            scrypt::Params::new(n.trailing_zeros() as u8, *r, *p)
+                .map_err(|_| bad_keystore("invalid scrypt parameters"))
+                .and_then(|params| {
+                    scrypt::scrypt(
+                        &password,
+                        &salt,
+                        &params,
+                        &mut decryption_key,
+                    )
+                    .map_err(|_| bad_keystore("invalid scrypt parameters"))
+                })
+        }
+        KdfParams::Pbkdf2 {
+            dklen,
+            c,
+            prf,
+            salt,
+        } => {
+            let salt = hex::decode(salt).map_err(|_| Error::BadEncoding)?;
+            if *dklen != DECRYPTION_KEY_LENGTH || prf != PBKDF2_PRF || *c == 0 {
+                return Err(bad_keystore("invalid pbkdf2 parameters"));
+            }
+            pbkdf2::pbkdf2::<Hmac<Sha256>>(
+                &password,
+                &salt,
+                *c,
+                &mut decryption_key,
+            );
+            Ok(())
+        }
+    };
+    password.zeroize();
+    result.map(|_| decryption_key)
+}
+
+fn compute_checksum(
+    decryption_key: &[u8; DECRYPTION_KEY_LENGTH],
+    cipher_message: &[u8],
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(&decryption_key[CIPHER_KEY_LENGTH..]);
+    hasher.update(cipher_message);
+    let mut checksum = [0u8; 32];
+    checksum.copy_from_slice(&hasher.finalize());
+    checksum
+}
+
+fn apply_aes_128_ctr(
+    decryption_key: &[u8; DECRYPTION_KEY_LENGTH],
+    iv: &[u8; IV_LENGTH],
+    message: &mut [u8],
+) {
+    let mut cipher = Ctr128BE::<Aes128>::new(
+        GenericArray::from_slice(&decryption_key[..CIPHER_KEY_LENGTH]),
+        GenericArray::from_slice(iv),
+    );
+    cipher.apply_keystream(message);
+}
+
+fn format_uuid_v4(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+// Keystore implementation, for the `SecretKey` types generated by
+// `bbs_bls_key_pair_impl`.
+macro_rules! keystore_impl {
+    () => {
+        impl SecretKey {
+            /// Encrypt the secret key with `password` into an EIP-2335
+            /// keystore, `path` is the EIP-2334 derivation path of the key,
+            /// if any.
+            pub fn to_keystore<R>(
+                &self,
+                password: &str,
+                kdf: KeystoreKdf,
+                path: &str,
+                rng: &mut R,
+            ) -> Result<Keystore, Error>
+            where
+                R: RngCore + CryptoRng,
+            {
+                let mut bytes = self.to_bytes();
+                let keystore = Keystore::encrypt(
+                    &bytes,
+                    &PublicKey::from(self).to_octets(),
+                    password,
+                    kdf,
+                    path,
+                    rng,
+                );
+                bytes.zeroize();
+                keystore
+            }
+
+            /// Decrypt a secret key from an EIP-2335 keystore, fails if
+            /// `password` is wrong or if the public key of the keystore, when
+            /// present, does not match the secret key.
+            pub fn from_keystore(
+                keystore: &Keystore,
+                password: &str,
+            ) -> Result<Self, Error> {
+                let mut bytes = keystore.decrypt(password)?;
+                let secret_key = Self::from_bytes(&bytes);
+                bytes.zeroize();
+                let secret_key = secret_key?;
+
+                let pubkey = PublicKey::from(&secret_key).to_octets();
+                if !keystore.pubkey.is_empty()
+                    && !keystore
+                        .pubkey
+                        .eq_ignore_ascii_case(&hex::encode(pubkey))
+                {
+                    return Err(Error::InvalidSecretKey);
+                }
+                Ok(secret_key)
+            }
+        }
+    };
+}
+
+pub(crate) use keystore_impl;
@@ -47,6 +47,27 @@ mod curves;
 /// Supported schemes from pairing crypto
 mod schemes;
 
+/// Hashing to the BLS12-381 curve and scalar field as defined in RFC 9380.
+pub mod hash;
+
+/// Interoperable encodings of BLS12-381 keys: Multikey, `did:key` and, with
+/// the `jwk` feature, JWK. The `nonstandard_der` feature adds
+/// SubjectPublicKeyInfo and PKCS#8 encodings which only round-trip with this
+/// crate.
+pub mod key_encoding;
+
+/// Password encrypted keystores of secret keys as defined in EIP-2335.
+#[cfg_attr(docsrs, doc(cfg(feature = "keystore")))]
+#[cfg(feature = "keystore")]
+pub mod keystore;
+
+/// Self-describing versioned encoding of signatures and proofs.
+pub mod envelope;
+
+/// Hex serde representation of octets, e.g. of the signature and proof types,
+/// to be used as `#[serde(with = "pairing_crypto::serde_hex")]`.
+pub mod serde_hex;
+
 pub use error::Error;
 
 /// Supported constructs for the BLS12-381 curve.
@@ -6,3 +6,21 @@ pub mod bbs_bound;
 
 /// The BLS signature scheme
 pub mod bls;
+
+/// Distributed key generation for BLS and BBS keys.
+pub mod dkg;
+
+/// The BBS04 short group signature scheme
+pub mod group_sig;
+
+/// The Boneh-Franklin identity-based encryption scheme
+pub mod ibe;
+
+/// KZG polynomial commitments
+pub mod kzg;
+
+/// The AFGH proxy re-encryption scheme
+pub mod pre;
+
+/// The Pointcheval-Sanders signature scheme
+pub mod ps;
@@ -4,6 +4,7 @@ pub use crate::schemes::bbs::api::dtos::{
     BbsProofGenRevealMessageRequest,
     BbsProofVerifyRequest,
     BbsSignRequest,
+    BbsSignWithSignerRequest,
     BbsVerifyRequest,
 };
 
@@ -1,6 +1,7 @@
 pub mod dtos;
 #[cfg(feature = "__private_bbs_fixtures_generator_api")]
 pub mod generators;
+pub(crate) mod key_pop;
 pub mod proof;
 pub mod signature;
 
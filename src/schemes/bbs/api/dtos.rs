@@ -28,6 +28,25 @@ impl<'a, T: AsRef<[u8]>> Default for BbsSignRequest<'a, T> {
     }
 }
 
+/// Sign request for a BBS signature, where the secret key dependent steps are
+/// performed by a `BbsSigner`.
+#[derive(Clone, Debug)]
+pub struct BbsSignWithSignerRequest<'a, T: AsRef<[u8]>> {
+    /// Header containing context and application specific information
+    pub header: Option<T>,
+    /// Vector of messages to sign
+    pub messages: Option<&'a [T]>,
+}
+
+impl<'a, T: AsRef<[u8]>> Default for BbsSignWithSignerRequest<'a, T> {
+    fn default() -> Self {
+        Self {
+            header: Default::default(),
+            messages: Default::default(),
+        }
+    }
+}
+
 /// Verify request for a BBS signature.
 #[derive(Clone, Debug)]
 pub struct BbsVerifyRequest<'a, T: AsRef<[u8]>> {
@@ -0,0 +1,34 @@
+use crate::{
+    bbs::{
+        ciphersuites::{
+            bls12_381::BBS_BLS12381G1_KEY_POP_LENGTH,
+            BbsCiphersuiteParameters,
+        },
+        core::{
+            key_pair::{PublicKey, SecretKey},
+            key_pop::KeyPop,
+        },
+    },
+    error::Error,
+};
+
+// Compute a proof of possession of a BBS secret key.
+pub(crate) fn pop_prove<C>(
+    sk: &SecretKey,
+) -> Result<[u8; BBS_BLS12381G1_KEY_POP_LENGTH], Error>
+where
+    C: BbsCiphersuiteParameters,
+{
+    Ok(KeyPop::new::<C>(sk)?.to_octets())
+}
+
+// Verify a proof of possession of the secret key of a BBS public key.
+pub(crate) fn pop_verify<C>(
+    pk: &PublicKey,
+    proof: &[u8; BBS_BLS12381G1_KEY_POP_LENGTH],
+) -> Result<bool, Error>
+where
+    C: BbsCiphersuiteParameters,
+{
+    KeyPop::from_octets(proof)?.verify::<C>(pk)
+}
@@ -4,16 +4,17 @@ use super::{
 };
 use crate::{
     bbs::{
-        ciphersuites::BbsCiphersuiteParameters,
+        ciphersuites::{BbsCiphersuiteParameters, BbsDraftVersion},
         core::{
             generator::memory_cached_generator::MemoryCachedGenerators,
+            proof_draft_06::Proof as ProofDraft06,
             types::ProofMessage,
         },
     },
     error::Error,
     schemes::bbs::core::{
         key_pair::PublicKey,
-        proof::Proof,
+        proof::{BbsProofInspection, Proof},
         signature::Signature,
         types::Message,
     },
@@ -33,6 +34,30 @@ pub fn get_proof_size(num_undisclosed_messages: usize) -> usize {
     Proof::get_size(num_undisclosed_messages)
 }
 
+/// Return the size of a `draft-irtf-cfrg-bbs-signatures-06` proof in bytes for
+/// `num_undisclosed_messages`.
+pub fn get_proof_size_draft_06(num_undisclosed_messages: usize) -> usize {
+    ProofDraft06::get_size(num_undisclosed_messages)
+}
+
+/// Parse a BBS signature proof of knowledge, without verifying it, and return
+/// its components.
+/// Malformed proofs are reported with `Error::MalformedProofComponent`.
+pub fn inspect_proof<T: AsRef<[u8]>>(
+    proof: T,
+) -> Result<BbsProofInspection, Error> {
+    Proof::inspect(proof)
+}
+
+/// Parse a `draft-irtf-cfrg-bbs-signatures-06` BBS signature proof of
+/// knowledge, without verifying it, and return its components.
+/// Malformed proofs are reported with `Error::MalformedProofComponent`.
+pub fn inspect_proof_draft_06<T: AsRef<[u8]>>(
+    proof: T,
+) -> Result<BbsProofInspection, Error> {
+    ProofDraft06::inspect(proof)
+}
+
 // helper function for parsing a BBS Proof Generation Request
 fn _parse_request_helper<T, C>(
     request: &BbsProofGenRequest<'_, T>,
@@ -90,16 +115,114 @@ where
         _parse_request_helper::<T, C>(request)?;
 
     // Generate the proof
-    let proof = Proof::new::<_, _, C>(
-        &pk,
-        &signature,
-        request.header.as_ref(),
-        request.presentation_header.as_ref(),
-        &generators,
-        &proof_messages,
-    )?;
+    match C::DRAFT_VERSION {
+        BbsDraftVersion::Legacy => Ok(Proof::new::<_, _, C>(
+            &pk,
+            &signature,
+            request.header.as_ref(),
+            request.presentation_header.as_ref(),
+            &generators,
+            &proof_messages,
+        )?
+        .to_octets()),
+        BbsDraftVersion::Draft06 => Ok(ProofDraft06::new::<_, _, C>(
+            &pk,
+            &signature,
+            request.header.as_ref(),
+            request.presentation_header.as_ref(),
+            &generators,
+            &proof_messages,
+        )?
+        .to_octets()),
+    }
+}
+
+// The operations `proof_verify` needs from a proof layout.
+trait VerifiableProof: Sized {
+    fn from_octets(bytes: &[u8]) -> Result<Self, Error>;
+
+    fn undisclosed_message_count(&self) -> usize;
 
-    Ok(proof.to_octets())
+    fn verify<T, C>(
+        &self,
+        pk: &PublicKey,
+        header: Option<T>,
+        ph: Option<T>,
+        generators: &MemoryCachedGenerators<C>,
+        disclosed_messages: &BTreeMap<usize, Message>,
+        total_no_of_messages: usize,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BbsCiphersuiteParameters;
+}
+
+impl VerifiableProof for Proof {
+    fn from_octets(bytes: &[u8]) -> Result<Self, Error> {
+        Proof::from_octets(bytes)
+    }
+
+    fn undisclosed_message_count(&self) -> usize {
+        self.m_hat_list.len()
+    }
+
+    fn verify<T, C>(
+        &self,
+        pk: &PublicKey,
+        header: Option<T>,
+        ph: Option<T>,
+        generators: &MemoryCachedGenerators<C>,
+        disclosed_messages: &BTreeMap<usize, Message>,
+        total_no_of_messages: usize,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BbsCiphersuiteParameters,
+    {
+        Proof::verify::<_, _, C>(
+            self,
+            pk,
+            header,
+            ph,
+            generators,
+            disclosed_messages,
+            Some(total_no_of_messages),
+        )
+    }
+}
+
+impl VerifiableProof for ProofDraft06 {
+    fn from_octets(bytes: &[u8]) -> Result<Self, Error> {
+        ProofDraft06::from_octets(bytes)
+    }
+
+    fn undisclosed_message_count(&self) -> usize {
+        self.m_hat_list.len()
+    }
+
+    fn verify<T, C>(
+        &self,
+        pk: &PublicKey,
+        header: Option<T>,
+        ph: Option<T>,
+        generators: &MemoryCachedGenerators<C>,
+        disclosed_messages: &BTreeMap<usize, Message>,
+        total_no_of_messages: usize,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BbsCiphersuiteParameters,
+    {
+        ProofDraft06::verify::<_, _, C>(
+            self,
+            pk,
+            header,
+            ph,
+            generators,
+            disclosed_messages,
+            Some(total_no_of_messages),
+        )
+    }
 }
 
 // Verify a BBS signature proof of knowledge.
@@ -109,18 +232,37 @@ pub(crate) fn proof_verify<T, C>(
 where
     T: AsRef<[u8]>,
     C: BbsCiphersuiteParameters,
+{
+    match C::DRAFT_VERSION {
+        BbsDraftVersion::Legacy => _proof_verify_helper::<Proof, T, C>(request),
+        BbsDraftVersion::Draft06 => {
+            _proof_verify_helper::<ProofDraft06, T, C>(request)
+        }
+    }
+}
+
+// helper function verifying a BBS signature proof of knowledge with the
+// proof layout `P`
+fn _proof_verify_helper<P, T, C>(
+    request: &BbsProofVerifyRequest<'_, T>,
+) -> Result<bool, Error>
+where
+    P: VerifiableProof,
+    T: AsRef<[u8]>,
+    C: BbsCiphersuiteParameters,
 {
     // Parse public key from request
     let public_key = PublicKey::from_octets(request.public_key)?;
 
-    // Parse proof from the request
-    let proof = Proof::from_octets(request.proof)?;
-
     // Deserialize the messages
     let messages = request.messages.unwrap_or(&[] as &[(usize, T)]);
 
+    // Parse proof from the request
+    let proof = P::from_octets(request.proof)?;
+
     // Calculate total messages count
-    let total_message_count = proof.m_hat_list.len() + messages.len();
+    let total_message_count =
+        proof.undisclosed_message_count() + messages.len();
 
     // Digest the revealed proof messages
     let messages: BTreeMap<usize, Message> =
@@ -130,13 +272,13 @@ where
     let generators =
         MemoryCachedGenerators::<C>::new(total_message_count, None)?;
 
-    proof.verify::<_, _, C>(
+    proof.verify::<_, C>(
         &public_key,
         request.header.as_ref(),
         request.presentation_header.as_ref(),
         &generators,
         &messages,
-        Some(total_message_count),
+        total_message_count,
     )
 }
 
@@ -155,15 +297,28 @@ where
         _parse_request_helper::<T, C>(request)?;
 
     // Generate the proof
-    let proof = Proof::new_with_rng::<_, _, _, C>(
-        &pk,
-        &signature,
-        request.header.as_ref(),
-        request.presentation_header.as_ref(),
-        &generators,
-        &proof_messages,
-        rng,
-    )?;
-
-    Ok(proof.to_octets())
+    match C::DRAFT_VERSION {
+        BbsDraftVersion::Legacy => Ok(Proof::new_with_rng::<_, _, _, C>(
+            &pk,
+            &signature,
+            request.header.as_ref(),
+            request.presentation_header.as_ref(),
+            &generators,
+            &proof_messages,
+            rng,
+        )?
+        .to_octets()),
+        BbsDraftVersion::Draft06 => {
+            Ok(ProofDraft06::new_with_rng::<_, _, _, C>(
+                &pk,
+                &signature,
+                request.header.as_ref(),
+                request.presentation_header.as_ref(),
+                &generators,
+                &proof_messages,
+                rng,
+            )?
+            .to_octets())
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use super::{
-    dtos::{BbsSignRequest, BbsVerifyRequest},
+    dtos::{BbsSignRequest, BbsSignWithSignerRequest, BbsVerifyRequest},
     utils::digest_messages,
 };
 use crate::{
@@ -11,7 +11,7 @@ use crate::{
         core::{
             generator::memory_cached_generator::MemoryCachedGenerators,
             key_pair::{PublicKey, SecretKey},
-            signature::Signature,
+            signature::{BbsSigner, Signature},
             types::Message,
         },
     },
@@ -49,6 +49,51 @@ where
     .map(|sig| sig.to_octets())
 }
 
+// Create a BBS signature, delegating the secret key dependent steps to
+// `signer`.
+pub(crate) fn sign_with_signer<T, S, C>(
+    signer: &S,
+    request: &BbsSignWithSignerRequest<'_, T>,
+) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+    S: BbsSigner,
+    C: BbsCiphersuiteParameters,
+{
+    // Get the public key from the signer
+    let pk = signer.public_key()?;
+
+    // Digest the supplied messages
+    let messages: Vec<Message> = digest_messages::<_, C>(request.messages)?;
+
+    // Derive generators
+    let generators = MemoryCachedGenerators::<C>::new(messages.len(), None)?;
+
+    // Compute the secret key independent part of the signature
+    let input = Signature::prepare::<_, _, _, C>(
+        &pk,
+        request.header.as_ref(),
+        &generators,
+        &messages,
+    )?;
+
+    // Let the signer complete the signature, and verify its output with the
+    // public key of the signer
+    let signature = Signature::from_octets(&signer.sign(&input)?)?;
+    if !signature.verify::<_, _, _, C>(
+        &pk,
+        request.header.as_ref(),
+        &generators,
+        &messages,
+    )? {
+        return Err(Error::CryptoOps {
+            cause: "the signer output does not verify with its public key"
+                .to_owned(),
+        });
+    }
+    Ok(signature.to_octets())
+}
+
 // Verify a BBS signature.
 pub(crate) fn verify<T, C>(
     request: &BbsVerifyRequest<'_, T>,
@@ -103,7 +103,7 @@ where
         });
     }
 
-    messages
+    let digested_messages: BTreeMap<usize, Message> = messages
         .iter()
         .map(|(i, m)| {
             match Message::from_arbitrary_data::<C>(m.as_ref(), None) {
@@ -111,5 +111,13 @@ where
                 Err(e) => Err(e),
             }
         })
-        .collect()
+        .collect::<Result<_, Error>>()?;
+
+    if digested_messages.len() != messages.len() {
+        return Err(Error::BadParams {
+            cause: "duplicate revealed message index".to_owned(),
+        });
+    }
+
+    Ok(digested_messages)
 }
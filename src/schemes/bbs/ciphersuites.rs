@@ -1,4 +1,11 @@
 use crate::{
+    bbs::{
+        BbsProofGenRequest,
+        BbsProofVerifyRequest,
+        BbsSignRequest,
+        BbsSignWithSignerRequest,
+        BbsVerifyRequest,
+    },
     common::{
         ciphersuite::CipherSuiteId,
         hash_param::{
@@ -15,38 +22,351 @@ use crate::{
     },
     Error,
 };
+use core::{fmt, str::FromStr};
 use group::Group;
 
 /// BBS BLS12-381 ciphersuites.
 pub mod bls12_381;
 /// BBS BLS12-381-Sha-256 ciphersuites.
 pub mod bls12_381_g1_sha_256;
+/// BBS BLS12-381-Sha-256 ciphersuites following
+/// `draft-irtf-cfrg-bbs-signatures-06`.
+pub mod bls12_381_g1_sha_256_draft_06;
 /// BBS BLS12-381-Shake-256 ciphersuites.
 pub mod bls12_381_g1_shake_256;
+/// BBS BLS12-381-Shake-256 ciphersuites following
+/// `draft-irtf-cfrg-bbs-signatures-06`.
+pub mod bls12_381_g1_shake_256_draft_06;
+/// Application defined BBS ciphersuites.
+pub mod custom;
+
+/// A BBS ciphersuite selected at runtime, e.g. from credential metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BbsCiphersuite {
+    /// The `bls12_381_g1_sha_256` ciphersuite.
+    Bls12381G1Sha256,
+    /// The `bls12_381_g1_shake_256` ciphersuite.
+    Bls12381G1Shake256,
+    /// The `bls12_381_g1_sha_256_draft_06` ciphersuite.
+    Bls12381G1Sha256Draft06,
+    /// The `bls12_381_g1_shake_256_draft_06` ciphersuite.
+    Bls12381G1Shake256Draft06,
+}
+
+impl BbsCiphersuite {
+    /// All the supported ciphersuites.
+    pub const ALL: [Self; 4] = [
+        Self::Bls12381G1Sha256,
+        Self::Bls12381G1Shake256,
+        Self::Bls12381G1Sha256Draft06,
+        Self::Bls12381G1Shake256Draft06,
+    ];
+
+    fn id(&self) -> CipherSuiteId {
+        match self {
+            Self::Bls12381G1Sha256 => CipherSuiteId::BbsBls12381G1XmdSha256,
+            Self::Bls12381G1Shake256 => CipherSuiteId::BbsBls12381G1XofShake256,
+            Self::Bls12381G1Sha256Draft06 => {
+                CipherSuiteId::BbsBls12381G1XmdSha256Draft06
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                CipherSuiteId::BbsBls12381G1XofShake256Draft06
+            }
+        }
+    }
+
+    /// The ciphersuite identifier as octets.
+    pub fn as_octets(&self) -> &'static [u8] {
+        self.id().as_octets()
+    }
+
+    /// The ciphersuite identifier as a string.
+    pub fn as_str(&self) -> &'static str {
+        // unwrap() is safe here since ciphersuite identifiers are ASCII
+        core::str::from_utf8(self.as_octets()).unwrap()
+    }
+
+    /// Get the ciphersuite with identifier `octets`.
+    pub fn from_octets(octets: &[u8]) -> Result<Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_octets() == octets)
+            .ok_or_else(|| Error::BadParams {
+                cause: "unknown BBS ciphersuite identifier".to_owned(),
+            })
+    }
+
+    /// Derive a secret key from `key_material` with the `KeyGen` operation
+    /// of this ciphersuite.
+    /// `key_dst` defaults to the `KeyGen` DST of the ciphersuite.
+    pub fn key_gen(
+        &self,
+        key_material: &[u8],
+        key_info: &[u8],
+        key_dst: Option<&[u8]>,
+    ) -> Result<bls12_381::SecretKey, Error> {
+        match self {
+            Self::Bls12381G1Sha256 => {
+                bls12_381_g1_sha_256::key_gen(key_material, key_info, key_dst)
+            }
+            Self::Bls12381G1Shake256 => {
+                bls12_381_g1_shake_256::key_gen(key_material, key_info, key_dst)
+            }
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::key_gen(
+                    key_material,
+                    key_info,
+                    key_dst,
+                )
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::key_gen(
+                    key_material,
+                    key_info,
+                    key_dst,
+                )
+            }
+        }
+    }
+
+    /// Compute a proof of possession of a BBS secret key with this
+    /// ciphersuite.
+    pub fn pop_prove(
+        &self,
+        sk: &bls12_381::SecretKey,
+    ) -> Result<[u8; bls12_381::BBS_BLS12381G1_KEY_POP_LENGTH], Error> {
+        match self {
+            Self::Bls12381G1Sha256 => bls12_381_g1_sha_256::pop_prove(sk),
+            Self::Bls12381G1Shake256 => bls12_381_g1_shake_256::pop_prove(sk),
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::pop_prove(sk)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::pop_prove(sk)
+            }
+        }
+    }
+
+    /// Verify a proof of possession of the secret key of a BBS public key
+    /// with this ciphersuite.
+    pub fn pop_verify(
+        &self,
+        pk: &bls12_381::PublicKey,
+        proof: &[u8; bls12_381::BBS_BLS12381G1_KEY_POP_LENGTH],
+    ) -> Result<bool, Error> {
+        match self {
+            Self::Bls12381G1Sha256 => {
+                bls12_381_g1_sha_256::pop_verify(pk, proof)
+            }
+            Self::Bls12381G1Shake256 => {
+                bls12_381_g1_shake_256::pop_verify(pk, proof)
+            }
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::pop_verify(pk, proof)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::pop_verify(pk, proof)
+            }
+        }
+    }
+
+    /// Create a BBS signature with this ciphersuite.
+    /// Security Warning: `secret_key` and `public_key` in `request` must be
+    /// related key-pair generated using `KeyPair` APIs.
+    pub fn sign<T>(
+        &self,
+        request: &BbsSignRequest<'_, T>,
+    ) -> Result<[u8; bls12_381::BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381G1Sha256 => bls12_381_g1_sha_256::sign(request),
+            Self::Bls12381G1Shake256 => bls12_381_g1_shake_256::sign(request),
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::sign(request)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::sign(request)
+            }
+        }
+    }
+
+    /// Create a BBS signature with this ciphersuite, delegating the secret
+    /// key dependent steps to `signer`.
+    pub fn sign_with_signer<T, S>(
+        &self,
+        signer: &S,
+        request: &BbsSignWithSignerRequest<'_, T>,
+    ) -> Result<[u8; bls12_381::BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+    where
+        T: AsRef<[u8]>,
+        S: bls12_381::BbsSigner,
+    {
+        match self {
+            Self::Bls12381G1Sha256 => {
+                bls12_381_g1_sha_256::sign_with_signer(signer, request)
+            }
+            Self::Bls12381G1Shake256 => {
+                bls12_381_g1_shake_256::sign_with_signer(signer, request)
+            }
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::sign_with_signer(signer, request)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::sign_with_signer(
+                    signer, request,
+                )
+            }
+        }
+    }
+
+    /// Verify a BBS signature with this ciphersuite.
+    pub fn verify<T>(
+        &self,
+        request: &BbsVerifyRequest<'_, T>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381G1Sha256 => bls12_381_g1_sha_256::verify(request),
+            Self::Bls12381G1Shake256 => bls12_381_g1_shake_256::verify(request),
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::verify(request)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::verify(request)
+            }
+        }
+    }
+
+    /// Generate a BBS signature proof of knowledge with this ciphersuite.
+    pub fn proof_gen<T>(
+        &self,
+        request: &BbsProofGenRequest<'_, T>,
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381G1Sha256 => bls12_381_g1_sha_256::proof_gen(request),
+            Self::Bls12381G1Shake256 => {
+                bls12_381_g1_shake_256::proof_gen(request)
+            }
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::proof_gen(request)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::proof_gen(request)
+            }
+        }
+    }
+
+    /// Verify a BBS signature proof of knowledge with this ciphersuite.
+    pub fn proof_verify<T>(
+        &self,
+        request: &BbsProofVerifyRequest<'_, T>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381G1Sha256 => {
+                bls12_381_g1_sha_256::proof_verify(request)
+            }
+            Self::Bls12381G1Shake256 => {
+                bls12_381_g1_shake_256::proof_verify(request)
+            }
+            Self::Bls12381G1Sha256Draft06 => {
+                bls12_381_g1_sha_256_draft_06::proof_verify(request)
+            }
+            Self::Bls12381G1Shake256Draft06 => {
+                bls12_381_g1_shake_256_draft_06::proof_verify(request)
+            }
+        }
+    }
+
+    /// Parse a BBS signature proof of knowledge with the proof layout of this
+    /// ciphersuite, without verifying it, and return its components.
+    pub fn inspect_proof<T: AsRef<[u8]>>(
+        &self,
+        proof: T,
+    ) -> Result<bls12_381::BbsProofInspection, Error> {
+        match self {
+            Self::Bls12381G1Sha256 | Self::Bls12381G1Shake256 => {
+                bls12_381::inspect_proof(proof)
+            }
+            Self::Bls12381G1Sha256Draft06 | Self::Bls12381G1Shake256Draft06 => {
+                bls12_381::inspect_proof_draft_06(proof)
+            }
+        }
+    }
+}
+
+impl fmt::Display for BbsCiphersuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BbsCiphersuite {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_octets(s.as_bytes())
+    }
+}
+
+/// Revision of the BBS signature draft a ciphersuite implements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BbsDraftVersion {
+    /// The revision implemented by the original ciphersuites of this crate.
+    Legacy,
+    /// `draft-irtf-cfrg-bbs-signatures-06`.
+    Draft06,
+}
 
 pub(crate) trait BbsCiphersuiteParameters:
     HashToScalarParameter + HashToCurveParameter
 {
+    /// Revision of the BBS signature draft followed by `Sign`, `ProofGen`
+    /// and `ProofVerify`.
+    const DRAFT_VERSION: BbsDraftVersion = BbsDraftVersion::Legacy;
+
     /// A seed value with global scope for `generator_seed` as defined in
     /// BBS signature Spec which is used by the `create_generators ` operation
     /// to compute the required set of message generators.
     fn generator_seed() -> Vec<u8> {
-        [Self::ID.as_octets(), b"MESSAGE_GENERATOR_SEED"].concat()
+        [Self::ID.api_id().as_slice(), b"MESSAGE_GENERATOR_SEED"].concat()
     }
 
     // The G1 base point generator seed.
     fn bp_generator_seed() -> Vec<u8> {
-        [Self::ID.as_octets(), b"BP_MESSAGE_GENERATOR_SEED"].concat()
+        [Self::ID.api_id().as_slice(), b"BP_MESSAGE_GENERATOR_SEED"].concat()
+    }
+
+    /// Default domain separation tag for the `KeyGen` operation.
+    fn default_key_gen_dst() -> Vec<u8> {
+        [Self::ID.api_id().as_slice(), b"KEYGEN_DST_"].concat()
+    }
+
+    /// Domain separation tag of the challenge of the `KeyPop` operation. It is
+    /// built from the ciphersuite ID rather than from the `api_id`: the
+    /// `api_id` of a draft-06 ciphersuite equals the ID of the matching
+    /// ciphersuite of the earlier draft.
+    fn key_pop_dst() -> Vec<u8> {
+        [Self::ID.as_octets(), b"KEY_POP_"].concat()
     }
 
     /// Seed DST which is used by the `create_generators ` operation.
     fn generator_seed_dst() -> Vec<u8> {
-        [Self::ID.as_octets(), b"SIG_GENERATOR_SEED_"].concat()
+        [Self::ID.api_id().as_slice(), b"SIG_GENERATOR_SEED_"].concat()
     }
 
     /// Generator DST which is used by the `create_generators ` operation.
     fn generator_dst() -> Vec<u8> {
-        [Self::ID.as_octets(), b"SIG_GENERATOR_DST_"].concat()
+        [Self::ID.api_id().as_slice(), b"SIG_GENERATOR_DST_"].concat()
     }
 
     /// Point on G1 to be used in signature and proof computation and
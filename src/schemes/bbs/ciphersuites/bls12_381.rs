@@ -4,10 +4,18 @@ use crate::curves::bls12_381::{
     OCTET_SCALAR_LENGTH,
 };
 pub use crate::schemes::bbs::{
-    api::proof::get_proof_size,
+    api::proof::{
+        get_proof_size,
+        get_proof_size_draft_06,
+        inspect_proof,
+        inspect_proof_draft_06,
+    },
     core::{
         constants::MIN_KEY_GEN_IKM_LENGTH,
         key_pair::{KeyPair, PublicKey, SecretKey},
+        proof::{BbsProof, BbsProofInspection},
+        proof_draft_06::BbsProofDraft06,
+        signature::{BbsSignature, BbsSigner, BbsSignerInput},
     },
 };
 
@@ -21,6 +29,10 @@ pub const BBS_BLS12381G1_PUBLIC_KEY_LENGTH: usize = OCTET_POINT_G2_LENGTH;
 pub const BBS_BLS12381G1_SIGNATURE_LENGTH: usize =
     OCTET_POINT_G1_LENGTH + OCTET_SCALAR_LENGTH;
 
+/// "KeyPop" proof of possession length in bytes for "BBS_BLS12381G1"
+/// ciphersuite.
+pub const BBS_BLS12381G1_KEY_POP_LENGTH: usize = 2 * OCTET_SCALAR_LENGTH;
+
 /// Number of random bytes required when creating random scalars.
 #[cfg(feature = "__private_bbs_fixtures_generator_api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "__private_bbs_fixtures_generator_api")))]
@@ -0,0 +1,204 @@
+use super::{
+    bls12_381::{
+        BbsSigner,
+        PublicKey,
+        SecretKey,
+        BBS_BLS12381G1_KEY_POP_LENGTH,
+        BBS_BLS12381G1_SIGNATURE_LENGTH,
+    },
+    BbsCiphersuiteParameters,
+    BbsDraftVersion,
+    CipherSuiteId,
+};
+use crate::{
+    bbs::{
+        BbsProofGenRequest,
+        BbsProofVerifyRequest,
+        BbsSignRequest,
+        BbsSignWithSignerRequest,
+        BbsVerifyRequest,
+    },
+    common::{
+        ciphersuite::CipherSuiteParameter,
+        hash_param::{
+            h2c::HashToCurveParameter,
+            h2s::HashToScalarParameter,
+            ExpandMessageParameter,
+        },
+    },
+    curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    Error,
+};
+use sha2::Sha256;
+
+#[cfg(feature = "__private_bbs_fixtures_generator_api")]
+use rand::{CryptoRng, RngCore};
+
+// The DSTs and generator seeds of the draft, including the `KeyGen` DST, are
+// built from its `api_id`, `ciphersuite_id || "H2G_HM2S_"`.
+#[derive(Debug, Clone)]
+pub(crate) struct Sha256Draft06Param;
+
+impl CipherSuiteParameter for Sha256Draft06Param {
+    const ID: CipherSuiteId = CipherSuiteId::BbsBls12381G1XmdSha256Draft06;
+}
+
+impl ExpandMessageParameter for Sha256Draft06Param {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+impl HashToScalarParameter for Sha256Draft06Param {}
+
+impl HashToCurveParameter for Sha256Draft06Param {}
+
+impl BbsCiphersuiteParameters for Sha256Draft06Param {
+    const DRAFT_VERSION: BbsDraftVersion = BbsDraftVersion::Draft06;
+}
+
+/// Derive a secret key from `key_material` with the BLS12-381-G1-Sha-256
+/// `KeyGen` operation as defined in
+/// `draft-irtf-cfrg-bbs-signatures-06`.
+/// `key_dst` defaults to the `KeyGen` DST of the ciphersuite.
+pub fn key_gen(
+    key_material: &[u8],
+    key_info: &[u8],
+    key_dst: Option<&[u8]>,
+) -> Result<SecretKey, Error> {
+    SecretKey::key_gen::<Sha256Draft06Param>(key_material, key_info, key_dst)
+}
+
+/// Compute a BLS12-381-G1-Sha-256 proof of possession of a BBS secret key.
+pub fn pop_prove(
+    sk: &SecretKey,
+) -> Result<[u8; BBS_BLS12381G1_KEY_POP_LENGTH], Error> {
+    crate::bbs::api::key_pop::pop_prove::<Sha256Draft06Param>(sk)
+}
+
+/// Verify a BLS12-381-G1-Sha-256 proof of possession of the secret key of a BBS
+/// public key.
+pub fn pop_verify(
+    pk: &PublicKey,
+    proof: &[u8; BBS_BLS12381G1_KEY_POP_LENGTH],
+) -> Result<bool, Error> {
+    crate::bbs::api::key_pop::pop_verify::<Sha256Draft06Param>(pk, proof)
+}
+
+/// Create a BLS12-381-G1-Sha-256 BBS signature as defined in
+/// `draft-irtf-cfrg-bbs-signatures-06`.
+/// Security Warning: `secret_key` and `public_key` in `request` must be related
+/// key-pair generated using `KeyPair` APIs.
+pub fn sign<T>(
+    request: &BbsSignRequest<'_, T>,
+) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::bbs::api::signature::sign::<_, Sha256Draft06Param>(request)
+}
+
+/// Create a BLS12-381-G1-Sha-256 BBS signature as defined in
+/// `draft-irtf-cfrg-bbs-signatures-06`, delegating the secret key dependent
+/// steps to `signer`.
+pub fn sign_with_signer<T, S>(
+    signer: &S,
+    request: &BbsSignWithSignerRequest<'_, T>,
+) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+    S: BbsSigner,
+{
+    crate::bbs::api::signature::sign_with_signer::<_, _, Sha256Draft06Param>(
+        signer, request,
+    )
+}
+
+/// Verify a BLS12-381-G1-Sha-256 BBS signature as defined in
+/// `draft-irtf-cfrg-bbs-signatures-06`.
+pub fn verify<T>(request: &BbsVerifyRequest<'_, T>) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::bbs::api::signature::verify::<_, Sha256Draft06Param>(request)
+}
+
+/// Generate a BLS12-381-G1-Sha-256 BBS signature proof of knowledge as defined
+/// in `draft-irtf-cfrg-bbs-signatures-06`.
+pub fn proof_gen<T>(
+    request: &BbsProofGenRequest<'_, T>,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::bbs::api::proof::proof_gen::<_, Sha256Draft06Param>(request)
+}
+
+/// Generate a BLS12-381-G1-Sha-256 BBS signature proof of knowledge as defined
+/// in `draft-irtf-cfrg-bbs-signatures-06` with a given rng.
+#[cfg_attr(docsrs, doc(cfg(feature = "__private_bbs_fixtures_generator_api")))]
+#[cfg(feature = "__private_bbs_fixtures_generator_api")]
+pub fn proof_gen_with_rng<T, R>(
+    request: &BbsProofGenRequest<'_, T>,
+    rng: R,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    crate::bbs::api::proof::proof_gen_with_rng::<_, _, Sha256Draft06Param>(
+        request, rng,
+    )
+}
+
+/// Verify a BLS12-381-G1-Sha-256 BBS signature proof of knowledge as defined in
+/// `draft-irtf-cfrg-bbs-signatures-06`.
+pub fn proof_verify<T>(
+    request: &BbsProofVerifyRequest<'_, T>,
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::bbs::api::proof::proof_verify::<_, Sha256Draft06Param>(request)
+}
+
+/// Create generators.
+#[cfg_attr(docsrs, doc(cfg(feature = "__private_bbs_fixtures_generator_api")))]
+#[cfg(feature = "__private_bbs_fixtures_generator_api")]
+pub fn create_generators(
+    count: usize,
+    private_holder_binding: Option<bool>,
+) -> Result<Vec<Vec<u8>>, Error> {
+    crate::bbs::api::generators::create_generators::<Sha256Draft06Param>(
+        count,
+        private_holder_binding,
+    )
+}
+
+#[cfg(feature = "__private_bbs_fixtures_generator_api")]
+use crate::curves::bls12_381::OCTET_SCALAR_LENGTH;
+
+/// Hash to scalar.
+#[cfg(feature = "__private_bbs_fixtures_generator_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "__private_bbs_fixtures_generator_api")))]
+pub fn hash_to_scalar(
+    msg_octets: &[u8],
+    dst: Option<&[u8]>,
+) -> Result<[u8; OCTET_SCALAR_LENGTH], Error> {
+    Sha256Draft06Param::hash_to_scalar(msg_octets, dst).map(|s| s.to_bytes_be())
+}
+
+/// Return the default hash to scalar dst.
+#[cfg(feature = "__private_bbs_fixtures_generator_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "__private_bbs_fixtures_generator_api")))]
+pub fn default_hash_to_scalar_dst() -> Vec<u8> {
+    Sha256Draft06Param::default_hash_to_scalar_dst()
+}
+
+/// Get's the `api_id` of the ciphersuite.
+pub fn api_id() -> Vec<u8> {
+    Sha256Draft06Param::ID.api_id()
+}
+
+/// Get's the ciphersuite id.
+pub fn ciphersuite_id() -> Vec<u8> {
+    Sha256Draft06Param::ID.as_octets().to_vec()
+}
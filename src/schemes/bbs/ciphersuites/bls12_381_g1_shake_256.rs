@@ -1,5 +1,11 @@
 use super::{
-    bls12_381::BBS_BLS12381G1_SIGNATURE_LENGTH,
+    bls12_381::{
+        BbsSigner,
+        PublicKey,
+        SecretKey,
+        BBS_BLS12381G1_KEY_POP_LENGTH,
+        BBS_BLS12381G1_SIGNATURE_LENGTH,
+    },
     BbsCiphersuiteParameters,
     CipherSuiteId,
 };
@@ -8,6 +14,7 @@ use crate::{
         BbsProofGenRequest,
         BbsProofVerifyRequest,
         BbsSignRequest,
+        BbsSignWithSignerRequest,
         BbsVerifyRequest,
     },
     common::{
@@ -43,6 +50,41 @@ impl HashToCurveParameter for Bls12381Shake256CipherSuiteParameter {}
 
 impl BbsCiphersuiteParameters for Bls12381Shake256CipherSuiteParameter {}
 
+/// Derive a secret key from `key_material` with the BLS12-381-G1-Shake-256
+/// `KeyGen` operation.
+/// `key_dst` defaults to the `KeyGen` DST of the ciphersuite.
+pub fn key_gen(
+    key_material: &[u8],
+    key_info: &[u8],
+    key_dst: Option<&[u8]>,
+) -> Result<SecretKey, Error> {
+    SecretKey::key_gen::<Bls12381Shake256CipherSuiteParameter>(
+        key_material,
+        key_info,
+        key_dst,
+    )
+}
+
+/// Compute a BLS12-381-G1-Shake-256 proof of possession of a BBS secret key.
+pub fn pop_prove(
+    sk: &SecretKey,
+) -> Result<[u8; BBS_BLS12381G1_KEY_POP_LENGTH], Error> {
+    crate::bbs::api::key_pop::pop_prove::<Bls12381Shake256CipherSuiteParameter>(
+        sk,
+    )
+}
+
+/// Verify a BLS12-381-G1-Shake-256 proof of possession of the secret key of a
+/// BBS public key.
+pub fn pop_verify(
+    pk: &PublicKey,
+    proof: &[u8; BBS_BLS12381G1_KEY_POP_LENGTH],
+) -> Result<bool, Error> {
+    crate::bbs::api::key_pop::pop_verify::<Bls12381Shake256CipherSuiteParameter>(
+        pk, proof,
+    )
+}
+
 /// Create a BLS12-381-G1-Shake-256 BBS signature.
 /// Security Warning: `secret_key` and `public_key` in `request` must be related
 /// key-pair generated using `KeyPair` APIs.
@@ -57,6 +99,23 @@ where
     )
 }
 
+/// Create a BLS12-381-G1-Shake-256 BBS signature, delegating the secret key
+/// dependent steps to `signer`.
+pub fn sign_with_signer<T, S>(
+    signer: &S,
+    request: &BbsSignWithSignerRequest<'_, T>,
+) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+    S: BbsSigner,
+{
+    crate::bbs::api::signature::sign_with_signer::<
+        _,
+        _,
+        Bls12381Shake256CipherSuiteParameter,
+    >(signer, request)
+}
+
 /// Verify a BLS12-381-G1-Shake-256 BBS signature.
 pub fn verify<T>(request: &BbsVerifyRequest<'_, T>) -> Result<bool, Error>
 where
@@ -0,0 +1,240 @@
+use super::{
+    bls12_381::{
+        BbsSigner,
+        PublicKey,
+        SecretKey,
+        BBS_BLS12381G1_KEY_POP_LENGTH,
+        BBS_BLS12381G1_SIGNATURE_LENGTH,
+    },
+    BbsCiphersuite,
+    BbsCiphersuiteParameters,
+    CipherSuiteId,
+};
+use crate::{
+    bbs::{
+        BbsProofGenRequest,
+        BbsProofVerifyRequest,
+        BbsSignRequest,
+        BbsSignWithSignerRequest,
+        BbsVerifyRequest,
+    },
+    common::{
+        ciphersuite::CipherSuiteParameter,
+        hash_param::{
+            h2c::HashToCurveParameter,
+            h2s::HashToScalarParameter,
+            ExpandMessageParameter,
+        },
+    },
+    hash::sealed::Sealed,
+    Error,
+};
+use core::{fmt::Debug, marker::PhantomData};
+
+pub use crate::hash::{
+    ExpandMessage,
+    XmdSha256,
+    XmdSha512,
+    XofShake128,
+    XofShake256,
+};
+
+/// An application defined BBS ciphersuite over BLS12-381 G1.
+/// Every domain separation tag of the ciphersuite is prefixed with `ID`, so
+/// `ID` must be non-empty ASCII and must differ from the identifiers of the
+/// ciphersuites provided by this crate. The functions of this module return
+/// `Error::BadParams` otherwise.
+pub trait BbsCustomCiphersuite: Debug + Clone {
+    /// Ciphersuite identifier.
+    const ID: &'static [u8];
+
+    /// The `expand_message` variant of the ciphersuite.
+    type ExpandMessage: ExpandMessage;
+
+    /// Seed of the message generators.
+    fn generator_seed() -> Vec<u8> {
+        [Self::ID, b"MESSAGE_GENERATOR_SEED"].concat()
+    }
+
+    /// Seed of the base point generator `P1`.
+    fn bp_generator_seed() -> Vec<u8> {
+        [Self::ID, b"BP_MESSAGE_GENERATOR_SEED"].concat()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CustomCipherSuiteParameter<S>(PhantomData<S>);
+
+impl<S: BbsCustomCiphersuite> CipherSuiteParameter
+    for CustomCipherSuiteParameter<S>
+{
+    const ID: CipherSuiteId = CipherSuiteId::Custom(S::ID);
+}
+
+impl<S: BbsCustomCiphersuite> ExpandMessageParameter
+    for CustomCipherSuiteParameter<S>
+{
+    type Expander = <S::ExpandMessage as Sealed>::Expander;
+}
+
+impl<S: BbsCustomCiphersuite> HashToScalarParameter
+    for CustomCipherSuiteParameter<S>
+{
+}
+
+impl<S: BbsCustomCiphersuite> HashToCurveParameter
+    for CustomCipherSuiteParameter<S>
+{
+}
+
+impl<S: BbsCustomCiphersuite> BbsCiphersuiteParameters
+    for CustomCipherSuiteParameter<S>
+{
+    fn generator_seed() -> Vec<u8> {
+        S::generator_seed()
+    }
+
+    fn bp_generator_seed() -> Vec<u8> {
+        S::bp_generator_seed()
+    }
+}
+
+// Check that the identifier of the custom ciphersuite `S` is valid.
+fn check_id<S: BbsCustomCiphersuite>() -> Result<(), Error> {
+    if S::ID.is_empty() || !S::ID.is_ascii() {
+        return Err(Error::BadParams {
+            cause: "custom ciphersuite identifier must be non-empty ASCII"
+                .to_owned(),
+        });
+    }
+    if BbsCiphersuite::ALL.iter().any(|c| c.as_octets() == S::ID) {
+        return Err(Error::BadParams {
+            cause: "custom ciphersuite identifier is the identifier of a \
+                    provided ciphersuite"
+                .to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// Derive a secret key from `key_material` with the `KeyGen` operation of the
+/// custom ciphersuite `S`.
+/// `key_dst` defaults to `S::ID || "KEYGEN_DST_"`.
+pub fn key_gen<S>(
+    key_material: &[u8],
+    key_info: &[u8],
+    key_dst: Option<&[u8]>,
+) -> Result<SecretKey, Error>
+where
+    S: BbsCustomCiphersuite,
+{
+    check_id::<S>()?;
+    SecretKey::key_gen::<CustomCipherSuiteParameter<S>>(
+        key_material,
+        key_info,
+        key_dst,
+    )
+}
+
+/// Compute a proof of possession of a BBS secret key with the custom
+/// ciphersuite `S`.
+pub fn pop_prove<S>(
+    sk: &SecretKey,
+) -> Result<[u8; BBS_BLS12381G1_KEY_POP_LENGTH], Error>
+where
+    S: BbsCustomCiphersuite,
+{
+    check_id::<S>()?;
+    crate::bbs::api::key_pop::pop_prove::<CustomCipherSuiteParameter<S>>(sk)
+}
+
+/// Verify a proof of possession of the secret key of a BBS public key with
+/// the custom ciphersuite `S`.
+pub fn pop_verify<S>(
+    pk: &PublicKey,
+    proof: &[u8; BBS_BLS12381G1_KEY_POP_LENGTH],
+) -> Result<bool, Error>
+where
+    S: BbsCustomCiphersuite,
+{
+    check_id::<S>()?;
+    crate::bbs::api::key_pop::pop_verify::<CustomCipherSuiteParameter<S>>(
+        pk, proof,
+    )
+}
+
+/// Create a BBS signature with the custom ciphersuite `S`.
+/// Security Warning: `secret_key` and `public_key` in `request` must be related
+/// key-pair generated using `KeyPair` APIs.
+pub fn sign<S, T>(
+    request: &BbsSignRequest<'_, T>,
+) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    S: BbsCustomCiphersuite,
+    T: AsRef<[u8]>,
+{
+    check_id::<S>()?;
+    crate::bbs::api::signature::sign::<_, CustomCipherSuiteParameter<S>>(
+        request,
+    )
+}
+
+/// Create a BBS signature with the custom ciphersuite `S`, delegating the
+/// secret key dependent steps to `signer`.
+pub fn sign_with_signer<S, T, K>(
+    signer: &K,
+    request: &BbsSignWithSignerRequest<'_, T>,
+) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    S: BbsCustomCiphersuite,
+    T: AsRef<[u8]>,
+    K: BbsSigner,
+{
+    check_id::<S>()?;
+    crate::bbs::api::signature::sign_with_signer::<
+        _,
+        _,
+        CustomCipherSuiteParameter<S>,
+    >(signer, request)
+}
+
+/// Verify a BBS signature with the custom ciphersuite `S`.
+pub fn verify<S, T>(request: &BbsVerifyRequest<'_, T>) -> Result<bool, Error>
+where
+    S: BbsCustomCiphersuite,
+    T: AsRef<[u8]>,
+{
+    check_id::<S>()?;
+    crate::bbs::api::signature::verify::<_, CustomCipherSuiteParameter<S>>(
+        request,
+    )
+}
+
+/// Generate a BBS signature proof of knowledge with the custom ciphersuite
+/// `S`.
+pub fn proof_gen<S, T>(
+    request: &BbsProofGenRequest<'_, T>,
+) -> Result<Vec<u8>, Error>
+where
+    S: BbsCustomCiphersuite,
+    T: AsRef<[u8]>,
+{
+    check_id::<S>()?;
+    crate::bbs::api::proof::proof_gen::<_, CustomCipherSuiteParameter<S>>(
+        request,
+    )
+}
+
+/// Verify a BBS signature proof of knowledge with the custom ciphersuite `S`.
+pub fn proof_verify<S, T>(
+    request: &BbsProofVerifyRequest<'_, T>,
+) -> Result<bool, Error>
+where
+    S: BbsCustomCiphersuite,
+    T: AsRef<[u8]>,
+{
+    check_id::<S>()?;
+    crate::bbs::api::proof::proof_verify::<_, CustomCipherSuiteParameter<S>>(
+        request,
+    )
+}
@@ -2,7 +2,9 @@ pub(crate) mod constants;
 pub(crate) mod generator;
 /// BBS Key pair.
 pub mod key_pair;
+pub(crate) mod key_pop;
 pub(crate) mod proof;
+pub(crate) mod proof_draft_06;
 pub(crate) mod signature;
 pub(crate) mod types;
 pub(crate) mod utils;
@@ -1,6 +1,14 @@
+#[cfg(feature = "nonstandard_der")]
+use crate::key_encoding::der::DER_OID_BLS12381G2;
+#[cfg(feature = "jwk")]
+use crate::key_encoding::{Jwk, JwkKeyType, JWK_CRV_BLS12381G2};
+#[cfg(feature = "keystore")]
+use crate::keystore::{keystore_impl, Keystore, KeystoreKdf};
 use crate::{
+    bbs::ciphersuites::BbsCiphersuiteParameters,
     common::{
         key_pair::bbs_bls_key_pair_impl,
+        serialization::i2osp_with_data,
         util::{print_byte_array, vec_to_byte_array},
     },
     curves::bls12_381::{
@@ -13,6 +21,11 @@ use crate::{
         OCTET_SCALAR_LENGTH,
     },
     error::Error,
+    key_encoding::{
+        key_encoding_impl,
+        MULTICODEC_BLS12381G2_PRIV,
+        MULTICODEC_BLS12381G2_PUB,
+    },
 };
 use ff::Field;
 use group::{Curve, Group};
@@ -32,3 +45,67 @@ bbs_bls_key_pair_impl!(
     generate_sk,
     sk_to_pk_in_g2
 );
+
+key_encoding_impl!(
+    OCTET_POINT_G2_LENGTH,
+    G2Projective,
+    G2Affine,
+    JWK_CRV_BLS12381G2,
+    MULTICODEC_BLS12381G2_PUB,
+    MULTICODEC_BLS12381G2_PRIV,
+    DER_OID_BLS12381G2
+);
+
+#[cfg(feature = "keystore")]
+keystore_impl!();
+
+impl SecretKey {
+    /// Computes a secret key from a key material as defined by
+    /// <https://identity.foundation/bbs-signature/draft-bbs-signatures.html#name-keygen>,
+    /// using the `hash_to_scalar` operation of the ciphersuite `C`.
+    pub(crate) fn key_gen<C>(
+        key_material: &[u8],
+        key_info: &[u8],
+        key_dst: Option<&[u8]>,
+    ) -> Result<Self, Error>
+    where
+        C: BbsCiphersuiteParameters,
+    {
+        if key_material.len() < MIN_KEY_GEN_IKM_LENGTH {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "key material is too short, needs to be at least \
+                     {MIN_KEY_GEN_IKM_LENGTH} bytes long"
+                ),
+            });
+        }
+        if key_info.len() > u16::MAX as usize {
+            return Err(Error::BadParams {
+                cause: "key info is too large".to_owned(),
+            });
+        }
+        let default_key_dst = C::default_key_gen_dst();
+        let key_dst = key_dst.unwrap_or(&default_key_dst);
+
+        // derive_input = key_material || I2OSP(length(key_info), 2) ||
+        // key_info
+        let derive_input =
+            [key_material, &i2osp_with_data(key_info, 2)?].concat();
+
+        // SK = hash_to_scalar(derive_input, key_dst)
+        let sk = C::hash_to_scalar(&derive_input, Some(key_dst))?;
+        if sk.is_zero().unwrap_u8() == 1u8 {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok(SecretKey(Box::new(sk)))
+    }
+
+    /// Computes a secret key from an IKM with the legacy derivation of this
+    /// crate, same as [`SecretKey::new`].
+    /// Keys derived this way differ from the ones of other BBS
+    /// implementations, new keys should be derived with the `key_gen`
+    /// function of the ciphersuite instead.
+    pub fn legacy_key_gen(ikm: &[u8], key_info: &[u8]) -> Option<Self> {
+        Self::new(ikm, key_info)
+    }
+}
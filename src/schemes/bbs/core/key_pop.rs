@@ -0,0 +1,125 @@
+#![allow(non_snake_case)]
+
+use super::{
+    key_pair::{PublicKey, SecretKey},
+    types::{Challenge, FiatShamirProof},
+};
+use crate::{
+    bbs::ciphersuites::BbsCiphersuiteParameters,
+    common::util::create_random_scalar,
+    curves::{
+        bls12_381::{G2Projective, OCTET_SCALAR_LENGTH},
+        point_serde::point_to_octets_g2,
+    },
+    error::Error,
+};
+use ff::Field;
+use rand::{CryptoRng, RngCore};
+use rand_core::OsRng;
+
+/// A proof of possession of the secret key `SK` of a BBS public key `PK`, i.e.
+/// a Schnorr proof of knowledge of `SK` such that `PK = SK * P2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct KeyPop {
+    /// Fiat-Shamir challenge.
+    pub(crate) c: Challenge,
+    /// Response to the challenge.
+    pub(crate) s: FiatShamirProof,
+}
+
+impl KeyPop {
+    /// The number of bytes needed to represent the proof of possession.
+    pub const SIZE_BYTES: usize = 2 * OCTET_SCALAR_LENGTH;
+
+    /// Prove possession of `SK`.
+    pub fn new<C>(SK: &SecretKey) -> Result<Self, Error>
+    where
+        C: BbsCiphersuiteParameters,
+    {
+        Self::new_with_rng::<_, C>(SK, OsRng)
+    }
+
+    /// Prove possession of `SK` using an externally supplied random number
+    /// generator.
+    pub fn new_with_rng<R, C>(SK: &SecretKey, mut rng: R) -> Result<Self, Error>
+    where
+        R: RngCore + CryptoRng,
+        C: BbsCiphersuiteParameters,
+    {
+        let SK = SK.as_scalar();
+        if SK.is_zero().unwrap_u8() == 1u8 {
+            return Err(Error::InvalidSecretKey);
+        }
+        let PK = C::p2() * SK;
+
+        // r = random_scalar()
+        // T = r * P2
+        let r = create_random_scalar(&mut rng)?;
+        let T = C::p2() * r;
+
+        // c = hash_to_scalar(PK || T, key_pop_dst)
+        // s = r + c * SK
+        let c = compute_challenge::<C>(&PK, &T)?;
+        let s = r + c.0 * SK;
+
+        Ok(Self {
+            c,
+            s: FiatShamirProof(s),
+        })
+    }
+
+    /// Verify the proof of possession of the secret key of `PK`.
+    pub fn verify<C>(&self, PK: &PublicKey) -> Result<bool, Error>
+    where
+        C: BbsCiphersuiteParameters,
+    {
+        // Validate the public key; it should not be an identity and should
+        // belong to subgroup G2.
+        if PK.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        // T = s * P2 - c * PK
+        let T = C::p2() * self.s.0 - PK.0 * self.c.0;
+
+        Ok(compute_challenge::<C>(&PK.0, &T)? == self.c)
+    }
+
+    /// Convert the proof of possession to a big-endian representation, i.e.
+    /// `c || s`.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        let mut bytes = [0u8; Self::SIZE_BYTES];
+        bytes[..OCTET_SCALAR_LENGTH].copy_from_slice(&self.c.to_bytes());
+        bytes[OCTET_SCALAR_LENGTH..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Get the proof of possession from a big-endian representation.
+    pub fn from_octets(bytes: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let (c, s) = bytes.split_at(OCTET_SCALAR_LENGTH);
+        let c = Challenge::from_bytes(c.try_into()?);
+        let s = FiatShamirProof::from_bytes(s.try_into()?);
+        if (c.is_none() | s.is_none()).unwrap_u8() == 1u8 {
+            return Err(Error::BadEncoding);
+        }
+        Ok(Self {
+            c: c.unwrap(),
+            s: s.unwrap(),
+        })
+    }
+}
+
+// c = hash_to_scalar(PK || T, key_pop_dst)
+fn compute_challenge<C>(
+    PK: &G2Projective,
+    T: &G2Projective,
+) -> Result<Challenge, Error>
+where
+    C: BbsCiphersuiteParameters,
+{
+    let data_to_hash = [point_to_octets_g2(PK), point_to_octets_g2(T)].concat();
+    Ok(Challenge(C::hash_to_scalar(
+        &data_to_hash,
+        Some(&C::key_pop_dst()),
+    )?))
+}
@@ -9,7 +9,10 @@ use super::{
 };
 use crate::{
     bbs::ciphersuites::BbsCiphersuiteParameters,
-    common::util::{create_random_scalar, print_byte_array},
+    common::{
+        serialization::octets_type,
+        util::{create_random_scalar, print_byte_array},
+    },
     curves::{
         bls12_381::{
             Bls12,
@@ -278,6 +281,20 @@ impl Proof {
                 ),
             });
         }
+        // Every message is either disclosed or has a response in the proof
+        if disclosed_messages.len() + self.m_hat_list.len()
+            != total_no_of_messages
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "Incorrect number of messages: [#messages: {}, \
+                     #hidden_messages: {}, #revealed_messages: {}]",
+                    total_no_of_messages,
+                    self.m_hat_list.len(),
+                    disclosed_messages.len()
+                ),
+            });
+        }
         if disclosed_messages
             .keys()
             .any(|r| *r >= total_no_of_messages)
@@ -453,26 +470,63 @@ impl Proof {
     /// treated as [48, 48, 32, 32, 32, 32*U ] to represent   
     /// proof = (Abar, Bbar, c, r2^, z^, (m^_1, ..., m^_U)).
     pub fn from_octets<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
+        Self::parse_octets(bytes.as_ref()).map_err(|(_, _, error)| error)
+    }
+
+    /// Inspect the components of a proof without verifying it. Parsing
+    /// failures are reported along with the malformed component and its byte
+    /// offset in `bytes`.
+    pub(crate) fn inspect<B: AsRef<[u8]>>(
+        bytes: B,
+    ) -> Result<BbsProofInspection, Error> {
+        let proof = Self::parse_octets(bytes.as_ref()).map_err(
+            |(component, offset, error)| Error::MalformedProofComponent {
+                component,
+                offset,
+                cause: error.to_string(),
+            },
+        )?;
+        Ok(BbsProofInspection {
+            undisclosed_message_count: proof.m_hat_list.len(),
+            a_bar: point_to_octets_g1(&proof.A_bar),
+            b_bar: point_to_octets_g1(&proof.B_bar),
+            d: None,
+            challenge: proof.c.to_bytes(),
+            m_hat_list: proof.m_hat_list.iter().map(|m| m.to_bytes()).collect(),
+        })
+    }
+
+    // Implementation of `from_octets`, the error is returned along with the
+    // name and the byte offset of the malformed component.
+    fn parse_octets(buffer: &[u8]) -> Result<Self, (String, usize, Error)> {
         const PROOF_LEN_FLOOR: usize =
             OCTET_POINT_G1_LENGTH * 2 + OCTET_SCALAR_LENGTH * 3;
-        let buffer = bytes.as_ref();
         if buffer.len() < PROOF_LEN_FLOOR {
-            return Err(Error::MalformedProof {
-                cause: format!(
-                    "not enough data, input buffer size: {} bytes",
-                    buffer.len()
-                ),
-            });
+            return Err((
+                "proof".to_owned(),
+                buffer.len(),
+                Error::MalformedProof {
+                    cause: format!(
+                        "not enough data, input buffer size: {} bytes",
+                        buffer.len()
+                    ),
+                },
+            ));
         }
         if (buffer.len() - PROOF_LEN_FLOOR) % OCTET_SCALAR_LENGTH != 0 {
-            return Err(Error::MalformedProof {
-                cause: format!(
-                    "variable length proof data size {} is not multiple of \
-                     `Scalar` size {} bytes",
-                    buffer.len() - PROOF_LEN_FLOOR,
-                    OCTET_SCALAR_LENGTH
-                ),
-            });
+            return Err((
+                "proof".to_owned(),
+                buffer.len()
+                    - (buffer.len() - PROOF_LEN_FLOOR) % OCTET_SCALAR_LENGTH,
+                Error::MalformedProof {
+                    cause: format!(
+                        "variable length proof data size {} is not multiple \
+                         of `Scalar` size {} bytes",
+                        buffer.len() - PROOF_LEN_FLOOR,
+                        OCTET_SCALAR_LENGTH
+                    ),
+                },
+            ));
         }
 
         let unrevealed_message_count =
@@ -482,40 +536,32 @@ impl Proof {
         let mut end = OCTET_POINT_G1_LENGTH;
 
         // Get Abar
-        let A_bar = extract_point_value(&mut offset, &mut end, buffer)?;
+        let A_bar = extract_point_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("A_bar".to_owned(), offset, e))?;
 
         // Get B_bar
-        let B_bar = extract_point_value(&mut offset, &mut end, buffer)?;
+        let B_bar = extract_point_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("B_bar".to_owned(), offset, e))?;
 
         end = offset + OCTET_SCALAR_LENGTH;
 
         // Get r2^, z^
-        let r2_hat = extract_scalar_value(&mut offset, &mut end, buffer)?;
-        let z_hat = extract_scalar_value(&mut offset, &mut end, buffer)?;
+        let r2_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("r2^".to_owned(), offset, e))?;
+        let z_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("z^".to_owned(), offset, e))?;
         // Get  (m^_j1, ..., m^_jU)
         let mut m_hat_list =
             Vec::<FiatShamirProof>::with_capacity(unrevealed_message_count);
-        for _ in 0..unrevealed_message_count {
-            let m_hat = extract_scalar_value(&mut offset, &mut end, buffer)?;
+        for i in 0..unrevealed_message_count {
+            let m_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+                .map_err(|e| (format!("m^_{}", i + 1), offset, e))?;
             m_hat_list.push(m_hat);
         }
 
         // Get c
-        let c = Challenge::from_bytes(slicer!(
-            buffer,
-            offset,
-            end,
-            OCTET_SCALAR_LENGTH
-        ));
-        if c.is_none().unwrap_u8() == 1u8 {
-            return Err(Error::MalformedProof {
-                cause: "failure while deserializing `c`".to_owned(),
-            });
-        }
-        let c = c.unwrap();
-        if c.0.is_zero().unwrap_u8() == 1u8 {
-            return Err(Error::UnexpectedZeroValue);
-        }
+        let c = extract_challenge(offset, end, buffer)
+            .map_err(|e| ("c".to_owned(), offset, e))?;
 
         Ok(Self {
             A_bar,
@@ -528,8 +574,51 @@ impl Proof {
     }
 }
 
+/// The components of a BBS signature proof of knowledge, as returned by
+/// `inspect_proof` and `inspect_proof_draft_06`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BbsProofInspection {
+    /// Number of undisclosed messages.
+    pub undisclosed_message_count: usize,
+    /// Compressed `Abar` point.
+    pub a_bar: [u8; OCTET_POINT_G1_LENGTH],
+    /// Compressed `Bbar` point.
+    pub b_bar: [u8; OCTET_POINT_G1_LENGTH],
+    /// Compressed `D` point, only present in
+    /// `draft-irtf-cfrg-bbs-signatures-06` proofs.
+    pub d: Option<[u8; OCTET_POINT_G1_LENGTH]>,
+    /// Challenge `c`.
+    pub challenge: [u8; OCTET_SCALAR_LENGTH],
+    /// Responses `(m^_1, ..., m^_U)` for the undisclosed messages.
+    pub m_hat_list: Vec<[u8; OCTET_SCALAR_LENGTH]>,
+}
+
+// Extract the `Challenge` value from the buffer
+fn extract_challenge(
+    offset: usize,
+    end: usize,
+    buffer: &[u8],
+) -> Result<Challenge, Error> {
+    let c = Challenge::from_bytes(slicer!(
+        buffer,
+        offset,
+        end,
+        OCTET_SCALAR_LENGTH
+    ));
+    if c.is_none().unwrap_u8() == 1u8 {
+        return Err(Error::MalformedProof {
+            cause: "failure while deserializing `c`".to_owned(),
+        });
+    }
+    let c = c.unwrap();
+    if c.0.is_zero().unwrap_u8() == 1u8 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    Ok(c)
+}
+
 // Extract a `G1Projective` value from the buffer
-fn extract_point_value(
+pub(super) fn extract_point_value(
     offset: &mut usize,
     end: &mut usize,
     buffer: &[u8],
@@ -549,7 +638,7 @@ fn extract_point_value(
 }
 
 // Extract a `FiatShamirProof` value from the buffer
-fn extract_scalar_value(
+pub(super) fn extract_scalar_value(
     offset: &mut usize,
     end: &mut usize,
     buffer: &[u8],
@@ -573,3 +662,9 @@ fn extract_scalar_value(
     *end = *offset + OCTET_SCALAR_LENGTH;
     Ok(value)
 }
+
+octets_type!(
+    /// A BBS signature proof of knowledge, validated on parsing.
+    BbsProof,
+    |octets| Proof::from_octets(octets).map(|_| ())
+);
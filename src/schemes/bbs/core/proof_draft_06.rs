@@ -0,0 +1,576 @@
+#![allow(non_snake_case)]
+
+use super::{
+    generator::Generators,
+    key_pair::PublicKey,
+    proof::{extract_point_value, extract_scalar_value, BbsProofInspection},
+    signature::Signature,
+    types::{Challenge, FiatShamirProof, Message, ProofMessage},
+    utils::{compute_B, compute_domain},
+};
+use crate::{
+    bbs::ciphersuites::BbsCiphersuiteParameters,
+    common::{
+        hash_param::constant::NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+        serialization::{i2osp, i2osp_with_data, octets_type},
+        util::{create_random_scalar, print_byte_array},
+    },
+    curves::{
+        bls12_381::{
+            Bls12,
+            G1Projective,
+            G2Prepared,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::point_to_octets_g1,
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult as _, MultiMillerLoop};
+use rand::{CryptoRng, RngCore};
+use rand_core::OsRng;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+#[cfg(not(feature = "alloc"))]
+use std::collections::BTreeMap;
+
+/// The zero-knowledge proof-of-knowledge of a signature as defined in
+/// `draft-irtf-cfrg-bbs-signatures-06`, see <https://www.ietf.org/archive/id/draft-irtf-cfrg-bbs-signatures-06.html#name-proof-generation-proofgen>.
+/// proof = (Abar, Bbar, D, e^, r1^, r3^, (m^_j1, ..., m^_jU), c), where `U`
+/// is number of unrevealed messages.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Proof {
+    /// \overline{A}
+    pub(crate) A_bar: G1Projective,
+    /// \overline{B}
+    pub(crate) B_bar: G1Projective,
+    /// D
+    pub(crate) D: G1Projective,
+    /// e^
+    pub(crate) e_hat: FiatShamirProof,
+    /// r1^
+    pub(crate) r1_hat: FiatShamirProof,
+    /// r3^
+    pub(crate) r3_hat: FiatShamirProof,
+    /// (m^_j1, ..., m^_jU)
+    pub(crate) m_hat_list: Vec<FiatShamirProof>,
+    /// c
+    pub(crate) c: Challenge,
+}
+
+impl core::fmt::Display for Proof {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Proof(A_bar: ")?;
+        print_byte_array!(f, point_to_octets_g1(&self.A_bar));
+        write!(f, ", B_bar: ")?;
+        print_byte_array!(f, point_to_octets_g1(&self.B_bar));
+        write!(f, ", D: ")?;
+        print_byte_array!(f, point_to_octets_g1(&self.D));
+        write!(
+            f,
+            ", e^: {}, r1^: {}, r3^: {}, m^_i: [",
+            self.e_hat.0, self.r1_hat.0, self.r3_hat.0
+        )?;
+        for (i, m_hat) in self.m_hat_list.iter().enumerate() {
+            write!(f, "m^_{}: {}, ", i + 1, m_hat.0)?;
+        }
+        write!(f, "], c: {})", self.c.0)
+    }
+}
+
+impl Proof {
+    /// Generates the zero-knowledge proof-of-knowledge of a signature, while
+    /// optionally selectively disclosing from the original set of signed
+    /// messages, as defined in `ProofGen` of the draft.
+    pub fn new<T, G, C>(
+        PK: &PublicKey,
+        signature: &Signature,
+        header: Option<T>,
+        ph: Option<T>,
+        generators: &G,
+        messages: &[ProofMessage],
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        G: Generators,
+        C: BbsCiphersuiteParameters,
+    {
+        Self::new_with_rng::<_, _, _, C>(
+            PK, signature, header, ph, generators, messages, OsRng,
+        )
+    }
+
+    /// Generates the zero-knowledge proof-of-knowledge of a signature, as
+    /// defined in `ProofGen` of the draft, using an externally supplied
+    /// random number generator.
+    pub fn new_with_rng<T, R, G, C>(
+        PK: &PublicKey,
+        signature: &Signature,
+        header: Option<T>,
+        ph: Option<T>,
+        generators: &G,
+        messages: &[ProofMessage],
+        mut rng: R,
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        R: RngCore + CryptoRng,
+        G: Generators,
+        C: BbsCiphersuiteParameters,
+    {
+        // Input parameter checks
+        // Error out if there is no `header` and not any `ProofMessage`
+        if header.is_none() && messages.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to prove".to_owned(),
+            });
+        }
+        // Error out if length of messages and generators are not equal
+        if messages.len() != generators.message_generators_length() {
+            return Err(Error::MessageGeneratorsLengthMismatch {
+                generators: generators.message_generators_length(),
+                messages: messages.len(),
+            });
+        }
+
+        // domain = calculate_domain(PK, Q_1, (H_1, ..., H_L), header, api_id)
+        let domain =
+            compute_domain::<_, _, C>(PK, header, messages.len(), generators)?;
+
+        // random_scalars = calculate_random_scalars(5 + U)
+        // (r1, r2, e~, r1~, r3~, m~_j1, ..., m~_jU) = random_scalars
+        let r1 = create_random_scalar(&mut rng)?;
+        let r2 = create_random_scalar(&mut rng)?;
+        let e_tilde = create_random_scalar(&mut rng)?;
+        let r1_tilde = create_random_scalar(&mut rng)?;
+        let r3_tilde = create_random_scalar(&mut rng)?;
+
+        let msg: Vec<_> = messages.iter().map(|m| m.get_message()).collect();
+
+        // B = P1 + Q_1 * domain + H_1 * msg_1 + ... + H_L * msg_L
+        let B = compute_B::<_, C>(&domain, msg.as_ref(), generators)?;
+
+        // D = B * r2
+        let D = B * r2;
+
+        // Abar = A * (r1 * r2)
+        let A_bar = signature.A * (r1 * r2);
+
+        // Bbar = D * r1 - Abar * e
+        let B_bar = G1Projective::multi_exp(&[D, A_bar], &[r1, -signature.e]);
+
+        // T1 = Abar * e~ + D * r1~
+        let T1 = G1Projective::multi_exp(&[A_bar, D], &[e_tilde, r1_tilde]);
+
+        // T2 = D * r3~ + H_j1 * m~_j1 + ... + H_jU * m~_jU
+        let mut T2_points = vec![D];
+        let mut T2_scalars = vec![r3_tilde];
+        let mut m_tilde_scalars = Vec::new();
+        let mut hidden_messages = Vec::new();
+        let mut disclosed_messages = BTreeMap::new();
+        for (i, generator) in generators.message_generators_iter().enumerate() {
+            match messages[i] {
+                ProofMessage::Revealed(m) => {
+                    disclosed_messages.insert(i, m);
+                }
+                ProofMessage::Hidden(m) => {
+                    let m_tilde = create_random_scalar(&mut rng)?;
+                    T2_points.push(generator);
+                    T2_scalars.push(m_tilde);
+                    m_tilde_scalars.push(m_tilde);
+                    hidden_messages.push(m.0);
+                }
+            }
+        }
+        let T2 = G1Projective::multi_exp(&T2_points, &T2_scalars);
+
+        // c = ProofChallengeCalculate(init_res, disclosed_indexes,
+        //                             disclosed_messages, ph, api_id)
+        let c = compute_challenge::<_, C>(
+            &A_bar,
+            &B_bar,
+            &D,
+            &T1,
+            &T2,
+            &disclosed_messages,
+            &domain,
+            ph,
+        )?;
+
+        // r3 = r2^-1 (mod r)
+        let r3 = r2.invert();
+        if r3.is_none().unwrap_u8() == 1u8 {
+            return Err(Error::CryptoOps {
+                cause: "Failed to invert `r2`".to_owned(),
+            });
+        };
+        let r3 = r3.unwrap();
+
+        // e^ = e~ + e * c
+        let e_hat = FiatShamirProof(e_tilde + signature.e * c.0);
+
+        // r1^ = r1~ - r1 * c
+        let r1_hat = FiatShamirProof(r1_tilde - r1 * c.0);
+
+        // r3^ = r3~ - r3 * c
+        let r3_hat = FiatShamirProof(r3_tilde - r3 * c.0);
+
+        // for j in (j1, j2,..., jU): m^_j = m~_j + msg_j * c
+        let m_hat_list = m_tilde_scalars
+            .iter()
+            .zip(hidden_messages.iter())
+            .map(|(m_tilde, msg)| FiatShamirProof(*m_tilde + (*msg) * c.0))
+            .collect::<Vec<FiatShamirProof>>();
+
+        Ok(Proof {
+            A_bar,
+            B_bar,
+            D,
+            e_hat,
+            r1_hat,
+            r3_hat,
+            m_hat_list,
+            c,
+        })
+    }
+
+    /// Verify the zero-knowledge proof-of-knowledge of a signature with
+    /// optionally selectively disclosed messages, as defined in `ProofVerify`
+    /// of the draft.
+    pub fn verify<T, G, C>(
+        &self,
+        PK: &PublicKey,
+        header: Option<T>,
+        ph: Option<T>,
+        generators: &G,
+        disclosed_messages: &BTreeMap<usize, Message>,
+        total_no_of_messages: Option<usize>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        G: Generators,
+        C: BbsCiphersuiteParameters,
+    {
+        // If total number of messages is not provided, it defaults to
+        // disclosed_messages number + m_hat number
+        let total_no_of_messages = total_no_of_messages
+            .unwrap_or(self.m_hat_list.len() + disclosed_messages.len());
+
+        // Input parameter checks
+        // Error out if there is no `header` and not any `ProofMessage`
+        if header.is_none() && (total_no_of_messages == 0) {
+            return Err(Error::BadParams {
+                cause: "nothing to verify".to_owned(),
+            });
+        }
+        // Check if input proof data commitments matches no. of hidden messages
+        if total_no_of_messages != generators.message_generators_length() {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "Incorrect number of messages and generators: \
+                     [#generators: {}, #hidden_messages: {}, \
+                     #revealed_messages: {}]",
+                    generators.message_generators_length(),
+                    self.m_hat_list.len(),
+                    disclosed_messages.len()
+                ),
+            });
+        }
+        // Every message is either disclosed or has a response in the proof
+        if disclosed_messages.len() + self.m_hat_list.len()
+            != total_no_of_messages
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "Incorrect number of messages: [#messages: {}, \
+                     #hidden_messages: {}, #revealed_messages: {}]",
+                    total_no_of_messages,
+                    self.m_hat_list.len(),
+                    disclosed_messages.len()
+                ),
+            });
+        }
+        if disclosed_messages
+            .keys()
+            .any(|r| *r >= total_no_of_messages)
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "revealed message index value is invalid, maximum allowed \
+                     value is {}",
+                    total_no_of_messages - 1
+                ),
+            });
+        }
+        // if KeyValidate(PK) is INVALID, return INVALID
+        if PK.is_valid().unwrap_u8() == 0u8 {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        // domain = calculate_domain(PK, Q_1, (H_1, ..., H_L), header, api_id)
+        let domain = compute_domain::<_, _, C>(
+            PK,
+            header,
+            generators.message_generators_length(),
+            generators,
+        )?;
+
+        // Bv = P1 + Q_1 * domain + H_i1 * msg_i1 + ... + H_iR * msg_iR
+        let mut Bv_points = vec![C::p1()?, generators.Q()];
+        let mut Bv_scalars = vec![Scalar::one(), domain];
+
+        // T2 = Bv * c + D * r3^ + H_j1 * m^_j1 + ... + H_jU * m^_jU
+        let mut T2_points = vec![self.D];
+        let mut T2_scalars = vec![self.r3_hat.0];
+        let mut j = 0;
+        for (i, generator) in generators.message_generators_iter().enumerate() {
+            if let Some(msg) = disclosed_messages.get(&i) {
+                Bv_points.push(generator);
+                Bv_scalars.push(msg.0);
+            } else {
+                T2_points.push(generator);
+                T2_scalars.push(self.m_hat_list[j].0);
+                j += 1;
+            }
+        }
+        let Bv = G1Projective::multi_exp(&Bv_points, &Bv_scalars);
+        T2_points.push(Bv);
+        T2_scalars.push(self.c.0);
+        let T2 = G1Projective::multi_exp(&T2_points, &T2_scalars);
+
+        // T1 = Bbar * c + Abar * e^ + D * r1^
+        let T1 = G1Projective::multi_exp(
+            &[self.B_bar, self.A_bar, self.D],
+            &[self.c.0, self.e_hat.0, self.r1_hat.0],
+        );
+
+        // cv = ProofChallengeCalculate(init_res, disclosed_indexes,
+        //                              messages, ph, api_id)
+        let cv = compute_challenge::<_, C>(
+            &self.A_bar,
+            &self.B_bar,
+            &self.D,
+            &T1,
+            &T2,
+            disclosed_messages,
+            &domain,
+            ph,
+        )?;
+
+        // if c != cv, return INVALID
+        if self.c != cv {
+            return Ok(false);
+        }
+
+        // if e(Abar, W) * e(Bbar, -BP2) != Identity_GT, return INVALID
+        // else return VALID
+        let P2 = C::p2().to_affine();
+        Ok(Bls12::multi_miller_loop(&[
+            (&self.A_bar.to_affine(), &G2Prepared::from(PK.0.to_affine())),
+            (&self.B_bar.to_affine(), &G2Prepared::from(-P2)),
+        ])
+        .final_exponentiation()
+        .is_identity()
+        .unwrap_u8()
+            == 1)
+    }
+
+    /// Return the size of proof in bytes for `num_undisclosed_messages`.
+    pub fn get_size(num_undisclosed_messages: usize) -> usize {
+        OCTET_POINT_G1_LENGTH * 3
+            + OCTET_SCALAR_LENGTH * (4 + num_undisclosed_messages)
+    }
+
+    /// Store the proof as a sequence of bytes in big endian format, as defined
+    /// in `proof_to_octets` of the draft.
+    /// For BLS12-381 based implementation the bytes sequence of
+    /// proof = (Abar, Bbar, D, e^, r1^, r3^, (m^_1, ..., m^_U), c)
+    /// will be [48, 48, 48, 32, 32, 32, 32*U, 32].
+    pub fn to_octets(&self) -> Vec<u8> {
+        let mut buffer =
+            Vec::with_capacity(Self::get_size(self.m_hat_list.len()));
+
+        buffer.extend_from_slice(&point_to_octets_g1(&self.A_bar));
+        buffer.extend_from_slice(&point_to_octets_g1(&self.B_bar));
+        buffer.extend_from_slice(&point_to_octets_g1(&self.D));
+        buffer.extend_from_slice(&self.e_hat.to_bytes());
+        buffer.extend_from_slice(&self.r1_hat.to_bytes());
+        buffer.extend_from_slice(&self.r3_hat.to_bytes());
+        for m_hat in &self.m_hat_list {
+            buffer.extend_from_slice(&m_hat.to_bytes());
+        }
+        buffer.extend_from_slice(&self.c.to_bytes());
+        buffer
+    }
+
+    /// Get the proof `Proof` from a sequence of bytes in big endian format, as
+    /// defined in `octets_to_proof` of the draft.
+    pub fn from_octets<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
+        Self::parse_octets(bytes.as_ref()).map_err(|(_, _, error)| error)
+    }
+
+    /// Inspect the components of a proof without verifying it. Parsing
+    /// failures are reported along with the malformed component and its byte
+    /// offset in `bytes`.
+    pub(crate) fn inspect<B: AsRef<[u8]>>(
+        bytes: B,
+    ) -> Result<BbsProofInspection, Error> {
+        let proof = Self::parse_octets(bytes.as_ref()).map_err(
+            |(component, offset, error)| Error::MalformedProofComponent {
+                component,
+                offset,
+                cause: error.to_string(),
+            },
+        )?;
+        Ok(BbsProofInspection {
+            undisclosed_message_count: proof.m_hat_list.len(),
+            a_bar: point_to_octets_g1(&proof.A_bar),
+            b_bar: point_to_octets_g1(&proof.B_bar),
+            d: Some(point_to_octets_g1(&proof.D)),
+            challenge: proof.c.to_bytes(),
+            m_hat_list: proof.m_hat_list.iter().map(|m| m.to_bytes()).collect(),
+        })
+    }
+
+    // Implementation of `from_octets`, the error is returned along with the
+    // name and the byte offset of the malformed component.
+    fn parse_octets(buffer: &[u8]) -> Result<Self, (String, usize, Error)> {
+        const PROOF_LEN_FLOOR: usize =
+            OCTET_POINT_G1_LENGTH * 3 + OCTET_SCALAR_LENGTH * 4;
+        if buffer.len() < PROOF_LEN_FLOOR {
+            return Err((
+                "proof".to_owned(),
+                buffer.len(),
+                Error::MalformedProof {
+                    cause: format!(
+                        "not enough data, input buffer size: {} bytes",
+                        buffer.len()
+                    ),
+                },
+            ));
+        }
+        if (buffer.len() - PROOF_LEN_FLOOR) % OCTET_SCALAR_LENGTH != 0 {
+            return Err((
+                "proof".to_owned(),
+                buffer.len()
+                    - (buffer.len() - PROOF_LEN_FLOOR) % OCTET_SCALAR_LENGTH,
+                Error::MalformedProof {
+                    cause: format!(
+                        "variable length proof data size {} is not multiple \
+                         of `Scalar` size {} bytes",
+                        buffer.len() - PROOF_LEN_FLOOR,
+                        OCTET_SCALAR_LENGTH
+                    ),
+                },
+            ));
+        }
+
+        let unrevealed_message_count =
+            (buffer.len() - PROOF_LEN_FLOOR) / OCTET_SCALAR_LENGTH;
+
+        let mut offset = 0usize;
+        let mut end = OCTET_POINT_G1_LENGTH;
+
+        // Get Abar, Bbar, D
+        let A_bar = extract_point_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("A_bar".to_owned(), offset, e))?;
+        let B_bar = extract_point_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("B_bar".to_owned(), offset, e))?;
+        let D = extract_point_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("D".to_owned(), offset, e))?;
+
+        end = offset + OCTET_SCALAR_LENGTH;
+
+        // Get e^, r1^, r3^
+        let e_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("e^".to_owned(), offset, e))?;
+        let r1_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("r1^".to_owned(), offset, e))?;
+        let r3_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+            .map_err(|e| ("r3^".to_owned(), offset, e))?;
+
+        // Get (m^_j1, ..., m^_jU)
+        let mut m_hat_list =
+            Vec::<FiatShamirProof>::with_capacity(unrevealed_message_count);
+        for i in 0..unrevealed_message_count {
+            let m_hat = extract_scalar_value(&mut offset, &mut end, buffer)
+                .map_err(|e| (format!("m^_{}", i + 1), offset, e))?;
+            m_hat_list.push(m_hat);
+        }
+
+        // Get c
+        let c = Challenge(
+            extract_scalar_value(&mut offset, &mut end, buffer)
+                .map_err(|e| ("c".to_owned(), offset, e))?
+                .0,
+        );
+
+        Ok(Self {
+            A_bar,
+            B_bar,
+            D,
+            e_hat,
+            r1_hat,
+            r3_hat,
+            m_hat_list,
+            c,
+        })
+    }
+}
+
+/// Compute the Fiat Shamir challenge as defined in `ProofChallengeCalculate`
+/// of the draft.
+#[allow(clippy::too_many_arguments)]
+fn compute_challenge<T, C>(
+    A_bar: &G1Projective,
+    B_bar: &G1Projective,
+    D: &G1Projective,
+    T1: &G1Projective,
+    T2: &G1Projective,
+    disclosed_messages: &BTreeMap<usize, Message>,
+    domain: &Scalar,
+    ph: Option<T>,
+) -> Result<Challenge, Error>
+where
+    T: AsRef<[u8]>,
+    C: BbsCiphersuiteParameters,
+{
+    // c_arr = (R, i1, msg_i1, ..., iR, msg_iR, Abar, Bbar, D, T1, T2, domain)
+    // c_octs = serialize(c_arr) || I2OSP(length(ph), 8) || ph
+    let mut data_to_hash = vec![];
+    data_to_hash.extend(i2osp(
+        disclosed_messages.len() as u64,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+    for (&i, msg) in disclosed_messages {
+        data_to_hash
+            .extend(i2osp(i as u64, NON_NEGATIVE_INTEGER_ENCODING_LENGTH)?);
+        data_to_hash.extend(msg.to_bytes());
+    }
+    for point in [A_bar, B_bar, D, T1, T2] {
+        data_to_hash.extend(point_to_octets_g1(point).as_ref());
+    }
+    data_to_hash.extend(domain.to_bytes_be());
+
+    let ph_bytes = ph.as_ref().map_or(&[] as &[u8], |v| v.as_ref());
+    data_to_hash.extend(i2osp_with_data(
+        ph_bytes,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+
+    // challenge = hash_to_scalar(c_octs, challenge_dst)
+    Ok(Challenge(C::hash_to_scalar(&data_to_hash, None)?))
+}
+
+octets_type!(
+    /// A BBS signature proof of knowledge as defined in
+    /// `draft-irtf-cfrg-bbs-signatures-06`, validated on parsing.
+    BbsProofDraft06,
+    |octets| Proof::from_octets(octets).map(|_| ())
+);
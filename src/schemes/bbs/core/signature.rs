@@ -6,8 +6,12 @@ use super::{
     utils::{compute_B, compute_domain},
 };
 use crate::{
-    bbs::ciphersuites::BbsCiphersuiteParameters,
-    common::util::print_byte_array,
+    bbs::ciphersuites::{
+        bls12_381::BBS_BLS12381G1_SIGNATURE_LENGTH,
+        BbsCiphersuiteParameters,
+        BbsDraftVersion,
+    },
+    common::{serialization::fixed_octets_type, util::print_byte_array},
     curves::{
         bls12_381::{
             Bls12,
@@ -142,6 +146,24 @@ impl Signature {
         generators: &G,
         messages: M,
     ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        M: AsRef<[Message]>,
+        G: Generators,
+        C: BbsCiphersuiteParameters,
+    {
+        Self::prepare::<_, _, _, C>(PK, header, generators, messages)?
+            .sign_with_secret_key(SK)
+    }
+
+    /// Compute the secret key independent part of the `Sign` operation, to be
+    /// completed by a `BbsSigner`.
+    pub fn prepare<T, M, G, C>(
+        PK: &PublicKey,
+        header: Option<T>,
+        generators: &G,
+        messages: M,
+    ) -> Result<BbsSignerInput, Error>
     where
         T: AsRef<[u8]>,
         M: AsRef<[Message]>,
@@ -165,45 +187,41 @@ impl Signature {
                 messages: messages.len(),
             });
         }
-        if SK.0.is_zero().unwrap_u8() == 1 {
-            return Err(Error::InvalidSecretKey);
-        }
 
         // domain
         //  = hash_to_scalar((PK||L||generators||Ciphersuite_ID||header), 1)
         let domain =
             compute_domain::<_, _, C>(PK, header, messages.len(), generators)?;
 
-        // e_s_octs = serialize((SK, domain, msg_1, ..., msg_L))
-        let mut data_to_hash = vec![];
-        data_to_hash.extend(SK.to_bytes().as_ref());
-        data_to_hash.extend(domain.to_bytes_be().as_ref());
-        for m in messages {
-            data_to_hash.extend(m.to_bytes().as_ref());
+        // The octets following `SK` in the input of the hash to `e`.
+        let mut e_input = vec![];
+        match C::DRAFT_VERSION {
+            BbsDraftVersion::Legacy => {
+                // e_s_octs = serialize((SK, domain, msg_1, ..., msg_L))
+                e_input.extend(domain.to_bytes_be().as_ref());
+                for m in messages {
+                    e_input.extend(m.to_bytes().as_ref());
+                }
+            }
+            BbsDraftVersion::Draft06 => {
+                // e_octs = serialize((SK, msg_1, ..., msg_L, domain))
+                for m in messages {
+                    e_input.extend(m.to_bytes().as_ref());
+                }
+                e_input.extend(domain.to_bytes_be().as_ref());
+            }
         }
 
-        // if e_s_octs is INVALID, return INVALID
-        // e_s_expand = expand_message(e_s_octs, expand_dst, expand_len * 2)
-        // if e_s_expand is INVALID, return INVALID
-        // e = hash_to_scalar(e_s_expand[0..(expand_len - 1)])
-        // s = hash_to_scalar(e_s_expand[expand_len..(expand_len * 2 - 1)])
-        let e = C::hash_to_e(&data_to_hash)?;
-
         // B = P1 + Q * domain + H_1 * msg_1 + ... + H_L * msg_L
         let B = compute_B::<_, C>(&domain, messages, generators)?;
-        let exp = (e + SK.as_scalar()).invert();
-        let exp = if exp.is_some().unwrap_u8() == 1u8 {
-            exp.unwrap()
-        } else {
-            return Err(Error::CryptoOps {
-                cause: "failed to generate `exp` for `A` component of \
-                        signature"
-                    .to_owned(),
-            });
-        };
 
-        // A = B * (1 / (SK + e))
-        Ok(Self { A: B * exp, e })
+        Ok(BbsSignerInput {
+            ciphersuite_id: C::ID.as_octets().to_vec(),
+            dst: C::default_hash_to_scalar_dst(),
+            e_input,
+            B,
+            hash_to_scalar: C::hash_to_scalar,
+        })
     }
 
     /// Generate a bound bbs signature.
@@ -422,3 +440,138 @@ impl Signature {
         Ok(Signature { A, e })
     }
 }
+
+/// The secret key independent part of the BBS `Sign` operation, to be
+/// completed by a [`BbsSigner`].
+/// A signer outside of this crate completes the operation from the public
+/// data of the input: it computes `e = hash_to_scalar(SK || e_input, dst)`,
+/// with the `hash_to_scalar` operation of the ciphersuite `ciphersuite_id`,
+/// and `A = B * 1 / (SK + e)`.
+#[derive(Debug, Clone)]
+pub struct BbsSignerInput {
+    ciphersuite_id: Vec<u8>,
+    dst: Vec<u8>,
+    e_input: Vec<u8>,
+    B: G1Projective,
+    hash_to_scalar: HashToScalarFn,
+}
+
+// The `hash_to_scalar` operation of a ciphersuite.
+type HashToScalarFn = fn(&[u8], Option<&[u8]>) -> Result<Scalar, Error>;
+
+impl BbsSignerInput {
+    /// The identifier of the ciphersuite, which defines the `hash_to_scalar`
+    /// operation.
+    pub fn ciphersuite_id(&self) -> &[u8] {
+        &self.ciphersuite_id
+    }
+
+    /// The domain separation tag of the `hash_to_scalar` operation computing
+    /// the `e` component of the signature.
+    pub fn dst(&self) -> &[u8] {
+        &self.dst
+    }
+
+    /// The octets which, prefixed with the serialized secret key `SK`, are
+    /// hashed to the `e` component of the signature with the `hash_to_scalar`
+    /// operation of the ciphersuite.
+    pub fn e_input(&self) -> &[u8] {
+        &self.e_input
+    }
+
+    /// The point `B` of G1, in compressed form, such that the `A` component
+    /// of the signature is `B * 1 / (SK + e)`.
+    pub fn b(&self) -> [u8; OCTET_POINT_G1_LENGTH] {
+        point_to_octets_g1(&self.B)
+    }
+
+    /// Serialize the public data of the input, to be sent to a signer, as
+    /// `I2OSP(len(ciphersuite_id), 8) || ciphersuite_id || I2OSP(len(dst), 8)
+    /// || dst || B || I2OSP(len(e_input), 8) || e_input`, with `B` in
+    /// compressed form.
+    pub fn to_octets(&self) -> Vec<u8> {
+        let mut octets = Vec::with_capacity(
+            3 * 8
+                + self.ciphersuite_id.len()
+                + self.dst.len()
+                + OCTET_POINT_G1_LENGTH
+                + self.e_input.len(),
+        );
+        octets.extend((self.ciphersuite_id.len() as u64).to_be_bytes());
+        octets.extend(&self.ciphersuite_id);
+        octets.extend((self.dst.len() as u64).to_be_bytes());
+        octets.extend(&self.dst);
+        octets.extend(self.b());
+        octets.extend((self.e_input.len() as u64).to_be_bytes());
+        octets.extend(&self.e_input);
+        octets
+    }
+
+    /// Complete the `Sign` operation with the secret key `sk`.
+    pub fn sign(
+        &self,
+        sk: &SecretKey,
+    ) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error> {
+        Ok(self.sign_with_secret_key(sk)?.to_octets())
+    }
+
+    fn sign_with_secret_key(&self, SK: &SecretKey) -> Result<Signature, Error> {
+        if SK.0.is_zero().unwrap_u8() == 1 {
+            return Err(Error::InvalidSecretKey);
+        }
+
+        // e = hash_to_scalar(SK || e_input, dst)
+        let data_to_hash = [SK.to_bytes().as_ref(), &self.e_input].concat();
+        let e = (self.hash_to_scalar)(&data_to_hash, Some(&self.dst))?;
+
+        let exp = (e + SK.as_scalar()).invert();
+        let exp = if exp.is_some().unwrap_u8() == 1u8 {
+            exp.unwrap()
+        } else {
+            return Err(Error::CryptoOps {
+                cause: "failed to generate `exp` for `A` component of \
+                        signature"
+                    .to_owned(),
+            });
+        };
+
+        // A = B * (1 / (SK + e))
+        Ok(Signature { A: self.B * exp, e })
+    }
+}
+
+/// Performs the secret key dependent steps of the BBS `Sign` operation, i.e.
+/// the computation of `e` and of `A = B * 1 / (SK + e)`. This allows the
+/// secret key to be held by a key management service or an HSM rather than
+/// by the application.
+pub trait BbsSigner {
+    /// Public key of the signer.
+    fn public_key(&self) -> Result<PublicKey, Error>;
+
+    /// Complete the `Sign` operation for `input` and return the signature.
+    fn sign(
+        &self,
+        input: &BbsSignerInput,
+    ) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>;
+}
+
+/// The in-process signer.
+impl BbsSigner for SecretKey {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.into())
+    }
+
+    fn sign(
+        &self,
+        input: &BbsSignerInput,
+    ) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error> {
+        input.sign(self)
+    }
+}
+
+fixed_octets_type!(
+    /// A BBS signature, validated on parsing.
+    BbsSignature,
+    BBS_BLS12381G1_SIGNATURE_LENGTH,
+    |octets| Signature::from_octets(octets).map(|_| ())
+);
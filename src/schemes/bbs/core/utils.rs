@@ -63,7 +63,7 @@ where
         data_to_hash.extend(point_to_octets_g1(&generator).as_ref());
     }
 
-    data_to_hash.extend(C::ID.as_octets());
+    data_to_hash.extend(C::ID.api_id());
 
     let _header_bytes = header.as_ref().map_or(&[] as &[u8], |v| v.as_ref());
     data_to_hash.extend(i2osp_with_data(
@@ -14,7 +14,7 @@ use crate::{
     error::Error,
     schemes::bbs::core::{
         key_pair::PublicKey,
-        proof::Proof,
+        proof::{BbsProofInspection, Proof},
         signature::Signature,
         types::Message,
     },
@@ -33,6 +33,15 @@ pub fn get_proof_size(num_undisclosed_messages: usize) -> usize {
     Proof::get_size(num_undisclosed_messages)
 }
 
+/// Parse a BBS bound signature proof of knowledge, without verifying it, and
+/// return its components.
+/// Malformed proofs are reported with `Error::MalformedProofComponent`.
+pub fn inspect_proof<T: AsRef<[u8]>>(
+    proof: T,
+) -> Result<BbsProofInspection, Error> {
+    Proof::inspect(proof)
+}
+
 // Generate a BBS bound signature proof of knowledge.
 pub(crate) fn proof_gen<T, C>(
     request: &BbsBoundProofGenRequest<'_, T>,
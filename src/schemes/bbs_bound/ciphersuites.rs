@@ -1,2 +1,162 @@
+use crate::{
+    bbs::ciphersuites::bls12_381::BBS_BLS12381G1_SIGNATURE_LENGTH,
+    bbs_bound::{
+        BbsBoundProofGenRequest,
+        BbsBoundProofVerifyRequest,
+        BbsBoundSignRequest,
+        BbsBoundVerifyRequest,
+        BlsKeyPopGenRequest,
+        BlsKeyPopVerifyRequest,
+    },
+    bls::ciphersuites::bls12_381::BLS_SIG_BLS12381G2_SIGNATURE_LENGTH,
+    common::ciphersuite::CipherSuiteId,
+    Error,
+};
+use core::{fmt, str::FromStr};
+
 /// BBS-BLS12-381-G1-Sha-256-BLS-SIG-BLS12-381-G2-SHA-256 ciphersuite.
 pub mod bls12_381_bbs_g1_bls_sig_g2_sha_256;
+
+/// A BBS bound ciphersuite selected at runtime, e.g. from credential metadata.
+/// A bound ciphersuite is identified by the identifier of the BBS ciphersuite
+/// it signs with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BbsBoundCiphersuite {
+    /// The `bls12_381_bbs_g1_bls_sig_g2_sha_256` ciphersuite.
+    Bls12381BbsG1BlsSigG2Sha256,
+}
+
+impl BbsBoundCiphersuite {
+    /// All the supported ciphersuites.
+    pub const ALL: [Self; 1] = [Self::Bls12381BbsG1BlsSigG2Sha256];
+
+    fn id(&self) -> CipherSuiteId {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                CipherSuiteId::BbsBls12381G1XmdSha256
+            }
+        }
+    }
+
+    /// The ciphersuite identifier as octets.
+    pub fn as_octets(&self) -> &'static [u8] {
+        self.id().as_octets()
+    }
+
+    /// The ciphersuite identifier as a string.
+    pub fn as_str(&self) -> &'static str {
+        // unwrap() is safe here since ciphersuite identifiers are ASCII
+        core::str::from_utf8(self.as_octets()).unwrap()
+    }
+
+    /// Get the ciphersuite with identifier `octets`.
+    pub fn from_octets(octets: &[u8]) -> Result<Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_octets() == octets)
+            .ok_or_else(|| Error::BadParams {
+                cause: "unknown BBS bound ciphersuite identifier".to_owned(),
+            })
+    }
+
+    /// Generate a commitment to a BLS secret key with this ciphersuite.
+    pub fn bls_key_pop(
+        &self,
+        request: &BlsKeyPopGenRequest<'_>,
+    ) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error> {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                bls12_381_bbs_g1_bls_sig_g2_sha_256::bls_key_pop(request)
+            }
+        }
+    }
+
+    /// Verify a commitment to a BLS secret key with this ciphersuite.
+    pub fn bls_key_pop_verify(
+        &self,
+        request: &BlsKeyPopVerifyRequest<'_>,
+    ) -> Result<bool, Error> {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                bls12_381_bbs_g1_bls_sig_g2_sha_256::bls_key_pop_verify(request)
+            }
+        }
+    }
+
+    /// Create a BBS bound signature with this ciphersuite.
+    /// Security Warning: `secret_key` and `public_key` in `request` must be
+    /// related key-pair generated using `KeyPair` APIs.
+    pub fn sign<T>(
+        &self,
+        request: &BbsBoundSignRequest<'_, T>,
+    ) -> Result<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH], Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                bls12_381_bbs_g1_bls_sig_g2_sha_256::sign(request)
+            }
+        }
+    }
+
+    /// Verify a BBS bound signature with this ciphersuite.
+    pub fn verify<T>(
+        &self,
+        request: &BbsBoundVerifyRequest<'_, T>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                bls12_381_bbs_g1_bls_sig_g2_sha_256::verify(request)
+            }
+        }
+    }
+
+    /// Generate a BBS bound signature proof of knowledge with this
+    /// ciphersuite.
+    pub fn proof_gen<T>(
+        &self,
+        request: &BbsBoundProofGenRequest<'_, T>,
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                bls12_381_bbs_g1_bls_sig_g2_sha_256::proof_gen(request)
+            }
+        }
+    }
+
+    /// Verify a BBS bound signature proof of knowledge with this ciphersuite.
+    pub fn proof_verify<T>(
+        &self,
+        request: &BbsBoundProofVerifyRequest<'_, T>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        match self {
+            Self::Bls12381BbsG1BlsSigG2Sha256 => {
+                bls12_381_bbs_g1_bls_sig_g2_sha_256::proof_verify(request)
+            }
+        }
+    }
+}
+
+impl fmt::Display for BbsBoundCiphersuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BbsBoundCiphersuite {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_octets(s.as_bytes())
+    }
+}
@@ -27,6 +27,7 @@ pub use crate::schemes::bbs::core::{
         PublicKey as BbsPublicKEy,
         SecretKey as BbsSecretKey,
     },
+    proof::BbsProofInspection,
 };
 
 ///  Generate a commitment to their BLS secret key.
@@ -75,7 +76,10 @@ where
     >(request)
 }
 
-pub use crate::schemes::bbs_bound::api::proof::get_proof_size;
+pub use crate::schemes::bbs_bound::api::proof::{
+    get_proof_size,
+    inspect_proof,
+};
 
 /// Generate a BLS12-381-G1-Shake-256 BBS bound signature proof of knowledge.
 pub fn proof_gen<T>(
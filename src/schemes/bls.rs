@@ -3,3 +3,10 @@ pub(crate) mod core;
 
 /// BLS ciphersuites abstraction over core implementation.
 pub mod ciphersuites;
+
+/// Verifiable random function built on BLS unique signatures.
+pub mod vrf;
+
+/// Blind BLS signatures on the `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_`
+/// ciphersuite.
+pub mod blind;
@@ -0,0 +1,131 @@
+use crate::{
+    bls::{
+        ciphersuites::{
+            bls12_381::BLS_SIG_BLS12381G2_SIGNATURE_LENGTH,
+            bls12_381_g2_sha_256_nul::Bls12381G2XmdSha256NulCipherSuiteParameter,
+        },
+        core::key_pair::SecretKey,
+    },
+    common::{
+        hash_param::h2c::HashToCurveParameter,
+        util::create_random_scalar,
+    },
+    curves::{
+        bls12_381::{G2Affine, G2Projective, Scalar, OCTET_POINT_G2_LENGTH},
+        point_serde::{octets_to_point_g2, point_to_octets_g2},
+    },
+    Error,
+};
+use ff::Field;
+use group::Group;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+/// "BlindedMessage" length in bytes.
+pub const BLS_SIG_BLS12381G2_BLINDED_MESSAGE_LENGTH: usize =
+    OCTET_POINT_G2_LENGTH;
+
+/// Blinding factor used to blind a message and to unblind the resulting
+/// signature. It must be kept secret by the requester and used only once.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BlindingFactor(Scalar);
+
+impl core::fmt::Debug for BlindingFactor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BlindingFactor(****)")
+    }
+}
+
+impl Drop for BlindingFactor {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Blind a message to be signed. Returns the blinded message, to be sent to
+/// the signer, and the blinding factor to later unblind the signature.
+pub fn blind<T, R>(
+    message: T,
+    rng: &mut R,
+) -> Result<
+    (
+        [u8; BLS_SIG_BLS12381G2_BLINDED_MESSAGE_LENGTH],
+        BlindingFactor,
+    ),
+    Error,
+>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    let message = message.as_ref();
+    // Error out if there is no `Message`
+    if message.is_empty() {
+        return Err(Error::BadParams {
+            cause: "nothing to blind".to_owned(),
+        });
+    }
+    let r = create_random_scalar(rng)?;
+    if r.is_zero().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+
+    let q = Bls12381G2XmdSha256NulCipherSuiteParameter::hash_to_g2(
+        message,
+        &Bls12381G2XmdSha256NulCipherSuiteParameter::default_hash_to_point_dst(
+        ),
+    )?;
+    Ok((point_to_octets_g2(&(q * r)), BlindingFactor(r)))
+}
+
+/// Sign a blinded message. The signer learns nothing about the message.
+pub fn blind_sign(
+    sk: &SecretKey,
+    blinded_message: &[u8; BLS_SIG_BLS12381G2_BLINDED_MESSAGE_LENGTH],
+) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error> {
+    if sk.0.is_zero().unwrap_u8() == 1 {
+        return Err(Error::InvalidSecretKey);
+    }
+    // Decode without the implicit subgroup check, so that a point outside
+    // of the prime order subgroup is rejected explicitly below, before any
+    // secret key operation.
+    let blinded_message = G2Affine::from_compressed_unchecked(blinded_message);
+    if blinded_message.is_none().unwrap_u8() == 1 {
+        return Err(Error::BadEncoding);
+    }
+    let blinded_message = blinded_message.unwrap();
+    if blinded_message.is_identity().unwrap_u8() == 1 {
+        return Err(Error::PointIsIdentity);
+    }
+    if (blinded_message.is_on_curve() & blinded_message.is_torsion_free())
+        .unwrap_u8()
+        == 0
+    {
+        return Err(Error::BadParams {
+            cause: "blinded message is not in the G2 subgroup".to_owned(),
+        });
+    }
+
+    Ok(point_to_octets_g2(
+        &(G2Projective::from(blinded_message) * (*sk.0)),
+    ))
+}
+
+/// Unblind a signature produced by `blind_sign`. The result is a standard
+/// signature of the original message for the
+/// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite.
+pub fn unblind(
+    blinded_signature: &[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH],
+    blinding_factor: &BlindingFactor,
+) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error> {
+    let blinded_signature = octets_to_point_g2(blinded_signature)?;
+    if blinded_signature.is_identity().unwrap_u8() == 1 {
+        return Err(Error::PointIsIdentity);
+    }
+    let r_inv = blinding_factor.0.invert();
+    if r_inv.is_none().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+
+    Ok(point_to_octets_g2(&(blinded_signature * r_inv.unwrap())))
+}
@@ -1,12 +1,29 @@
 use crate::{
-    common::hash_param::h2c::HashToCurveParameter,
+    common::{
+        ciphersuite::CipherSuiteId,
+        hash_param::h2c::HashToCurveParameter,
+    },
     curves::bls12_381::{G1Projective, G2Projective},
+    Error,
 };
+use bls12_381::BLS_SIG_BLS12381G2_SIGNATURE_LENGTH;
+use bls12_381_g1::BLS_SIG_BLS12381G1_SIGNATURE_LENGTH;
+use core::{convert::TryFrom, fmt, str::FromStr};
 use group::Group;
 
 /// BLS-SIG BLS12-381 ciphersuites.
 pub mod bls12_381;
 
+/// BLS-SIG BLS12-381 minimal-signature-size ciphersuites.
+pub mod bls12_381_g1;
+
+/// BLS BLS12-381-G1-Sha-256-Aug ciphersuites.
+pub mod bls12_381_g1_sha_256_aug;
+/// BLS BLS12-381-G1-Sha-256-Nul ciphersuites.
+pub mod bls12_381_g1_sha_256_nul;
+/// BLS BLS12-381-G1-Sha-256-Pop ciphersuites.
+pub mod bls12_381_g1_sha_256_pop;
+
 /// BLS BLS12-381-G2-Shake-256-Aug ciphersuites.
 pub mod bls12_381_g2_sha_256_aug;
 /// BLS BLS12-381-G2-Shake-256-Nul ciphersuites.
@@ -31,3 +48,191 @@ pub(crate) trait BlsSigAugCiphersuiteParameters:
     BlsCiphersuiteParameters
 {
 }
+
+/// A BLS ciphersuite selected at runtime, e.g. from credential metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlsCiphersuite {
+    /// The `bls12_381_g2_sha_256_nul` ciphersuite.
+    Bls12381G2Sha256Nul,
+    /// The `bls12_381_g2_sha_256_aug` ciphersuite.
+    Bls12381G2Sha256Aug,
+    /// The `bls12_381_g2_sha_256_pop` ciphersuite.
+    Bls12381G2Sha256Pop,
+    /// The `bls12_381_g1_sha_256_nul` ciphersuite.
+    Bls12381G1Sha256Nul,
+    /// The `bls12_381_g1_sha_256_aug` ciphersuite.
+    Bls12381G1Sha256Aug,
+    /// The `bls12_381_g1_sha_256_pop` ciphersuite.
+    Bls12381G1Sha256Pop,
+}
+
+impl BlsCiphersuite {
+    /// All the supported ciphersuites.
+    pub const ALL: [Self; 6] = [
+        Self::Bls12381G2Sha256Nul,
+        Self::Bls12381G2Sha256Aug,
+        Self::Bls12381G2Sha256Pop,
+        Self::Bls12381G1Sha256Nul,
+        Self::Bls12381G1Sha256Aug,
+        Self::Bls12381G1Sha256Pop,
+    ];
+
+    fn id(&self) -> CipherSuiteId {
+        match self {
+            Self::Bls12381G2Sha256Nul => {
+                CipherSuiteId::BlsSigBls12381G2XmdSha256Nul
+            }
+            Self::Bls12381G2Sha256Aug => {
+                CipherSuiteId::BlsSigBls12381G2XmdSha256Aug
+            }
+            Self::Bls12381G2Sha256Pop => {
+                CipherSuiteId::BlsSigBls12381G2XmdSha256Pop
+            }
+            Self::Bls12381G1Sha256Nul => {
+                CipherSuiteId::BlsSigBls12381G1XmdSha256Nul
+            }
+            Self::Bls12381G1Sha256Aug => {
+                CipherSuiteId::BlsSigBls12381G1XmdSha256Aug
+            }
+            Self::Bls12381G1Sha256Pop => {
+                CipherSuiteId::BlsSigBls12381G1XmdSha256Pop
+            }
+        }
+    }
+
+    /// The ciphersuite identifier as octets.
+    pub fn as_octets(&self) -> &'static [u8] {
+        self.id().as_octets()
+    }
+
+    /// The ciphersuite identifier as a string.
+    pub fn as_str(&self) -> &'static str {
+        // unwrap() is safe here since ciphersuite identifiers are ASCII
+        core::str::from_utf8(self.as_octets()).unwrap()
+    }
+
+    /// Get the ciphersuite with identifier `octets`.
+    pub fn from_octets(octets: &[u8]) -> Result<Self, Error> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.as_octets() == octets)
+            .ok_or_else(|| Error::BadParams {
+                cause: "unknown BLS ciphersuite identifier".to_owned(),
+            })
+    }
+
+    /// Sign a message with the big-endian encoded `secret_key`.
+    pub fn sign<T>(
+        &self,
+        secret_key: &[u8],
+        message: T,
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        let secret_key = secret_key.to_vec();
+        Ok(match self {
+            Self::Bls12381G2Sha256Nul => bls12_381_g2_sha_256_nul::sign(
+                &bls12_381::SecretKey::from_vec(&secret_key)?,
+                message,
+            )?
+            .to_vec(),
+            Self::Bls12381G2Sha256Aug => bls12_381_g2_sha_256_aug::sign(
+                &bls12_381::SecretKey::from_vec(&secret_key)?,
+                message,
+            )?
+            .to_vec(),
+            Self::Bls12381G2Sha256Pop => bls12_381_g2_sha_256_pop::sign(
+                &bls12_381::SecretKey::from_vec(&secret_key)?,
+                message,
+            )?
+            .to_vec(),
+            Self::Bls12381G1Sha256Nul => bls12_381_g1_sha_256_nul::sign(
+                &bls12_381_g1::SecretKey::from_vec(&secret_key)?,
+                message,
+            )?
+            .to_vec(),
+            Self::Bls12381G1Sha256Aug => bls12_381_g1_sha_256_aug::sign(
+                &bls12_381_g1::SecretKey::from_vec(&secret_key)?,
+                message,
+            )?
+            .to_vec(),
+            Self::Bls12381G1Sha256Pop => bls12_381_g1_sha_256_pop::sign(
+                &bls12_381_g1::SecretKey::from_vec(&secret_key)?,
+                message,
+            )?
+            .to_vec(),
+        })
+    }
+
+    /// Verify a `signature` on a message with the compressed `public_key`.
+    pub fn verify<T>(
+        &self,
+        public_key: &[u8],
+        message: T,
+        signature: &[u8],
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        let public_key = public_key.to_vec();
+        match self {
+            Self::Bls12381G2Sha256Nul => bls12_381_g2_sha_256_nul::verify(
+                &bls12_381::PublicKey::from_vec(&public_key)?,
+                message,
+                &<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH]>::try_from(
+                    signature,
+                )?,
+            ),
+            Self::Bls12381G2Sha256Aug => bls12_381_g2_sha_256_aug::verify(
+                &bls12_381::PublicKey::from_vec(&public_key)?,
+                message,
+                &<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH]>::try_from(
+                    signature,
+                )?,
+            ),
+            Self::Bls12381G2Sha256Pop => bls12_381_g2_sha_256_pop::verify(
+                &bls12_381::PublicKey::from_vec(&public_key)?,
+                message,
+                &<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH]>::try_from(
+                    signature,
+                )?,
+            ),
+            Self::Bls12381G1Sha256Nul => bls12_381_g1_sha_256_nul::verify(
+                &bls12_381_g1::PublicKey::from_vec(&public_key)?,
+                message,
+                &<[u8; BLS_SIG_BLS12381G1_SIGNATURE_LENGTH]>::try_from(
+                    signature,
+                )?,
+            ),
+            Self::Bls12381G1Sha256Aug => bls12_381_g1_sha_256_aug::verify(
+                &bls12_381_g1::PublicKey::from_vec(&public_key)?,
+                message,
+                &<[u8; BLS_SIG_BLS12381G1_SIGNATURE_LENGTH]>::try_from(
+                    signature,
+                )?,
+            ),
+            Self::Bls12381G1Sha256Pop => bls12_381_g1_sha_256_pop::verify(
+                &bls12_381_g1::PublicKey::from_vec(&public_key)?,
+                message,
+                &<[u8; BLS_SIG_BLS12381G1_SIGNATURE_LENGTH]>::try_from(
+                    signature,
+                )?,
+            ),
+        }
+    }
+}
+
+impl fmt::Display for BlsCiphersuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for BlsCiphersuite {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_octets(s.as_bytes())
+    }
+}
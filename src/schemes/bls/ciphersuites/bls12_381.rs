@@ -4,9 +4,13 @@ use crate::curves::bls12_381::{
     OCTET_SCALAR_LENGTH,
 };
 
+use crate::schemes::ibe::core::bf::{CIPHERTEXT_OVERHEAD, MAX_MESSAGE_LENGTH};
+
 pub use crate::schemes::bls::core::{
     constants::MIN_KEY_GEN_IKM_LENGTH,
     key_pair::{KeyPair, PublicKey, SecretKey},
+    signature::{BlsSignature, BlsSigner},
+    tlock::round_message,
 };
 
 /// "SecretKey" length in bytes for "BLS_SIG_BLS12381G2" ciphersuite.
@@ -17,3 +21,13 @@ pub const BLS_SIG_BLS12381G2_PUBLIC_KEY_LENGTH: usize = OCTET_POINT_G1_LENGTH;
 
 /// "Signature" length in bytes for "BLS_SIG_BLS12381G2" ciphersuite.
 pub const BLS_SIG_BLS12381G2_SIGNATURE_LENGTH: usize = OCTET_POINT_G2_LENGTH;
+
+/// Length in bytes of a timelock ciphertext in excess of the message length,
+/// for "BLS_SIG_BLS12381G2" ciphersuite.
+pub const BLS_SIG_BLS12381G2_TIMELOCK_CIPHERTEXT_OVERHEAD: usize =
+    CIPHERTEXT_OVERHEAD;
+
+/// Maximum length in bytes of a message encrypted by timelock encryption, for
+/// "BLS_SIG_BLS12381G2" ciphersuite.
+pub const BLS_SIG_BLS12381G2_TIMELOCK_MAX_MESSAGE_LENGTH: usize =
+    MAX_MESSAGE_LENGTH;
@@ -0,0 +1,20 @@
+use crate::curves::bls12_381::{
+    OCTET_POINT_G1_LENGTH,
+    OCTET_POINT_G2_LENGTH,
+    OCTET_SCALAR_LENGTH,
+};
+
+pub use crate::schemes::bls::core::{
+    constants::MIN_KEY_GEN_IKM_LENGTH,
+    key_pair_g2::{KeyPair, PublicKey, SecretKey},
+    signature_g1::{BlsSignature, BlsSigner},
+};
+
+/// "SecretKey" length in bytes for "BLS_SIG_BLS12381G1" ciphersuite.
+pub const BLS_SIG_BLS12381G1_SECRET_KEY_LENGTH: usize = OCTET_SCALAR_LENGTH;
+
+/// "PublicKey" length in bytes for "BLS_SIG_BLS12381G1" ciphersuite.
+pub const BLS_SIG_BLS12381G1_PUBLIC_KEY_LENGTH: usize = OCTET_POINT_G2_LENGTH;
+
+/// "Signature" length in bytes for "BLS_SIG_BLS12381G1" ciphersuite.
+pub const BLS_SIG_BLS12381G1_SIGNATURE_LENGTH: usize = OCTET_POINT_G1_LENGTH;
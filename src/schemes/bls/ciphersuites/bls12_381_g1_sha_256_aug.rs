@@ -0,0 +1,104 @@
+use crate::{
+    bls::core::{
+        key_pair_g2::{PublicKey, SecretKey},
+        signature_g1::{BlsSigner, Signature},
+    },
+    common::{
+        ciphersuite::{CipherSuiteId, CipherSuiteParameter},
+        hash_param::{h2c::HashToCurveParameter, ExpandMessageParameter},
+    },
+    curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    Error,
+};
+use sha2::Sha256;
+
+use super::{
+    bls12_381_g1::BLS_SIG_BLS12381G1_SIGNATURE_LENGTH,
+    BlsCiphersuiteParameters,
+    BlsSigAugCiphersuiteParameters,
+};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Bls12381G1XmdSha256AugCipherSuiteParameter;
+
+impl CipherSuiteParameter for Bls12381G1XmdSha256AugCipherSuiteParameter {
+    const ID: CipherSuiteId = CipherSuiteId::BlsSigBls12381G1XmdSha256Aug;
+}
+
+impl BlsSigAugCiphersuiteParameters
+    for Bls12381G1XmdSha256AugCipherSuiteParameter
+{
+}
+
+impl ExpandMessageParameter for Bls12381G1XmdSha256AugCipherSuiteParameter {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+impl HashToCurveParameter for Bls12381G1XmdSha256AugCipherSuiteParameter {}
+
+impl BlsCiphersuiteParameters for Bls12381G1XmdSha256AugCipherSuiteParameter {}
+
+/// Sign a message.
+pub fn sign<T>(
+    sk: &SecretKey,
+    message: T,
+) -> Result<[u8; BLS_SIG_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+{
+    let pk: PublicKey = sk.into();
+    let data_to_sign = [pk.to_octets().as_ref(), message.as_ref()].concat();
+    let signature = crate::schemes::bls::core::signature_g1::Signature::new::<
+        _,
+        Bls12381G1XmdSha256AugCipherSuiteParameter,
+    >(
+        sk,
+        data_to_sign,
+        Bls12381G1XmdSha256AugCipherSuiteParameter::default_hash_to_point_dst(),
+    )?;
+    Ok(signature.to_octets())
+}
+
+/// Sign a message, delegating the secret key dependent step to `signer`.
+pub fn sign_with_signer<S, T>(
+    signer: &S,
+    message: T,
+) -> Result<[u8; BLS_SIG_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    S: BlsSigner,
+    T: AsRef<[u8]>,
+{
+    let pk = signer.public_key()?;
+    let data_to_sign = [pk.to_octets().as_ref(), message.as_ref()].concat();
+    let signature = Signature::new_with_signer::<
+        _,
+        Bls12381G1XmdSha256AugCipherSuiteParameter,
+        _,
+    >(
+        signer,
+        data_to_sign,
+        Bls12381G1XmdSha256AugCipherSuiteParameter::default_hash_to_point_dst(),
+    )?;
+    Ok(signature.to_octets())
+}
+
+/// Verify a `Signature`.
+pub fn verify<T>(
+    pk: &PublicKey,
+    message: T,
+    signature: &[u8; BLS_SIG_BLS12381G1_SIGNATURE_LENGTH],
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    let data_to_sign = [pk.to_octets().as_ref(), message.as_ref()].concat();
+    let signature =
+        crate::schemes::bls::core::signature_g1::Signature::from_octets(
+            signature,
+        )?;
+    signature.verify::<_, Bls12381G1XmdSha256AugCipherSuiteParameter>(
+        pk,
+        data_to_sign,
+        Bls12381G1XmdSha256AugCipherSuiteParameter::default_hash_to_point_dst(),
+    )
+}
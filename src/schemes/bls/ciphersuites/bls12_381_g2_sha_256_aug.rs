@@ -1,5 +1,8 @@
 use crate::{
-    bls::core::key_pair::{PublicKey, SecretKey},
+    bls::core::{
+        key_pair::{PublicKey, SecretKey},
+        signature::{BlsSigner, Signature},
+    },
     common::{
         ciphersuite::{CipherSuiteId, CipherSuiteParameter},
         hash_param::{h2c::HashToCurveParameter, ExpandMessageParameter},
@@ -56,6 +59,29 @@ where
     Ok(signature.to_octets())
 }
 
+/// Sign a message, delegating the secret key dependent step to `signer`.
+pub fn sign_with_signer<S, T>(
+    signer: &S,
+    message: T,
+) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error>
+where
+    S: BlsSigner,
+    T: AsRef<[u8]>,
+{
+    let pk = signer.public_key()?;
+    let data_to_sign = [pk.to_octets().as_ref(), message.as_ref()].concat();
+    let signature = Signature::new_with_signer::<
+        _,
+        Bls12381G2XmdSha256AugCipherSuiteParameter,
+        _,
+    >(
+        signer,
+        data_to_sign,
+        Bls12381G2XmdSha256AugCipherSuiteParameter::default_hash_to_point_dst(),
+    )?;
+    Ok(signature.to_octets())
+}
+
 /// Verify a `Signature`.
 pub fn verify<T>(
     pk: &PublicKey,
@@ -1,12 +1,18 @@
 use crate::{
-    bls::core::key_pair::{PublicKey, SecretKey},
+    bls::core::{
+        key_pair::{PublicKey, SecretKey},
+        signature::{BlsSigner, Signature},
+        tlock::round_message,
+    },
     common::{
         ciphersuite::{CipherSuiteId, CipherSuiteParameter},
         hash_param::{h2c::HashToCurveParameter, ExpandMessageParameter},
     },
     curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    ibe::core::bf::{decrypt, encrypt, BfParameter, Ciphertext},
     Error,
 };
+use rand::{CryptoRng, RngCore};
 use sha2::Sha256;
 
 use super::{
@@ -29,6 +35,12 @@ impl HashToCurveParameter for Bls12381G2XmdSha256NulCipherSuiteParameter {}
 
 impl BlsCiphersuiteParameters for Bls12381G2XmdSha256NulCipherSuiteParameter {}
 
+impl BfParameter for Bls12381G2XmdSha256NulCipherSuiteParameter {
+    const DST_SUFFIX_H2: &'static [u8] = b"TLOCK_H2_";
+    const DST_SUFFIX_H3: &'static [u8] = b"TLOCK_H3_";
+    const DST_SUFFIX_H4: &'static [u8] = b"TLOCK_H4_";
+}
+
 /// Sign a message.
 pub fn sign<T>(
     sk: &SecretKey,
@@ -37,7 +49,7 @@ pub fn sign<T>(
 where
     T: AsRef<[u8]>,
 {
-    let signature = crate::schemes::bls::core::signature::Signature::new::<
+    let signature = Signature::new::<
         _,
         Bls12381G2XmdSha256NulCipherSuiteParameter,
     >(
@@ -49,6 +61,28 @@ where
     Ok(signature.to_octets())
 }
 
+/// Sign a message, delegating the secret key dependent step to `signer`.
+pub fn sign_with_signer<S, T>(
+    signer: &S,
+    message: T,
+) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error>
+where
+    S: BlsSigner,
+    T: AsRef<[u8]>,
+{
+    let signature = Signature::new_with_signer::<
+        _,
+        Bls12381G2XmdSha256NulCipherSuiteParameter,
+        _,
+    >(
+        signer,
+        message.as_ref(),
+        Bls12381G2XmdSha256NulCipherSuiteParameter::default_hash_to_point_dst()
+            .as_ref(),
+    )?;
+    Ok(signature.to_octets())
+}
+
 /// Verify a `Signature`.
 pub fn verify<T>(
     pk: &PublicKey,
@@ -58,10 +92,7 @@ pub fn verify<T>(
 where
     T: AsRef<[u8]>,
 {
-    let signature =
-        crate::schemes::bls::core::signature::Signature::from_octets(
-            signature,
-        )?;
+    let signature = Signature::from_octets(signature)?;
     signature.verify::<_, Bls12381G2XmdSha256NulCipherSuiteParameter>(
         pk,
         message.as_ref(),
@@ -69,3 +100,39 @@ where
             .as_ref(),
     )
 }
+
+/// Encrypt a message to a future `round` of a randomness beacon with public
+/// key `pk`. The message can only be decrypted once the beacon publishes its
+/// signature of `round_message(round)`.
+pub fn timelock_encrypt<T, R>(
+    pk: &PublicKey,
+    round: u64,
+    message: T,
+    rng: &mut R,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    Ok(encrypt::<Bls12381G2XmdSha256NulCipherSuiteParameter, _>(
+        &pk.0,
+        &round_message(round),
+        message.as_ref(),
+        rng,
+    )?
+    .to_octets())
+}
+
+/// Decrypt a message encrypted by `timelock_encrypt`, using the beacon
+/// signature of the round it was encrypted to.
+pub fn timelock_decrypt(
+    round_signature: &[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let round_signature = Signature::from_octets(round_signature)?;
+    let ciphertext = Ciphertext::from_octets(ciphertext)?;
+    decrypt::<Bls12381G2XmdSha256NulCipherSuiteParameter>(
+        &round_signature.0,
+        &ciphertext,
+    )
+}
@@ -1,12 +1,18 @@
 use crate::{
-    bls::core::key_pair::{PublicKey, SecretKey},
+    bls::core::{
+        key_pair::{PublicKey, SecretKey},
+        signature::{BlsSigner, Signature},
+        tlock::round_message,
+    },
     common::{
         ciphersuite::{CipherSuiteId, CipherSuiteParameter},
         hash_param::{h2c::HashToCurveParameter, ExpandMessageParameter},
     },
     curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    ibe::core::bf::{decrypt, encrypt, BfParameter, Ciphertext},
     Error,
 };
+use rand::{CryptoRng, RngCore};
 use sha2::Sha256;
 
 use super::{
@@ -28,6 +34,12 @@ impl HashToCurveParameter for Bls12381G2XmdSha256PopCipherSuiteParameter {}
 
 impl BlsCiphersuiteParameters for Bls12381G2XmdSha256PopCipherSuiteParameter {}
 
+impl BfParameter for Bls12381G2XmdSha256PopCipherSuiteParameter {
+    const DST_SUFFIX_H2: &'static [u8] = b"TLOCK_H2_";
+    const DST_SUFFIX_H3: &'static [u8] = b"TLOCK_H3_";
+    const DST_SUFFIX_H4: &'static [u8] = b"TLOCK_H4_";
+}
+
 /// Sign a message.
 pub fn sign<T>(
     sk: &SecretKey,
@@ -48,6 +60,28 @@ where
     Ok(signature.to_octets())
 }
 
+/// Sign a message, delegating the secret key dependent step to `signer`.
+pub fn sign_with_signer<S, T>(
+    signer: &S,
+    message: T,
+) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error>
+where
+    S: BlsSigner,
+    T: AsRef<[u8]>,
+{
+    let signature = Signature::new_with_signer::<
+        _,
+        Bls12381G2XmdSha256PopCipherSuiteParameter,
+        _,
+    >(
+        signer,
+        message.as_ref(),
+        Bls12381G2XmdSha256PopCipherSuiteParameter::default_hash_to_point_dst()
+            .as_ref(),
+    )?;
+    Ok(signature.to_octets())
+}
+
 /// Verify a `Signature`.
 pub fn verify<T>(
     pk: &PublicKey,
@@ -100,9 +134,193 @@ pub fn pop_verify(
     )
 }
 
+/// Encrypt a message to a future `round` of a randomness beacon with public
+/// key `pk`. The message can only be decrypted once the beacon publishes its
+/// signature of `round_message(round)`.
+pub fn timelock_encrypt<T, R>(
+    pk: &PublicKey,
+    round: u64,
+    message: T,
+    rng: &mut R,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    Ok(encrypt::<Bls12381G2XmdSha256PopCipherSuiteParameter, _>(
+        &pk.0,
+        &round_message(round),
+        message.as_ref(),
+        rng,
+    )?
+    .to_octets())
+}
+
+/// Decrypt a message encrypted by `timelock_encrypt`, using the beacon
+/// signature of the round it was encrypted to.
+pub fn timelock_decrypt(
+    round_signature: &[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH],
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let round_signature = Signature::from_octets(round_signature)?;
+    let ciphertext = Ciphertext::from_octets(ciphertext)?;
+    decrypt::<Bls12381G2XmdSha256PopCipherSuiteParameter>(
+        &round_signature.0,
+        &ciphertext,
+    )
+}
+
 fn pop_dst<C>() -> Vec<u8>
 where
     C: BlsCiphersuiteParameters,
 {
     [b"BLS_POP_", &C::ID.as_octets().to_vec()[8..]].concat()
 }
+
+/// Aggregate signatures produced by different signers on the same message
+/// into a single signature.
+pub fn aggregate_signatures(
+    signatures: &[[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH]],
+) -> Result<[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH], Error> {
+    let signatures = signatures
+        .iter()
+        .map(Signature::from_octets)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Signature::aggregate(&signatures)?.to_octets())
+}
+
+/// Registry of signer public keys for multi-signatures on a common message.
+///
+/// A public key is only registered once its proof of possession has been
+/// verified, which protects the aggregate public key against rogue-key
+/// attacks. Signers of an aggregate signature are then identified by a bitmap
+/// over the registration order, where bit `i % 8` of byte `i / 8` is set if
+/// the `i`-th registered signer took part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiSig {
+    public_keys: Vec<PublicKey>,
+}
+
+impl MultiSig {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a public key after verifying its proof of possession, and
+    /// return the index of the signer in the registry.
+    pub fn register(
+        &mut self,
+        pk: &PublicKey,
+        proof: &[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH],
+    ) -> Result<usize, Error> {
+        if self.public_keys.contains(pk) {
+            return Err(Error::BadParams {
+                cause: "public key is already registered".to_owned(),
+            });
+        }
+        if !pop_verify(pk, proof)? {
+            return Err(Error::BadParams {
+                cause: "proof of possession verification failed".to_owned(),
+            });
+        }
+        self.public_keys.push(*pk);
+        Ok(self.public_keys.len() - 1)
+    }
+
+    /// Registered public keys, in registration order.
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// Number of registered signers.
+    pub fn len(&self) -> usize {
+        self.public_keys.len()
+    }
+
+    /// Whether no signer is registered.
+    pub fn is_empty(&self) -> bool {
+        self.public_keys.is_empty()
+    }
+
+    /// Length in bytes of a signer bitmap for this registry.
+    pub fn bitmap_length(&self) -> usize {
+        self.public_keys.len().div_ceil(8)
+    }
+
+    /// Create a signer bitmap from a list of signer indices.
+    pub fn signers_to_bitmap(
+        &self,
+        signers: &[usize],
+    ) -> Result<Vec<u8>, Error> {
+        let mut bitmap = vec![0u8; self.bitmap_length()];
+        for &i in signers {
+            if i >= self.public_keys.len() {
+                return Err(Error::BadParams {
+                    cause: format!(
+                        "signer index {} is out of range, registry has {} \
+                         signers",
+                        i,
+                        self.public_keys.len()
+                    ),
+                });
+            }
+            bitmap[i / 8] |= 1 << (i % 8);
+        }
+        Ok(bitmap)
+    }
+
+    /// Aggregate the public keys of the signers selected by `signers`.
+    pub fn aggregate_public_key(
+        &self,
+        signers: &[u8],
+    ) -> Result<PublicKey, Error> {
+        if signers.len() != self.bitmap_length() {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "signer bitmap length mismatch, expected {}, found {}",
+                    self.bitmap_length(),
+                    signers.len()
+                ),
+            });
+        }
+        let selected = self
+            .public_keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| signers[i / 8] & (1 << (i % 8)) != 0)
+            .map(|(_, pk)| pk.0)
+            .collect::<Vec<_>>();
+        let selected_count = signers
+            .iter()
+            .map(|b| b.count_ones() as usize)
+            .sum::<usize>();
+        // Error out if the bitmap selects unregistered signers
+        if selected.len() != selected_count {
+            return Err(Error::BadParams {
+                cause: "signer bitmap selects unregistered signers".to_owned(),
+            });
+        }
+        // Error out if there is no signer
+        if selected.is_empty() {
+            return Err(Error::BadParams {
+                cause: "signer bitmap is empty".to_owned(),
+            });
+        }
+        Ok(PublicKey(selected.into_iter().sum()))
+    }
+
+    /// Verify an aggregate signature on `message` by the signers selected by
+    /// `signers`.
+    pub fn verify<T>(
+        &self,
+        message: T,
+        signature: &[u8; BLS_SIG_BLS12381G2_SIGNATURE_LENGTH],
+        signers: &[u8],
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        verify(&self.aggregate_public_key(signers)?, message, signature)
+    }
+}
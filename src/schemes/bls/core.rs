@@ -1,5 +1,11 @@
 pub(crate) mod constants;
 pub(crate) mod signature;
+pub(crate) mod signature_g1;
+pub(crate) mod tlock;
 
 /// BLS Key pair.
 pub(crate) mod key_pair;
+
+/// BLS Key pair with public keys in G2, used by the minimal-signature-size
+/// ciphersuites.
+pub(crate) mod key_pair_g2;
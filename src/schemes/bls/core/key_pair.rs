@@ -1,4 +1,10 @@
 use super::constants::MIN_KEY_GEN_IKM_LENGTH;
+#[cfg(feature = "nonstandard_der")]
+use crate::key_encoding::der::DER_OID_BLS12381G1;
+#[cfg(feature = "jwk")]
+use crate::key_encoding::{Jwk, JwkKeyType, JWK_CRV_BLS12381G1};
+#[cfg(feature = "keystore")]
+use crate::keystore::{keystore_impl, Keystore, KeystoreKdf};
 use crate::{
     common::{
         key_pair::bbs_bls_key_pair_impl,
@@ -14,6 +20,11 @@ use crate::{
         OCTET_SCALAR_LENGTH,
     },
     error::Error,
+    key_encoding::{
+        key_encoding_impl,
+        MULTICODEC_BLS12381G1_PRIV,
+        MULTICODEC_BLS12381G1_PUB,
+    },
 };
 use ff::Field;
 use group::{Curve, Group};
@@ -31,3 +42,16 @@ bbs_bls_key_pair_impl!(
     generate_sk,
     sk_to_pk_in_g1
 );
+
+key_encoding_impl!(
+    OCTET_POINT_G1_LENGTH,
+    G1Projective,
+    G1Affine,
+    JWK_CRV_BLS12381G1,
+    MULTICODEC_BLS12381G1_PUB,
+    MULTICODEC_BLS12381G1_PRIV,
+    DER_OID_BLS12381G1
+);
+
+#[cfg(feature = "keystore")]
+keystore_impl!();
@@ -0,0 +1,57 @@
+use super::constants::MIN_KEY_GEN_IKM_LENGTH;
+#[cfg(feature = "nonstandard_der")]
+use crate::key_encoding::der::DER_OID_BLS12381G2;
+#[cfg(feature = "jwk")]
+use crate::key_encoding::{Jwk, JwkKeyType, JWK_CRV_BLS12381G2};
+#[cfg(feature = "keystore")]
+use crate::keystore::{keystore_impl, Keystore, KeystoreKdf};
+use crate::{
+    common::{
+        key_pair::bbs_bls_key_pair_impl,
+        util::{print_byte_array, vec_to_byte_array},
+    },
+    curves::bls12_381::{
+        generate_sk,
+        sk_to_pk_in_g2,
+        G2Affine,
+        G2Projective,
+        Scalar,
+        OCTET_POINT_G2_LENGTH,
+        OCTET_SCALAR_LENGTH,
+    },
+    error::Error,
+    key_encoding::{
+        key_encoding_impl,
+        MULTICODEC_BLS12381G2_PRIV,
+        MULTICODEC_BLS12381G2_PUB,
+    },
+};
+use ff::Field;
+use group::{Curve, Group};
+use rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use subtle::Choice;
+use zeroize::Zeroize;
+
+bbs_bls_key_pair_impl!(
+    MIN_KEY_GEN_IKM_LENGTH,
+    OCTET_SCALAR_LENGTH,
+    OCTET_POINT_G2_LENGTH,
+    G2Projective,
+    G2Affine,
+    generate_sk,
+    sk_to_pk_in_g2
+);
+
+key_encoding_impl!(
+    OCTET_POINT_G2_LENGTH,
+    G2Projective,
+    G2Affine,
+    JWK_CRV_BLS12381G2,
+    MULTICODEC_BLS12381G2_PUB,
+    MULTICODEC_BLS12381G2_PRIV,
+    DER_OID_BLS12381G2
+);
+
+#[cfg(feature = "keystore")]
+keystore_impl!();
@@ -1,6 +1,6 @@
 use crate::{
     bls::ciphersuites::BlsCiphersuiteParameters,
-    common::util::print_byte_array,
+    common::{serialization::fixed_octets_type, util::print_byte_array},
     curves::{
         bls12_381::{Bls12, G2Prepared, G2Projective, OCTET_POINT_G2_LENGTH},
         point_serde::{octets_to_point_g2, point_to_octets_g2},
@@ -25,7 +25,7 @@ use super::key_pair::{PublicKey, SecretKey};
 
 /// A BLS signature
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub(crate) struct Signature(G2Projective);
+pub(crate) struct Signature(pub(crate) G2Projective);
 
 impl core::fmt::Display for Signature {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -127,6 +127,59 @@ impl Signature {
         Ok(Self(q * (*sk.0)))
     }
 
+    /// Generate a new `Signature`, delegating the multiplication by the
+    /// secret key to `signer`. The signature is verified with the public key
+    /// of `signer`, a signature which does not verify is an error.
+    pub fn new_with_signer<T, C, S>(
+        signer: &S,
+        message: T,
+        dst: T,
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BlsCiphersuiteParameters,
+        S: BlsSigner,
+    {
+        let message = message.as_ref();
+
+        // Input parameter checks
+        // Error out if there is no `Messages`
+        if message.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to sign".to_owned(),
+            });
+        }
+
+        let q = C::hash_to_g2(message, dst.as_ref())?;
+
+        // Check the output of the signer against its public key, a faulty
+        // signer must not produce a signature which does not verify.
+        let signature =
+            Self::from_octets(&signer.sign_point(&point_to_octets_g2(&q))?)?;
+        if !signature.verify::<_, C>(
+            &signer.public_key()?,
+            message,
+            dst.as_ref(),
+        )? {
+            return Err(Error::CryptoOps {
+                cause: "the signer output does not verify with its public key"
+                    .to_owned(),
+            });
+        }
+        Ok(signature)
+    }
+
+    /// Aggregate a list of `Signature`s into a single `Signature`.
+    pub fn aggregate(signatures: &[Self]) -> Result<Self, Error> {
+        // Error out if there is no `Signature`
+        if signatures.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to aggregate".to_owned(),
+            });
+        }
+        Ok(Self(signatures.iter().map(|s| s.0).sum()))
+    }
+
     /// Check if the `Self` is valid.
     pub fn is_valid(&self) -> Choice {
         (!self.0.is_identity())
@@ -197,3 +250,45 @@ impl Signature {
         Ok(signature)
     }
 }
+
+/// Performs the secret key dependent step of the BLS `Sign` operation, i.e.
+/// the multiplication of the message hashed to G2 by the secret key. This
+/// allows the secret key to be held by a key management service or an HSM
+/// rather than by the application.
+pub trait BlsSigner {
+    /// Public key of the signer.
+    fn public_key(&self) -> Result<PublicKey, Error>;
+
+    /// Multiply `point`, a point of G2 in compressed form, by the secret key
+    /// and return the product in compressed form.
+    fn sign_point(
+        &self,
+        point: &[u8; OCTET_POINT_G2_LENGTH],
+    ) -> Result<[u8; OCTET_POINT_G2_LENGTH], Error>;
+}
+
+/// The in-process signer.
+impl BlsSigner for SecretKey {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.into())
+    }
+
+    fn sign_point(
+        &self,
+        point: &[u8; OCTET_POINT_G2_LENGTH],
+    ) -> Result<[u8; OCTET_POINT_G2_LENGTH], Error> {
+        if self.0.is_zero().unwrap_u8() == 1 {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok(point_to_octets_g2(
+            &(octets_to_point_g2(point)? * (*self.0)),
+        ))
+    }
+}
+
+fixed_octets_type!(
+    /// A BLS signature in G2, validated on parsing.
+    BlsSignature,
+    OCTET_POINT_G2_LENGTH,
+    |octets| Signature::from_octets(octets).map(|_| ())
+);
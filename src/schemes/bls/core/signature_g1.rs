@@ -0,0 +1,283 @@
+use crate::{
+    bls::ciphersuites::BlsCiphersuiteParameters,
+    common::{serialization::fixed_octets_type, util::print_byte_array},
+    curves::{
+        bls12_381::{Bls12, G1Projective, G2Prepared, OCTET_POINT_G1_LENGTH},
+        point_serde::{octets_to_point_g1, point_to_octets_g1},
+    },
+    error::Error,
+};
+use core::fmt;
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use serde::{
+    de::{Error as DError, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use subtle::{Choice, ConditionallySelectable};
+
+use super::key_pair_g2::{PublicKey, SecretKey};
+
+/// A BLS signature in G1, as used by the minimal-signature-size ciphersuites.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Signature(G1Projective);
+
+impl core::fmt::Display for Signature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Signature(")?;
+        print_byte_array!(f, &self.0.to_affine().to_compressed());
+        write!(f, ")",)
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = self.to_octets();
+        let mut seq = s.serialize_tuple(bytes.len())?;
+        for b in &bytes {
+            seq.serialize_element(b)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(d: D) -> Result<Signature, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor;
+
+        impl<'de> Visitor<'de> for ArrayVisitor {
+            type Value = Signature;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "expected byte array")
+            }
+
+            #[allow(clippy::needless_range_loop)]
+            fn visit_seq<A>(self, mut seq: A) -> Result<Signature, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut arr = [0u8; Signature::SIZE_BYTES];
+                for i in 0..arr.len() {
+                    arr[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| DError::invalid_length(i, &self))?;
+                }
+                Signature::from_octets(&arr).map_err(|_| {
+                    DError::invalid_value(
+                        serde::de::Unexpected::Bytes(&arr),
+                        &self,
+                    )
+                })
+            }
+        }
+
+        d.deserialize_tuple(Signature::SIZE_BYTES, ArrayVisitor)
+    }
+}
+
+impl Default for Signature {
+    fn default() -> Self {
+        Self(G1Projective::identity())
+    }
+}
+
+impl ConditionallySelectable for Signature {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(G1Projective::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Signature {
+    /// The number of bytes in a `Signature`.
+    pub const SIZE_BYTES: usize = OCTET_POINT_G1_LENGTH;
+
+    /// Generate a new `Signature`.
+    pub fn new<T, C>(sk: &SecretKey, message: T, dst: T) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BlsCiphersuiteParameters,
+    {
+        let message = message.as_ref();
+
+        // Input parameter checks
+        // Error out if there is no `Messages`
+        if message.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to sign".to_owned(),
+            });
+        }
+        if sk.0.is_zero().unwrap_u8() == 1 {
+            return Err(Error::InvalidSecretKey);
+        }
+
+        let q = C::hash_to_g1(message, dst.as_ref())?;
+
+        Ok(Self(q * (*sk.0)))
+    }
+
+    /// Generate a new `Signature`, delegating the multiplication by the
+    /// secret key to `signer`. The signature is verified with the public key
+    /// of `signer`, a signature which does not verify is an error.
+    pub fn new_with_signer<T, C, S>(
+        signer: &S,
+        message: T,
+        dst: T,
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BlsCiphersuiteParameters,
+        S: BlsSigner,
+    {
+        let message = message.as_ref();
+
+        // Input parameter checks
+        // Error out if there is no `Messages`
+        if message.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to sign".to_owned(),
+            });
+        }
+
+        let q = C::hash_to_g1(message, dst.as_ref())?;
+
+        // Check the output of the signer against its public key, a faulty
+        // signer must not produce a signature which does not verify.
+        let signature =
+            Self::from_octets(&signer.sign_point(&point_to_octets_g1(&q))?)?;
+        if !signature.verify::<_, C>(
+            &signer.public_key()?,
+            message,
+            dst.as_ref(),
+        )? {
+            return Err(Error::CryptoOps {
+                cause: "the signer output does not verify with its public key"
+                    .to_owned(),
+            });
+        }
+        Ok(signature)
+    }
+
+    /// Check if the `Self` is valid.
+    pub fn is_valid(&self) -> Choice {
+        (!self.0.is_identity())
+            & self.0.is_on_curve()
+            & self.0.to_affine().is_torsion_free()
+    }
+
+    /// Verify a signature.
+    pub fn verify<T, C>(
+        &self,
+        pk: &PublicKey,
+        message: T,
+        dst: T,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        C: BlsCiphersuiteParameters,
+    {
+        let message = message.as_ref();
+        // Input parameter checks
+        // Error out if there is no `Message`
+        if message.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to verify".to_owned(),
+            });
+        }
+        // Validate the public key; it should not be an identity and should
+        // belong to subgroup.
+        if pk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        let xp = pk.0;
+        let q = C::hash_to_g1(message, dst.as_ref())?;
+        let p = C::p2();
+
+        // C1 = pairing(Q, xP)
+        let c1 = (&q.to_affine(), &G2Prepared::from(xp.to_affine()));
+
+        // C2 = pairing(-R, P)
+        // -R, because we use multi_miller_loop
+        let c2 = (&(-self.0.to_affine()), &G2Prepared::from(p.to_affine()));
+
+        // C1 == C2
+        // multi_miller_loop(C1, C2) == 1
+        Ok(Bls12::multi_miller_loop(&[c1, c2])
+            .final_exponentiation()
+            .is_identity()
+            .unwrap_u8()
+            == 1u8)
+    }
+
+    /// Get the octets representation of `Signature`.
+    pub fn to_octets(self) -> [u8; Self::SIZE_BYTES] {
+        point_to_octets_g1(&self.0)
+    }
+
+    /// Get the `Signature` from a sequence of bytes in big endian
+    /// format.
+    pub fn from_octets(data: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let signature = Self(octets_to_point_g1(data)?);
+        // Validate the signature; it should not be an identity and should
+        // belong to subgroup.
+        if signature.is_valid().unwrap_u8() == 0 {
+            return Err(Error::MalformedSignature {
+                cause: "signature is invalid".to_owned(),
+            });
+        }
+        Ok(signature)
+    }
+}
+
+/// Performs the secret key dependent step of the BLS `Sign` operation, i.e.
+/// the multiplication of the message hashed to G1 by the secret key. This
+/// allows the secret key to be held by a key management service or an HSM
+/// rather than by the application.
+pub trait BlsSigner {
+    /// Public key of the signer.
+    fn public_key(&self) -> Result<PublicKey, Error>;
+
+    /// Multiply `point`, a point of G1 in compressed form, by the secret key
+    /// and return the product in compressed form.
+    fn sign_point(
+        &self,
+        point: &[u8; OCTET_POINT_G1_LENGTH],
+    ) -> Result<[u8; OCTET_POINT_G1_LENGTH], Error>;
+}
+
+/// The in-process signer.
+impl BlsSigner for SecretKey {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.into())
+    }
+
+    fn sign_point(
+        &self,
+        point: &[u8; OCTET_POINT_G1_LENGTH],
+    ) -> Result<[u8; OCTET_POINT_G1_LENGTH], Error> {
+        if self.0.is_zero().unwrap_u8() == 1 {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok(point_to_octets_g1(
+            &(octets_to_point_g1(point)? * (*self.0)),
+        ))
+    }
+}
+
+fixed_octets_type!(
+    /// A BLS signature in G1, validated on parsing.
+    BlsSignature,
+    OCTET_POINT_G1_LENGTH,
+    |octets| Signature::from_octets(octets).map(|_| ())
+);
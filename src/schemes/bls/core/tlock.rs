@@ -0,0 +1,7 @@
+use sha2::{Digest, Sha256};
+
+/// Message signed by a beacon for `round`, i.e. the identity a ciphertext is
+/// locked to. This is `SHA-256(I2OSP(round, 8))`.
+pub fn round_message(round: u64) -> [u8; 32] {
+    Sha256::digest(&round.to_be_bytes()).into()
+}
@@ -0,0 +1,101 @@
+use crate::{
+    bls::{
+        ciphersuites::{
+            bls12_381::BLS_SIG_BLS12381G2_SIGNATURE_LENGTH,
+            BlsCiphersuiteParameters,
+        },
+        core::{
+            key_pair::{PublicKey, SecretKey},
+            signature::Signature,
+        },
+    },
+    common::{
+        ciphersuite::{CipherSuiteId, CipherSuiteParameter},
+        hash_param::{h2c::HashToCurveParameter, ExpandMessageParameter},
+    },
+    curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    Error,
+};
+use sha2::{Digest, Sha256};
+
+/// "Proof" length in bytes for the BLS VRF.
+pub const BLS_VRF_BLS12381G2_PROOF_LENGTH: usize =
+    BLS_SIG_BLS12381G2_SIGNATURE_LENGTH;
+
+/// "Output" length in bytes for the BLS VRF.
+pub const BLS_VRF_BLS12381G2_OUTPUT_LENGTH: usize = 32;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Bls12381G2XmdSha256VrfCipherSuiteParameter;
+
+impl CipherSuiteParameter for Bls12381G2XmdSha256VrfCipherSuiteParameter {
+    const ID: CipherSuiteId = CipherSuiteId::BlsVrfBls12381G2XmdSha256;
+}
+
+impl ExpandMessageParameter for Bls12381G2XmdSha256VrfCipherSuiteParameter {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+impl HashToCurveParameter for Bls12381G2XmdSha256VrfCipherSuiteParameter {}
+
+impl BlsCiphersuiteParameters for Bls12381G2XmdSha256VrfCipherSuiteParameter {}
+
+/// Evaluate the VRF on `input`, returning the output and the proof that the
+/// output was computed correctly. The proof is a BLS signature of `input`,
+/// which is unique for a given key and input, and the output is derived from
+/// it.
+pub fn prove<T>(
+    sk: &SecretKey,
+    input: T,
+) -> Result<
+    (
+        [u8; BLS_VRF_BLS12381G2_OUTPUT_LENGTH],
+        [u8; BLS_VRF_BLS12381G2_PROOF_LENGTH],
+    ),
+    Error,
+>
+where
+    T: AsRef<[u8]>,
+{
+    let proof = Signature::new::<_, Bls12381G2XmdSha256VrfCipherSuiteParameter>(
+        sk,
+        input.as_ref(),
+        Bls12381G2XmdSha256VrfCipherSuiteParameter::default_hash_to_point_dst()
+            .as_ref(),
+    )?
+    .to_octets();
+    Ok((proof_to_output(&proof), proof))
+}
+
+/// Verify a VRF `proof` for `input` under `pk` and return the VRF output.
+/// Returns `Error::SignatureVerification` if the proof is not valid.
+pub fn verify<T>(
+    pk: &PublicKey,
+    input: T,
+    proof: &[u8; BLS_VRF_BLS12381G2_PROOF_LENGTH],
+) -> Result<[u8; BLS_VRF_BLS12381G2_OUTPUT_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+{
+    let signature = Signature::from_octets(proof)?;
+    if !signature.verify::<_, Bls12381G2XmdSha256VrfCipherSuiteParameter>(
+        pk,
+        input.as_ref(),
+        Bls12381G2XmdSha256VrfCipherSuiteParameter::default_hash_to_point_dst()
+            .as_ref(),
+    )? {
+        return Err(Error::SignatureVerification);
+    }
+    Ok(proof_to_output(proof))
+}
+
+// Output = SHA-256(ciphersuite_id || "OUTPUT_" || proof).
+fn proof_to_output(
+    proof: &[u8; BLS_VRF_BLS12381G2_PROOF_LENGTH],
+) -> [u8; BLS_VRF_BLS12381G2_OUTPUT_LENGTH] {
+    let mut hasher = Sha256::new();
+    hasher.update(Bls12381G2XmdSha256VrfCipherSuiteParameter::ID.as_octets());
+    hasher.update(b"OUTPUT_");
+    hasher.update(proof);
+    hasher.finalize().into()
+}
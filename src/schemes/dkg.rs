@@ -0,0 +1,11 @@
+// Core implementation of the Pedersen DKG with Feldman verifiable secret
+// sharing.
+pub(crate) mod core;
+
+pub use crate::schemes::dkg::core::{DkgParameters, ParticipantIndex};
+
+/// DKG producing BLS key shares, with public keys in G1.
+pub mod bls;
+
+/// DKG producing BBS key shares, with public keys in G2.
+pub mod bbs;
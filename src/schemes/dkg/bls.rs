@@ -0,0 +1,26 @@
+use super::core::{
+    dkg_impl,
+    evaluate_commitments,
+    evaluate_polynomial,
+    DkgParameters,
+    ParticipantIndex,
+};
+use crate::{
+    common::util::create_random_scalar,
+    curves::bls12_381::{G1Projective, Scalar},
+    error::Error,
+};
+use ff::Field;
+use group::Group;
+use rand::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "alloc"))]
+use std::collections::{BTreeMap, BTreeSet};
+
+pub use crate::schemes::bls::core::key_pair::{PublicKey, SecretKey};
+
+dkg_impl!(G1Projective, SecretKey, PublicKey);
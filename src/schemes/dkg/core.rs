@@ -0,0 +1,495 @@
+use crate::{curves::bls12_381::Scalar, error::Error};
+use ff::Field;
+use group::Group;
+use serde::{Deserialize, Serialize};
+
+/// Index of a DKG participant. Participants are numbered from `1` to `n`, `0`
+/// is reserved as the evaluation point of the shared secret.
+pub type ParticipantIndex = u32;
+
+/// Public parameters of a DKG session, which must be agreed on by all
+/// participants before the first round.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DkgParameters {
+    /// Minimum number of key shares needed to use the group secret key.
+    pub threshold: u32,
+    /// Total number of participants.
+    pub participants: u32,
+}
+
+impl DkgParameters {
+    /// Create DKG parameters for a `threshold`-out-of-`participants` sharing.
+    pub fn new(threshold: u32, participants: u32) -> Result<Self, Error> {
+        if threshold == 0 || threshold > participants {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "invalid threshold {threshold} for {participants} \
+                     participants"
+                ),
+            });
+        }
+        Ok(Self {
+            threshold,
+            participants,
+        })
+    }
+
+    /// Check if `index` identifies a participant of the session.
+    pub(crate) fn check_index(
+        &self,
+        index: ParticipantIndex,
+    ) -> Result<(), Error> {
+        if index == 0 || index > self.participants {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "participant index {index} is out of range [1, {}]",
+                    self.participants
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Evaluate the polynomial with `coefficients`, lowest degree first, at `x`.
+pub(crate) fn evaluate_polynomial(
+    coefficients: &[Scalar],
+    x: ParticipantIndex,
+) -> Scalar {
+    let x = Scalar::from(x as u64);
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, a| acc * x + a)
+}
+
+/// Evaluate the Feldman commitments to a polynomial at `x`, i.e. compute the
+/// commitment to the share of participant `x`.
+pub(crate) fn evaluate_commitments<G>(
+    commitments: &[G],
+    x: ParticipantIndex,
+) -> G
+where
+    G: Group<Scalar = Scalar>,
+{
+    let x = Scalar::from(x as u64);
+    commitments
+        .iter()
+        .rev()
+        .fold(G::identity(), |acc, c| acc * x + c)
+}
+
+// DKG participant and round messages implementation.
+macro_rules! dkg_impl {
+    (
+        $point_projective_type:ident,
+        $secret_key_type:ident,
+        $public_key_type:ident
+    ) => {
+        /// Broadcast message of the first round: Feldman commitments to the
+        /// coefficients of the dealer's secret polynomial.
+        #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+        pub struct DealerCommitment {
+            /// Index of the dealer.
+            pub dealer: ParticipantIndex,
+            /// Commitments `P * a_k` to the coefficients `a_0, ..., a_{t-1}`.
+            pub(crate) commitments: Vec<$point_projective_type>,
+        }
+
+        /// Private message of the first round: the share of the dealer's
+        /// secret for one recipient. It must be sent over a confidential and
+        /// authenticated channel.
+        #[derive(Clone, Serialize, Deserialize)]
+        pub struct SecretShare {
+            /// Index of the dealer.
+            pub dealer: ParticipantIndex,
+            /// Index of the recipient.
+            pub recipient: ParticipantIndex,
+            /// Share value `f_dealer(recipient)`.
+            pub(crate) value: Scalar,
+        }
+
+        impl core::fmt::Debug for SecretShare {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                write!(
+                    f,
+                    "SecretShare(dealer: {}, recipient: {}, value: ****)",
+                    self.dealer, self.recipient
+                )
+            }
+        }
+
+        impl Drop for SecretShare {
+            fn drop(&mut self) {
+                self.value = Scalar::zero();
+            }
+        }
+
+        /// Broadcast message of the second round: a complaint against a
+        /// dealer whose share was either not received or is inconsistent with
+        /// its commitments.
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Serialize,
+            Deserialize,
+        )]
+        pub struct Complaint {
+            /// Index of the participant raising the complaint.
+            pub complainer: ParticipantIndex,
+            /// Index of the accused dealer.
+            pub dealer: ParticipantIndex,
+        }
+
+        /// Broadcast message of the third round: an accused dealer reveals the
+        /// disputed share so that every participant can check it against the
+        /// dealer's commitments.
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        pub struct ComplaintResponse {
+            /// Index of the accused dealer.
+            pub dealer: ParticipantIndex,
+            /// Index of the participant who raised the complaint.
+            pub complainer: ParticipantIndex,
+            /// Revealed share value `f_dealer(complainer)`.
+            pub(crate) value: Scalar,
+        }
+
+        /// Outcome of a DKG session for one participant.
+        #[derive(Clone, Debug)]
+        pub struct DkgOutput {
+            /// Index of the participant.
+            pub index: ParticipantIndex,
+            /// Dealers which were not disqualified, in increasing order.
+            pub qualified: Vec<ParticipantIndex>,
+            /// Key share of the participant.
+            pub secret_key: $secret_key_type,
+            /// Group public key.
+            pub public_key: $public_key_type,
+            /// Public keys of the key shares of every participant, which can
+            /// be used to check partial results produced with those shares.
+            pub share_public_keys: BTreeMap<ParticipantIndex, $public_key_type>,
+        }
+
+        /// State of a participant in a DKG session. Each participant acts
+        /// both as a dealer of its own secret and as a recipient of the
+        /// shares of the other dealers.
+        pub struct Participant {
+            index: ParticipantIndex,
+            parameters: DkgParameters,
+            coefficients: Vec<Scalar>,
+            commitments:
+                BTreeMap<ParticipantIndex, Vec<$point_projective_type>>,
+            shares: BTreeMap<ParticipantIndex, Scalar>,
+            complaints: BTreeSet<Complaint>,
+            disqualified: BTreeSet<ParticipantIndex>,
+        }
+
+        impl Drop for Participant {
+            fn drop(&mut self) {
+                for c in self.coefficients.iter_mut() {
+                    *c = Scalar::zero();
+                }
+                for s in self.shares.values_mut() {
+                    *s = Scalar::zero();
+                }
+            }
+        }
+
+        impl Participant {
+            /// Join a DKG session as participant `index`, sampling a random
+            /// secret polynomial of degree `threshold - 1`.
+            pub fn new<R>(
+                index: ParticipantIndex,
+                parameters: DkgParameters,
+                rng: &mut R,
+            ) -> Result<Self, Error>
+            where
+                R: RngCore + CryptoRng,
+            {
+                let parameters = DkgParameters::new(
+                    parameters.threshold,
+                    parameters.participants,
+                )?;
+                parameters.check_index(index)?;
+
+                let coefficients = (0..parameters.threshold)
+                    .map(|_| create_random_scalar(&mut *rng))
+                    .collect::<Result<Vec<Scalar>, Error>>()?;
+
+                Ok(Self {
+                    index,
+                    parameters,
+                    coefficients,
+                    commitments: BTreeMap::new(),
+                    shares: BTreeMap::new(),
+                    complaints: BTreeSet::new(),
+                    disqualified: BTreeSet::new(),
+                })
+            }
+
+            /// Index of the participant.
+            pub fn index(&self) -> ParticipantIndex {
+                self.index
+            }
+
+            /// Round 1: produce the commitment to broadcast, and the shares
+            /// to send privately to each of the other participants.
+            pub fn deal(&mut self) -> (DealerCommitment, Vec<SecretShare>) {
+                let commitments = self
+                    .coefficients
+                    .iter()
+                    .map(|a| $point_projective_type::generator() * a)
+                    .collect::<Vec<_>>();
+                self.commitments.insert(self.index, commitments.clone());
+                self.shares.insert(
+                    self.index,
+                    evaluate_polynomial(&self.coefficients, self.index),
+                );
+
+                let shares = (1..=self.parameters.participants)
+                    .filter(|j| *j != self.index)
+                    .map(|j| SecretShare {
+                        dealer: self.index,
+                        recipient: j,
+                        value: evaluate_polynomial(&self.coefficients, j),
+                    })
+                    .collect();
+
+                (
+                    DealerCommitment {
+                        dealer: self.index,
+                        commitments,
+                    },
+                    shares,
+                )
+            }
+
+            /// Round 2: check the commitments broadcast by the other dealers
+            /// and the shares they sent to this participant. Returns the
+            /// complaints to broadcast, against every dealer whose share is
+            /// missing, misaddressed or inconsistent with its commitments.
+            pub fn process_shares(
+                &mut self,
+                commitments: &[DealerCommitment],
+                shares: &[SecretShare],
+            ) -> Result<Vec<Complaint>, Error> {
+                for c in commitments {
+                    // Commitments of unknown dealers are ignored.
+                    if self.parameters.check_index(c.dealer).is_err()
+                        || c.dealer == self.index
+                    {
+                        continue;
+                    }
+                    // A dealer broadcasting malformed or conflicting
+                    // commitments is disqualified right away.
+                    if c.commitments.len() != self.parameters.threshold as usize
+                    {
+                        self.disqualified.insert(c.dealer);
+                        continue;
+                    }
+                    if let Some(previous) =
+                        self.commitments.insert(c.dealer, c.commitments.clone())
+                    {
+                        if previous != c.commitments {
+                            self.disqualified.insert(c.dealer);
+                        }
+                    }
+                }
+
+                for s in shares {
+                    // Shares of unknown dealers, or addressed to another
+                    // participant, are ignored: the dealer gets a complaint
+                    // unless a valid share was received from it.
+                    if self.parameters.check_index(s.dealer).is_err()
+                        || s.recipient != self.index
+                    {
+                        continue;
+                    }
+                    if s.dealer != self.index
+                        && self.verify_share(s.dealer, self.index, &s.value)
+                    {
+                        self.shares.insert(s.dealer, s.value);
+                    }
+                }
+
+                Ok(self
+                    .commitments
+                    .keys()
+                    .filter(|d| {
+                        !self.disqualified.contains(d)
+                            && !self.shares.contains_key(d)
+                    })
+                    .map(|d| Complaint {
+                        complainer: self.index,
+                        dealer: *d,
+                    })
+                    .collect())
+            }
+
+            /// Round 3: record the complaints broadcast by all the
+            /// participants. Returns the responses to broadcast, revealing the
+            /// shares disputed by complaints raised against this participant.
+            pub fn process_complaints(
+                &mut self,
+                complaints: &[Complaint],
+            ) -> Result<Vec<ComplaintResponse>, Error> {
+                let mut responses = vec![];
+                for c in complaints {
+                    // Complaints involving unknown participants are ignored.
+                    if self.parameters.check_index(c.complainer).is_err()
+                        || self.parameters.check_index(c.dealer).is_err()
+                    {
+                        continue;
+                    }
+                    // A dealer complaining about itself is disqualified.
+                    if c.complainer == c.dealer {
+                        self.disqualified.insert(c.dealer);
+                        continue;
+                    }
+                    if !self.commitments.contains_key(&c.dealer)
+                        || self.disqualified.contains(&c.dealer)
+                    {
+                        continue;
+                    }
+                    if self.complaints.insert(*c) && c.dealer == self.index {
+                        responses.push(ComplaintResponse {
+                            dealer: self.index,
+                            complainer: c.complainer,
+                            value: evaluate_polynomial(
+                                &self.coefficients,
+                                c.complainer,
+                            ),
+                        });
+                    }
+                }
+                Ok(responses)
+            }
+
+            /// Round 4: check the shares revealed in response to complaints.
+            /// A dealer which leaves a complaint unanswered, or reveals a share
+            /// inconsistent with its commitments, is disqualified.
+            pub fn process_complaint_responses(
+                &mut self,
+                responses: &[ComplaintResponse],
+            ) -> Result<(), Error> {
+                let mut resolved = BTreeSet::new();
+                for r in responses {
+                    let complaint = Complaint {
+                        complainer: r.complainer,
+                        dealer: r.dealer,
+                    };
+                    if !self.complaints.contains(&complaint) {
+                        continue;
+                    }
+                    if self.verify_share(r.dealer, r.complainer, &r.value) {
+                        resolved.insert(complaint);
+                        if r.complainer == self.index {
+                            self.shares.insert(r.dealer, r.value);
+                        }
+                    }
+                }
+
+                for c in &self.complaints {
+                    if !resolved.contains(c) {
+                        self.disqualified.insert(c.dealer);
+                    }
+                }
+                self.complaints.clear();
+                Ok(())
+            }
+
+            /// Indices of the dealers disqualified so far.
+            pub fn disqualified(&self) -> Vec<ParticipantIndex> {
+                self.disqualified.iter().copied().collect()
+            }
+
+            /// Complete the DKG session and compute the key share of this
+            /// participant together with the group public key. The qualified
+            /// dealers are the ones which broadcast commitments and were not
+            /// disqualified.
+            pub fn finalize(&self) -> Result<DkgOutput, Error> {
+                let qualified = self
+                    .commitments
+                    .keys()
+                    .filter(|d| !self.disqualified.contains(d))
+                    .copied()
+                    .collect::<Vec<ParticipantIndex>>();
+                if qualified.len() < self.parameters.threshold as usize {
+                    return Err(Error::CryptoOps {
+                        cause: format!(
+                            "only {} qualified dealers, threshold is {}",
+                            qualified.len(),
+                            self.parameters.threshold
+                        ),
+                    });
+                }
+
+                let mut secret = Scalar::zero();
+                let mut group_point = $point_projective_type::identity();
+                for d in &qualified {
+                    let share =
+                        self.shares.get(d).ok_or_else(|| Error::CryptoOps {
+                            cause: format!(
+                                "missing share from qualified dealer {d}"
+                            ),
+                        })?;
+                    secret += share;
+                    group_point += self.commitments[d][0];
+                }
+                if secret.is_zero().unwrap_u8() == 1u8 {
+                    return Err(Error::InvalidSecretKey);
+                }
+
+                let share_public_keys = (1..=self.parameters.participants)
+                    .map(|j| {
+                        let point = qualified.iter().fold(
+                            $point_projective_type::identity(),
+                            |acc, d| {
+                                acc + evaluate_commitments(
+                                    &self.commitments[d],
+                                    j,
+                                )
+                            },
+                        );
+                        (j, $public_key_type(point))
+                    })
+                    .collect();
+
+                Ok(DkgOutput {
+                    index: self.index,
+                    qualified,
+                    secret_key: $secret_key_type(Box::new(secret)),
+                    public_key: $public_key_type(group_point),
+                    share_public_keys,
+                })
+            }
+
+            // Check a share of `dealer` for `recipient` against the dealer's
+            // commitments.
+            fn verify_share(
+                &self,
+                dealer: ParticipantIndex,
+                recipient: ParticipantIndex,
+                value: &Scalar,
+            ) -> bool {
+                match self.commitments.get(&dealer) {
+                    Some(commitments) => {
+                        $point_projective_type::generator() * value
+                            == evaluate_commitments(commitments, recipient)
+                    }
+                    None => false,
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use dkg_impl;
@@ -0,0 +1,6 @@
+// Core implementation of the BBS04 short group signature scheme.
+pub(crate) mod core;
+
+/// BBS04 short group signature ciphersuites abstraction over core
+/// implementation.
+pub mod ciphersuites;
@@ -0,0 +1,5 @@
+/// BBS04 short group signature BLS12-381 ciphersuites.
+pub mod bls12_381;
+
+/// BBS04 short group signature BLS12-381-Sha-256 ciphersuite.
+pub mod bls12_381_sha_256;
@@ -0,0 +1,53 @@
+use crate::{
+    curves::bls12_381::{
+        OCTET_POINT_G1_LENGTH,
+        OCTET_POINT_G2_LENGTH,
+        OCTET_SCALAR_LENGTH,
+    },
+    schemes::group_sig::core::signature::Signature,
+};
+
+pub use crate::schemes::group_sig::core::{
+    keys::{
+        GroupKeys,
+        GroupPublicKey,
+        IssuerSecretKey,
+        MemberSecretKey,
+        OpenerSecretKey,
+    },
+    revocation::RevocationList,
+};
+
+/// "GroupPublicKey" length in bytes for "GROUP_SIG_BBS04_BLS12381"
+/// ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_PUBLIC_KEY_LENGTH: usize =
+    GroupPublicKey::SIZE_BYTES;
+
+/// "IssuerSecretKey" length in bytes for "GROUP_SIG_BBS04_BLS12381"
+/// ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_ISSUER_KEY_LENGTH: usize =
+    OCTET_SCALAR_LENGTH;
+
+/// "OpenerSecretKey" length in bytes for "GROUP_SIG_BBS04_BLS12381"
+/// ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_OPENER_KEY_LENGTH: usize =
+    2 * OCTET_SCALAR_LENGTH;
+
+/// "MemberSecretKey" length in bytes for "GROUP_SIG_BBS04_BLS12381"
+/// ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_MEMBER_KEY_LENGTH: usize =
+    OCTET_POINT_G1_LENGTH + OCTET_SCALAR_LENGTH;
+
+/// Length in bytes of the member tag returned by opening a signature, for
+/// "GROUP_SIG_BBS04_BLS12381" ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_MEMBER_TAG_LENGTH: usize =
+    OCTET_POINT_G1_LENGTH;
+
+/// "Signature" length in bytes for "GROUP_SIG_BBS04_BLS12381" ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_SIGNATURE_LENGTH: usize =
+    Signature::SIZE_BYTES;
+
+/// Length in bytes of a revocation list entry for "GROUP_SIG_BBS04_BLS12381"
+/// ciphersuite.
+pub const GROUP_SIG_BBS04_BLS12381_REVOCATION_ENTRY_LENGTH: usize =
+    OCTET_POINT_G1_LENGTH + OCTET_POINT_G2_LENGTH + OCTET_SCALAR_LENGTH;
@@ -0,0 +1,88 @@
+use super::bls12_381::{
+    GroupPublicKey,
+    MemberSecretKey,
+    OpenerSecretKey,
+    GROUP_SIG_BBS04_BLS12381_MEMBER_TAG_LENGTH,
+    GROUP_SIG_BBS04_BLS12381_SIGNATURE_LENGTH,
+};
+use crate::{
+    common::{
+        ciphersuite::{CipherSuiteId, CipherSuiteParameter},
+        hash_param::{h2s::HashToScalarParameter, ExpandMessageParameter},
+    },
+    curves::{
+        bls12_381::hash_to_curve::ExpandMsgXmd,
+        point_serde::point_to_octets_g1,
+    },
+    group_sig::core::signature::Signature,
+    Error,
+};
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Bls12381Sha256CipherSuiteParameter;
+
+impl CipherSuiteParameter for Bls12381Sha256CipherSuiteParameter {
+    const ID: CipherSuiteId = CipherSuiteId::GroupSigBbs04Bls12381XmdSha256;
+}
+
+impl ExpandMessageParameter for Bls12381Sha256CipherSuiteParameter {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+impl HashToScalarParameter for Bls12381Sha256CipherSuiteParameter {}
+
+/// Sign a message on behalf of the group with the key of a member.
+/// `gpk` and `member_key` must be up to date with the revocation list.
+pub fn sign<T, R>(
+    gpk: &GroupPublicKey,
+    member_key: &MemberSecretKey,
+    message: T,
+    rng: &mut R,
+) -> Result<[u8; GROUP_SIG_BBS04_BLS12381_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    Signature::new::<Bls12381Sha256CipherSuiteParameter, _>(
+        gpk,
+        member_key,
+        message.as_ref(),
+        rng,
+    )
+    .map(|sig| sig.to_octets())
+}
+
+/// Verify a group signature.
+pub fn verify<T>(
+    gpk: &GroupPublicKey,
+    message: T,
+    signature: &[u8; GROUP_SIG_BBS04_BLS12381_SIGNATURE_LENGTH],
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    Signature::from_octets(signature)?
+        .verify::<Bls12381Sha256CipherSuiteParameter>(gpk, message.as_ref())
+}
+
+/// Open a group signature to the tag of the member who created it, see
+/// `MemberSecretKey::tag`. The signature is verified before it is opened.
+pub fn open<T>(
+    gpk: &GroupPublicKey,
+    opener_key: &OpenerSecretKey,
+    message: T,
+    signature: &[u8; GROUP_SIG_BBS04_BLS12381_SIGNATURE_LENGTH],
+) -> Result<[u8; GROUP_SIG_BBS04_BLS12381_MEMBER_TAG_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+{
+    let signature = Signature::from_octets(signature)?;
+    if !signature
+        .verify::<Bls12381Sha256CipherSuiteParameter>(gpk, message.as_ref())?
+    {
+        return Err(Error::SignatureVerification);
+    }
+    Ok(point_to_octets_g1(&signature.open(opener_key)))
+}
@@ -0,0 +1,6 @@
+pub(crate) mod signature;
+
+/// Group manager and member keys.
+pub(crate) mod keys;
+/// Revocation of group members.
+pub(crate) mod revocation;
@@ -0,0 +1,379 @@
+use crate::{
+    common::util::create_random_scalar,
+    curves::{
+        bls12_381::{
+            Bls12,
+            G1Projective,
+            G2Prepared,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{
+            octets_to_point_g1,
+            octets_to_point_g2,
+            point_to_octets_g1,
+            point_to_octets_g2,
+        },
+    },
+    error::Error,
+};
+use core::convert::TryFrom;
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::{CryptoRng, RngCore};
+use subtle::Choice;
+use zeroize::Zeroize;
+
+/// The public key of a group, `(g1, g2, h, u, v, w)` where
+/// `u * xi_1 = v * xi_2 = h` for the opener secret key `(xi_1, xi_2)`, and
+/// `w = g2 * gamma` for the issuer secret key `gamma`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GroupPublicKey {
+    pub(crate) g1: G1Projective,
+    pub(crate) g2: G2Projective,
+    pub(crate) h: G1Projective,
+    pub(crate) u: G1Projective,
+    pub(crate) v: G1Projective,
+    pub(crate) w: G2Projective,
+}
+
+impl GroupPublicKey {
+    /// Number of bytes needed to represent the group public key.
+    pub const SIZE_BYTES: usize =
+        4 * OCTET_POINT_G1_LENGTH + 2 * OCTET_POINT_G2_LENGTH;
+
+    /// Check if the `GroupPublicKey` is valid.
+    pub fn is_valid(&self) -> Choice {
+        is_valid_g1(&self.g1)
+            & is_valid_g1(&self.h)
+            & is_valid_g1(&self.u)
+            & is_valid_g1(&self.v)
+            & is_valid_g2(&self.g2)
+            & is_valid_g2(&self.w)
+    }
+
+    /// Get the representation of the group public key as the concatenation
+    /// of the compressed points `g1 || g2 || h || u || v || w`.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        let mut octets = [0u8; Self::SIZE_BYTES];
+        let (g1, rest) = octets.split_at_mut(OCTET_POINT_G1_LENGTH);
+        let (g2, rest) = rest.split_at_mut(OCTET_POINT_G2_LENGTH);
+        let (h, rest) = rest.split_at_mut(OCTET_POINT_G1_LENGTH);
+        let (u, rest) = rest.split_at_mut(OCTET_POINT_G1_LENGTH);
+        let (v, w) = rest.split_at_mut(OCTET_POINT_G1_LENGTH);
+        g1.copy_from_slice(&point_to_octets_g1(&self.g1));
+        g2.copy_from_slice(&point_to_octets_g2(&self.g2));
+        h.copy_from_slice(&point_to_octets_g1(&self.h));
+        u.copy_from_slice(&point_to_octets_g1(&self.u));
+        v.copy_from_slice(&point_to_octets_g1(&self.v));
+        w.copy_from_slice(&point_to_octets_g2(&self.w));
+        octets
+    }
+
+    /// Convert from the representation of `to_octets` to a group public key.
+    pub fn from_octets(octets: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let (g1, rest) = octets.split_at(OCTET_POINT_G1_LENGTH);
+        let (g2, rest) = rest.split_at(OCTET_POINT_G2_LENGTH);
+        let (h, rest) = rest.split_at(OCTET_POINT_G1_LENGTH);
+        let (u, rest) = rest.split_at(OCTET_POINT_G1_LENGTH);
+        let (v, w) = rest.split_at(OCTET_POINT_G1_LENGTH);
+
+        let gpk = Self {
+            g1: slice_to_point_g1(g1)?,
+            g2: slice_to_point_g2(g2)?,
+            h: slice_to_point_g1(h)?,
+            u: slice_to_point_g1(u)?,
+            v: slice_to_point_g1(v)?,
+            w: slice_to_point_g2(w)?,
+        };
+        if gpk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        Ok(gpk)
+    }
+}
+
+/// The secret key of the issuer of member keys, `gamma`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IssuerSecretKey(pub(crate) Box<Scalar>);
+
+/// The secret key of the opener of signatures, `(xi_1, xi_2)`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct OpenerSecretKey {
+    pub(crate) xi_1: Scalar,
+    pub(crate) xi_2: Scalar,
+}
+
+/// The secret key of a group member, `(A, x)` where
+/// `A * (gamma + x) = g1`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MemberSecretKey {
+    pub(crate) a: G1Projective,
+    pub(crate) x: Scalar,
+}
+
+impl core::fmt::Debug for IssuerSecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "****")
+    }
+}
+
+impl core::fmt::Debug for OpenerSecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "****")
+    }
+}
+
+impl core::fmt::Debug for MemberSecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "****")
+    }
+}
+
+impl Zeroize for IssuerSecretKey {
+    fn zeroize(&mut self) {
+        self.0 = Box::new(Scalar::zero());
+    }
+}
+
+impl Zeroize for OpenerSecretKey {
+    fn zeroize(&mut self) {
+        self.xi_1 = Scalar::zero();
+        self.xi_2 = Scalar::zero();
+    }
+}
+
+impl Zeroize for MemberSecretKey {
+    fn zeroize(&mut self) {
+        self.a = G1Projective::identity();
+        self.x = Scalar::zero();
+    }
+}
+
+impl Drop for IssuerSecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Drop for OpenerSecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl Drop for MemberSecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// The keys created by the group setup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupKeys {
+    /// Group public key.
+    pub public_key: GroupPublicKey,
+
+    /// Secret key of the issuer of member keys.
+    pub issuer_key: IssuerSecretKey,
+
+    /// Secret key of the opener of signatures.
+    pub opener_key: OpenerSecretKey,
+}
+
+impl GroupKeys {
+    /// Set up a new group from a CS-PRNG.
+    pub fn random<R>(rng: &mut R) -> Result<Self, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let gamma = random_non_zero_scalar(rng)?;
+        let xi_1 = random_non_zero_scalar(rng)?;
+        let xi_2 = random_non_zero_scalar(rng)?;
+
+        let g1 = G1Projective::generator();
+        let g2 = G2Projective::generator();
+        let h = g1 * random_non_zero_scalar(rng)?;
+        let public_key = GroupPublicKey {
+            g1,
+            g2,
+            h,
+            u: h * xi_1.invert().unwrap(),
+            v: h * xi_2.invert().unwrap(),
+            w: g2 * gamma,
+        };
+
+        Ok(Self {
+            public_key,
+            issuer_key: IssuerSecretKey(Box::new(gamma)),
+            opener_key: OpenerSecretKey { xi_1, xi_2 },
+        })
+    }
+}
+
+impl IssuerSecretKey {
+    /// Number of bytes needed to represent the issuer secret key.
+    pub const SIZE_BYTES: usize = OCTET_SCALAR_LENGTH;
+
+    /// Issue the key of a new group member.
+    pub fn issue<R>(
+        &self,
+        gpk: &GroupPublicKey,
+        rng: &mut R,
+    ) -> Result<MemberSecretKey, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let x = random_non_zero_scalar(rng)?;
+        Ok(MemberSecretKey {
+            a: self.member_base(&gpk.g1, &x)?,
+            x,
+        })
+    }
+
+    /// Compute `p * 1 / (gamma + x)`.
+    pub(crate) fn member_base<P>(&self, p: &P, x: &Scalar) -> Result<P, Error>
+    where
+        P: Group<Scalar = Scalar>,
+    {
+        let exp = (*self.0 + x).invert();
+        if exp.is_none().unwrap_u8() == 1 {
+            return Err(Error::UnexpectedZeroValue);
+        }
+        Ok(*p * exp.unwrap())
+    }
+
+    /// Convert the issuer secret key to a big-endian representation.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE_BYTES] {
+        self.0.to_bytes_be()
+    }
+
+    /// Convert a big-endian representation of the issuer secret key.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        Ok(Self(Box::new(bytes_to_non_zero_scalar(bytes)?)))
+    }
+}
+
+impl OpenerSecretKey {
+    /// Number of bytes needed to represent the opener secret key.
+    pub const SIZE_BYTES: usize = 2 * OCTET_SCALAR_LENGTH;
+
+    /// Convert the opener secret key to a big-endian representation,
+    /// `xi_1 || xi_2`.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE_BYTES] {
+        let mut bytes = [0u8; Self::SIZE_BYTES];
+        bytes[..OCTET_SCALAR_LENGTH].copy_from_slice(&self.xi_1.to_bytes_be());
+        bytes[OCTET_SCALAR_LENGTH..].copy_from_slice(&self.xi_2.to_bytes_be());
+        bytes
+    }
+
+    /// Convert a big-endian representation of the opener secret key.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let (xi_1, xi_2) = bytes.split_at(OCTET_SCALAR_LENGTH);
+        Ok(Self {
+            xi_1: bytes_to_non_zero_scalar(
+                &<[u8; OCTET_SCALAR_LENGTH]>::try_from(xi_1)?,
+            )?,
+            xi_2: bytes_to_non_zero_scalar(
+                &<[u8; OCTET_SCALAR_LENGTH]>::try_from(xi_2)?,
+            )?,
+        })
+    }
+}
+
+impl MemberSecretKey {
+    /// Number of bytes needed to represent the member secret key.
+    pub const SIZE_BYTES: usize = OCTET_POINT_G1_LENGTH + OCTET_SCALAR_LENGTH;
+
+    /// Verify the member secret key is valid for the group public key,
+    /// `e(A, w + g2 * x) == e(g1, g2)`.
+    pub fn verify(&self, gpk: &GroupPublicKey) -> bool {
+        let g2 = G2Prepared::from(-gpk.g2.to_affine());
+        let w = G2Prepared::from((gpk.w + gpk.g2 * self.x).to_affine());
+        Bls12::multi_miller_loop(&[
+            (&self.a.to_affine(), &w),
+            (&gpk.g1.to_affine(), &g2),
+        ])
+        .final_exponentiation()
+        .is_identity()
+        .unwrap_u8()
+            == 1
+    }
+
+    /// The tag of the member, as returned when opening a signature of the
+    /// member under the same group public key. The tag changes when the key
+    /// is updated to a new revocation list.
+    pub fn tag(&self) -> [u8; OCTET_POINT_G1_LENGTH] {
+        point_to_octets_g1(&self.a)
+    }
+
+    /// Convert the member secret key to a big-endian representation,
+    /// `A || x`.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        let mut bytes = [0u8; Self::SIZE_BYTES];
+        bytes[..OCTET_POINT_G1_LENGTH]
+            .copy_from_slice(&point_to_octets_g1(&self.a));
+        bytes[OCTET_POINT_G1_LENGTH..].copy_from_slice(&self.x.to_bytes_be());
+        bytes
+    }
+
+    /// Convert a big-endian representation of the member secret key.
+    pub fn from_octets(bytes: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let (a, x) = bytes.split_at(OCTET_POINT_G1_LENGTH);
+        let a = slice_to_point_g1(a)?;
+        if a.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+        Ok(Self {
+            a,
+            x: bytes_to_non_zero_scalar(
+                &<[u8; OCTET_SCALAR_LENGTH]>::try_from(x)?,
+            )?,
+        })
+    }
+}
+
+fn random_non_zero_scalar<R>(rng: &mut R) -> Result<Scalar, Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let s = create_random_scalar(rng)?;
+    if s.is_zero().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    Ok(s)
+}
+
+fn bytes_to_non_zero_scalar(
+    bytes: &[u8; OCTET_SCALAR_LENGTH],
+) -> Result<Scalar, Error> {
+    let s = Scalar::from_bytes_be(bytes);
+    if s.is_none().unwrap_u8() == 1 {
+        return Err(Error::BadEncoding);
+    }
+    let s = s.unwrap();
+    if s.is_zero().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    Ok(s)
+}
+
+pub(crate) fn slice_to_point_g1(bytes: &[u8]) -> Result<G1Projective, Error> {
+    octets_to_point_g1(&<[u8; OCTET_POINT_G1_LENGTH]>::try_from(bytes)?)
+}
+
+pub(crate) fn slice_to_point_g2(bytes: &[u8]) -> Result<G2Projective, Error> {
+    octets_to_point_g2(&<[u8; OCTET_POINT_G2_LENGTH]>::try_from(bytes)?)
+}
+
+fn is_valid_g1(p: &G1Projective) -> Choice {
+    !p.is_identity() & p.is_on_curve() & p.to_affine().is_torsion_free()
+}
+
+fn is_valid_g2(p: &G2Projective) -> Choice {
+    !p.is_identity() & p.is_on_curve() & p.to_affine().is_torsion_free()
+}
@@ -0,0 +1,169 @@
+use super::keys::{
+    slice_to_point_g1,
+    slice_to_point_g2,
+    GroupPublicKey,
+    IssuerSecretKey,
+    MemberSecretKey,
+};
+use crate::{
+    curves::{
+        bls12_381::{
+            G1Projective,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{point_to_octets_g1, point_to_octets_g2},
+    },
+    error::Error,
+};
+use core::convert::TryFrom;
+use ff::Field;
+
+/// Revocation token of a member `(A*, x*)` against the group public key
+/// `(g1, g2, ...)` it is applied to, `(g1 / (gamma + x*), g2 / (gamma + x*),
+/// x*)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct RevocationToken {
+    a_1: G1Projective,
+    a_2: G2Projective,
+    x: Scalar,
+}
+
+impl RevocationToken {
+    const SIZE_BYTES: usize =
+        OCTET_POINT_G1_LENGTH + OCTET_POINT_G2_LENGTH + OCTET_SCALAR_LENGTH;
+}
+
+/// Revocation list of a group, as defined in section 7 of
+/// <https://crypto.stanford.edu/~dabo/pubs/papers/groupsigs.pdf>.
+/// Each revocation changes the group public key, so that the remaining
+/// members have to update their keys and the revoked members can no longer
+/// sign. Tokens are applied in order, starting from the group public key
+/// created by the group setup.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RevocationList {
+    tokens: Vec<RevocationToken>,
+}
+
+impl RevocationList {
+    /// Create an empty revocation list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of revoked members.
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    /// Check if no member is revoked.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Revoke a member. `gpk` is the group public key created by the group
+    /// setup.
+    pub fn revoke(
+        &mut self,
+        issuer_key: &IssuerSecretKey,
+        gpk: &GroupPublicKey,
+        member_key: &MemberSecretKey,
+    ) -> Result<(), Error> {
+        if self.tokens.iter().any(|t| t.x == member_key.x) {
+            return Err(Error::BadParams {
+                cause: "member is already revoked".to_owned(),
+            });
+        }
+        let current = self.public_key(gpk);
+        self.tokens.push(RevocationToken {
+            a_1: issuer_key.member_base(&current.g1, &member_key.x)?,
+            a_2: issuer_key.member_base(&current.g2, &member_key.x)?,
+            x: member_key.x,
+        });
+        Ok(())
+    }
+
+    /// Compute the current group public key from the group public key
+    /// created by the group setup.
+    pub fn public_key(&self, gpk: &GroupPublicKey) -> GroupPublicKey {
+        self.tokens.iter().fold(*gpk, |gpk, t| {
+            // w' = g2' * gamma = g2 - g2' * x*
+            GroupPublicKey {
+                g1: t.a_1,
+                g2: t.a_2,
+                w: gpk.g2 - t.a_2 * t.x,
+                ..gpk
+            }
+        })
+    }
+
+    /// Update the key of a member to the current group public key. Fails if
+    /// the member is revoked.
+    pub fn update_member_key(
+        &self,
+        member_key: &MemberSecretKey,
+    ) -> Result<MemberSecretKey, Error> {
+        self.tokens.iter().try_fold(member_key.clone(), |key, t| {
+            // A' = (A* - A) / (x - x*)
+            let exp = (key.x - t.x).invert();
+            if exp.is_none().unwrap_u8() == 1 {
+                return Err(Error::BadParams {
+                    cause: "member is revoked".to_owned(),
+                });
+            }
+            Ok(MemberSecretKey {
+                a: (t.a_1 - key.a) * exp.unwrap(),
+                x: key.x,
+            })
+        })
+    }
+
+    /// Get the representation of the revocation list as the concatenation of
+    /// its tokens `A*_1 || A*_2 || x*`.
+    pub fn to_octets(&self) -> Vec<u8> {
+        let mut octets =
+            Vec::with_capacity(self.tokens.len() * RevocationToken::SIZE_BYTES);
+        for t in &self.tokens {
+            octets.extend_from_slice(&point_to_octets_g1(&t.a_1));
+            octets.extend_from_slice(&point_to_octets_g2(&t.a_2));
+            octets.extend_from_slice(&t.x.to_bytes_be());
+        }
+        octets
+    }
+
+    /// Convert from the representation of `to_octets` to a revocation list.
+    pub fn from_octets(octets: &[u8]) -> Result<Self, Error> {
+        if octets.len() % RevocationToken::SIZE_BYTES != 0 {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "invalid revocation list length {}, expected a multiple \
+                     of {} bytes",
+                    octets.len(),
+                    RevocationToken::SIZE_BYTES
+                ),
+            });
+        }
+        let tokens = octets
+            .chunks(RevocationToken::SIZE_BYTES)
+            .map(|chunk| {
+                let (a_1, rest) = chunk.split_at(OCTET_POINT_G1_LENGTH);
+                let (a_2, x) = rest.split_at(OCTET_POINT_G2_LENGTH);
+                let x = Scalar::from_bytes_be(
+                    &<[u8; OCTET_SCALAR_LENGTH]>::try_from(x)?,
+                );
+                if x.is_none().unwrap_u8() == 1 {
+                    return Err(Error::BadEncoding);
+                }
+                Ok(RevocationToken {
+                    a_1: slice_to_point_g1(a_1)?,
+                    a_2: slice_to_point_g2(a_2)?,
+                    x: x.unwrap(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { tokens })
+    }
+}
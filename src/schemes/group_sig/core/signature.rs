@@ -0,0 +1,292 @@
+#![allow(non_snake_case)]
+
+use super::keys::{
+    slice_to_point_g1,
+    GroupPublicKey,
+    MemberSecretKey,
+    OpenerSecretKey,
+};
+use crate::{
+    common::{
+        hash_param::{
+            constant::NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+            h2s::HashToScalarParameter,
+        },
+        serialization::i2osp_with_data,
+        util::create_random_scalar,
+    },
+    curves::{
+        bls12_381::{
+            Bls12,
+            G1Projective,
+            G2Prepared,
+            Gt,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{point_to_octets_g1, point_to_octets_gt},
+    },
+    error::Error,
+};
+use core::convert::TryFrom;
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::{CryptoRng, RngCore};
+
+/// A BBS04 short group signature
+/// `(T1, T2, T3, c, s_alpha, s_beta, s_x, s_delta_1, s_delta_2)`, as defined
+/// in section 5 of <https://crypto.stanford.edu/~dabo/pubs/papers/groupsigs.pdf>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Signature {
+    pub(crate) T1: G1Projective,
+    pub(crate) T2: G1Projective,
+    pub(crate) T3: G1Projective,
+    pub(crate) c: Scalar,
+    pub(crate) s_alpha: Scalar,
+    pub(crate) s_beta: Scalar,
+    pub(crate) s_x: Scalar,
+    pub(crate) s_delta_1: Scalar,
+    pub(crate) s_delta_2: Scalar,
+}
+
+impl Signature {
+    /// The number of bytes in a `Signature`.
+    pub const SIZE_BYTES: usize =
+        3 * OCTET_POINT_G1_LENGTH + 6 * OCTET_SCALAR_LENGTH;
+
+    /// Sign a message with the key of a group member.
+    pub fn new<C, R>(
+        gpk: &GroupPublicKey,
+        member_key: &MemberSecretKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, Error>
+    where
+        C: HashToScalarParameter,
+        R: RngCore + CryptoRng,
+    {
+        if !member_key.verify(gpk) {
+            return Err(Error::BadParams {
+                cause: "member key is not valid for the group public key"
+                    .to_owned(),
+            });
+        }
+
+        // Linear encryption of A
+        // T1 = u * alpha, T2 = v * beta, T3 = A + h * (alpha + beta)
+        let alpha = create_random_scalar(&mut *rng)?;
+        let beta = create_random_scalar(&mut *rng)?;
+        let T1 = gpk.u * alpha;
+        let T2 = gpk.v * beta;
+        let T3 = member_key.a + gpk.h * (alpha + beta);
+        let delta_1 = member_key.x * alpha;
+        let delta_2 = member_key.x * beta;
+
+        // Commitments of the proof of knowledge of (alpha, beta, x, delta_1,
+        // delta_2)
+        let r_alpha = create_random_scalar(&mut *rng)?;
+        let r_beta = create_random_scalar(&mut *rng)?;
+        let r_x = create_random_scalar(&mut *rng)?;
+        let r_delta_1 = create_random_scalar(&mut *rng)?;
+        let r_delta_2 = create_random_scalar(&mut *rng)?;
+
+        // R1 = u * r_alpha, R2 = v * r_beta
+        let R1 = gpk.u * r_alpha;
+        let R2 = gpk.v * r_beta;
+        // R3 = e(T3, g2) * r_x - e(h, w) * (r_alpha + r_beta)
+        //      - e(h, g2) * (r_delta_1 + r_delta_2)
+        let R3 = pairing_sum(
+            gpk,
+            &(T3 * r_x - gpk.h * (r_delta_1 + r_delta_2)),
+            &(-gpk.h * (r_alpha + r_beta)),
+        );
+        // R4 = T1 * r_x - u * r_delta_1, R5 = T2 * r_x - v * r_delta_2
+        let R4 = T1 * r_x - gpk.u * r_delta_1;
+        let R5 = T2 * r_x - gpk.v * r_delta_2;
+
+        let c = compute_challenge::<C>(
+            gpk,
+            [&T1, &T2, &T3, &R1, &R2],
+            &R3,
+            [&R4, &R5],
+            message,
+        )?;
+
+        Ok(Self {
+            T1,
+            T2,
+            T3,
+            c,
+            s_alpha: r_alpha + c * alpha,
+            s_beta: r_beta + c * beta,
+            s_x: r_x + c * member_key.x,
+            s_delta_1: r_delta_1 + c * delta_1,
+            s_delta_2: r_delta_2 + c * delta_2,
+        })
+    }
+
+    /// Verify a signature under a group public key.
+    pub fn verify<C>(
+        &self,
+        gpk: &GroupPublicKey,
+        message: &[u8],
+    ) -> Result<bool, Error>
+    where
+        C: HashToScalarParameter,
+    {
+        if gpk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let c = self.c;
+        // R1 = u * s_alpha - T1 * c, R2 = v * s_beta - T2 * c
+        let R1 = gpk.u * self.s_alpha - self.T1 * c;
+        let R2 = gpk.v * self.s_beta - self.T2 * c;
+        // R3 = e(T3, g2) * s_x - e(h, w) * (s_alpha + s_beta)
+        //      - e(h, g2) * (s_delta_1 + s_delta_2)
+        //      + (e(T3, w) - e(g1, g2)) * c
+        let R3 = pairing_sum(
+            gpk,
+            &(self.T3 * self.s_x
+                - gpk.h * (self.s_delta_1 + self.s_delta_2)
+                - gpk.g1 * c),
+            &(self.T3 * c - gpk.h * (self.s_alpha + self.s_beta)),
+        );
+        // R4 = T1 * s_x - u * s_delta_1, R5 = T2 * s_x - v * s_delta_2
+        let R4 = self.T1 * self.s_x - gpk.u * self.s_delta_1;
+        let R5 = self.T2 * self.s_x - gpk.v * self.s_delta_2;
+
+        let c = compute_challenge::<C>(
+            gpk,
+            [&self.T1, &self.T2, &self.T3, &R1, &R2],
+            &R3,
+            [&R4, &R5],
+            message,
+        )?;
+        Ok(c == self.c)
+    }
+
+    /// Recover the `A` value of the member key used to sign,
+    /// `A = T3 - (T1 * xi_1 + T2 * xi_2)`.
+    pub fn open(&self, opener_key: &OpenerSecretKey) -> G1Projective {
+        self.T3 - (self.T1 * opener_key.xi_1 + self.T2 * opener_key.xi_2)
+    }
+
+    /// Get the octets representation of `Signature`,
+    /// `T1 || T2 || T3 || c || s_alpha || s_beta || s_x || s_delta_1 ||
+    /// s_delta_2`.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        let mut octets = [0u8; Self::SIZE_BYTES];
+        let points = [&self.T1, &self.T2, &self.T3];
+        let scalars = [
+            &self.c,
+            &self.s_alpha,
+            &self.s_beta,
+            &self.s_x,
+            &self.s_delta_1,
+            &self.s_delta_2,
+        ];
+        let (point_octets, scalar_octets) =
+            octets.split_at_mut(3 * OCTET_POINT_G1_LENGTH);
+        for (chunk, p) in point_octets
+            .chunks_mut(OCTET_POINT_G1_LENGTH)
+            .zip(points.iter())
+        {
+            chunk.copy_from_slice(&point_to_octets_g1(p));
+        }
+        for (chunk, s) in scalar_octets
+            .chunks_mut(OCTET_SCALAR_LENGTH)
+            .zip(scalars.iter())
+        {
+            chunk.copy_from_slice(&s.to_bytes_be());
+        }
+        octets
+    }
+
+    /// Convert from the representation of `to_octets` to a `Signature`.
+    pub fn from_octets(octets: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let (point_octets, scalar_octets) =
+            octets.split_at(3 * OCTET_POINT_G1_LENGTH);
+        let points = point_octets
+            .chunks(OCTET_POINT_G1_LENGTH)
+            .map(|chunk| {
+                let p = slice_to_point_g1(chunk)?;
+                if p.is_identity().unwrap_u8() == 1 {
+                    return Err(Error::PointIsIdentity);
+                }
+                Ok(p)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let scalars = scalar_octets
+            .chunks(OCTET_SCALAR_LENGTH)
+            .map(|chunk| {
+                let s = Scalar::from_bytes_be(
+                    &<[u8; OCTET_SCALAR_LENGTH]>::try_from(chunk)?,
+                );
+                if s.is_none().unwrap_u8() == 1 {
+                    return Err(Error::MalformedSignature {
+                        cause: "failure while deserializing a `Scalar` value"
+                            .to_owned(),
+                    });
+                }
+                Ok(s.unwrap())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if scalars[0].is_zero().unwrap_u8() == 1 {
+            return Err(Error::UnexpectedZeroValue);
+        }
+
+        Ok(Self {
+            T1: points[0],
+            T2: points[1],
+            T3: points[2],
+            c: scalars[0],
+            s_alpha: scalars[1],
+            s_beta: scalars[2],
+            s_x: scalars[3],
+            s_delta_1: scalars[4],
+            s_delta_2: scalars[5],
+        })
+    }
+}
+
+/// Compute `e(p, g2) + e(q, w)`.
+fn pairing_sum(gpk: &GroupPublicKey, p: &G1Projective, q: &G1Projective) -> Gt {
+    Bls12::multi_miller_loop(&[
+        (&p.to_affine(), &G2Prepared::from(gpk.g2.to_affine())),
+        (&q.to_affine(), &G2Prepared::from(gpk.w.to_affine())),
+    ])
+    .final_exponentiation()
+}
+
+/// Compute the Fiat-Shamir challenge.
+/// c = hash_to_scalar(gpk || T1 || T2 || T3 || R1 || R2 || R3 || R4 || R5 ||
+/// I2OSP(length(message), 8) || message)
+fn compute_challenge<C>(
+    gpk: &GroupPublicKey,
+    g1_points: [&G1Projective; 5],
+    R3: &Gt,
+    R4_R5: [&G1Projective; 2],
+    message: &[u8],
+) -> Result<Scalar, Error>
+where
+    C: HashToScalarParameter,
+{
+    let mut data_to_hash = vec![];
+    data_to_hash.extend(gpk.to_octets());
+    for p in g1_points {
+        data_to_hash.extend(point_to_octets_g1(p));
+    }
+    data_to_hash.extend(point_to_octets_gt(R3)?);
+    for p in R4_R5 {
+        data_to_hash.extend(point_to_octets_g1(p));
+    }
+    data_to_hash.extend(i2osp_with_data(
+        message,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+
+    C::hash_to_scalar(&data_to_hash, None)
+}
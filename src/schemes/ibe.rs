@@ -0,0 +1,5 @@
+// Core implementation of the Boneh-Franklin IBE scheme.
+pub(crate) mod core;
+
+/// Boneh-Franklin IBE ciphersuites abstraction over core implementation.
+pub mod ciphersuites;
@@ -0,0 +1,5 @@
+/// Boneh-Franklin IBE BLS12-381 ciphersuites.
+pub mod bls12_381;
+
+/// Boneh-Franklin IBE BLS12-381-G2-Sha-256 ciphersuite.
+pub mod bls12_381_g2_sha_256;
@@ -0,0 +1,32 @@
+use crate::{
+    curves::bls12_381::{
+        OCTET_POINT_G1_LENGTH,
+        OCTET_POINT_G2_LENGTH,
+        OCTET_SCALAR_LENGTH,
+    },
+    schemes::ibe::core::bf::{CIPHERTEXT_OVERHEAD, MAX_MESSAGE_LENGTH},
+};
+
+pub use crate::schemes::ibe::core::{
+    constants::MIN_KEY_GEN_IKM_LENGTH,
+    identity_key::IdentitySecretKey,
+    key_pair::{KeyPair, PublicKey, SecretKey},
+};
+
+/// Master "SecretKey" length in bytes for "IBE_BF_BLS12381G2" ciphersuite.
+pub const IBE_BF_BLS12381G2_SECRET_KEY_LENGTH: usize = OCTET_SCALAR_LENGTH;
+
+/// Master "PublicKey" length in bytes for "IBE_BF_BLS12381G2" ciphersuite.
+pub const IBE_BF_BLS12381G2_PUBLIC_KEY_LENGTH: usize = OCTET_POINT_G1_LENGTH;
+
+/// "IdentitySecretKey" length in bytes for "IBE_BF_BLS12381G2" ciphersuite.
+pub const IBE_BF_BLS12381G2_IDENTITY_SECRET_KEY_LENGTH: usize =
+    OCTET_POINT_G2_LENGTH;
+
+/// Length in bytes of a ciphertext in excess of the message length, for
+/// "IBE_BF_BLS12381G2" ciphersuite.
+pub const IBE_BF_BLS12381G2_CIPHERTEXT_OVERHEAD: usize = CIPHERTEXT_OVERHEAD;
+
+/// Maximum length in bytes of an encrypted message, for "IBE_BF_BLS12381G2"
+/// ciphersuite.
+pub const IBE_BF_BLS12381G2_MAX_MESSAGE_LENGTH: usize = MAX_MESSAGE_LENGTH;
@@ -0,0 +1,95 @@
+use crate::{
+    common::{
+        ciphersuite::{CipherSuiteId, CipherSuiteParameter},
+        hash_param::{h2c::HashToCurveParameter, ExpandMessageParameter},
+    },
+    curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    ibe::core::{
+        bf::{BfParameter, Ciphertext},
+        identity_key::IdentitySecretKey,
+        key_pair::{PublicKey, SecretKey},
+    },
+    Error,
+};
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+
+#[derive(Debug, Clone)]
+pub(crate) struct IbeBls12381G2XmdSha256CipherSuiteParameter;
+
+impl CipherSuiteParameter for IbeBls12381G2XmdSha256CipherSuiteParameter {
+    const ID: CipherSuiteId = CipherSuiteId::IbeBfBls12381G2XmdSha256;
+}
+
+impl ExpandMessageParameter for IbeBls12381G2XmdSha256CipherSuiteParameter {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+impl HashToCurveParameter for IbeBls12381G2XmdSha256CipherSuiteParameter {}
+
+impl BfParameter for IbeBls12381G2XmdSha256CipherSuiteParameter {
+    const DST_SUFFIX_H2: &'static [u8] = b"IBE_H2_";
+    const DST_SUFFIX_H3: &'static [u8] = b"IBE_H3_";
+    const DST_SUFFIX_H4: &'static [u8] = b"IBE_H4_";
+}
+
+/// Extract the secret key of an identity from the master secret key.
+pub fn extract<T>(
+    msk: &SecretKey,
+    identity: T,
+) -> Result<IdentitySecretKey, Error>
+where
+    T: AsRef<[u8]>,
+{
+    IdentitySecretKey::extract::<IbeBls12381G2XmdSha256CipherSuiteParameter>(
+        msk,
+        identity.as_ref(),
+    )
+}
+
+/// Verify that an `IdentitySecretKey` is the secret key of an identity for
+/// the master public key.
+pub fn verify_identity_key<T>(
+    mpk: &PublicKey,
+    identity: T,
+    identity_key: &IdentitySecretKey,
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    identity_key.verify::<IbeBls12381G2XmdSha256CipherSuiteParameter>(
+        mpk,
+        identity.as_ref(),
+    )
+}
+
+/// Encrypt a message to an identity under the master public key.
+pub fn encrypt<T, I, R>(
+    mpk: &PublicKey,
+    identity: I,
+    message: T,
+    rng: &mut R,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    I: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    Ok(crate::schemes::ibe::core::bf::encrypt::<
+        IbeBls12381G2XmdSha256CipherSuiteParameter,
+        _,
+    >(&mpk.0, identity.as_ref(), message.as_ref(), rng)?
+    .to_octets())
+}
+
+/// Decrypt a ciphertext with the secret key of the identity it was encrypted
+/// to.
+pub fn decrypt(
+    identity_key: &IdentitySecretKey,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let ciphertext = Ciphertext::from_octets(ciphertext)?;
+    crate::schemes::ibe::core::bf::decrypt::<
+        IbeBls12381G2XmdSha256CipherSuiteParameter,
+    >(&identity_key.0, &ciphertext)
+}
@@ -0,0 +1,6 @@
+pub(crate) mod bf;
+pub(crate) mod constants;
+pub(crate) mod identity_key;
+
+/// IBE master key pair.
+pub(crate) mod key_pair;
@@ -0,0 +1,224 @@
+use crate::{
+    common::hash_param::h2c::HashToCurveParameter,
+    curves::{
+        bls12_381::{
+            hash_to_curve::{ExpandMessageState, InitExpandMessage},
+            Bls12,
+            G1Projective,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+        },
+        point_serde::{
+            octets_to_point_g1,
+            point_to_octets_g1,
+            point_to_octets_gt,
+        },
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::Engine;
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+/// Length in bytes of the random value `sigma` of the Fujisaki-Okamoto
+/// transform.
+pub(crate) const SIGMA_LENGTH: usize = 32;
+
+/// Maximum length in bytes of a message, bounded by the maximum output length
+/// of `expand_message`.
+pub(crate) const MAX_MESSAGE_LENGTH: usize = 255 * 32;
+
+/// Length in bytes of a ciphertext, in excess of the message length.
+pub(crate) const CIPHERTEXT_OVERHEAD: usize =
+    OCTET_POINT_G1_LENGTH + SIGMA_LENGTH;
+
+/// Parameters of the Boneh-Franklin IBE, on top of the hash to curve ones.
+pub(crate) trait BfParameter: HashToCurveParameter {
+    /// Suffixes of the domain separation tags of the hash functions used in
+    /// the Fujisaki-Okamoto transform, appended to the ciphersuite ID.
+    const DST_SUFFIX_H2: &'static [u8];
+    const DST_SUFFIX_H3: &'static [u8];
+    const DST_SUFFIX_H4: &'static [u8];
+}
+
+/// A ciphertext of the Boneh-Franklin IBE with the Fujisaki-Okamoto
+/// transform, `(U, V, W)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Ciphertext {
+    u: G1Projective,
+    v: [u8; SIGMA_LENGTH],
+    w: Vec<u8>,
+}
+
+impl Ciphertext {
+    /// Get the octets representation of `Ciphertext` as `U || V || W`.
+    pub fn to_octets(&self) -> Vec<u8> {
+        [point_to_octets_g1(&self.u).as_ref(), &self.v, &self.w].concat()
+    }
+
+    /// Get the `Ciphertext` from its octets representation.
+    pub fn from_octets(data: &[u8]) -> Result<Self, Error> {
+        if data.len() <= CIPHERTEXT_OVERHEAD
+            || data.len() > CIPHERTEXT_OVERHEAD + MAX_MESSAGE_LENGTH
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "invalid ciphertext length {}, expected more than {} and \
+                     at most {} bytes",
+                    data.len(),
+                    CIPHERTEXT_OVERHEAD,
+                    CIPHERTEXT_OVERHEAD + MAX_MESSAGE_LENGTH
+                ),
+            });
+        }
+        let mut u = [0u8; OCTET_POINT_G1_LENGTH];
+        u.copy_from_slice(&data[..OCTET_POINT_G1_LENGTH]);
+        let u = octets_to_point_g1(&u)?;
+        if u.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+        let mut v = [0u8; SIGMA_LENGTH];
+        v.copy_from_slice(&data[OCTET_POINT_G1_LENGTH..CIPHERTEXT_OVERHEAD]);
+        Ok(Self {
+            u,
+            v,
+            w: data[CIPHERTEXT_OVERHEAD..].to_vec(),
+        })
+    }
+}
+
+/// Encrypt `message` to `identity` under the master public key `mpk`. The
+/// ciphertext can be decrypted with the secret key of `identity`, `msk *
+/// H(identity)`, which is also the BLS signature of `identity` under `mpk`.
+pub(crate) fn encrypt<C, R>(
+    mpk: &G1Projective,
+    identity: &[u8],
+    message: &[u8],
+    rng: &mut R,
+) -> Result<Ciphertext, Error>
+where
+    C: BfParameter,
+    R: RngCore + CryptoRng,
+{
+    if identity.is_empty() {
+        return Err(Error::BadParams {
+            cause: "empty identity".to_owned(),
+        });
+    }
+    if message.is_empty() || message.len() > MAX_MESSAGE_LENGTH {
+        return Err(Error::BadParams {
+            cause: format!(
+                "invalid message length {}, expected between 1 and {} bytes",
+                message.len(),
+                MAX_MESSAGE_LENGTH
+            ),
+        });
+    }
+    // Validate the master public key; it should not be an identity and
+    // should belong to subgroup.
+    if (mpk.is_identity() | !mpk.to_affine().is_torsion_free()).unwrap_u8() == 1
+    {
+        return Err(Error::InvalidPublicKey);
+    }
+
+    let mut sigma = [0u8; SIGMA_LENGTH];
+    rng.fill_bytes(&mut sigma);
+
+    // r = H3(sigma || M)
+    let r = h3::<C>(&sigma, message);
+    if r.is_zero().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    // U = r * P1
+    let u = G1Projective::generator() * r;
+    // g_id = e(r * MPK, H(identity))
+    let q_id = C::hash_to_g2(identity, &C::default_hash_to_point_dst())?;
+    let g_id = Bls12::pairing(&(mpk * r).to_affine(), &q_id.to_affine());
+    // V = sigma XOR H2(g_id)
+    let mut v = h2::<C>(&point_to_octets_gt(&g_id)?);
+    xor(&mut v, &sigma);
+    // W = M XOR H4(sigma)
+    let mut w = h4::<C>(&sigma, message.len());
+    xor(&mut w, message);
+
+    sigma.zeroize();
+    Ok(Ciphertext { u, v, w })
+}
+
+/// Decrypt `ciphertext` with the secret key of the identity it was encrypted
+/// to.
+pub(crate) fn decrypt<C>(
+    identity_key: &G2Projective,
+    ciphertext: &Ciphertext,
+) -> Result<Vec<u8>, Error>
+where
+    C: BfParameter,
+{
+    // g_id = e(U, d_id)
+    let g_id =
+        Bls12::pairing(&ciphertext.u.to_affine(), &identity_key.to_affine());
+    // sigma = V XOR H2(g_id)
+    let mut sigma = h2::<C>(&point_to_octets_gt(&g_id)?);
+    xor(&mut sigma, &ciphertext.v);
+    // M = W XOR H4(sigma)
+    let mut message = h4::<C>(&sigma, ciphertext.w.len());
+    xor(&mut message, &ciphertext.w);
+
+    // Check U == H3(sigma || M) * P1
+    let r = h3::<C>(&sigma, &message);
+    sigma.zeroize();
+    if G1Projective::generator() * r != ciphertext.u {
+        message.zeroize();
+        return Err(Error::CryptoOps {
+            cause: "decryption failed, invalid ciphertext or identity key"
+                .to_owned(),
+        });
+    }
+    Ok(message)
+}
+
+fn expand<C>(data: &[u8], dst_suffix: &[u8], out: &mut [u8])
+where
+    C: BfParameter,
+{
+    let dst = [C::ID.as_octets(), dst_suffix].concat();
+    let mut expander = C::Expander::init_expand(data, &dst, out.len());
+    expander.read_into(out);
+}
+
+// H2: GT -> {0, 1}^256.
+fn h2<C>(g_id: &[u8]) -> [u8; SIGMA_LENGTH]
+where
+    C: BfParameter,
+{
+    let mut out = [0u8; SIGMA_LENGTH];
+    expand::<C>(g_id, C::DST_SUFFIX_H2, &mut out);
+    out
+}
+
+// H3: {0, 1}^256 x {0, 1}^* -> Zr.
+fn h3<C>(sigma: &[u8], message: &[u8]) -> Scalar
+where
+    C: BfParameter,
+{
+    let mut buf = [0u8; 64];
+    expand::<C>(&[sigma, message].concat(), C::DST_SUFFIX_H3, &mut buf[16..]);
+    Scalar::from_wide_bytes_be_mod_r(&buf)
+}
+
+// H4: {0, 1}^256 -> {0, 1}^len.
+fn h4<C>(sigma: &[u8], len: usize) -> Vec<u8>
+where
+    C: BfParameter,
+{
+    let mut out = vec![0u8; len];
+    expand::<C>(sigma, C::DST_SUFFIX_H4, &mut out);
+    out
+}
+
+fn xor(out: &mut [u8], other: &[u8]) {
+    out.iter_mut().zip(other.iter()).for_each(|(a, b)| *a ^= b);
+}
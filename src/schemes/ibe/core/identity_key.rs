@@ -0,0 +1,123 @@
+use super::key_pair::{PublicKey, SecretKey};
+use crate::{
+    common::hash_param::h2c::HashToCurveParameter,
+    curves::{
+        bls12_381::{
+            Bls12,
+            G1Projective,
+            G2Prepared,
+            G2Projective,
+            OCTET_POINT_G2_LENGTH,
+        },
+        point_serde::{octets_to_point_g2, point_to_octets_g2},
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use subtle::Choice;
+use zeroize::Zeroize;
+
+/// Secret key of an identity, extracted from the master secret key by the
+/// private key generator.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IdentitySecretKey(pub(crate) G2Projective);
+
+impl core::fmt::Debug for IdentitySecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "****")
+    }
+}
+
+impl Zeroize for IdentitySecretKey {
+    fn zeroize(&mut self) {
+        self.0 = G2Projective::identity();
+    }
+}
+
+impl Drop for IdentitySecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl IdentitySecretKey {
+    /// Number of bytes needed to represent the identity secret key.
+    pub const SIZE_BYTES: usize = OCTET_POINT_G2_LENGTH;
+
+    /// Extract the secret key of `identity`, `d_id = msk * H(identity)`.
+    pub(crate) fn extract<C>(
+        msk: &SecretKey,
+        identity: &[u8],
+    ) -> Result<Self, Error>
+    where
+        C: HashToCurveParameter,
+    {
+        if identity.is_empty() {
+            return Err(Error::BadParams {
+                cause: "empty identity".to_owned(),
+            });
+        }
+        if msk.0.is_zero().unwrap_u8() == 1 {
+            return Err(Error::InvalidSecretKey);
+        }
+        let q_id = C::hash_to_g2(identity, &C::default_hash_to_point_dst())?;
+        Ok(Self(q_id * (*msk.0)))
+    }
+
+    /// Check if the `Self` is valid.
+    pub fn is_valid(&self) -> Choice {
+        (!self.0.is_identity())
+            & self.0.is_on_curve()
+            & self.0.to_affine().is_torsion_free()
+    }
+
+    /// Verify that `self` is the secret key of `identity` for the master
+    /// public key `mpk`, i.e. `e(mpk, H(identity)) == e(P1, d_id)`.
+    pub(crate) fn verify<C>(
+        &self,
+        mpk: &PublicKey,
+        identity: &[u8],
+    ) -> Result<bool, Error>
+    where
+        C: HashToCurveParameter,
+    {
+        if identity.is_empty() {
+            return Err(Error::BadParams {
+                cause: "empty identity".to_owned(),
+            });
+        }
+        if mpk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        let q_id = C::hash_to_g2(identity, &C::default_hash_to_point_dst())?;
+        let p = G1Projective::generator();
+
+        Ok(Bls12::multi_miller_loop(&[
+            (&mpk.0.to_affine(), &G2Prepared::from(q_id.to_affine())),
+            (&(-p.to_affine()), &G2Prepared::from(self.0.to_affine())),
+        ])
+        .final_exponentiation()
+        .is_identity()
+        .unwrap_u8()
+            == 1u8)
+    }
+
+    /// Get the octets representation of `IdentitySecretKey`.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        point_to_octets_g2(&self.0)
+    }
+
+    /// Get the `IdentitySecretKey` from a sequence of bytes in big endian
+    /// format.
+    pub fn from_octets(data: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let key = Self(octets_to_point_g2(data)?);
+        if key.is_valid().unwrap_u8() == 0 {
+            return Err(Error::BadParams {
+                cause: "identity secret key is invalid".to_owned(),
+            });
+        }
+        Ok(key)
+    }
+}
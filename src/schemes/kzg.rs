@@ -0,0 +1,5 @@
+// Core implementation of KZG polynomial commitments.
+pub(crate) mod core;
+
+/// KZG commitments to EIP-4844 blobs, and multi-point openings.
+pub mod eip4844;
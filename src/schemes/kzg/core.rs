@@ -0,0 +1,7 @@
+pub(crate) mod constants;
+pub(crate) mod fft;
+pub(crate) mod polynomial;
+pub(crate) mod proof;
+
+/// KZG trusted setup.
+pub(crate) mod setup;
@@ -0,0 +1,36 @@
+/// Number of field elements in a blob.
+pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
+
+/// Number of bytes to store a field element.
+pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
+
+/// Number of bytes to store a blob.
+pub const BYTES_PER_BLOB: usize =
+    FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+
+/// Number of bytes to store a commitment.
+pub const BYTES_PER_COMMITMENT: usize = 48;
+
+/// Number of bytes to store a proof.
+pub const BYTES_PER_PROOF: usize = 48;
+
+/// Number of `G2` points, in monomial form, in the trusted setup.
+pub const G2_POINTS_IN_SETUP: usize = 65;
+
+/// Domain separator of the Fiat-Shamir challenge of a blob proof.
+pub(crate) const FIAT_SHAMIR_PROTOCOL_DOMAIN: &[u8] = b"FSBLOBVERIFY_V1_";
+
+/// Domain separator of the random challenge of batch verification.
+pub(crate) const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: &[u8] = b"RCKZGBATCH___V1_";
+
+/// Generator of the multiplicative group of the scalar field.
+pub(crate) const PRIMITIVE_ROOT_OF_UNITY: u64 = 7;
+
+/// `(r - 1) / FIELD_ELEMENTS_PER_BLOB` in little-endian 64-bit limbs, where
+/// `r` is the order of the scalar field.
+pub(crate) const ROOT_OF_UNITY_EXPONENT: [u64; 4] = [
+    0xbfeffffffff00000,
+    0x80553bda402fffe5,
+    0xd483339d80809a1d,
+    0x00073eda753299d7,
+];
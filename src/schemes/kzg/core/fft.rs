@@ -0,0 +1,55 @@
+use crate::{curves::bls12_381::Scalar, error::Error};
+use ff::Field;
+
+/// Permute `values` by reversing the bits of their indices. The length of
+/// `values` must be a power of two.
+pub(crate) fn bit_reversal_permutation<T: Clone>(values: &[T]) -> Vec<T> {
+    let n = values.len();
+    if n <= 1 {
+        return values.to_vec();
+    }
+    let shift = usize::BITS - n.trailing_zeros();
+    (0..n)
+        .map(|i| values[i.reverse_bits() >> shift].clone())
+        .collect()
+}
+
+/// Evaluate the polynomial with coefficients `values` at the powers of
+/// `root`, a primitive `n`-th root of unity where `n` is the length of
+/// `values`, in place.
+pub(crate) fn fft(values: &mut [Scalar], root: &Scalar) {
+    let n = values.len();
+    let permuted = bit_reversal_permutation(values);
+    values.copy_from_slice(&permuted);
+
+    let mut len = 2;
+    while len <= n {
+        // Primitive `len`-th root of unity.
+        let w_len = root.pow_vartime([(n / len) as u64]);
+        for start in (0..n).step_by(len) {
+            let mut w = Scalar::one();
+            for i in 0..len / 2 {
+                let u = values[start + i];
+                let v = values[start + i + len / 2] * w;
+                values[start + i] = u + v;
+                values[start + i + len / 2] = u - v;
+                w *= w_len;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Inverse of `fft`, interpolating the coefficients of a polynomial from its
+/// evaluations at the powers of `root`, in place.
+pub(crate) fn ifft(values: &mut [Scalar], root: &Scalar) -> Result<(), Error> {
+    let root_inverse = root.invert();
+    let n_inverse = Scalar::from(values.len() as u64).invert();
+    if (root_inverse.is_none() | n_inverse.is_none()).unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    fft(values, &root_inverse.unwrap());
+    let n_inverse = n_inverse.unwrap();
+    values.iter_mut().for_each(|v| *v *= n_inverse);
+    Ok(())
+}
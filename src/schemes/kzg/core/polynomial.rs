@@ -0,0 +1,81 @@
+// Helpers on polynomials in coefficient form, where the coefficient of `X^i`
+// is at index `i`.
+use crate::{curves::bls12_381::Scalar, error::Error};
+use ff::Field;
+
+/// Evaluate a polynomial at `x`.
+pub(crate) fn evaluate(coefficients: &[Scalar], x: &Scalar) -> Scalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::zero(), |acc, c| acc * x + c)
+}
+
+/// Vanishing polynomial `(X - z_1) * ... * (X - z_k)` of `zs`.
+pub(crate) fn vanishing(zs: &[Scalar]) -> Vec<Scalar> {
+    let mut coefficients = vec![Scalar::one()];
+    for z in zs {
+        // Multiply by (X - z).
+        coefficients.insert(0, Scalar::zero());
+        for i in 0..coefficients.len() - 1 {
+            let c = coefficients[i + 1] * z;
+            coefficients[i] -= c;
+        }
+    }
+    coefficients
+}
+
+/// Divide `numerator` by `denominator`, returning the quotient and the
+/// remainder.
+pub(crate) fn divide(
+    numerator: &[Scalar],
+    denominator: &[Scalar],
+) -> Result<(Vec<Scalar>, Vec<Scalar>), Error> {
+    let leading = denominator.last().map(|c| c.invert());
+    let leading_inverse = match leading {
+        Some(inverse) if inverse.is_some().unwrap_u8() == 1 => inverse.unwrap(),
+        _ => return Err(Error::UnexpectedZeroValue),
+    };
+    if numerator.len() < denominator.len() {
+        return Ok((vec![Scalar::zero()], numerator.to_vec()));
+    }
+
+    let mut remainder = numerator.to_vec();
+    let mut quotient =
+        vec![Scalar::zero(); numerator.len() - denominator.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let q = remainder[i + denominator.len() - 1] * leading_inverse;
+        quotient[i] = q;
+        for (j, d) in denominator.iter().enumerate() {
+            remainder[i + j] -= q * d;
+        }
+    }
+    remainder.truncate(denominator.len() - 1);
+    Ok((quotient, remainder))
+}
+
+/// Lagrange interpolation of the polynomial of degree less than `k` going
+/// through the `k` points `(zs[i], ys[i])`. The `zs` must be distinct.
+pub(crate) fn interpolate(
+    zs: &[Scalar],
+    ys: &[Scalar],
+) -> Result<Vec<Scalar>, Error> {
+    let z = vanishing(zs);
+    let mut coefficients = vec![Scalar::zero(); zs.len()];
+    for (z_i, y_i) in zs.iter().zip(ys.iter()) {
+        // Z(X) / (X - z_i) is zero at every z_j except z_i.
+        let (basis, _) = divide(&z, &[-*z_i, Scalar::one()])?;
+        let denominator = evaluate(&basis, z_i).invert();
+        if denominator.is_none().unwrap_u8() == 1 {
+            return Err(Error::BadParams {
+                cause: "interpolation points are not distinct".to_owned(),
+            });
+        }
+        let factor = *y_i * denominator.unwrap();
+        coefficients
+            .iter_mut()
+            .zip(basis.iter())
+            .for_each(|(c, b)| *c += factor * b);
+    }
+    Ok(coefficients)
+}
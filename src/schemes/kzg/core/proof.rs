@@ -0,0 +1,338 @@
+use super::{
+    constants::{
+        BYTES_PER_BLOB,
+        BYTES_PER_FIELD_ELEMENT,
+        FIAT_SHAMIR_PROTOCOL_DOMAIN,
+        FIELD_ELEMENTS_PER_BLOB,
+        RANDOM_CHALLENGE_KZG_BATCH_DOMAIN,
+    },
+    fft::{bit_reversal_permutation, fft, ifft},
+    polynomial::{divide, interpolate, vanishing},
+    setup::TrustedSetup,
+};
+use crate::{
+    curves::{
+        bls12_381::{Bls12, G1Projective, G2Prepared, G2Projective, Scalar},
+        point_serde::point_to_octets_g1,
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use sha2::{Digest, Sha256};
+
+/// Convert a blob to a polynomial in evaluation form, checking that every
+/// field element is canonically encoded.
+pub(crate) fn blob_to_polynomial(blob: &[u8]) -> Result<Vec<Scalar>, Error> {
+    if blob.len() != BYTES_PER_BLOB {
+        return Err(Error::BadParams {
+            cause: format!(
+                "invalid blob length {}, expected {}",
+                blob.len(),
+                BYTES_PER_BLOB
+            ),
+        });
+    }
+    blob.chunks(BYTES_PER_FIELD_ELEMENT)
+        .map(|chunk| {
+            let mut octets = [0u8; BYTES_PER_FIELD_ELEMENT];
+            octets.copy_from_slice(chunk);
+            bytes_to_bls_field(&octets)
+        })
+        .collect()
+}
+
+/// Convert octets to a field element, checking that they are canonically
+/// encoded.
+pub(crate) fn bytes_to_bls_field(
+    octets: &[u8; BYTES_PER_FIELD_ELEMENT],
+) -> Result<Scalar, Error> {
+    let scalar = Scalar::from_bytes_be(octets);
+    if scalar.is_none().unwrap_u8() == 1 {
+        return Err(Error::BadEncoding);
+    }
+    Ok(scalar.unwrap())
+}
+
+/// Commit to a polynomial in evaluation form.
+pub(crate) fn commit(
+    setup: &TrustedSetup,
+    polynomial: &[Scalar],
+) -> G1Projective {
+    G1Projective::multi_exp(&setup.g1_lagrange, polynomial)
+}
+
+/// Evaluate a polynomial in evaluation form at `z`, with the barycentric
+/// formula if `z` is not in the domain.
+pub(crate) fn evaluate_polynomial_in_evaluation_form(
+    setup: &TrustedSetup,
+    polynomial: &[Scalar],
+    z: &Scalar,
+) -> Result<Scalar, Error> {
+    if let Some(i) = setup.roots_of_unity_brp.iter().position(|w| w == z) {
+        return Ok(polynomial[i]);
+    }
+
+    let mut result = Scalar::zero();
+    for (p, w) in polynomial.iter().zip(setup.roots_of_unity_brp.iter()) {
+        // z is not in the domain, so z - w is not zero.
+        result += *p * w * (*z - w).invert().unwrap();
+    }
+    let width_inverse = Scalar::from(FIELD_ELEMENTS_PER_BLOB as u64).invert();
+    if width_inverse.is_none().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    Ok(result
+        * (z.pow_vartime([FIELD_ELEMENTS_PER_BLOB as u64]) - Scalar::one())
+        * width_inverse.unwrap())
+}
+
+/// Fiat-Shamir challenge of the evaluation point of a blob proof.
+pub(crate) fn compute_challenge(blob: &[u8], commitment: &[u8]) -> Scalar {
+    hash_to_bls_field(&[
+        FIAT_SHAMIR_PROTOCOL_DOMAIN,
+        &(FIELD_ELEMENTS_PER_BLOB as u128).to_be_bytes(),
+        blob,
+        commitment,
+    ])
+}
+
+/// Compute the proof of the evaluation of a polynomial in evaluation form at
+/// `z`, returning the proof and the evaluation.
+pub(crate) fn compute_kzg_proof(
+    setup: &TrustedSetup,
+    polynomial: &[Scalar],
+    z: &Scalar,
+) -> Result<(G1Projective, Scalar), Error> {
+    let y = evaluate_polynomial_in_evaluation_form(setup, polynomial, z)?;
+
+    // q(w_i) = (p(w_i) - y) / (w_i - z)
+    let mut quotient = vec![Scalar::zero(); FIELD_ELEMENTS_PER_BLOB];
+    let mut m = None;
+    for (i, (p, w)) in polynomial
+        .iter()
+        .zip(setup.roots_of_unity_brp.iter())
+        .enumerate()
+    {
+        if w == z {
+            m = Some(i);
+            continue;
+        }
+        quotient[i] = (*p - y) * (*w - z).invert().unwrap();
+    }
+    // If z = w_m is in the domain, q(w_m) = sum_{i != m} (p(w_i) - y) * w_i
+    // / (z * (z - w_i)).
+    if let Some(m) = m {
+        quotient[m] = polynomial
+            .iter()
+            .zip(setup.roots_of_unity_brp.iter())
+            .enumerate()
+            .filter(|(i, _)| *i != m)
+            .fold(Scalar::zero(), |acc, (_, (p, w))| {
+                acc + (*p - y) * w * (*z * (*z - w)).invert().unwrap()
+            });
+    }
+
+    Ok((commit(setup, &quotient), y))
+}
+
+/// Verify the proof that the polynomial committed to by `commitment`
+/// evaluates to `y` at `z`.
+pub(crate) fn verify_kzg_proof(
+    setup: &TrustedSetup,
+    commitment: &G1Projective,
+    z: &Scalar,
+    y: &Scalar,
+    proof: &G1Projective,
+) -> bool {
+    // e(C - [y], -[1]) * e(proof, [s - z]) == 1
+    let x_minus_z = setup.g2_monomial[1] - setup.g2_monomial[0] * z;
+    let p_minus_y = commitment - G1Projective::generator() * y;
+    pairing_check(&[
+        (p_minus_y, -G2Projective::generator()),
+        (*proof, x_minus_z),
+    ])
+}
+
+/// Batch verify proofs of evaluation of polynomials, with random linear
+/// combination.
+pub(crate) fn verify_kzg_proof_batch(
+    setup: &TrustedSetup,
+    commitments: &[G1Projective],
+    zs: &[Scalar],
+    ys: &[Scalar],
+    proofs: &[G1Projective],
+) -> bool {
+    let n = commitments.len();
+    let mut data = vec![
+        RANDOM_CHALLENGE_KZG_BATCH_DOMAIN.to_vec(),
+        (FIELD_ELEMENTS_PER_BLOB as u64).to_be_bytes().to_vec(),
+        (n as u64).to_be_bytes().to_vec(),
+    ];
+    for (((commitment, z), y), proof) in commitments
+        .iter()
+        .zip(zs.iter())
+        .zip(ys.iter())
+        .zip(proofs.iter())
+    {
+        data.push(point_to_octets_g1(commitment).to_vec());
+        data.push(z.to_bytes_be().to_vec());
+        data.push(y.to_bytes_be().to_vec());
+        data.push(point_to_octets_g1(proof).to_vec());
+    }
+    let r =
+        hash_to_bls_field(&data.iter().map(Vec::as_slice).collect::<Vec<_>>());
+
+    let mut r_power = Scalar::one();
+    let mut r_powers = Vec::with_capacity(n);
+    for _ in 0..n {
+        r_powers.push(r_power);
+        r_power *= r;
+    }
+    let proof_lincomb = G1Projective::multi_exp(proofs, &r_powers);
+    let proof_z_lincomb = G1Projective::multi_exp(
+        proofs,
+        &r_powers
+            .iter()
+            .zip(zs.iter())
+            .map(|(r, z)| r * z)
+            .collect::<Vec<_>>(),
+    );
+    let c_minus_ys = commitments
+        .iter()
+        .zip(ys.iter())
+        .map(|(c, y)| c - G1Projective::generator() * y)
+        .collect::<Vec<_>>();
+    let c_minus_y_lincomb = G1Projective::multi_exp(&c_minus_ys, &r_powers);
+
+    pairing_check(&[
+        (proof_lincomb, -setup.g2_monomial[1]),
+        (
+            c_minus_y_lincomb + proof_z_lincomb,
+            G2Projective::generator(),
+        ),
+    ])
+}
+
+/// Compute the proof of the evaluation of a polynomial in evaluation form at
+/// the distinct points `zs`, returning the proof and the evaluations.
+pub(crate) fn compute_kzg_multi_proof(
+    setup: &TrustedSetup,
+    polynomial: &[Scalar],
+    zs: &[Scalar],
+) -> Result<(G1Projective, Vec<Scalar>), Error> {
+    check_multi_proof_points(setup, zs)?;
+    let ys = zs
+        .iter()
+        .map(|z| evaluate_polynomial_in_evaluation_form(setup, polynomial, z))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // q(X) = (p(X) - I(X)) / Z(X), where I interpolates the evaluations and
+    // Z vanishes on `zs`.
+    let mut coefficients = bit_reversal_permutation(polynomial);
+    ifft(&mut coefficients, &setup.root_of_unity)?;
+    let interpolation = interpolate(zs, &ys)?;
+    coefficients
+        .iter_mut()
+        .zip(interpolation.iter())
+        .for_each(|(c, i)| *c -= i);
+    let (quotient, _) = divide(&coefficients, &vanishing(zs))?;
+
+    Ok((commit_coefficients(setup, &quotient), ys))
+}
+
+/// Verify the proof that the polynomial committed to by `commitment`
+/// evaluates to `ys` at the distinct points `zs`.
+pub(crate) fn verify_kzg_multi_proof(
+    setup: &TrustedSetup,
+    commitment: &G1Projective,
+    zs: &[Scalar],
+    ys: &[Scalar],
+    proof: &G1Projective,
+) -> Result<bool, Error> {
+    check_multi_proof_points(setup, zs)?;
+    if zs.len() != ys.len() {
+        return Err(Error::BadParams {
+            cause: format!(
+                "number of points {} and evaluations {} mismatch",
+                zs.len(),
+                ys.len()
+            ),
+        });
+    }
+    let interpolation = interpolate(zs, ys)?;
+    let vanishing = vanishing(zs);
+
+    // e(C - [I(s)], -[1]) * e(proof, [Z(s)]) == 1
+    let c_minus_i = commitment - commit_coefficients(setup, &interpolation);
+    let z_s = G2Projective::multi_exp(
+        &setup.g2_monomial[..vanishing.len()],
+        &vanishing,
+    );
+    Ok(pairing_check(&[
+        (c_minus_i, -G2Projective::generator()),
+        (*proof, z_s),
+    ]))
+}
+
+// Commit to a polynomial of degree less than `FIELD_ELEMENTS_PER_BLOB` in
+// coefficient form, by evaluating it over the domain.
+fn commit_coefficients(
+    setup: &TrustedSetup,
+    coefficients: &[Scalar],
+) -> G1Projective {
+    let mut evaluations = coefficients.to_vec();
+    evaluations.resize(FIELD_ELEMENTS_PER_BLOB, Scalar::zero());
+    fft(&mut evaluations, &setup.root_of_unity);
+    commit(setup, &bit_reversal_permutation(&evaluations))
+}
+
+fn check_multi_proof_points(
+    setup: &TrustedSetup,
+    zs: &[Scalar],
+) -> Result<(), Error> {
+    // [Z(s)] is computed from the `G2` points of the setup.
+    if zs.is_empty() || zs.len() >= setup.g2_monomial.len() {
+        return Err(Error::BadParams {
+            cause: format!(
+                "invalid number of points {}, expected between 1 and {}",
+                zs.len(),
+                setup.g2_monomial.len() - 1
+            ),
+        });
+    }
+    for (i, z) in zs.iter().enumerate() {
+        if zs[..i].contains(z) {
+            return Err(Error::BadParams {
+                cause: "points are not distinct".to_owned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// SHA-256 of the concatenation of `data`, reduced modulo the order of the
+// scalar field.
+fn hash_to_bls_field(data: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha256::new();
+    data.iter().for_each(|d| hasher.update(d));
+    let mut buf = [0u8; 64];
+    buf[32..].copy_from_slice(&hasher.finalize());
+    Scalar::from_wide_bytes_be_mod_r(&buf)
+}
+
+// Check that the product of the pairings of `pairs` is the identity.
+fn pairing_check(pairs: &[(G1Projective, G2Projective)]) -> bool {
+    let pairs = pairs
+        .iter()
+        .map(|(p, q)| (p.to_affine(), G2Prepared::from(q.to_affine())))
+        .collect::<Vec<_>>();
+    Bls12::multi_miller_loop(
+        &pairs.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>(),
+    )
+    .final_exponentiation()
+    .is_identity()
+    .unwrap_u8()
+        == 1u8
+}
@@ -0,0 +1,192 @@
+use super::{
+    constants::{
+        FIELD_ELEMENTS_PER_BLOB,
+        G2_POINTS_IN_SETUP,
+        PRIMITIVE_ROOT_OF_UNITY,
+        ROOT_OF_UNITY_EXPONENT,
+    },
+    fft::bit_reversal_permutation,
+};
+use crate::{
+    curves::{
+        bls12_381::{
+            G1Projective,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+        },
+        point_serde::{octets_to_point_g1, octets_to_point_g2},
+    },
+    error::Error,
+};
+use ff::Field;
+use group::Group;
+
+/// KZG trusted setup, as used by EIP-4844: `FIELD_ELEMENTS_PER_BLOB` points
+/// of `G1` in Lagrange form over the bit-reversal permuted roots of unity, and
+/// `G2_POINTS_IN_SETUP` points of `G2` in monomial form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedSetup {
+    pub(crate) g1_lagrange: Vec<G1Projective>,
+    pub(crate) g2_monomial: Vec<G2Projective>,
+    // Primitive `FIELD_ELEMENTS_PER_BLOB`-th root of unity.
+    pub(crate) root_of_unity: Scalar,
+    // Powers of `root_of_unity`, in bit-reversal permutation order.
+    pub(crate) roots_of_unity_brp: Vec<Scalar>,
+}
+
+impl TrustedSetup {
+    /// Load a trusted setup from compressed points, the `G1` points in
+    /// Lagrange form and natural order, as `KZG_SETUP_G1_LAGRANGE` of
+    /// EIP-4844, and the `G2` points in monomial form.
+    pub fn from_octets(
+        g1_lagrange: &[[u8; OCTET_POINT_G1_LENGTH]],
+        g2_monomial: &[[u8; OCTET_POINT_G2_LENGTH]],
+    ) -> Result<Self, Error> {
+        if g1_lagrange.len() != FIELD_ELEMENTS_PER_BLOB
+            || g2_monomial.len() != G2_POINTS_IN_SETUP
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "trusted setup size mismatch, expected {} G1 and {} G2 \
+                     points, found {} and {}",
+                    FIELD_ELEMENTS_PER_BLOB,
+                    G2_POINTS_IN_SETUP,
+                    g1_lagrange.len(),
+                    g2_monomial.len()
+                ),
+            });
+        }
+        Ok(Self::new(
+            bit_reversal_permutation(
+                &g1_lagrange
+                    .iter()
+                    .map(octets_to_point_g1)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            g2_monomial
+                .iter()
+                .map(octets_to_point_g2)
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+
+    /// Load a trusted setup in the text format of the EIP-4844 reference
+    /// implementations: the number of `G1` points and the number of `G2`
+    /// points, followed by the hex encoded `G1` points in Lagrange form and
+    /// the hex encoded `G2` points in monomial form, one per line. Any
+    /// further line, such as `G1` points in monomial form, is ignored.
+    pub fn from_text(text: &str) -> Result<Self, Error> {
+        let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty());
+        let mut next_count = || -> Result<usize, Error> {
+            lines.next().and_then(|l| l.parse().ok()).ok_or_else(|| {
+                Error::BadParams {
+                    cause: "invalid trusted setup header".to_owned(),
+                }
+            })
+        };
+        let g1_count = next_count()?;
+        let g2_count = next_count()?;
+
+        let g1_lagrange = lines
+            .by_ref()
+            .take(g1_count)
+            .map(hex_to_byte_array::<OCTET_POINT_G1_LENGTH>)
+            .collect::<Result<Vec<_>, _>>()?;
+        let g2_monomial = lines
+            .take(g2_count)
+            .map(hex_to_byte_array::<OCTET_POINT_G2_LENGTH>)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_octets(&g1_lagrange, &g2_monomial)
+    }
+
+    /// Generate a trusted setup from a known `secret`.
+    ///
+    /// This is insecure, as anyone knowing `secret` can forge proofs, and
+    /// must only be used for testing.
+    pub fn insecure_from_secret(secret: &[u8; 32]) -> Result<Self, Error> {
+        let s = Scalar::from_bytes_be(secret);
+        if s.is_none().unwrap_u8() == 1 {
+            return Err(Error::BadEncoding);
+        }
+        let s = s.unwrap();
+
+        let roots_of_unity_brp =
+            bit_reversal_permutation(&roots_of_unity(&root_of_unity()));
+        // L_i(s) = w_i * (s^n - 1) / (n * (s - w_i))
+        let s_n_minus_one =
+            s.pow_vartime([FIELD_ELEMENTS_PER_BLOB as u64]) - Scalar::one();
+        let g1_lagrange = roots_of_unity_brp
+            .iter()
+            .map(|w| {
+                let denominator =
+                    (Scalar::from(FIELD_ELEMENTS_PER_BLOB as u64) * (s - w))
+                        .invert();
+                if denominator.is_none().unwrap_u8() == 1 {
+                    return Err(Error::BadParams {
+                        cause: "secret is a root of unity".to_owned(),
+                    });
+                }
+                Ok(G1Projective::generator()
+                    * (*w * s_n_minus_one * denominator.unwrap()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut s_i = Scalar::one();
+        let g2_monomial = (0..G2_POINTS_IN_SETUP)
+            .map(|_| {
+                let point = G2Projective::generator() * s_i;
+                s_i *= s;
+                point
+            })
+            .collect();
+
+        Ok(Self::new(g1_lagrange, g2_monomial))
+    }
+
+    fn new(
+        g1_lagrange: Vec<G1Projective>,
+        g2_monomial: Vec<G2Projective>,
+    ) -> Self {
+        let root_of_unity = root_of_unity();
+        Self {
+            g1_lagrange,
+            g2_monomial,
+            root_of_unity,
+            roots_of_unity_brp: bit_reversal_permutation(&roots_of_unity(
+                &root_of_unity,
+            )),
+        }
+    }
+}
+
+// Primitive `FIELD_ELEMENTS_PER_BLOB`-th root of unity.
+fn root_of_unity() -> Scalar {
+    Scalar::from(PRIMITIVE_ROOT_OF_UNITY).pow_vartime(ROOT_OF_UNITY_EXPONENT)
+}
+
+// Powers of `root`, in natural order.
+fn roots_of_unity(root: &Scalar) -> Vec<Scalar> {
+    let mut current = Scalar::one();
+    (0..FIELD_ELEMENTS_PER_BLOB)
+        .map(|_| {
+            let power = current;
+            current *= root;
+            power
+        })
+        .collect()
+}
+
+fn hex_to_byte_array<const N: usize>(hex: &str) -> Result<[u8; N], Error> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() != 2 * N || !hex.is_ascii() {
+        return Err(Error::BadEncoding);
+    }
+    let mut bytes = [0u8; N];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16)
+            .map_err(|_| Error::BadEncoding)?;
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,198 @@
+use crate::{
+    curves::{
+        bls12_381::G1Projective,
+        point_serde::{octets_to_point_g1, point_to_octets_g1},
+    },
+    kzg::core::proof::{
+        blob_to_polynomial,
+        bytes_to_bls_field,
+        commit,
+        compute_challenge,
+        evaluate_polynomial_in_evaluation_form,
+    },
+    Error,
+};
+
+pub use crate::schemes::kzg::core::{
+    constants::{
+        BYTES_PER_BLOB,
+        BYTES_PER_COMMITMENT,
+        BYTES_PER_FIELD_ELEMENT,
+        BYTES_PER_PROOF,
+        FIELD_ELEMENTS_PER_BLOB,
+        G2_POINTS_IN_SETUP,
+    },
+    setup::TrustedSetup,
+};
+
+/// Compute the commitment to a blob.
+pub fn blob_to_kzg_commitment(
+    blob: &[u8],
+    setup: &TrustedSetup,
+) -> Result<[u8; BYTES_PER_COMMITMENT], Error> {
+    let polynomial = blob_to_polynomial(blob)?;
+    Ok(point_to_octets_g1(&commit(setup, &polynomial)))
+}
+
+/// Compute the proof of the evaluation of the polynomial of a blob at `z`,
+/// returning the proof and the evaluation.
+pub fn compute_kzg_proof(
+    blob: &[u8],
+    z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    setup: &TrustedSetup,
+) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), Error> {
+    let polynomial = blob_to_polynomial(blob)?;
+    let (proof, y) = crate::schemes::kzg::core::proof::compute_kzg_proof(
+        setup,
+        &polynomial,
+        &bytes_to_bls_field(z)?,
+    )?;
+    Ok((point_to_octets_g1(&proof), y.to_bytes_be()))
+}
+
+/// Verify the proof that the polynomial committed to by `commitment`
+/// evaluates to `y` at `z`.
+pub fn verify_kzg_proof(
+    commitment: &[u8; BYTES_PER_COMMITMENT],
+    z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    y: &[u8; BYTES_PER_FIELD_ELEMENT],
+    proof: &[u8; BYTES_PER_PROOF],
+    setup: &TrustedSetup,
+) -> Result<bool, Error> {
+    Ok(crate::schemes::kzg::core::proof::verify_kzg_proof(
+        setup,
+        &octets_to_point_g1(commitment)?,
+        &bytes_to_bls_field(z)?,
+        &bytes_to_bls_field(y)?,
+        &octets_to_point_g1(proof)?,
+    ))
+}
+
+/// Compute the proof of the evaluation of the polynomial of a blob at the
+/// Fiat-Shamir challenge derived from the blob and its commitment.
+pub fn compute_blob_kzg_proof(
+    blob: &[u8],
+    commitment: &[u8; BYTES_PER_COMMITMENT],
+    setup: &TrustedSetup,
+) -> Result<[u8; BYTES_PER_PROOF], Error> {
+    let polynomial = blob_to_polynomial(blob)?;
+    // Validate the commitment.
+    octets_to_point_g1(commitment)?;
+    let z = compute_challenge(blob, commitment);
+    let (proof, _) = crate::schemes::kzg::core::proof::compute_kzg_proof(
+        setup,
+        &polynomial,
+        &z,
+    )?;
+    Ok(point_to_octets_g1(&proof))
+}
+
+/// Verify the proof that a blob matches its commitment.
+pub fn verify_blob_kzg_proof(
+    blob: &[u8],
+    commitment: &[u8; BYTES_PER_COMMITMENT],
+    proof: &[u8; BYTES_PER_PROOF],
+    setup: &TrustedSetup,
+) -> Result<bool, Error> {
+    verify_blob_kzg_proof_batch(&[blob], &[*commitment], &[*proof], setup)
+}
+
+/// Batch verify the proofs that blobs match their commitments.
+pub fn verify_blob_kzg_proof_batch<T>(
+    blobs: &[T],
+    commitments: &[[u8; BYTES_PER_COMMITMENT]],
+    proofs: &[[u8; BYTES_PER_PROOF]],
+    setup: &TrustedSetup,
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+        return Err(Error::BadParams {
+            cause: format!(
+                "number of blobs {}, commitments {} and proofs {} mismatch",
+                blobs.len(),
+                commitments.len(),
+                proofs.len()
+            ),
+        });
+    }
+
+    let mut commitment_points = Vec::with_capacity(blobs.len());
+    let mut proof_points = Vec::with_capacity(blobs.len());
+    let mut zs = Vec::with_capacity(blobs.len());
+    let mut ys = Vec::with_capacity(blobs.len());
+    for ((blob, commitment), proof) in
+        blobs.iter().zip(commitments.iter()).zip(proofs.iter())
+    {
+        let polynomial = blob_to_polynomial(blob.as_ref())?;
+        let z = compute_challenge(blob.as_ref(), commitment);
+        ys.push(evaluate_polynomial_in_evaluation_form(
+            setup,
+            &polynomial,
+            &z,
+        )?);
+        zs.push(z);
+        commitment_points.push(octets_to_point_g1(commitment)?);
+        proof_points.push(octets_to_point_g1(proof)?);
+    }
+
+    Ok(crate::schemes::kzg::core::proof::verify_kzg_proof_batch(
+        setup,
+        &commitment_points,
+        &zs,
+        &ys,
+        &proof_points,
+    ))
+}
+
+/// Compute the proof of the evaluations of the polynomial of a blob at the
+/// distinct points `zs`, returning the proof and the evaluations. At most
+/// `G2_POINTS_IN_SETUP - 1` points can be opened at once.
+pub fn compute_kzg_multi_proof(
+    blob: &[u8],
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    setup: &TrustedSetup,
+) -> Result<([u8; BYTES_PER_PROOF], Vec<[u8; BYTES_PER_FIELD_ELEMENT]>), Error>
+{
+    let polynomial = blob_to_polynomial(blob)?;
+    let zs = zs
+        .iter()
+        .map(bytes_to_bls_field)
+        .collect::<Result<Vec<_>, _>>()?;
+    let (proof, ys) =
+        crate::schemes::kzg::core::proof::compute_kzg_multi_proof(
+            setup,
+            &polynomial,
+            &zs,
+        )?;
+    Ok((
+        point_to_octets_g1(&proof),
+        ys.iter().map(|y| y.to_bytes_be()).collect(),
+    ))
+}
+
+/// Verify the proof that the polynomial committed to by `commitment`
+/// evaluates to `ys` at the distinct points `zs`.
+pub fn verify_kzg_multi_proof(
+    commitment: &[u8; BYTES_PER_COMMITMENT],
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    proof: &[u8; BYTES_PER_PROOF],
+    setup: &TrustedSetup,
+) -> Result<bool, Error> {
+    let to_scalars = |values: &[[u8; BYTES_PER_FIELD_ELEMENT]]| {
+        values
+            .iter()
+            .map(bytes_to_bls_field)
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let commitment: G1Projective = octets_to_point_g1(commitment)?;
+    crate::schemes::kzg::core::proof::verify_kzg_multi_proof(
+        setup,
+        &commitment,
+        &to_scalars(zs)?,
+        &to_scalars(ys)?,
+        &octets_to_point_g1(proof)?,
+    )
+}
@@ -0,0 +1,6 @@
+// Core implementation of the AFGH proxy re-encryption scheme.
+pub(crate) mod core;
+
+/// AFGH proxy re-encryption ciphersuites abstraction over core
+/// implementation.
+pub mod ciphersuites;
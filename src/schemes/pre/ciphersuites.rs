@@ -0,0 +1,5 @@
+/// AFGH proxy re-encryption BLS12-381 ciphersuites.
+pub mod bls12_381;
+
+/// AFGH proxy re-encryption BLS12-381-Sha-256 ciphersuite.
+pub mod bls12_381_sha_256;
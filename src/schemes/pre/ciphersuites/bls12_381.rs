@@ -0,0 +1,43 @@
+use crate::{
+    curves::bls12_381::{
+        OCTET_POINT_G1_LENGTH,
+        OCTET_POINT_G2_LENGTH,
+        OCTET_SCALAR_LENGTH,
+    },
+    schemes::pre::core::afgh::{
+        FIRST_LEVEL_CIPHERTEXT_OVERHEAD,
+        MAX_MESSAGE_LENGTH,
+        SECOND_LEVEL_CIPHERTEXT_OVERHEAD,
+    },
+};
+
+pub use crate::schemes::pre::core::{
+    afgh::ReEncryptionKey,
+    constants::MIN_KEY_GEN_IKM_LENGTH,
+    key_pair::{KeyPair, PublicKey, SecretKey},
+};
+
+/// "SecretKey" length in bytes for "PRE_AFGH_BLS12381" ciphersuite.
+pub const PRE_AFGH_BLS12381_SECRET_KEY_LENGTH: usize = OCTET_SCALAR_LENGTH;
+
+/// "PublicKey" length in bytes for "PRE_AFGH_BLS12381" ciphersuite.
+pub const PRE_AFGH_BLS12381_PUBLIC_KEY_LENGTH: usize =
+    OCTET_POINT_G1_LENGTH + OCTET_POINT_G2_LENGTH;
+
+/// "ReEncryptionKey" length in bytes for "PRE_AFGH_BLS12381" ciphersuite.
+pub const PRE_AFGH_BLS12381_RE_ENCRYPTION_KEY_LENGTH: usize =
+    OCTET_POINT_G2_LENGTH;
+
+/// Length in bytes of a second-level ciphertext in excess of the message
+/// length, for "PRE_AFGH_BLS12381" ciphersuite.
+pub const PRE_AFGH_BLS12381_SECOND_LEVEL_CIPHERTEXT_OVERHEAD: usize =
+    SECOND_LEVEL_CIPHERTEXT_OVERHEAD;
+
+/// Length in bytes of a first-level ciphertext in excess of the message
+/// length, for "PRE_AFGH_BLS12381" ciphersuite.
+pub const PRE_AFGH_BLS12381_FIRST_LEVEL_CIPHERTEXT_OVERHEAD: usize =
+    FIRST_LEVEL_CIPHERTEXT_OVERHEAD;
+
+/// Maximum length in bytes of an encrypted message, for "PRE_AFGH_BLS12381"
+/// ciphersuite.
+pub const PRE_AFGH_BLS12381_MAX_MESSAGE_LENGTH: usize = MAX_MESSAGE_LENGTH;
@@ -0,0 +1,94 @@
+use super::bls12_381::{PublicKey, ReEncryptionKey, SecretKey};
+use crate::{
+    common::{
+        ciphersuite::{CipherSuiteId, CipherSuiteParameter},
+        hash_param::ExpandMessageParameter,
+    },
+    curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    pre::core::afgh::{self, FirstLevelCiphertext, SecondLevelCiphertext},
+    Error,
+};
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+
+#[derive(Debug, Clone)]
+pub(crate) struct PreBls12381XmdSha256CipherSuiteParameter;
+
+impl CipherSuiteParameter for PreBls12381XmdSha256CipherSuiteParameter {
+    const ID: CipherSuiteId = CipherSuiteId::PreAfghBls12381XmdSha256;
+}
+
+impl ExpandMessageParameter for PreBls12381XmdSha256CipherSuiteParameter {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+/// Encrypt a message to a public key as a second-level ciphertext, which can
+/// be decrypted with `decrypt` by the owner of the key, or re-encrypted to a
+/// delegatee with `re_encrypt`.
+pub fn encrypt<T, R>(
+    pk: &PublicKey,
+    message: T,
+    rng: &mut R,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    afgh::encrypt_second_level::<PreBls12381XmdSha256CipherSuiteParameter, _>(
+        pk,
+        message.as_ref(),
+        rng,
+    )?
+    .to_octets()
+}
+
+/// Encrypt a message to a public key as a first-level ciphertext, which can
+/// only be decrypted with `decrypt_first_level` by the owner of the key.
+pub fn encrypt_first_level<T, R>(
+    pk: &PublicKey,
+    message: T,
+    rng: &mut R,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    R: RngCore + CryptoRng,
+{
+    afgh::encrypt_first_level::<PreBls12381XmdSha256CipherSuiteParameter, _>(
+        pk,
+        message.as_ref(),
+        rng,
+    )?
+    .to_octets()
+}
+
+/// Re-encrypt a second-level ciphertext to a first-level ciphertext for the
+/// delegatee of the re-encryption key.
+pub fn re_encrypt(
+    rk: &ReEncryptionKey,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let ciphertext = SecondLevelCiphertext::from_octets(ciphertext)?;
+    afgh::re_encrypt(rk, &ciphertext).to_octets()
+}
+
+/// Decrypt a second-level ciphertext.
+pub fn decrypt(sk: &SecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    let ciphertext = SecondLevelCiphertext::from_octets(ciphertext)?;
+    afgh::decrypt_second_level::<PreBls12381XmdSha256CipherSuiteParameter>(
+        sk,
+        &ciphertext,
+    )
+}
+
+/// Decrypt a first-level ciphertext, either encrypted directly to the key or
+/// re-encrypted by a proxy.
+pub fn decrypt_first_level(
+    sk: &SecretKey,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let ciphertext = FirstLevelCiphertext::from_octets(ciphertext)?;
+    afgh::decrypt_first_level::<PreBls12381XmdSha256CipherSuiteParameter>(
+        sk,
+        &ciphertext,
+    )
+}
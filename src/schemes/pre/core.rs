@@ -0,0 +1,5 @@
+pub(crate) mod afgh;
+pub(crate) mod constants;
+
+/// Proxy re-encryption key pair.
+pub(crate) mod key_pair;
@@ -0,0 +1,404 @@
+use super::key_pair::{PublicKey, SecretKey};
+use crate::{
+    common::{hash_param::ExpandMessageParameter, util::create_random_scalar},
+    curves::{
+        bls12_381::{
+            hash_to_curve::{ExpandMessageState, InitExpandMessage},
+            Bls12,
+            G1Projective,
+            G2Projective,
+            Gt,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_POINT_GT_LENGTH,
+        },
+        point_serde::{
+            octets_to_point_g1,
+            octets_to_point_g2,
+            octets_to_point_gt,
+            point_to_octets_g1,
+            point_to_octets_g2,
+            point_to_octets_gt,
+        },
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::Engine;
+use rand::{CryptoRng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Length in bytes of the authentication tag of a ciphertext.
+pub(crate) const TAG_LENGTH: usize = 32;
+
+/// Maximum length in bytes of a message, bounded by the maximum output length
+/// of `expand_message` which derives the authentication key and the key
+/// stream.
+pub(crate) const MAX_MESSAGE_LENGTH: usize = 255 * 32 - TAG_LENGTH;
+
+/// Length in bytes of a second-level ciphertext, in excess of the message
+/// length.
+pub(crate) const SECOND_LEVEL_CIPHERTEXT_OVERHEAD: usize =
+    OCTET_POINT_G1_LENGTH + OCTET_POINT_GT_LENGTH + TAG_LENGTH;
+
+/// Length in bytes of a first-level ciphertext, in excess of the message
+/// length.
+pub(crate) const FIRST_LEVEL_CIPHERTEXT_OVERHEAD: usize =
+    2 * OCTET_POINT_GT_LENGTH + TAG_LENGTH;
+
+/// Suffixes of the domain separation tags of the key derivation and the
+/// authentication tag.
+const DST_SUFFIX_KEY: &[u8] = b"PRE_KEY_";
+const DST_SUFFIX_TAG: &[u8] = b"PRE_TAG_";
+
+/// A re-encryption key from a delegator `a` to a delegatee `b`, `P2 * b / a`.
+/// It allows a proxy to transform second-level ciphertexts for the delegator
+/// into first-level ciphertexts for the delegatee, without learning the
+/// messages. First-level ciphertexts can not be re-encrypted further.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReEncryptionKey(pub(crate) G2Projective);
+
+impl ReEncryptionKey {
+    /// Number of bytes needed to represent the re-encryption key.
+    pub const SIZE_BYTES: usize = OCTET_POINT_G2_LENGTH;
+
+    /// Generate the re-encryption key from the secret key of the delegator to
+    /// the public key of the delegatee.
+    pub fn new(
+        delegator_sk: &SecretKey,
+        delegatee_pk: &PublicKey,
+    ) -> Result<Self, Error> {
+        if delegatee_pk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        let a_inv = delegator_sk.0.invert();
+        if a_inv.is_none().unwrap_u8() == 1 {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok(Self(delegatee_pk.p2 * a_inv.unwrap()))
+    }
+
+    /// Get the compressed representation of the re-encryption key.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        point_to_octets_g2(&self.0)
+    }
+
+    /// Convert from the representation of `to_octets` to a re-encryption key.
+    pub fn from_octets(octets: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let p = octets_to_point_g2(octets)?;
+        if p.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+        Ok(Self(p))
+    }
+}
+
+/// A second-level ciphertext `(P1 * a * r, m + Z * r, W, T)` for the key `a`
+/// where `m` is a random element of GT, `Z = e(P1, P2)`, `W` is the message
+/// encrypted with a key stream derived from `m` and `T` the authentication
+/// tag of `W`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SecondLevelCiphertext {
+    c1: G1Projective,
+    c2: Gt,
+    w: Vec<u8>,
+    tag: [u8; TAG_LENGTH],
+}
+
+/// A first-level ciphertext `(Z * b * r, m + Z * r, W, T)` for the key `b`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FirstLevelCiphertext {
+    c1: Gt,
+    c2: Gt,
+    w: Vec<u8>,
+    tag: [u8; TAG_LENGTH],
+}
+
+impl SecondLevelCiphertext {
+    /// Get the octets representation of the ciphertext as `c1 || c2 || T ||
+    /// W`.
+    pub fn to_octets(&self) -> Result<Vec<u8>, Error> {
+        Ok([
+            point_to_octets_g1(&self.c1).as_ref(),
+            &point_to_octets_gt(&self.c2)?,
+            &self.tag,
+            &self.w,
+        ]
+        .concat())
+    }
+
+    /// Get the ciphertext from its octets representation.
+    pub fn from_octets(data: &[u8]) -> Result<Self, Error> {
+        check_ciphertext_length(data, SECOND_LEVEL_CIPHERTEXT_OVERHEAD)?;
+        let (c1, rest) = data.split_at(OCTET_POINT_G1_LENGTH);
+        let (c2, rest) = rest.split_at(OCTET_POINT_GT_LENGTH);
+        let (tag, w) = rest.split_at(TAG_LENGTH);
+
+        let mut c1_octets = [0u8; OCTET_POINT_G1_LENGTH];
+        c1_octets.copy_from_slice(c1);
+        let c1 = octets_to_point_g1(&c1_octets)?;
+        if c1.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+        let mut c2_octets = [0u8; OCTET_POINT_GT_LENGTH];
+        c2_octets.copy_from_slice(c2);
+        let mut tag_octets = [0u8; TAG_LENGTH];
+        tag_octets.copy_from_slice(tag);
+        Ok(Self {
+            c1,
+            c2: octets_to_point_gt(&c2_octets)?,
+            w: w.to_vec(),
+            tag: tag_octets,
+        })
+    }
+}
+
+impl FirstLevelCiphertext {
+    /// Get the octets representation of the ciphertext as `c1 || c2 || T ||
+    /// W`.
+    pub fn to_octets(&self) -> Result<Vec<u8>, Error> {
+        Ok([
+            point_to_octets_gt(&self.c1)?.as_ref(),
+            &point_to_octets_gt(&self.c2)?,
+            &self.tag,
+            &self.w,
+        ]
+        .concat())
+    }
+
+    /// Get the ciphertext from its octets representation.
+    pub fn from_octets(data: &[u8]) -> Result<Self, Error> {
+        check_ciphertext_length(data, FIRST_LEVEL_CIPHERTEXT_OVERHEAD)?;
+        let (c1, rest) = data.split_at(OCTET_POINT_GT_LENGTH);
+        let (c2, rest) = rest.split_at(OCTET_POINT_GT_LENGTH);
+        let (tag, w) = rest.split_at(TAG_LENGTH);
+
+        let mut c1_octets = [0u8; OCTET_POINT_GT_LENGTH];
+        c1_octets.copy_from_slice(c1);
+        let c1 = octets_to_point_gt(&c1_octets)?;
+        if c1.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+        let mut c2_octets = [0u8; OCTET_POINT_GT_LENGTH];
+        c2_octets.copy_from_slice(c2);
+        let mut tag_octets = [0u8; TAG_LENGTH];
+        tag_octets.copy_from_slice(tag);
+        Ok(Self {
+            c1,
+            c2: octets_to_point_gt(&c2_octets)?,
+            w: w.to_vec(),
+            tag: tag_octets,
+        })
+    }
+}
+
+/// Encrypt `message` to `pk` as a second-level ciphertext, which can be
+/// decrypted by the owner of `pk` or re-encrypted to a delegatee.
+pub(crate) fn encrypt_second_level<C, R>(
+    pk: &PublicKey,
+    message: &[u8],
+    rng: &mut R,
+) -> Result<SecondLevelCiphertext, Error>
+where
+    C: ExpandMessageParameter,
+    R: RngCore + CryptoRng,
+{
+    let (r, m) = encryption_randomness(pk, message, rng)?;
+    let (w, tag) = seal::<C>(&m, message)?;
+    Ok(SecondLevelCiphertext {
+        // c1 = P1 * a * r
+        c1: pk.p1 * r,
+        // c2 = m + Z * r
+        c2: m + Gt::generator() * r,
+        w,
+        tag,
+    })
+}
+
+/// Encrypt `message` to `pk` as a first-level ciphertext, which can only be
+/// decrypted by the owner of `pk`.
+pub(crate) fn encrypt_first_level<C, R>(
+    pk: &PublicKey,
+    message: &[u8],
+    rng: &mut R,
+) -> Result<FirstLevelCiphertext, Error>
+where
+    C: ExpandMessageParameter,
+    R: RngCore + CryptoRng,
+{
+    let (r, m) = encryption_randomness(pk, message, rng)?;
+    let (w, tag) = seal::<C>(&m, message)?;
+    Ok(FirstLevelCiphertext {
+        // c1 = e(P1 * b, P2) * r
+        c1: Bls12::pairing(
+            &(pk.p1 * r).to_affine(),
+            &G2Projective::generator().to_affine(),
+        ),
+        // c2 = m + Z * r
+        c2: m + Gt::generator() * r,
+        w,
+        tag,
+    })
+}
+
+/// Re-encrypt a second-level ciphertext to a first-level ciphertext for the
+/// delegatee of `rk`, `c1' = e(c1, rk) = Z * b * r`.
+pub(crate) fn re_encrypt(
+    rk: &ReEncryptionKey,
+    ciphertext: &SecondLevelCiphertext,
+) -> FirstLevelCiphertext {
+    FirstLevelCiphertext {
+        c1: Bls12::pairing(&ciphertext.c1.to_affine(), &rk.0.to_affine()),
+        c2: ciphertext.c2,
+        w: ciphertext.w.clone(),
+        tag: ciphertext.tag,
+    }
+}
+
+/// Decrypt a second-level ciphertext, `m = c2 - e(c1 / a, P2)`.
+pub(crate) fn decrypt_second_level<C>(
+    sk: &SecretKey,
+    ciphertext: &SecondLevelCiphertext,
+) -> Result<Vec<u8>, Error>
+where
+    C: ExpandMessageParameter,
+{
+    let a_inv = sk.0.invert();
+    if a_inv.is_none().unwrap_u8() == 1 {
+        return Err(Error::InvalidSecretKey);
+    }
+    let z_r = Bls12::pairing(
+        &(ciphertext.c1 * a_inv.unwrap()).to_affine(),
+        &G2Projective::generator().to_affine(),
+    );
+    open::<C>(&(ciphertext.c2 - z_r), &ciphertext.w, &ciphertext.tag)
+}
+
+/// Decrypt a first-level ciphertext, `m = c2 - c1 / b`.
+pub(crate) fn decrypt_first_level<C>(
+    sk: &SecretKey,
+    ciphertext: &FirstLevelCiphertext,
+) -> Result<Vec<u8>, Error>
+where
+    C: ExpandMessageParameter,
+{
+    let b_inv = sk.0.invert();
+    if b_inv.is_none().unwrap_u8() == 1 {
+        return Err(Error::InvalidSecretKey);
+    }
+    let z_r = ciphertext.c1 * b_inv.unwrap();
+    open::<C>(&(ciphertext.c2 - z_r), &ciphertext.w, &ciphertext.tag)
+}
+
+// Check the public key and the message, and sample the randomness `r` and
+// the random element `m` of GT the message key is derived from.
+fn encryption_randomness<R>(
+    pk: &PublicKey,
+    message: &[u8],
+    rng: &mut R,
+) -> Result<(Scalar, Gt), Error>
+where
+    R: RngCore + CryptoRng,
+{
+    if pk.is_valid().unwrap_u8() == 0 {
+        return Err(Error::InvalidPublicKey);
+    }
+    if message.is_empty() || message.len() > MAX_MESSAGE_LENGTH {
+        return Err(Error::BadParams {
+            cause: format!(
+                "invalid message length {}, expected between 1 and {} bytes",
+                message.len(),
+                MAX_MESSAGE_LENGTH
+            ),
+        });
+    }
+    let r = create_random_scalar(&mut *rng)?;
+    if r.is_zero().unwrap_u8() == 1 {
+        return Err(Error::UnexpectedZeroValue);
+    }
+    Ok((r, Gt::random(&mut *rng)))
+}
+
+// Encrypt `message` with the key stream derived from `m` and compute the
+// authentication tag.
+fn seal<C>(m: &Gt, message: &[u8]) -> Result<(Vec<u8>, [u8; TAG_LENGTH]), Error>
+where
+    C: ExpandMessageParameter,
+{
+    let mut keys = derive_keys::<C>(m, message.len())?;
+    let mut w = keys.split_off(TAG_LENGTH);
+    xor(&mut w, message);
+    let tag = compute_tag::<C>(&keys, &w);
+    keys.zeroize();
+    Ok((w, tag))
+}
+
+// Check the authentication tag and decrypt `w` with the key stream derived
+// from `m`.
+fn open<C>(m: &Gt, w: &[u8], tag: &[u8; TAG_LENGTH]) -> Result<Vec<u8>, Error>
+where
+    C: ExpandMessageParameter,
+{
+    let mut keys = derive_keys::<C>(m, w.len())?;
+    let mut message = keys.split_off(TAG_LENGTH);
+    let expected_tag = compute_tag::<C>(&keys, w);
+    keys.zeroize();
+    if expected_tag[..].ct_eq(&tag[..]).unwrap_u8() == 0 {
+        message.zeroize();
+        return Err(Error::CryptoOps {
+            cause: "decryption failed, invalid ciphertext or key".to_owned(),
+        });
+    }
+    xor(&mut message, w);
+    Ok(message)
+}
+
+// Derive the authentication key followed by the key stream from `m`.
+fn derive_keys<C>(m: &Gt, len: usize) -> Result<Vec<u8>, Error>
+where
+    C: ExpandMessageParameter,
+{
+    let mut out = vec![0u8; TAG_LENGTH + len];
+    expand::<C>(&point_to_octets_gt(m)?, DST_SUFFIX_KEY, &mut out);
+    Ok(out)
+}
+
+fn compute_tag<C>(mac_key: &[u8], w: &[u8]) -> [u8; TAG_LENGTH]
+where
+    C: ExpandMessageParameter,
+{
+    let mut tag = [0u8; TAG_LENGTH];
+    expand::<C>(&[mac_key, w].concat(), DST_SUFFIX_TAG, &mut tag);
+    tag
+}
+
+fn expand<C>(data: &[u8], dst_suffix: &[u8], out: &mut [u8])
+where
+    C: ExpandMessageParameter,
+{
+    let dst = [C::ID.as_octets(), dst_suffix].concat();
+    let mut expander = C::Expander::init_expand(data, &dst, out.len());
+    expander.read_into(out);
+}
+
+fn check_ciphertext_length(data: &[u8], overhead: usize) -> Result<(), Error> {
+    if data.len() <= overhead || data.len() > overhead + MAX_MESSAGE_LENGTH {
+        return Err(Error::BadParams {
+            cause: format!(
+                "invalid ciphertext length {}, expected more than {} and at \
+                 most {} bytes",
+                data.len(),
+                overhead,
+                overhead + MAX_MESSAGE_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn xor(out: &mut [u8], other: &[u8]) {
+    out.iter_mut().zip(other.iter()).for_each(|(a, b)| *a ^= b);
+}
@@ -0,0 +1,215 @@
+use super::constants::MIN_KEY_GEN_IKM_LENGTH;
+use crate::{
+    curves::{
+        bls12_381::{
+            generate_sk,
+            Bls12,
+            G1Projective,
+            G2Prepared,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{
+            octets_to_point_g1,
+            octets_to_point_g2,
+            point_to_octets_g1,
+            point_to_octets_g2,
+        },
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::{CryptoRng, RngCore};
+use subtle::Choice;
+use zeroize::Zeroize;
+
+/// Secret key type, `a`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SecretKey(pub(crate) Box<Scalar>);
+
+impl core::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "****")
+    }
+}
+
+impl Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.0 = Box::new(Scalar::zero());
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl SecretKey {
+    /// Number of bytes needed to represent the secret key.
+    pub const SIZE_BYTES: usize = OCTET_SCALAR_LENGTH;
+
+    /// Computes a secret key from an IKM, as defined by
+    /// <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-04#section-2.3>
+    pub fn new(ikm_in: &[u8], key_info: &[u8]) -> Option<Self> {
+        let mut random_ikm = [0u8; MIN_KEY_GEN_IKM_LENGTH];
+
+        let ikm = if ikm_in.is_empty() {
+            let mut rng = rand_core::OsRng;
+            if rng.try_fill_bytes(&mut random_ikm).is_err() {
+                return None;
+            }
+            &random_ikm
+        } else {
+            ikm_in
+        };
+
+        generate_sk(ikm, key_info)
+            .filter(|s| s.is_zero().unwrap_u8() == 0u8)
+            .map(|s| Self(Box::new(s)))
+    }
+
+    /// Compute a secret key from a CS-PRNG.
+    pub fn random<R>(rng: &mut R, key_info: &[u8]) -> Option<Self>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut ikm = [0u8; MIN_KEY_GEN_IKM_LENGTH];
+
+        if rng.try_fill_bytes(&mut ikm).is_ok() {
+            return Self::new(ikm.as_ref(), key_info);
+        }
+        None
+    }
+
+    /// Convert the secret key to a big-endian representation.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE_BYTES] {
+        self.0.to_bytes_be()
+    }
+
+    /// Convert a big-endian representation of the secret key.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let s = Scalar::from_bytes_be(bytes);
+        if s.is_none().unwrap_u8() == 1u8
+            || s.unwrap().is_zero().unwrap_u8() == 1u8
+        {
+            return Err(Error::InvalidSecretKey);
+        }
+        Ok(Self(Box::new(s.unwrap())))
+    }
+}
+
+/// Public key type, `(P1 * a, P2 * a)`. The G1 component is used to encrypt
+/// to the key and the G2 component to delegate decryption rights to the key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub(crate) p1: G1Projective,
+    pub(crate) p2: G2Projective,
+}
+
+impl From<&SecretKey> for PublicKey {
+    fn from(sk: &SecretKey) -> Self {
+        Self {
+            p1: G1Projective::generator() * *sk.0,
+            p2: G2Projective::generator() * *sk.0,
+        }
+    }
+}
+
+impl PublicKey {
+    /// Number of bytes needed to represent the public key.
+    pub const SIZE_BYTES: usize = OCTET_POINT_G1_LENGTH + OCTET_POINT_G2_LENGTH;
+
+    /// Check if the `PublicKey` is valid, i.e. both components are valid
+    /// points and share the same discrete logarithm.
+    pub fn is_valid(&self) -> Choice {
+        let points_are_valid = !self.p1.is_identity()
+            & self.p1.is_on_curve()
+            & self.p1.to_affine().is_torsion_free()
+            & !self.p2.is_identity()
+            & self.p2.is_on_curve()
+            & self.p2.to_affine().is_torsion_free();
+        if points_are_valid.unwrap_u8() == 0 {
+            return points_are_valid;
+        }
+
+        // e(p1, P2) == e(P1, p2)
+        Bls12::multi_miller_loop(&[
+            (
+                &self.p1.to_affine(),
+                &G2Prepared::from(-G2Projective::generator().to_affine()),
+            ),
+            (
+                &G1Projective::generator().to_affine(),
+                &G2Prepared::from(self.p2.to_affine()),
+            ),
+        ])
+        .final_exponentiation()
+        .is_identity()
+    }
+
+    /// Get the representation of the public key as the concatenation of the
+    /// compressed points `p1 || p2`.
+    pub fn to_octets(&self) -> [u8; Self::SIZE_BYTES] {
+        let mut octets = [0u8; Self::SIZE_BYTES];
+        octets[..OCTET_POINT_G1_LENGTH]
+            .copy_from_slice(&point_to_octets_g1(&self.p1));
+        octets[OCTET_POINT_G1_LENGTH..]
+            .copy_from_slice(&point_to_octets_g2(&self.p2));
+        octets
+    }
+
+    /// Convert from the representation of `to_octets` to a public key.
+    pub fn from_octets(octets: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let mut p1 = [0u8; OCTET_POINT_G1_LENGTH];
+        let mut p2 = [0u8; OCTET_POINT_G2_LENGTH];
+        p1.copy_from_slice(&octets[..OCTET_POINT_G1_LENGTH]);
+        p2.copy_from_slice(&octets[OCTET_POINT_G1_LENGTH..]);
+        let pk = Self {
+            p1: octets_to_point_g1(&p1)?,
+            p2: octets_to_point_g2(&p2)?,
+        };
+        if pk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        Ok(pk)
+    }
+}
+
+/// A proxy re-encryption key pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyPair {
+    /// Secret key.
+    pub secret_key: SecretKey,
+
+    /// Public key.
+    pub public_key: PublicKey,
+}
+
+impl KeyPair {
+    /// Generate a key pair from provided IKM.
+    pub fn new(ikm: &[u8], key_info: &[u8]) -> Option<Self> {
+        let secret_key = SecretKey::new(ikm, key_info)?;
+        Some(Self {
+            public_key: PublicKey::from(&secret_key),
+            secret_key,
+        })
+    }
+
+    /// Generate a key pair from a CS-PRNG.
+    pub fn random<R>(rng: &mut R, key_info: &[u8]) -> Option<Self>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let secret_key = SecretKey::random(rng, key_info)?;
+        Some(Self {
+            public_key: PublicKey::from(&secret_key),
+            secret_key,
+        })
+    }
+}
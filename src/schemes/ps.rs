@@ -0,0 +1,14 @@
+pub(crate) mod api;
+pub use crate::schemes::ps::api::dtos::{
+    PsProofGenRequest,
+    PsProofGenRevealMessageRequest,
+    PsProofVerifyRequest,
+    PsSignRequest,
+    PsVerifyRequest,
+};
+
+// Core implementation of PS scheme.
+pub(crate) mod core;
+
+/// PS ciphersuites abstraction over core implementation.
+pub mod ciphersuites;
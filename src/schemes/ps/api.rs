@@ -0,0 +1,5 @@
+pub mod dtos;
+pub mod proof;
+pub mod signature;
+
+pub(crate) mod utils;
@@ -0,0 +1,121 @@
+use crate::ps::ciphersuites::bls12_381::PS_BLS12381G1_SIGNATURE_LENGTH;
+
+/// Sign request for a PS signature.
+/// Keys are variable length, as their size depends on the number of messages
+/// they support.
+#[derive(Clone, Debug)]
+pub struct PsSignRequest<'a, T: AsRef<[u8]>> {
+    /// Secret key
+    pub secret_key: &'a [u8],
+    /// Public key
+    pub public_key: &'a [u8],
+    /// Header containing context and application specific information
+    pub header: Option<T>,
+    /// Vector of messages to sign
+    pub messages: Option<&'a [T]>,
+}
+
+impl<'a, T: AsRef<[u8]>> Default for PsSignRequest<'a, T> {
+    fn default() -> Self {
+        Self {
+            secret_key: &[0u8; 0],
+            public_key: &[0u8; 0],
+            header: Default::default(),
+            messages: Default::default(),
+        }
+    }
+}
+
+/// Verify request for a PS signature.
+#[derive(Clone, Debug)]
+pub struct PsVerifyRequest<'a, T: AsRef<[u8]>> {
+    /// Public key
+    pub public_key: &'a [u8],
+    /// Header containing context and application specific information
+    pub header: Option<T>,
+    /// Vector of messages to verify against a signature
+    pub messages: Option<&'a [T]>,
+    /// Signature to verify
+    pub signature: &'a [u8; PS_BLS12381G1_SIGNATURE_LENGTH],
+}
+
+impl<'a, T: AsRef<[u8]>> Default for PsVerifyRequest<'a, T> {
+    fn default() -> Self {
+        Self {
+            public_key: &[0u8; 0],
+            header: Default::default(),
+            messages: Default::default(),
+            signature: &[0u8; PS_BLS12381G1_SIGNATURE_LENGTH],
+        }
+    }
+}
+
+/// Sub structure for describing which messages to reveal in a derived proof.
+#[derive(Clone, Default, Debug)]
+pub struct PsProofGenRevealMessageRequest<T: AsRef<[u8]>> {
+    /// Indicates whether to reveal the current message in the derived proof
+    pub reveal: bool,
+    /// Value of the message
+    pub value: T,
+}
+
+/// Derive proof request for computing a signature proof of knowledge for a
+/// supplied PS signature.
+#[derive(Clone, Debug)]
+pub struct PsProofGenRequest<'a, T: AsRef<[u8]>> {
+    /// Public key associated to the PS signature
+    pub public_key: &'a [u8],
+    /// Header containing context and application specific information
+    pub header: Option<T>,
+    /// Vector of messages protected by the signature, including a flag
+    /// indicating which to reveal in the derived proof
+    pub messages: Option<&'a [PsProofGenRevealMessageRequest<T>]>,
+    /// Signature to derive the signature proof of knowledge from
+    pub signature: &'a [u8; PS_BLS12381G1_SIGNATURE_LENGTH],
+    /// Presentation header to be bound to the signature proof of knowledge
+    pub presentation_header: Option<T>,
+    /// Flag which indicates if the signature verification should be done
+    /// before actual proof computation.
+    pub verify_signature: Option<bool>,
+}
+
+impl<'a, T: AsRef<[u8]>> Default for PsProofGenRequest<'a, T> {
+    fn default() -> Self {
+        Self {
+            public_key: &[0u8; 0],
+            header: Default::default(),
+            messages: Default::default(),
+            signature: &[0u8; PS_BLS12381G1_SIGNATURE_LENGTH],
+            presentation_header: Default::default(),
+            verify_signature: None,
+        }
+    }
+}
+
+/// Verify proof request for verifying a supplied signature proof of knowledge.
+#[derive(Clone, Debug)]
+pub struct PsProofVerifyRequest<'a, T: AsRef<[u8]>> {
+    /// Public key associated to the signature proof of knowledge (who signed
+    /// the original PS signature the proof is derived from)
+    pub public_key: &'a [u8],
+    /// Header containing context and application specific information
+    pub header: Option<T>,
+    /// Presentation header associated to the signature proof of knowledge
+    pub presentation_header: Option<T>,
+    /// Proof to verify
+    pub proof: &'a [u8],
+    /// Revealed messages to validate against the signature proof of knowledge
+    pub messages: Option<&'a [(usize, T)]>,
+}
+
+impl<'a, T: AsRef<[u8]>> Default for PsProofVerifyRequest<'a, T> {
+    fn default() -> Self {
+        Self {
+            public_key: &[0u8; 0],
+            header: Default::default(),
+            messages: Default::default(),
+            presentation_header: Default::default(),
+            proof: &[0u8; 0],
+        }
+    }
+}
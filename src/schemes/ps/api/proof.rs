@@ -0,0 +1,84 @@
+use super::{
+    dtos::{PsProofGenRequest, PsProofVerifyRequest},
+    utils::{digest_proof_messages, digest_revealed_proof_messages},
+};
+use crate::{
+    error::Error,
+    ps::{
+        ciphersuites::PsCiphersuiteParameters,
+        core::{key_pair::PublicKey, proof::Proof, signature::Signature},
+    },
+};
+
+/// Return the size of proof in bytes for `num_undisclosed_messages`.
+pub fn get_proof_size(num_undisclosed_messages: usize) -> usize {
+    Proof::get_size(num_undisclosed_messages)
+}
+
+// Generate a PS signature proof of knowledge.
+pub(crate) fn proof_gen<T, C>(
+    request: &PsProofGenRequest<'_, T>,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    // Parse public key from request
+    let pk = PublicKey::from_octets(request.public_key)?;
+
+    let (digested_messages, proof_messages) =
+        digest_proof_messages::<_, C>(request.messages)?;
+
+    // Parse signature from request
+    let signature = Signature::from_octets(request.signature)?;
+
+    let verify_signature = request.verify_signature.unwrap_or(true);
+    if verify_signature {
+        // Verify the signature to check the messages supplied are valid
+        if !(signature.verify::<_, C>(
+            &pk,
+            request.header.as_ref(),
+            &digested_messages,
+        )?) {
+            return Err(Error::SignatureVerification);
+        }
+    };
+
+    // Generate the proof
+    let proof = Proof::new::<_, C>(
+        &pk,
+        &signature,
+        request.header.as_ref(),
+        request.presentation_header.as_ref(),
+        &proof_messages,
+    )?;
+
+    Ok(proof.to_octets())
+}
+
+// Verify a PS signature proof of knowledge.
+pub(crate) fn proof_verify<T, C>(
+    request: &PsProofVerifyRequest<'_, T>,
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    // Parse public key from request
+    let pk = PublicKey::from_octets(request.public_key)?;
+
+    // Digest the revealed proof messages
+    let messages = digest_revealed_proof_messages::<_, C>(
+        request.messages.unwrap_or(&[]),
+    )?;
+
+    // Parse proof from request
+    let proof = Proof::from_octets(request.proof)?;
+
+    proof.verify::<_, C>(
+        &pk,
+        request.header.as_ref(),
+        request.presentation_header.as_ref(),
+        &messages,
+    )
+}
@@ -0,0 +1,70 @@
+use super::{
+    dtos::{PsSignRequest, PsVerifyRequest},
+    utils::digest_messages,
+};
+use crate::{
+    error::Error,
+    ps::{
+        ciphersuites::{
+            bls12_381::PS_BLS12381G1_SIGNATURE_LENGTH,
+            PsCiphersuiteParameters,
+        },
+        core::{
+            key_pair::{PublicKey, SecretKey},
+            signature::Signature,
+            types::Message,
+        },
+    },
+};
+use rand_core::OsRng;
+
+// Create a PS signature.
+pub(crate) fn sign<T, C>(
+    request: &PsSignRequest<'_, T>,
+) -> Result<[u8; PS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    // Parse the secret key
+    let sk = SecretKey::from_bytes(request.secret_key)?;
+
+    // Parse public key from request
+    let pk = PublicKey::from_octets(request.public_key)?;
+
+    // Digest the supplied messages
+    let messages: Vec<Message> = digest_messages::<_, C>(request.messages)?;
+
+    // Produce the signature and return
+    Signature::new::<_, C>(&sk, &pk, request.header.as_ref(), &messages)
+        .map(|sig| sig.to_octets())
+}
+
+// Verify a PS signature.
+pub(crate) fn verify<T, C>(
+    request: &PsVerifyRequest<'_, T>,
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    // Parse public key from request
+    let pk = PublicKey::from_octets(request.public_key)?;
+
+    // Digest the supplied messages
+    let messages: Vec<Message> = digest_messages::<_, C>(request.messages)?;
+
+    // Parse signature from request
+    let signature = Signature::from_octets(request.signature)?;
+
+    signature.verify::<_, C>(&pk, request.header.as_ref(), &messages)
+}
+
+// Rerandomize a PS signature.
+pub(crate) fn rerandomize(
+    signature: &[u8; PS_BLS12381G1_SIGNATURE_LENGTH],
+) -> Result<[u8; PS_BLS12381G1_SIGNATURE_LENGTH], Error> {
+    Signature::from_octets(signature)?
+        .rerandomize(&mut OsRng)
+        .map(|sig| sig.to_octets())
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+#[cfg(not(feature = "alloc"))]
+use std::collections::BTreeMap;
+
+use super::dtos::PsProofGenRevealMessageRequest;
+use crate::{
+    error::Error,
+    ps::{
+        ciphersuites::PsCiphersuiteParameters,
+        core::types::{Message, ProofMessage},
+    },
+};
+
+/// Digests the set of input messages and returns in the form of an internal
+/// structure
+pub(crate) fn digest_messages<T, C>(
+    messages: Option<&[T]>,
+) -> Result<Vec<Message>, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    if let Some(messages) = messages {
+        return messages
+            .iter()
+            .map(|msg| Message::from_arbitrary_data::<C>(msg.as_ref(), None))
+            .collect();
+    }
+    Ok(vec![])
+}
+
+/// Digests a set of supplied proof messages
+pub(super) fn digest_proof_messages<T, C>(
+    messages: Option<&[PsProofGenRevealMessageRequest<T>]>,
+) -> Result<(Vec<Message>, Vec<ProofMessage>), Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    let mut digested_messages = vec![];
+    let mut proof_messages = vec![];
+    if let Some(messages) = messages {
+        for m in messages {
+            let digested_message =
+                Message::from_arbitrary_data::<C>(m.value.as_ref(), None)?;
+            digested_messages.push(digested_message);
+            if m.reveal {
+                proof_messages.push(ProofMessage::Revealed(digested_message))
+            } else {
+                proof_messages.push(ProofMessage::Hidden(digested_message))
+            }
+        }
+    }
+    Ok((digested_messages, proof_messages))
+}
+
+pub(crate) fn digest_revealed_proof_messages<T, C>(
+    messages: &[(usize, T)],
+) -> Result<BTreeMap<usize, Message>, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    messages
+        .iter()
+        .map(|(i, m)| {
+            Message::from_arbitrary_data::<C>(m.as_ref(), None).map(|m| (*i, m))
+        })
+        .collect()
+}
@@ -0,0 +1,28 @@
+use crate::{
+    common::hash_param::{
+        h2c::HashToCurveParameter,
+        h2s::HashToScalarParameter,
+    },
+    curves::bls12_381::G2Projective,
+};
+use group::Group;
+
+/// PS BLS12-381 ciphersuites.
+pub mod bls12_381;
+/// PS BLS12-381-Sha-256 ciphersuites.
+pub mod bls12_381_g1_sha_256;
+
+pub(crate) trait PsCiphersuiteParameters:
+    HashToScalarParameter + HashToCurveParameter
+{
+    /// DST used to hash the signed messages to the G1 base point `h` of a
+    /// signature.
+    fn signature_dst() -> Vec<u8> {
+        [Self::ID.as_octets(), b"SIG_H2G_"].concat()
+    }
+
+    /// Point on G2 to be used during signature and proof verification.
+    fn p2() -> G2Projective {
+        G2Projective::generator()
+    }
+}
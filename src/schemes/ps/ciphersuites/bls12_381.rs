@@ -0,0 +1,27 @@
+use crate::curves::bls12_381::{
+    OCTET_POINT_G1_LENGTH,
+    OCTET_POINT_G2_LENGTH,
+    OCTET_SCALAR_LENGTH,
+};
+pub use crate::schemes::ps::{
+    api::proof::get_proof_size,
+    core::{
+        constants::MIN_KEY_GEN_IKM_LENGTH,
+        key_pair::{KeyPair, PublicKey, SecretKey},
+    },
+};
+
+/// Length in bytes of a "SecretKey" for `message_count` messages for
+/// "PS_BLS12381G1" ciphersuite.
+pub const fn ps_bls12381g1_secret_key_length(message_count: usize) -> usize {
+    (message_count + 2) * OCTET_SCALAR_LENGTH
+}
+
+/// Length in bytes of a "PublicKey" for `message_count` messages for
+/// "PS_BLS12381G1" ciphersuite.
+pub const fn ps_bls12381g1_public_key_length(message_count: usize) -> usize {
+    (message_count + 2) * OCTET_POINT_G2_LENGTH
+}
+
+/// "Signature" length in bytes for "PS_BLS12381G1" ciphersuite.
+pub const PS_BLS12381G1_SIGNATURE_LENGTH: usize = 2 * OCTET_POINT_G1_LENGTH;
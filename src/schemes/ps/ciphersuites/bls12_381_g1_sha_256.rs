@@ -0,0 +1,96 @@
+use super::{
+    bls12_381::PS_BLS12381G1_SIGNATURE_LENGTH,
+    PsCiphersuiteParameters,
+};
+use crate::{
+    common::{
+        ciphersuite::{CipherSuiteId, CipherSuiteParameter},
+        hash_param::{
+            h2c::HashToCurveParameter,
+            h2s::HashToScalarParameter,
+            ExpandMessageParameter,
+        },
+    },
+    curves::bls12_381::hash_to_curve::ExpandMsgXmd,
+    ps::{
+        PsProofGenRequest,
+        PsProofVerifyRequest,
+        PsSignRequest,
+        PsVerifyRequest,
+    },
+    Error,
+};
+use sha2::Sha256;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Bls12381Sha256CipherSuiteParameter;
+
+impl CipherSuiteParameter for Bls12381Sha256CipherSuiteParameter {
+    const ID: CipherSuiteId = CipherSuiteId::PsBls12381G1XmdSha256;
+}
+
+impl ExpandMessageParameter for Bls12381Sha256CipherSuiteParameter {
+    type Expander = ExpandMsgXmd<Sha256>;
+}
+
+impl HashToScalarParameter for Bls12381Sha256CipherSuiteParameter {}
+
+impl HashToCurveParameter for Bls12381Sha256CipherSuiteParameter {}
+
+impl PsCiphersuiteParameters for Bls12381Sha256CipherSuiteParameter {}
+
+/// Create a BLS12-381-G1-Sha-256 PS signature.
+/// Security Warning: `secret_key` and `public_key` in `request` must be related
+/// key-pair generated using `KeyPair` APIs.
+pub fn sign<T>(
+    request: &PsSignRequest<'_, T>,
+) -> Result<[u8; PS_BLS12381G1_SIGNATURE_LENGTH], Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::ps::api::signature::sign::<_, Bls12381Sha256CipherSuiteParameter>(
+        request,
+    )
+}
+
+/// Verify a BLS12-381-G1-Sha-256 PS signature.
+pub fn verify<T>(request: &PsVerifyRequest<'_, T>) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::ps::api::signature::verify::<_, Bls12381Sha256CipherSuiteParameter>(
+        request,
+    )
+}
+
+/// Rerandomize a BLS12-381-G1-Sha-256 PS signature. The result is a fresh
+/// signature on the same messages which can not be linked to the input.
+pub fn rerandomize(
+    signature: &[u8; PS_BLS12381G1_SIGNATURE_LENGTH],
+) -> Result<[u8; PS_BLS12381G1_SIGNATURE_LENGTH], Error> {
+    crate::ps::api::signature::rerandomize(signature)
+}
+
+/// Generate a BLS12-381-G1-Sha-256 PS signature proof of knowledge.
+pub fn proof_gen<T>(
+    request: &PsProofGenRequest<'_, T>,
+) -> Result<Vec<u8>, Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::ps::api::proof::proof_gen::<_, Bls12381Sha256CipherSuiteParameter>(
+        request,
+    )
+}
+
+/// Verify a BLS12-381-G1-Sha-256 PS signature proof of knowledge.
+pub fn proof_verify<T>(
+    request: &PsProofVerifyRequest<'_, T>,
+) -> Result<bool, Error>
+where
+    T: AsRef<[u8]>,
+{
+    crate::ps::api::proof::proof_verify::<_, Bls12381Sha256CipherSuiteParameter>(
+        request,
+    )
+}
@@ -0,0 +1,8 @@
+pub(crate) mod constants;
+pub(crate) mod proof;
+pub(crate) mod signature;
+pub(crate) mod types;
+pub(crate) mod utils;
+
+/// PS Key pair.
+pub mod key_pair;
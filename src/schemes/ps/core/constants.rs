@@ -0,0 +1,2 @@
+/// Minimum length of key generation IKM data in bytes.
+pub const MIN_KEY_GEN_IKM_LENGTH: usize = 32;
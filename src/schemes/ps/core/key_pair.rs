@@ -0,0 +1,262 @@
+use super::constants::MIN_KEY_GEN_IKM_LENGTH;
+use crate::{
+    common::serialization::i2osp,
+    curves::{
+        bls12_381::{
+            generate_sk,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{octets_to_point_g2, point_to_octets_g2},
+    },
+    error::Error,
+};
+use ff::Field;
+use group::{Curve, Group};
+use rand::{CryptoRng, RngCore};
+use subtle::Choice;
+use zeroize::Zeroize;
+
+/// Secret key type, `(x, y_0, y_1, ..., y_L)` for `L` messages. `y_0` signs
+/// the domain of a signature, which binds it to the public key and header.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SecretKey {
+    pub(crate) x: Scalar,
+    pub(crate) y: Vec<Scalar>,
+}
+
+impl core::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "****")
+    }
+}
+
+impl Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.x = Scalar::zero();
+        self.y.iter_mut().for_each(|y| *y = Scalar::zero());
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl SecretKey {
+    /// Computes a secret key for `message_count` messages from an IKM. Each
+    /// scalar is derived as defined by
+    /// <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-04#section-2.3>
+    /// with `key_info || I2OSP(index, 8)` as key information.
+    pub fn new(
+        ikm_in: &[u8],
+        key_info: &[u8],
+        message_count: usize,
+    ) -> Option<Self> {
+        let mut random_ikm = [0u8; MIN_KEY_GEN_IKM_LENGTH];
+
+        let ikm = if ikm_in.is_empty() {
+            let mut rng = rand_core::OsRng;
+            if rng.try_fill_bytes(&mut random_ikm).is_err() {
+                return None;
+            }
+            &random_ikm
+        } else {
+            ikm_in
+        };
+
+        let mut scalars = (0..message_count + 2)
+            .map(|i| {
+                let info = [key_info, &i2osp(i as u64, 8).ok()?].concat();
+                generate_sk(ikm, &info)
+                    .filter(|s| s.is_zero().unwrap_u8() == 0u8)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let x = scalars.remove(0);
+        Some(Self { x, y: scalars })
+    }
+
+    /// Compute a secret key for `message_count` messages from a CS-PRNG.
+    pub fn random<R>(
+        rng: &mut R,
+        key_info: &[u8],
+        message_count: usize,
+    ) -> Option<Self>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut ikm = [0u8; MIN_KEY_GEN_IKM_LENGTH];
+
+        if rng.try_fill_bytes(&mut ikm).is_ok() {
+            return Self::new(ikm.as_ref(), key_info, message_count);
+        }
+        None
+    }
+
+    /// Number of messages the key can sign.
+    pub fn message_count(&self) -> usize {
+        self.y.len() - 1
+    }
+
+    /// Convert the secret key to a big-endian representation, `x || y_0 ||
+    /// ... || y_L`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        core::iter::once(&self.x)
+            .chain(self.y.iter())
+            .flat_map(|s| s.to_bytes_be())
+            .collect()
+    }
+
+    /// Convert a big-endian representation of the secret key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 2 * OCTET_SCALAR_LENGTH
+            || bytes.len() % OCTET_SCALAR_LENGTH != 0
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "invalid secret key length {}, expected a multiple of {} \
+                     bytes of at least {} bytes",
+                    bytes.len(),
+                    OCTET_SCALAR_LENGTH,
+                    2 * OCTET_SCALAR_LENGTH
+                ),
+            });
+        }
+        let mut scalars = bytes
+            .chunks(OCTET_SCALAR_LENGTH)
+            .map(|chunk| {
+                let mut octets = [0u8; OCTET_SCALAR_LENGTH];
+                octets.copy_from_slice(chunk);
+                let s = Scalar::from_bytes_be(&octets);
+                if s.is_none().unwrap_u8() == 1u8
+                    || s.unwrap().is_zero().unwrap_u8() == 1u8
+                {
+                    return Err(Error::InvalidSecretKey);
+                }
+                Ok(s.unwrap())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let x = scalars.remove(0);
+        Ok(Self { x, y: scalars })
+    }
+}
+
+/// Public key type, `(X, Y_0, Y_1, ..., Y_L)` in G2 for `L` messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub(crate) x: G2Projective,
+    pub(crate) y: Vec<G2Projective>,
+}
+
+impl From<&SecretKey> for PublicKey {
+    fn from(sk: &SecretKey) -> Self {
+        Self {
+            x: G2Projective::generator() * sk.x,
+            y: sk.y.iter().map(|y| G2Projective::generator() * y).collect(),
+        }
+    }
+}
+
+impl PublicKey {
+    /// Number of messages the key can verify.
+    pub fn message_count(&self) -> usize {
+        self.y.len() - 1
+    }
+
+    /// Check if the `PublicKey` is valid.
+    pub fn is_valid(&self) -> Choice {
+        core::iter::once(&self.x).chain(self.y.iter()).fold(
+            Choice::from(1u8),
+            |acc, p| {
+                acc & !p.is_identity()
+                    & p.is_on_curve()
+                    & p.to_affine().is_torsion_free()
+            },
+        )
+    }
+
+    /// Get the representation of the public key as the concatenation of the
+    /// compressed G2 points `X || Y_0 || ... || Y_L`.
+    pub fn to_octets(&self) -> Vec<u8> {
+        core::iter::once(&self.x)
+            .chain(self.y.iter())
+            .flat_map(point_to_octets_g2)
+            .collect()
+    }
+
+    /// Convert from the representation of `to_octets` to a public key.
+    pub fn from_octets(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 2 * OCTET_POINT_G2_LENGTH
+            || bytes.len() % OCTET_POINT_G2_LENGTH != 0
+        {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "invalid public key length {}, expected a multiple of {} \
+                     bytes of at least {} bytes",
+                    bytes.len(),
+                    OCTET_POINT_G2_LENGTH,
+                    2 * OCTET_POINT_G2_LENGTH
+                ),
+            });
+        }
+        let mut points = bytes
+            .chunks(OCTET_POINT_G2_LENGTH)
+            .map(|chunk| {
+                let mut octets = [0u8; OCTET_POINT_G2_LENGTH];
+                octets.copy_from_slice(chunk);
+                octets_to_point_g2(&octets)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let x = points.remove(0);
+        let pk = Self { x, y: points };
+        if pk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        Ok(pk)
+    }
+}
+
+/// A PS key pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPair {
+    /// Secret key.
+    pub secret_key: SecretKey,
+
+    /// Public key.
+    pub public_key: PublicKey,
+}
+
+impl KeyPair {
+    /// Generate a PS key pair for `message_count` messages from provided IKM.
+    pub fn new(
+        ikm: &[u8],
+        key_info: &[u8],
+        message_count: usize,
+    ) -> Option<Self> {
+        let secret_key = SecretKey::new(ikm, key_info, message_count)?;
+        Some(Self {
+            public_key: PublicKey::from(&secret_key),
+            secret_key,
+        })
+    }
+
+    /// Generate a PS key pair for `message_count` messages from a CS-PRNG.
+    pub fn random<R>(
+        rng: &mut R,
+        key_info: &[u8],
+        message_count: usize,
+    ) -> Option<Self>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut ikm = [0u8; MIN_KEY_GEN_IKM_LENGTH];
+
+        if rng.try_fill_bytes(&mut ikm).is_ok() {
+            return Self::new(ikm.as_ref(), key_info, message_count);
+        }
+        None
+    }
+}
@@ -0,0 +1,365 @@
+#![allow(non_snake_case)]
+
+use super::{
+    key_pair::PublicKey,
+    signature::{pairing_check, Signature},
+    types::{Challenge, FiatShamirProof, Message, ProofMessage},
+    utils::compute_domain,
+};
+use crate::{
+    common::{
+        hash_param::constant::NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+        serialization::{i2osp, i2osp_with_data},
+        util::create_random_scalar,
+    },
+    curves::{
+        bls12_381::{
+            G1Projective,
+            G2Projective,
+            Scalar,
+            OCTET_POINT_G1_LENGTH,
+            OCTET_POINT_G2_LENGTH,
+            OCTET_SCALAR_LENGTH,
+        },
+        point_serde::{
+            octets_to_point_g1,
+            octets_to_point_g2,
+            point_to_octets_g1,
+            point_to_octets_g2,
+        },
+    },
+    error::Error,
+    ps::ciphersuites::PsCiphersuiteParameters,
+};
+use core::convert::TryFrom;
+use ff::Field;
+use group::Group;
+use rand::{CryptoRng, RngCore};
+use rand_core::OsRng;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+#[cfg(not(feature = "alloc"))]
+use std::collections::BTreeMap;
+
+/// The zero-knowledge proof-of-knowledge of a PS signature, where the
+/// signature is rerandomized and blinded as
+/// `sigma_1' = sigma_1 * r`, `sigma_2' = (sigma_2 + sigma_1 * t) * r`, and
+/// `K = P2 * t + sum(Y_j * msg_j)` over the hidden messages commits to the
+/// blinding factor and the hidden messages.
+/// proof = (sigma_1', sigma_2', K, c, t^, (m^_1, ..., m^_U)), where `U` is
+/// number of unrevealed messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Proof {
+    /// sigma_1'
+    pub(crate) sigma_1: G1Projective,
+    /// sigma_2'
+    pub(crate) sigma_2: G1Projective,
+    /// K
+    pub(crate) K: G2Projective,
+    /// c
+    pub(crate) c: Challenge,
+    /// t^
+    pub(crate) t_hat: FiatShamirProof,
+    /// (m^_1, ..., m^_U)
+    pub(crate) m_hat_list: Vec<FiatShamirProof>,
+}
+
+impl Proof {
+    /// Generates the zero-knowledge proof-of-knowledge of a signature, while
+    /// optionally selectively disclosing from the original set of signed
+    /// messages.
+    pub fn new<T, C>(
+        pk: &PublicKey,
+        signature: &Signature,
+        header: Option<T>,
+        ph: Option<T>,
+        messages: &[ProofMessage],
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        C: PsCiphersuiteParameters,
+    {
+        Self::new_with_rng::<_, _, C>(
+            pk, signature, header, ph, messages, OsRng,
+        )
+    }
+
+    /// Generates the zero-knowledge proof-of-knowledge of a signature using
+    /// an externally supplied random number generator.
+    pub fn new_with_rng<T, R, C>(
+        pk: &PublicKey,
+        signature: &Signature,
+        header: Option<T>,
+        ph: Option<T>,
+        messages: &[ProofMessage],
+        mut rng: R,
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        R: RngCore + CryptoRng,
+        C: PsCiphersuiteParameters,
+    {
+        // Error out if there is no `header` and not any `ProofMessage`
+        if header.is_none() && messages.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to prove".to_owned(),
+            });
+        }
+
+        let domain = compute_domain::<_, C>(pk, header, messages.len())?;
+
+        // sigma_1' = sigma_1 * r, sigma_2' = (sigma_2 + sigma_1 * t) * r
+        let r = create_random_scalar(&mut rng)?;
+        let t = create_random_scalar(&mut rng)?;
+        let sigma_1 = signature.sigma_1 * r;
+        let sigma_2 = (signature.sigma_2 + signature.sigma_1 * t) * r;
+
+        // K = P2 * t + sum(Y_j * msg_j), T = P2 * t~ + sum(Y_j * m~_j)
+        let P2 = C::p2();
+        let t_tilde = create_random_scalar(&mut rng)?;
+        let mut K = P2 * t;
+        let mut T = P2 * t_tilde;
+        let mut m_tilde_list = Vec::new();
+        let mut revealed = BTreeMap::new();
+        for (i, (m, Y)) in messages.iter().zip(pk.y[1..].iter()).enumerate() {
+            match m {
+                ProofMessage::Hidden(m) => {
+                    let m_tilde = create_random_scalar(&mut rng)?;
+                    K += Y * m.0;
+                    T += Y * m_tilde;
+                    m_tilde_list.push((m_tilde, *m));
+                }
+                ProofMessage::Revealed(_) => {
+                    revealed.insert(i, m.get_message());
+                }
+            }
+        }
+
+        let c = compute_challenge::<_, C>(
+            &sigma_1, &sigma_2, &K, &T, &revealed, &domain, ph,
+        )?;
+
+        Ok(Self {
+            sigma_1,
+            sigma_2,
+            K,
+            c,
+            t_hat: FiatShamirProof(t_tilde + c.0 * t),
+            m_hat_list: m_tilde_list
+                .into_iter()
+                .map(|(m_tilde, m)| FiatShamirProof(m_tilde + c.0 * m.0))
+                .collect(),
+        })
+    }
+
+    /// Verify the zero-knowledge proof-of-knowledge of a signature with
+    /// optionally selectively disclosed messages from the original set of
+    /// signed messages.
+    pub fn verify<T, C>(
+        &self,
+        pk: &PublicKey,
+        header: Option<T>,
+        ph: Option<T>,
+        disclosed_messages: &BTreeMap<usize, Message>,
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        C: PsCiphersuiteParameters,
+    {
+        if pk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let total_message_count =
+            disclosed_messages.len() + self.m_hat_list.len();
+        if disclosed_messages.keys().any(|i| *i >= total_message_count) {
+            return Err(Error::BadParams {
+                cause: format!(
+                    "revealed message index is out of bounds, \
+                     total_message_count is {total_message_count}",
+                ),
+            });
+        }
+
+        let domain = compute_domain::<_, C>(pk, header, total_message_count)?;
+
+        // T = P2 * t^ + sum(Y_j * m^_j) - K * c
+        let P2 = C::p2();
+        let mut T = P2 * self.t_hat.0 - self.K * self.c.0;
+        // W = X + Y_0 * domain + sum(Y_i * msg_i) + K
+        let mut W = pk.x + pk.y[0] * domain + self.K;
+        let mut m_hat_iter = self.m_hat_list.iter();
+        for (i, Y) in pk.y[1..].iter().enumerate() {
+            match disclosed_messages.get(&i) {
+                Some(m) => W += Y * m.0,
+                None => {
+                    // Length is checked above, so there is always a response
+                    // for each hidden message
+                    let m_hat =
+                        m_hat_iter.next().ok_or_else(|| Error::BadParams {
+                            cause: "missing response for a hidden message"
+                                .to_owned(),
+                        })?;
+                    T += Y * m_hat.0;
+                }
+            }
+        }
+
+        let c = compute_challenge::<_, C>(
+            &self.sigma_1,
+            &self.sigma_2,
+            &self.K,
+            &T,
+            disclosed_messages,
+            &domain,
+            ph,
+        )?;
+        if c != self.c {
+            return Ok(false);
+        }
+
+        Ok(pairing_check::<C>(&self.sigma_1, &W, &self.sigma_2))
+    }
+
+    /// Return the size of proof in bytes for `num_undisclosed_messages`.
+    pub fn get_size(num_undisclosed_messages: usize) -> usize {
+        OCTET_POINT_G1_LENGTH * 2
+            + OCTET_POINT_G2_LENGTH
+            + OCTET_SCALAR_LENGTH * (2 + num_undisclosed_messages)
+    }
+
+    /// Store the proof as a sequence of bytes in big endian format,
+    /// `sigma_1' || sigma_2' || K || c || t^ || m^_1 || ... || m^_U`, i.e.
+    /// [48, 48, 96, 32, 32, 32*U] bytes for BLS12-381.
+    pub fn to_octets(&self) -> Vec<u8> {
+        let mut buffer =
+            Vec::with_capacity(Self::get_size(self.m_hat_list.len()));
+        buffer.extend_from_slice(&point_to_octets_g1(&self.sigma_1));
+        buffer.extend_from_slice(&point_to_octets_g1(&self.sigma_2));
+        buffer.extend_from_slice(&point_to_octets_g2(&self.K));
+        buffer.extend_from_slice(&self.c.to_bytes());
+        buffer.extend_from_slice(&self.t_hat.to_bytes());
+        for m_hat in &self.m_hat_list {
+            buffer.extend_from_slice(&m_hat.to_bytes());
+        }
+        buffer
+    }
+
+    /// Get the proof `Proof` from a sequence of bytes in big endian format as
+    /// produced by `to_octets`.
+    pub fn from_octets<B: AsRef<[u8]>>(bytes: B) -> Result<Self, Error> {
+        let buffer = bytes.as_ref();
+        let proof_len_floor = Self::get_size(0);
+        if buffer.len() < proof_len_floor {
+            return Err(Error::MalformedProof {
+                cause: format!(
+                    "not enough data, input buffer size: {} bytes",
+                    buffer.len()
+                ),
+            });
+        }
+        if (buffer.len() - proof_len_floor) % OCTET_SCALAR_LENGTH != 0 {
+            return Err(Error::MalformedProof {
+                cause: format!(
+                    "variable length proof data size {} is not multiple of \
+                     `Scalar` size {} bytes",
+                    buffer.len() - proof_len_floor,
+                    OCTET_SCALAR_LENGTH
+                ),
+            });
+        }
+
+        let (sigma_1, buffer) = buffer.split_at(OCTET_POINT_G1_LENGTH);
+        let sigma_1 = octets_to_point_g1(
+            &<[u8; OCTET_POINT_G1_LENGTH]>::try_from(sigma_1)?,
+        )?;
+        let (sigma_2, buffer) = buffer.split_at(OCTET_POINT_G1_LENGTH);
+        let sigma_2 = octets_to_point_g1(
+            &<[u8; OCTET_POINT_G1_LENGTH]>::try_from(sigma_2)?,
+        )?;
+        let (K, buffer) = buffer.split_at(OCTET_POINT_G2_LENGTH);
+        let K =
+            octets_to_point_g2(&<[u8; OCTET_POINT_G2_LENGTH]>::try_from(K)?)?;
+        if sigma_1.is_identity().unwrap_u8() == 1
+            || sigma_2.is_identity().unwrap_u8() == 1
+        {
+            return Err(Error::PointIsIdentity);
+        }
+
+        let mut scalars = buffer
+            .chunks(OCTET_SCALAR_LENGTH)
+            .map(|chunk| {
+                let value = Scalar::from_bytes_be(
+                    &<[u8; OCTET_SCALAR_LENGTH]>::try_from(chunk)?,
+                );
+                if value.is_none().unwrap_u8() == 1u8 {
+                    return Err(Error::MalformedProof {
+                        cause: "failure while deserializing a `Scalar` value"
+                            .to_owned(),
+                    });
+                }
+                let value = value.unwrap();
+                if value.is_zero().unwrap_u8() == 1u8 {
+                    return Err(Error::UnexpectedZeroValue);
+                }
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>, Error>>()?
+            .into_iter();
+
+        let c = Challenge(scalars.next().ok_or(Error::BadEncoding)?);
+        let t_hat = FiatShamirProof(scalars.next().ok_or(Error::BadEncoding)?);
+
+        Ok(Self {
+            sigma_1,
+            sigma_2,
+            K,
+            c,
+            t_hat,
+            m_hat_list: scalars.map(FiatShamirProof).collect(),
+        })
+    }
+}
+
+/// Compute Fiat Shamir heuristic challenge.
+/// c = hash_to_scalar(sigma_1' || sigma_2' || K || T || R || i1 || msg_i1 ||
+/// ... || iR || msg_iR || domain || I2OSP(length(ph), 8) || ph)
+fn compute_challenge<T, C>(
+    sigma_1: &G1Projective,
+    sigma_2: &G1Projective,
+    K: &G2Projective,
+    T: &G2Projective,
+    disclosed_messages: &BTreeMap<usize, Message>,
+    domain: &Scalar,
+    ph: Option<T>,
+) -> Result<Challenge, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    let mut data_to_hash = vec![];
+    data_to_hash.extend(point_to_octets_g1(sigma_1));
+    data_to_hash.extend(point_to_octets_g1(sigma_2));
+    data_to_hash.extend(point_to_octets_g2(K));
+    data_to_hash.extend(point_to_octets_g2(T));
+    data_to_hash.extend(i2osp(
+        disclosed_messages.len() as u64,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+    for (i, m) in disclosed_messages {
+        data_to_hash
+            .extend(i2osp(*i as u64, NON_NEGATIVE_INTEGER_ENCODING_LENGTH)?);
+        data_to_hash.extend(m.to_bytes());
+    }
+    data_to_hash.extend(domain.to_bytes_be());
+
+    let ph_bytes = ph.as_ref().map_or(&[] as &[u8], |v| v.as_ref());
+    data_to_hash.extend(i2osp_with_data(
+        ph_bytes,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+
+    Ok(Challenge(C::hash_to_scalar(&data_to_hash, None)?))
+}
@@ -0,0 +1,180 @@
+#![allow(non_snake_case)]
+
+use super::{
+    key_pair::{PublicKey, SecretKey},
+    types::Message,
+    utils::compute_domain,
+};
+use crate::{
+    common::util::create_random_scalar,
+    curves::{
+        bls12_381::{
+            Bls12,
+            G1Projective,
+            G2Prepared,
+            G2Projective,
+            OCTET_POINT_G1_LENGTH,
+        },
+        point_serde::{octets_to_point_g1, point_to_octets_g1},
+    },
+    error::Error,
+    ps::ciphersuites::PsCiphersuiteParameters,
+};
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::{CryptoRng, RngCore};
+
+/// A Pointcheval-Sanders signature `(sigma_1, sigma_2)` with
+/// `sigma_2 = sigma_1 * (x + y_0 * domain + y_1 * msg_1 + ... + y_L * msg_L)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Signature {
+    pub(crate) sigma_1: G1Projective,
+    pub(crate) sigma_2: G1Projective,
+}
+
+impl Signature {
+    /// The number of bytes in a `Signature`.
+    pub const SIZE_BYTES: usize = 2 * OCTET_POINT_G1_LENGTH;
+
+    /// Generate a new `Signature` where all messages are known to the signer.
+    /// `sigma_1` is derived deterministically by hashing the domain and the
+    /// messages to G1, so that the signer does not need a source of
+    /// randomness.
+    pub fn new<T, C>(
+        sk: &SecretKey,
+        pk: &PublicKey,
+        header: Option<T>,
+        messages: &[Message],
+    ) -> Result<Self, Error>
+    where
+        T: AsRef<[u8]>,
+        C: PsCiphersuiteParameters,
+    {
+        if header.is_none() && messages.is_empty() {
+            return Err(Error::BadParams {
+                cause: "nothing to sign, header and messages are empty"
+                    .to_owned(),
+            });
+        }
+        if messages.len() != sk.message_count() {
+            return Err(Error::MessageGeneratorsLengthMismatch {
+                generators: sk.message_count(),
+                messages: messages.len(),
+            });
+        }
+
+        let domain = compute_domain::<_, C>(pk, header, messages.len())?;
+
+        let mut data_to_hash = domain.to_bytes_be().to_vec();
+        for m in messages {
+            data_to_hash.extend(m.to_bytes());
+        }
+        let sigma_1 = C::hash_to_g1(&data_to_hash, &C::signature_dst())?;
+        if sigma_1.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+
+        // e = x + y_0 * domain + y_1 * msg_1 + ... + y_L * msg_L
+        let e = sk.y[1..]
+            .iter()
+            .zip(messages.iter())
+            .fold(sk.x + sk.y[0] * domain, |acc, (y, m)| acc + y * m.0);
+
+        Ok(Self {
+            sigma_1,
+            sigma_2: sigma_1 * e,
+        })
+    }
+
+    /// Verify a signature.
+    pub fn verify<T, C>(
+        &self,
+        pk: &PublicKey,
+        header: Option<T>,
+        messages: &[Message],
+    ) -> Result<bool, Error>
+    where
+        T: AsRef<[u8]>,
+        C: PsCiphersuiteParameters,
+    {
+        if pk.is_valid().unwrap_u8() == 0 {
+            return Err(Error::InvalidPublicKey);
+        }
+        if self.sigma_1.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+
+        let domain = compute_domain::<_, C>(pk, header, messages.len())?;
+
+        // X + Y_0 * domain + Y_1 * msg_1 + ... + Y_L * msg_L
+        let W = pk.y[1..]
+            .iter()
+            .zip(messages.iter())
+            .fold(pk.x + pk.y[0] * domain, |acc, (y, m)| acc + y * m.0);
+
+        Ok(pairing_check::<C>(&self.sigma_1, &W, &self.sigma_2))
+    }
+
+    /// Randomize the signature so that it can not be linked to the original,
+    /// `(sigma_1 * r, sigma_2 * r)` for a random non-zero `r`. The result is
+    /// a valid signature over the same messages.
+    pub fn rerandomize<R>(&self, rng: &mut R) -> Result<Self, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let r = create_random_scalar(rng)?;
+        Ok(Self {
+            sigma_1: self.sigma_1 * r,
+            sigma_2: self.sigma_2 * r,
+        })
+    }
+
+    /// Get the octets representation of `Signature`, the compressed
+    /// `sigma_1 || sigma_2`.
+    pub fn to_octets(self) -> [u8; Self::SIZE_BYTES] {
+        let mut bytes = [0u8; Self::SIZE_BYTES];
+        bytes[..OCTET_POINT_G1_LENGTH]
+            .copy_from_slice(&point_to_octets_g1(&self.sigma_1));
+        bytes[OCTET_POINT_G1_LENGTH..]
+            .copy_from_slice(&point_to_octets_g1(&self.sigma_2));
+        bytes
+    }
+
+    /// Convert from the representation of `to_octets` to a `Signature`.
+    pub fn from_octets(data: &[u8; Self::SIZE_BYTES]) -> Result<Self, Error> {
+        let mut sigma_1 = [0u8; OCTET_POINT_G1_LENGTH];
+        let mut sigma_2 = [0u8; OCTET_POINT_G1_LENGTH];
+        sigma_1.copy_from_slice(&data[..OCTET_POINT_G1_LENGTH]);
+        sigma_2.copy_from_slice(&data[OCTET_POINT_G1_LENGTH..]);
+
+        let sigma_1 = octets_to_point_g1(&sigma_1)?;
+        if sigma_1.is_identity().unwrap_u8() == 1 {
+            return Err(Error::PointIsIdentity);
+        }
+        Ok(Self {
+            sigma_1,
+            sigma_2: octets_to_point_g1(&sigma_2)?,
+        })
+    }
+}
+
+/// Check `e(sigma_1, W) == e(sigma_2, P2)`.
+pub(crate) fn pairing_check<C>(
+    sigma_1: &G1Projective,
+    W: &G2Projective,
+    sigma_2: &G1Projective,
+) -> bool
+where
+    C: PsCiphersuiteParameters,
+{
+    // -P2, because we use multi_miller_loop
+    let P2 = -C::p2();
+    Bls12::multi_miller_loop(&[
+        (&sigma_1.to_affine(), &G2Prepared::from(W.to_affine())),
+        (&sigma_2.to_affine(), &G2Prepared::from(P2.to_affine())),
+    ])
+    .final_exponentiation()
+    .is_identity()
+    .unwrap_u8()
+        == 1u8
+}
@@ -0,0 +1,57 @@
+use crate::{
+    curves::{
+        bls12_381::{Scalar, OCTET_SCALAR_LENGTH},
+        scalar_type::scalar_wrapper,
+    },
+    error::Error,
+    ps::ciphersuites::PsCiphersuiteParameters,
+};
+use serde::{Deserialize, Serialize};
+use subtle::CtOption;
+
+scalar_wrapper!(
+    /// A message to be signed.
+    Message
+);
+
+scalar_wrapper!(
+    /// Proof challenge.
+    Challenge
+);
+
+scalar_wrapper!(
+    /// Response of the Fiat-Shamir transformed Schnorr proof.
+    FiatShamirProof
+);
+
+impl Message {
+    /// Map arbitrary data to `Message`.
+    pub fn from_arbitrary_data<C>(
+        message: &[u8],
+        dst: Option<&[u8]>,
+    ) -> Result<Self, Error>
+    where
+        C: PsCiphersuiteParameters,
+    {
+        Ok(Self(C::map_message_to_scalar_as_hash(message, dst)?))
+    }
+}
+
+/// A message classification by the prover.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum ProofMessage {
+    /// Message will be revealed to a verifier.
+    Revealed(Message),
+    /// Message will be hidden from a verifier.
+    Hidden(Message),
+}
+
+impl ProofMessage {
+    /// Extract the internal message.
+    pub fn get_message(&self) -> Message {
+        match *self {
+            ProofMessage::Revealed(r) => r,
+            ProofMessage::Hidden(h) => h,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use super::key_pair::PublicKey;
+use crate::{
+    common::{
+        hash_param::constant::NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+        serialization::{i2osp, i2osp_with_data},
+    },
+    curves::bls12_381::Scalar,
+    error::Error,
+    ps::ciphersuites::PsCiphersuiteParameters,
+};
+
+/// Computes `domain` value.
+/// domain = hash_to_scalar(PK || L || Ciphersuite_ID || I2OSP(length(header),
+/// 8) || header)
+pub(crate) fn compute_domain<T, C>(
+    pk: &PublicKey,
+    header: Option<T>,
+    message_count: usize,
+) -> Result<Scalar, Error>
+where
+    T: AsRef<[u8]>,
+    C: PsCiphersuiteParameters,
+{
+    // Error out if the public key does not cover the messages
+    if message_count != pk.message_count() {
+        return Err(Error::MessageGeneratorsLengthMismatch {
+            generators: pk.message_count(),
+            messages: message_count,
+        });
+    }
+
+    let mut data_to_hash = vec![];
+    data_to_hash.extend(pk.to_octets());
+    data_to_hash.extend(i2osp(
+        message_count as u64,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+    data_to_hash.extend(C::ID.as_octets());
+
+    let header_bytes = header.as_ref().map_or(&[] as &[u8], |v| v.as_ref());
+    data_to_hash.extend(i2osp_with_data(
+        header_bytes,
+        NON_NEGATIVE_INTEGER_ENCODING_LENGTH,
+    )?);
+
+    C::hash_to_scalar(&data_to_hash, None)
+}
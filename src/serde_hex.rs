@@ -0,0 +1,31 @@
+use crate::{common::serialization::deserialize_octets_with, Error};
+use core::fmt::Display;
+use serde::{Deserializer, Serializer};
+
+/// Serialize `octets` as a lowercase hex string for human-readable formats,
+/// and as bytes otherwise.
+pub fn serialize<T, S>(octets: &T, s: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]>,
+    S: Serializer,
+{
+    if s.is_human_readable() {
+        s.serialize_str(&hex::encode(octets))
+    } else {
+        s.serialize_bytes(octets.as_ref())
+    }
+}
+
+/// Deserialize a value serialized by [`serialize`], the octets are parsed,
+/// and validated, with the `TryFrom<&[u8]>` implementation of `T`.
+pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+    for<'a> <T as TryFrom<&'a [u8]>>::Error: Display,
+    D: Deserializer<'de>,
+{
+    let octets = deserialize_octets_with(d, "hex string or byte array", |v| {
+        hex::decode(v).map_err(|_| Error::BadEncoding)
+    })?;
+    T::try_from(octets.as_slice()).map_err(serde::de::Error::custom)
+}
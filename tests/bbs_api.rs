@@ -1,35 +1,97 @@
 use bbs_fixtures_generator::mock_rng::MockRng;
-use blstrs::hash_to_curve::{ExpandMsgXmd, ExpandMsgXof};
+use blstrs::{
+    hash_to_curve::{ExpandMsgXmd, ExpandMsgXof},
+    G1Affine,
+    G1Projective,
+    Scalar,
+};
 use pairing_crypto::{
     bbs::{
         ciphersuites::{
-            bls12_381::{KeyPair, BBS_BLS12381G1_EXPAND_LEN},
+            bls12_381::{
+                get_proof_size_draft_06,
+                inspect_proof,
+                inspect_proof_draft_06,
+                BbsProof,
+                BbsProofDraft06,
+                BbsSignature,
+                BbsSigner,
+                BbsSignerInput,
+                KeyPair,
+                PublicKey,
+                SecretKey,
+                BBS_BLS12381G1_EXPAND_LEN,
+                BBS_BLS12381G1_PUBLIC_KEY_LENGTH,
+            },
             bls12_381_g1_sha_256::{
                 ciphersuite_id as bls12_381_g1_sha_256_ciphersuite_id,
+                key_gen as bls12_381_g1_sha_256_key_gen,
+                pop_prove as bls12_381_g1_sha_256_pop_prove,
+                pop_verify as bls12_381_g1_sha_256_pop_verify,
                 proof_gen as bls12_381_g1_sha_256_proof_gen,
                 proof_gen_with_rng as bls12_381_g1_sha_256_proof_gen_with_rng,
                 proof_verify as bls12_381_g1_sha_256_proof_verify,
                 sign as bls12_381_g1_sha_256_sign,
+                sign_with_signer as bls12_381_g1_sha_256_sign_with_signer,
                 verify as bls12_381_g1_sha_256_verify,
             },
+            bls12_381_g1_sha_256_draft_06::{
+                api_id as bls12_381_g1_sha_256_draft_06_api_id,
+                ciphersuite_id as bls12_381_g1_sha_256_draft_06_ciphersuite_id,
+                key_gen as bls12_381_g1_sha_256_draft_06_key_gen,
+                proof_gen as bls12_381_g1_sha_256_draft_06_proof_gen,
+                proof_gen_with_rng as bls12_381_g1_sha_256_draft_06_proof_gen_with_rng,
+                proof_verify as bls12_381_g1_sha_256_draft_06_proof_verify,
+                sign as bls12_381_g1_sha_256_draft_06_sign,
+                sign_with_signer as bls12_381_g1_sha_256_draft_06_sign_with_signer,
+                verify as bls12_381_g1_sha_256_draft_06_verify,
+            },
             bls12_381_g1_shake_256::{
                 ciphersuite_id as bls12_381_g1_shake_256_ciphersuite_id,
+                key_gen as bls12_381_g1_shake_256_key_gen,
+                pop_verify as bls12_381_g1_shake_256_pop_verify,
                 proof_gen as bls12_381_g1_shake_256_proof_gen,
                 proof_gen_with_rng as bls12_381_g1_shake_256_proof_gen_with_rng,
                 proof_verify as bls12_381_g1_shake_256_proof_verify,
                 sign as bls12_381_g1_shake_256_sign,
+                sign_with_signer as bls12_381_g1_shake_256_sign_with_signer,
                 verify as bls12_381_g1_shake_256_verify,
             },
+            bls12_381_g1_shake_256_draft_06::{
+                api_id as bls12_381_g1_shake_256_draft_06_api_id,
+                ciphersuite_id as bls12_381_g1_shake_256_draft_06_ciphersuite_id,
+                proof_gen as bls12_381_g1_shake_256_draft_06_proof_gen,
+                proof_gen_with_rng as bls12_381_g1_shake_256_draft_06_proof_gen_with_rng,
+                proof_verify as bls12_381_g1_shake_256_draft_06_proof_verify,
+                sign as bls12_381_g1_shake_256_draft_06_sign,
+                verify as bls12_381_g1_shake_256_draft_06_verify,
+            },
+            custom::{
+                key_gen as custom_key_gen,
+                pop_verify as custom_pop_verify,
+                proof_gen as custom_proof_gen,
+                proof_verify as custom_proof_verify,
+                sign as custom_sign,
+                sign_with_signer as custom_sign_with_signer,
+                verify as custom_verify,
+                BbsCustomCiphersuite,
+                XmdSha256,
+                XmdSha512,
+            },
+            BbsCiphersuite,
         },
         BbsProofGenRequest,
         BbsProofGenRevealMessageRequest,
         BbsProofVerifyRequest,
         BbsSignRequest,
+        BbsSignWithSignerRequest,
         BbsVerifyRequest,
     },
+    hash::{hash_to_scalars, XofShake256},
     Error,
 };
 use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 use sha3::Shake256;
 
@@ -135,6 +197,183 @@ fn sign_verify_e2e_nominal() {
     );
 }
 
+// A signer which only exchanges octets with the application, as a key
+// management service would: it completes the signature from the serialized
+// signer input.
+struct RemoteSigner([u8; 32]);
+
+// Split a field prefixed with its length as an 8 bytes integer.
+fn split_length_prefixed(octets: &[u8]) -> (&[u8], &[u8]) {
+    let (length, octets) = octets.split_at(8);
+    octets.split_at(u64::from_be_bytes(length.try_into().unwrap()) as usize)
+}
+
+impl BbsSigner for RemoteSigner {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        SecretKey::from_bytes(&self.0)?.public_key()
+    }
+
+    fn sign(&self, input: &BbsSignerInput) -> Result<[u8; 80], Error> {
+        let octets = input.to_octets();
+        let (ciphersuite_id, octets) = split_length_prefixed(&octets);
+        let (dst, octets) = split_length_prefixed(octets);
+        let (b, octets) = octets.split_at(48);
+        let (e_input, octets) = split_length_prefixed(octets);
+        assert!(octets.is_empty());
+        assert_eq!(ciphersuite_id, input.ciphersuite_id());
+        assert_eq!(dst, input.dst());
+        assert_eq!(b, input.b());
+        assert_eq!(e_input, input.e_input());
+
+        // e = hash_to_scalar(SK || e_input, dst)
+        let data_to_hash = [self.0.as_ref(), e_input].concat();
+        let e = if ciphersuite_id.starts_with(b"BBS_BLS12381G1_XOF:SHAKE-256_")
+        {
+            hash_to_scalars::<XofShake256>(&data_to_hash, dst, 1)?[0]
+        } else {
+            hash_to_scalars::<XmdSha256>(&data_to_hash, dst, 1)?[0]
+        };
+
+        // A = B * (1 / (SK + e))
+        let sk = Scalar::from_bytes_be(&self.0).unwrap();
+        let exp = ff::Field::invert(&(sk + Scalar::from_bytes_be(&e).unwrap()))
+            .unwrap();
+        let b = G1Affine::from_compressed(b.try_into().unwrap()).unwrap();
+        let a = G1Affine::from(G1Projective::from(b) * exp);
+
+        let mut signature = [0u8; 80];
+        signature[..48].copy_from_slice(&a.to_compressed());
+        signature[48..].copy_from_slice(&e);
+        Ok(signature)
+    }
+}
+
+// A signer which signs with another key than the one of its public key.
+struct FaultySigner(KeyPair, SecretKey);
+
+impl BbsSigner for FaultySigner {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.0.public_key)
+    }
+
+    fn sign(&self, input: &BbsSignerInput) -> Result<[u8; 80], Error> {
+        input.sign(&self.1)
+    }
+}
+
+macro_rules! sign_with_signer_e2e_nominal {
+    ($sign_with_signer_fn:ident, $signature_test_vector:ident) => {
+        let header = TEST_HEADER.as_ref();
+        let messages = &TEST_CLAIMS;
+
+        for i in 0..TEST_KEY_INFOS.len() {
+            let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[i])
+                .expect("key generation failed");
+            let request = BbsSignWithSignerRequest {
+                header: Some(header),
+                messages: Some(messages),
+            };
+
+            let expected_signature = hex::decode($signature_test_vector[i])
+                .expect("hex decoding failed");
+            for signature in [
+                $sign_with_signer_fn(&key_pair.secret_key, &request),
+                $sign_with_signer_fn(
+                    &RemoteSigner(key_pair.secret_key.to_bytes()),
+                    &request,
+                ),
+            ] {
+                assert_eq!(
+                    signature.expect("signature generation failed").to_vec(),
+                    expected_signature
+                );
+            }
+        }
+    };
+}
+
+#[test]
+fn sign_with_signer_e2e_nominal() {
+    sign_with_signer_e2e_nominal!(
+        bls12_381_g1_shake_256_sign_with_signer,
+        EXPECTED_SIGNATURES_SHAKE_256
+    );
+
+    sign_with_signer_e2e_nominal!(
+        bls12_381_g1_sha_256_sign_with_signer,
+        EXPECTED_SIGNATURES_SHA_256
+    );
+
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let secret_key = key_pair.secret_key.to_bytes();
+    let public_key = key_pair.public_key.to_octets();
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS;
+    let request = BbsSignWithSignerRequest {
+        header: Some(header),
+        messages: Some(messages),
+    };
+    let sign_request = BbsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    };
+
+    assert_eq!(
+        bls12_381_g1_sha_256_draft_06_sign_with_signer(
+            &key_pair.secret_key,
+            &request
+        ),
+        bls12_381_g1_sha_256_draft_06_sign(&sign_request)
+    );
+    assert_eq!(
+        custom_sign_with_signer::<Bls12381G1Sha512, _, _>(
+            &key_pair.secret_key,
+            &request
+        ),
+        custom_sign::<Bls12381G1Sha512, _>(&sign_request)
+    );
+    assert_eq!(
+        BbsCiphersuite::Bls12381G1Shake256
+            .sign_with_signer(&key_pair.secret_key, &request),
+        BbsCiphersuite::Bls12381G1Shake256.sign(&sign_request)
+    );
+}
+
+#[test]
+fn sign_with_faulty_signer() {
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let other_key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[1])
+        .expect("key generation failed");
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS;
+
+    // The output of a signer which does not hold the secret key of its
+    // public key is rejected.
+    assert!(matches!(
+        bls12_381_g1_sha_256_sign_with_signer(
+            &FaultySigner(key_pair.clone(), other_key_pair.secret_key.clone()),
+            &BbsSignWithSignerRequest {
+                header: Some(header),
+                messages: Some(messages),
+            },
+        ),
+        Err(Error::CryptoOps { .. })
+    ));
+
+    // Invalid inputs are rejected before reaching the signer.
+    assert!(matches!(
+        bls12_381_g1_sha_256_sign_with_signer::<&[u8], _>(
+            &key_pair.secret_key,
+            &BbsSignWithSignerRequest::default(),
+        ),
+        Err(Error::BadParams { .. })
+    ));
+}
+
 // Expected shake256 proof fixtures using MockRng.
 const EXPECTED_PROOFS_SHAKE256: [[&str; 6]; 7] = [
     ["83d7301474943bd266205ae40b3eceb41eac94c54cee21a008e3597681ce28a72e7380d3526be077a2ef03aef9b6f43688534b4d98f5ac313caa7fe869d395873d6e26de0e6ff59af604829581e4fac5fdf469fbf121c8c1d3da1b60a6fc6f42067868d08bb46eecbc89c43624b5810e1bd684b5ab45ed99e1fe750ec17d5d312528cc0582a1d5bad23cb6e30741cb1e95efee6064afc748a269f957761552f004a8770286495ba60b1b0f27bb1e3d1b201ec1408f0b8b506b30280528d85ecf2937c5dcc89f16e3a25c5108f8d66b56d18a0b1b00108a316f8cba9ef363a0000188117d80607fea0fea72231e47f0ccbf0c46cd2e534f72e8c3e3991f41e7b40706d67a05ec1a674d28edcadc6cddbe0768193123fec7846f773e34e5bfc0b4362d5b6136e7bf50013d68bad2f4e9160958f042dc21462ae48d8e4f33d484053de5abb9b7b2c5bfbc928de1fb8c19d513869fa356afd965d7910d538e3ca715415e4dec5f176d19098ef08ff762e28c3bc5792ab0b18046582c6c4945370293", "b37e9798fa5469b7df5c5ed01d970bedf438e257f6d701aac153858248e8960b5c8b65021bebd5358af4a88660aa6dc098170d4fe37afa0848bb08fa7747892217e04118b9a7bec14bac295e789fd0732309b062217e4c18bf1de527a2574ff5469456264d4baa5706247d9ecd0425190e6abfa9c4678332c30fa7bfd9a4b66148578d6b1a987c09712764042d53e97d43cc07a9e54aeccc03519fd2cebd3ee733a04b9c07eb199d3d0e08ba4d810e326220ca70ad1fc18adea7406d0cf30cb6346275030c35a7f7cffb45479b581dd8e44b050054ccf2f9caa7c6a1660280ad0e2173c33cc57ccf5b5aadbc5bf82f2ebe12b7069bb35211463fb298f1d429fd2e9d78af1ab7b98383a1b4fc125bf2fadc9ad3390912518afdfa0c2535e510bd18d303b8765838aaf5e4839884e5c20888fb948ee1279fc9d024a19d8bf708263130b6d72da24f6b56ba892048fc32ec2f6e375cc495ca700b3df8ba8a167f16", "868d0cd75a21f71fa4d3266fcabd53685e9d2e7738586d5f69befdb813be1013b7cb105edf65c2b6dbe3cc80b66948018c6a48629a27aa5002efbfce654d64bbeef7f12058fe85374538576570d201c5b17409e9cdf0a49bd38adac500e82d116d2ed9d563d095a8d08ceb88447728d8a0415ef38936b2c90edc2548b8d174f9468eb41e2c7ea1564eead0f77c1830c671e720da87a4af7572bad5f7294415ef2dd0160c45fcc7abf2ee5232a1ddcadc0a70e528bf37c16c3acb9202919660b7378d0502b41095848fa29eea1bb72ba3122eddf2bda3e0cc34bcfd093df79e7206c3dbbab80d477c519d434fe3a90cc5c1f466fcadb60bf20a78d9bada7cf547636c3f45e1f18150d2bc5a8b9f5eee8e4e9ddcc268babcc75d9ed011bd920ee364ceb7500a64f075deb23d13a2470697364dcd3f7722ac67850bb2940a072d48", "8d61c945782c36c2ebfa29d5b30253b826b153356ebff9f3dce8c4d0fca95844a294ae463eb8b7073bccc01beaec0406875e22fdad4b193ce0677ab1c2f2244a775d8ec57b26d1f47e9ef20c63408b8c59935d46779c25d42161b3a7ef1caa7020e63878338d42ef1b7739ac5aac6199cb9f70c2c7c181ba6a3307053ff9974214fb1c14dc7f7e47bfafecf1130639ab5900a486e25a941d75d2de10ccc6da472f93ff7b96d4f82085be71e8be789294eafa538920c30bdeb9cc4c7668d8ee9a6dc4b15a39a7c8407e846ca0f41f8257a1a4243b1217b3a0c784d4a51fdb2bdd0a8463b646225f4df831716b37baf33f87c93e4046d3b61fbd306ccf38d4dbcb255cba80dda46f5520e4afcd69ef376af21425fdf2f11f2986419192a96355a9", "a873f4ede35fc58d847e8632459ac273ba96881b8a9668c7b7762b76c2344108fea73d10bbf3903bca13877659b3818e8a29b3a31b09476907996bac907dcdab7f66fd341f82decf65c443bb28b0821574ee1593f97065ff3b5b0b083e206edc2810e9f773b596b7efb0414ea69bcab4127d480d7971088d75702db2230749b85c9e0ca9085b1de447852f3c87bd75f260f9a15814d37491c85f27a8e9ab332c66aade8adb9410a443c19b90ad271982b1ed813d194d3e058ac9df36863b5d6f0432a710168bc26ae50bc266831a59c2f7d496363be26ead5f4681c6f1c9bb0869691654b2827d62685ba36e3ffbdface0895a5e06d3b2e138127dbaed2fcb96", "b36140fc979a794f3cbe579caa6e6f4f07d2d22016c1367744634ee216f6af1e66b233125f486e0fd80e233eee19c443acdb156c7eec962a24fae709a1af3666e582457c5acb1ec4d8783e84dadecc1ba61d5549ef07c5cf7f2d3987c9f5fe466e4710581a820d54ec3e4957f44428ee0431bf40c872aff6e81c1724b63665cc3b3c76d3e5bb496ad3e89b48b86ef1ebceca8fa22b60e0dc7dc67dee94c187cc0f93592a6259a17fec3d5d0618f0a5cd3040194ebe71464d65b59a406d38fb7b18d49c7db0ee0f0bd1447d340b45ea80aa55d51d848a25a8331a600bc1cbe8d3"],
@@ -157,6 +396,30 @@ const EXPECTED_PROOFS_SHA256: [[&str; 6]; 7] = [
     ["aac5ab25e0996f32271275ba54ab6cc1fbd2806883992e05f25659f510428dccde9c4c98963daf7817ffbcc9a8f725b2aef13f60f38dd8a55f70e78894fd59e38ccfc45c66d06a147d4bfcdd693f5210f07f13b4faf5137c79994ee5824abf9232edc61a6b4dd13a2b641c9244be750bc184fe297e12a94626a2f9d2890234200ad73ffe8a515d619e64903e7df8be9b58fe50e2e11bd9af768a219f4a60e7f117abbe1425a5ea348be7be5ac2f0942c59189fcb5c7ed5d79bab235aa99c0d4b4a75ccba46ec706316c77e561bc32bfcc108a362d593ffadfbc0e21659abdd1c58e0f702fcefa51b7f0d27917b60a36bd7c2b4c7873b18de05bbc682b6bf57203816f4bfa93e2a0eae69c228c7d756417944541c877a0ab1c4f905e82ff579380158ea3e10ed232a182188a8940f7a20d260756c4edf4f482316d622e5ad31d60f170cb2ba1b47bee9e10015cb5f0c1221888953c8055b17a4f9681f795924033967ff0ef26b0a96591e974322d5f70523e88d46405e272f26e957e4d680a22e", "a98be12cfb44a6239f5a862317aecf42af98887ad7a9a780994084f02b951479f6112e9f98c4026fea81f1fd3d5a18c5b0547eec01fb3cef66bbb8e89387cbd6336ec72258e0de4edb8304c0af5f09d47987fb5bc52b519cab326d1e52400b3a694f40703f25583a0fb3ded96697be4f864deb6d0f336ef7f431990b3a52ac4d29e4a457e0bac733b0f6590e7defb2a541ae804a233580801b565b417ed5db991c00d70db9622fbc0eee6ecbe5bbb4d00f3262b56d32f2e8cf61da5e7184057049d55711610a34e46b3767971c5d226010d3e914286fb1b077fae9e599d6c64d2f26b85247dff6e83f5bd017ca15a10c72e79c9d3b96523ba9266b115564c26e4ddb1e2e09c7f235d56724a77b4f352e6e36fcb9ccd6f0a821a45fd920b546f81d4116a1043dc159e430ad1eb78dc3e5a5f001fb3f313d49d4fdc1daba91030420490421079aa23e2715634e059e97b7698f44a3bee2083df71c6341bcabf905", "aa5c682a198728424af59b36c9c4aae9ccdac86e7e6b542f775df28d315f40fe7dc1a206a6fd73854a265cd63d8d6411a6e1932d3fac4902ecdce31c1246b0cd318609e44513288f64270096f66e5e492e92ed1864d4343e9694581133ea74fa3e107234eb1189cfe12a16eabcad338f6b320be501a529e6b9b824105e63e29c28c86c62680f082e158e1f7ad551cfbec03bac4bb1e7d72e32ec4f12788efc1c21ba0aa11f100b398bc8817a3e256ae276358a355e8afe9d0140c8db1faa7c5748e2f239f9910e9cc38d6deddc5a434fed179d25e5cac854bfd870383dbfa2181f0fa9dd4dc261d3c3ca6777b067ef99811c91f148757044b1d956afd8e20969055d03fb310a27f22efade0cd677313b8758b918b18c38e96cb4079d4c64bf37228a410d089837bbce082624e55cf04600ace787c1991e32715ca11db6502662", "8ca05dc485720fc45bcfaba886f420d90f76fc54251a2f90f7684bd2e3459cfac7b7ece10cd6c4b109253ae75fa226b7950bd15773fbd6678fc5b8524b35fc98d2b212c7192d2a69553f009941b6e861669221a976ad7f73fd71350446b25e3b6495dcd982317fda2d5ce4138ef2323dc76f0d8bf638fa941455606abc8a841b2c02e51a4340a7fa8d580339ed2550fa5d84aa48efc9b60cedd84af964bb21eb0d2db4e9a57f1c21329d1c32668a0f39da4b00c4b85fd98ce4845f9ec4cd4cd414a62de723e22862d6200f9a78833758a85174e82deedd1634f7d8497535cac535ed5f68b0c2cf96bb9f54ee146560547ecc2400e4f2d11cf145c1c182f5ea0c3f142b687bc9d8e616b7d4221cd7842c7372c7a7be567ce889928b3e1b59233c", "845b3868c151edc09ee546602c4ddbc14457b8f6b0248002a1beee2cdadcb0c2a6bed381929aa52fb17aef8b8372b074a09fc965f759639e1cc66829a4ef9c5115c5258afacc02c05081e1bc862b6b45bbf09b99cd87d645a55d7e0e04f0cca7277da42027effc3141081a6e27048fb002e73617ab24c41e3d3f509ae771fd765de7d61b1ed9caaaed253c60bd37bd11aeded072c78e5ca2bd54edba37c14f0c3e5906de559c559f9f509a94b5534eca08898d9e1eb9f665a5e81e2af836fd2856698bd19887d9d927320e7b755bc986593fc7f0b39c1e4bd46c3439da22708b462c41aac0f2955e9eb4b5fb727d3d1b7993edeb9e90333de4ebd274819bf7a6", "b93738ead0f1afeb9101a777d8ec07b9c82a0711fa83bb93040c1b8427e8283ba3bc7601fc5581cd7403a6e82531df24a8a96be9c74f13b6ab4a85548921444570784e997a2f553a3767a0f4aa29d362cec74a95c9216bcf28889e79c94f97c4680e71cb5fa0601c553767ba17c63c911ba8c9b190806b7eb1f38d972619c7d45a18e293f3461ea0d6344585881620cc5aebecd9bdfbf3fd5d6bb8a860a979ab2e0e30bcbb75e0d29ee44aa5a5f721dd8c640b49920630d64745e4e426266ff629d5edf2c032a9d43a0935f1446f3372ee4f01649738c0962e74843c8eb10c42"],
 ];
 
+// Expected draft-06 shake256 proof fixtures using MockRng.
+// These were generated with this implementation.
+const EXPECTED_PROOFS_SHAKE256_DRAFT_06: [[&str; 6]; 7] = [
+    ["9602be0211f0df7dc8d9cc6b89fcfe1b8d1e277b1874375b7c999dd476b63e1ded816d146bc5e34ed43a9be9d29727aa88fc54dfaff4c0b19461923b0c01452637829d36496e141a55e67823b04ea9cbfd87c7e714f5802a5f37e6bd080e32e1972786b2d5bdbe41a7bfa9fe95e1240e5780cd30ef29ead1151c99f96520bce4a1fb14aa47cfedf45ec6f79f50da3cc7378d1b074bc14eb80656bf99888afb902593fad64328da6910a01c5bb81d99b63916eb9bfc0b9f6b587f7e8559ac03d4a75db076a7204222837d3f3f7dcbfc0a2fe872d4d52a33f1512927eeee3589bbed80ce0e36a8c592723ef253e7a35d920b3048341a9c0e9c374fa0ba4a01477dd6d71fb96c037e7152096a2ff42c67307231256e98a0516f6cd78c8cc25813d044de497a2ac82099314d78d6c12ccc4a212bd593a5fd08463cbf273d4a4569983e806e3a40aed0f1e852cb62db3484bc35f0bee0919399cdce217eb45087f0d61703b9ab96e5199aca02617ac7b16d511c4098c5dae8813e3d07ba41dca0f8cff4dfa88d50ec90fca8b5882db19eb168174149747c313bb0fdc0b7e91f9cc4ce152d5f0de41046b6aebf6049ee00f11056d959dea67d2da214c6f335369ac3c8c2ce3b3d85352a86a9d57eee4ae504e2", "968076bfd9912e2b9003e06b1a9bc7982313efa8c3347ecebee440a459fc8d6bda8acbd346dd75141670ecf85a9e652e830556f1842a1f908e40497b5568768eb2e156de400d5f99b8866bbd1e5824c627cc48c37981028fe009b44ab6e3b92194e92a09bbc2587269d8ea468199f9a3f5d466e689e543a444df28a84f154d2e371017f8e9aa7b2307868c3c725ed28b27e875df7d285838768b7452cabd2c44112125a22be8d40075caaf1f582fddd25b50ebacc6adc5119a9fdf6b3c2ba17d05c3b80e8e0fccb08649f963b8e608f367f00f708dec2efe7555cd1503f6cd98459f9ce4224f1af4c0bf51636412d18f1193eba86eafb97cd3d760c26d2bc77cccef22e1583ab789308ecb6600b659f36eae993f550f25ee040663606fdf5432f6c11676fa32fa90bc6ad82094c90bee172281f8d613c6647089464cece5b535ecc791489de397913eb4c2603fdd34795f4c176aea0138546472a706275b84c2ce9f6032e5f3a51a9cc03a85018568cd0271ca958c5b8621c34ad42fdcde9a55ddbc82f8184f40f7955a6c32aabbe5273a2aa5a2ad4523758e4ddea4afc06c8edc521a93477cd237b840c547ce64959d", "a0fd5332796403dfb3aef3e175913c68ecd235a06f8ed1174ab9b7e2e188b638f4db7673264a6b804f59883e6e23a709b611de8a5d8d3eb364e0e180baf80f986cf0a6dd44445036cdadc6e87c28ae45836116253b65c4581c667127ba99c4ebaa965b6f530d9e7e42ef98a31c11f92883d1a48b4c97b334ad8602f820837ecee4f978b472d2d0c0f7fcab0194376de707d53336c25b2953812b72286119341d47dc20491f92d743f838fbce7de8421e4e183039e53878789b47bd7e0e5f58df926239e532a1f2a3cb5a1660b42d74c51f13e243cf91e3a1e8a466d183df3f60adc315535ca36d0939e59954606e3c4402e2156d9e9f7395eb730c95dc3306f62e370fa24b69018ffdf90e693146a27f63aecad7c440aaebb88aed0f92e264d0389f0be785cafc8d47d14b1d523c9ee85d68da6b77f53778886ec25a9d22680a5bd9ce4e6d1267076f251443ed991377690c4ccd39f1d8b0d4a28b11bde78074bebcbf62cd3b31845df3cc44a3cbd8552f0fc3c8335e78ee2dd2216d538d9ef1f5cab931e1c172caff91800c53e34a36", "b2f7e610d179d880d1c064fdb3ee99ce198cb3bdaee085cca520a2c6e376e7440d7853be8729764611edcb6a4ecfc5f998f1d406f535ebb79631a9c800db0812c5190f050e13e5e2a2075393c0849e9646a532cf7269258c9b01caac7fa69ca596a52ca7248d9ef728b7b065fada8eacaddf52c07447889caf1e544f1248b6ee81cf9aecc252ec621fb72ae127af5e5c2f6ce0e7231e86b4915f5adaad7cf64eeadf98e9d3cafac88ae1f9a57a7444d24f13f1d7e90132ed8ac62acc99189209bd98ac893d020388d9b2e9562fd0303821b90bd1fb83bb1b99ecc303ddb56f3740f3c11636d12aae504c98641525a2ea3b13ca73cbd542b20723d9899be0f29aa90eba8208d629b63a8a1cbf40471f0d5211204fdb034183a91886e7ae68731fa501eb7cc833ccf85b5efcac319cf4cc3abbbfcd181ab2ddf344f5280d609ea7306b653610150a6ea1a23273b102f0a5043e07d1ef63cdc696afcf75986648c2ccd6c24d6b91072f90c7d7b096678cd2", "822bb97facb51a53219c16e1c06c6679a8f64d6080a24873643f710666e8aff992b1bff8a2b67f41567efbc53d0f2ef9b76e55fabe0a40a55be1ed16f55db5e38f0e0cc23ce673b6302d55735ac936e4e8254ddd41c7e2bc785fa666782d88c48d4c14257de984ba1e5564a8a8598dd3b5c98725bbf803cf8cbc8701e8aee5f5c4a7ea9c29c9f071e2b0d131cb1900992179b41d0e240781e1953557ffe3c031e695d5ad71ba2989cb2614acc0d7cb631139de9dc3497db909696e5ede4995421f09964181bd7d536221ecad42b4704412332af5cd3a8d0af9ea71ed7e94b6b956085164225db21533bd904d9b4e426835179677bc501111f165a49958024707c850646ca079d0c9e660c04679be24493fee6dd97746b9574b5ea7ec9a3e0df397a25e6df5dd37127298a7806109cdfb1ca7102a6b1b7c758f737b3a1d7d6725aed6ecea0e5465ffb6c0b4721f5dc029", "983b7ac7b5c4664150f9020e327e02e736c69231689d2f798ad5f268e27227c82aa56379205f20b9644905a78b301a5b91bae2a0d5b66450e6dca252e291f7f2c83df969a0415ee93b06033d172183b2771ebb9392bfdc8106c99c90336132dda186dad4e2f27342f4e583dd8fccd5b2d346846d5f3b337497ca4e8508f4698a7cbe0395a25349e5714eec8857c8596a29cd48e8796d522231a9f2e9dbcd999d262cc1f3826771248c2275353ea954f96b6c749c43d9ebfa692cee97cfc64cca8661d0e7dc321524068e6c53c89ae19b508891aa46e14cd924aa45ac9ac267fa9f9710884f17eb3a8fcabed0aca1d3c640a81f81b95f90bc8409935c5f6db7daeaeac4efca7d55d3e9ee19e39706a6692d75947d2b9ad00b82525db5a82665c1effdfcab405cc197b007a2e69e52080b"],
+    ["8366e0109aa35aef713885ac133d8f57dd72ada41247a78ddf3050a356f0551941ad6579a86795877c8672398600292eb767916979fcf6695a1830eead92336b159cbd66f42883fc69f60fffc949a9070032e9b6b654ee050f250534d065ecf7b7fadeeb96d70839d0b6c4b3921d3e42d0b559005b71d8f563786fdff2280af12b220b766ad76b5d9198551f3b1f44f62253814fdcf534bd42db6bdfcad8d570ccd3d15d301c4cdaba338a7d61b2c0d60c633d11470d297cf0cac50c33db46ac3f499370a485afec853e0eb5598b710206aa3a26c24663869118779b2d65cc8592265d2c866f0a6d9df666a2799532f3639dd341d885bc1a5b65f9f75e983383218005f0d44dca9de1587f23a0cc4bc9208f587667238ad37b797940941956529fc5baa16ae63a9a558c3ced879574bf37cde43250083096ae78e17b491e34fd8a82a818834e2366ce454f2431fc7064536a20a726fdb8e94ad66d5dd228a93668eb768b68e43be3befbaba1d2951c8d138e2862c9091b47b92dca03e796055803785142eaaf476d925b03206dae05c3476b477b1557f21aa9c4e76853aae0c8c4010ecd2acbf98f8570f997a942ba4e2d790a188c470eec30ea9999836f50d1a0b3af0bf7c222affab16aa22f196f7a", "b109c13cfb2bdcfc626f7d7b3bc14efcdf08f3ef4b7f089abe607a7708aa1f2280c2f2999ed092c192e058ed2da8805e896999dfc7a4542562db35e2159a491d6ddc447de2918b36b16308a51b8f2c086829fb6bc1bb45b6d51249dcbe7fec50aa5e9a1a00ff1d27763b9c4dfa6d5a41efc9d08de736a181c162eb866d893d31999041ef9f41cf2d3faeb9bec5a23b655cd4819b8a19d2ab154c37cd34f1b4073ba9f3b6955f987eea7b00f5d94ae90218fefc946ff4ba58fc2ead6540bcee83e3c89b72df89e7d4974060d02b86fe2f6e7cd761ebab031ea0b98eecebc8a81a604e641bf0b81a78c43064a3a00ebc20075fc0a8e7028c0f42840b080d98f61a1671b059df4fc7b373d3be5785cae3732486807bf42526669e1f85f62824216045fcfe1809c2aa1839838eb092b2dc9b245f4496febdfaee9f701207b008782e64afbc9d43fadb4cd69479a400811eb75d4548e89a691de90ea581e76d2997ef0833076a1c4e469083af70ff2995f3de5a14208e87882ce2facf9cda33221ccb54e3c2968f16cd7519a53fa0618e0be9173b660b0fe3ab222b8c67f50b03b6727bda2ad449fe83f2a83f7ba3a8da09b0", "a8119af3efbdcf902686e5abe1b7decef34a426165c6c57338427ff7acf9b2da7e710b26f7f2ab7245b82462cbd1b7aca78f56ae0d3240f8dc43a18899f83a11ae433c41688166079de5b69554f004ca91ebfc4b01e3b39580fdc0753144a8178cce801715ecffaf662fefb2e337ea10424ee8754c17e1ee76d8ac2daeb73dc0f7ea8236fada613ffa55b4966b45941b3354a0c06f61dafb8df382ade2cfa69944aceeb48945d11da0124ade0f10f6c43fc9b6a8997eb84e8d778ed9e53990afc202e6319a28f609a0b21b49e206f7470d96ad125cd1335aeac905bbefe2523fb8e477569fd0d9a682c642c85821059f354446b59cbd6d3e4bb04eccb7afdf2cde4fbc57d3d84ed9601cac3e15d08ec36e50db8d3526edfa4fb4061f49aa5157ddf9c6d29d7656d2b9bd087f07779ec90a5e97f66e323f03f4ab5a91ef891bce72fbc771825f0cfbc736033ac5232a42409b45237b083becaf23cf447baceeb499c8914edcfcd3a6a7b434742bdbafcb4061aa57c232bc8e150cca10adfbdb96f6815b216b8dbc0850717d3905048b78", "b35f86d0219bb80dca8bed27d321b3fb7f5777c8f360aa04f6d9ef95d33b85bfa2916ba97422ee57950ab04048899b95b01748cb4ba628342a82c44ecb4f24b2c25480f1628b31e438143fb9e5935f42f0a9169239feeafb59397a95fe41e5048ac7d818a9dae67c0ed39c1f2e8317b74ffa1570bbb989eaa1086b07aff2d58d1dadc270cf80e53620b1a7c5c919fbb946c455a376f46c61db8c0ecbe49431176f51bfef92ade3c88fa6e8a03b88b2655b7b06520a99b366a8c059c2bb6edd87456fc7ceb2dbdebf5447b7c001c99c4043f262cb8099b4edf8c0948048b5c9294f0e667bdd211610f29335639b4d546c52c2e3d86e508f4c02dc3df081f0ea74eea9f0ffca59907512ef02da37b8fbb8361f3ca5055c9ff728d0238f35835cf02bc9478e2cf6cc312a3efe060c3bf7812dc685bb1129b0b6bf48ad28706f0bfeb602783d5b5f3200be74eaaed25e9de473c90152313665d60f5ef5f792c664e0237ee05122c4421b5ecb3119aec81097", "b25aaed0a3e9db7aa23dc8da3819ccfecda51a6439be8cf4b8172349083e11cef12598d84bb1ffd9e99c02b5c6f9153f8dc1896bbc58d4d8b0971193ea1a7d5841a0a7dde89eb49375c03689757f6576cb440fb9312b307319d1f55d290e204a90fc3c3f388889073ae6609838d678be61e42c4b8285786511ef3a37cceeebc093e733186f40a709acd84ca9eb1f093e72abd9fb3f8ec06b198d251a0695b6a41f86928deefbed6375971a9280a40ce9193069c0b89ca36e099beee564da15199caeefc7502e106589ba61ae30585c8a6b24a1652ce0868b4f0e06e6d2a1e070a26060800f1858be6de0c376cb1060950e093482b3f5cc7e5c850156a07a7cb248055a6bb6e0a173de246b2b441630d1552ed398b9e501f070d65cd5c8bffbab23ed55405b02c0c5a2240f80813d7c4c6232a3a32f67e6764ce5ee3f1dc09cccd7048a851b99f307ac294fc132f38e2d", "b0b3378d8bf704fa068b188acb932d7e52d12a49d0ab9da5c247746c0c6be83e96108f9b3e3000824f450f25d847c29798c0a705707a2cf7a8786b497459f0617cb6524e930152bcad6a6e047f1375cdd2858af4f2ed5ca9c9be201d03ff1893b9ef76d703fa079b4ca440e8ea11ccf1cf2d20180830958a2482d55c76af866bc927f235e701661d7855af4269a6880673ce228900ed09519a754b2fdbd195c6d7f0e6037d164594fe426f55e41388c7318eb534ce8c918f1b26ab7b059cb65b4c1f5713328eef33fbf5a11f29768b30108c06e6f6bc9d321a2526fbf94a63dbf5e473e4af339265d1a0f55abd297a9e6cedec4558f4dfd25bc836eb3419b02edee91d9965cf07de6e4da3a93901c61a25b2249ce28f467ce77b8e26f2782d3ef73a84317b880f18fba2d86f509efc4c"],
+    ["859ab8db1445375b46d74d61cd64a57d360242648e76614a5ff3c866edd1972336fd7c7497f6a5126c2677db9ce069a68e57a0ccf66b1eac8c59f9cc15b792f33e9e902fd9b2c41c5701722b638a86558d63ad66706f000de4c30b6642c391898970abbf44016ce93bffb945bb216d22c06b908580178b448d7c31a5ef645e86ec526debb63674e52034e01ed240ec490915180f1669ef1b004b4b88f39869394b227ad94fd2ffa6885b0f4d1bd201f35f5344fd88589f2a6208b78891b3c861e0d55ac4492cb7f3d2ba29e56f3a1057528c9f00b11c405645ba8fe4e59d39eb522b4f42db8e5a09ab019de6cf09427321b27a5d7cce86095d3f8ba94541da251a11b5091d5339a461bfcc9b345e53a356132c0d725cb536a1b3ab4f39c9a36b4087296953cce76d24f3a93303f38b92605084fffabd55a89fc644143089f95cbead96e1c8f8d04e07f2175428c45fc133a871c76e84b6e939e1988a721455b5ceefcc1b7edae200d3c9ddf572d997dc3252ff31c9bff8c9ca435e988f94263874fcb43a42066382d97834ff69c7d3415bf27a1ccf7bbbfebd466866bd4e2f55641a8b066a174c6f6d79cddeeca5654f1f965af64738397202cc9f706ceac2c3da1aa431acfb41ff035c3e822a731249", "8c3bf45a32eeba5edb5afa528799d0923d56782402dbf278df17b7fcbcb788ae8c7d36fee7328301af21cc4a0220a35499bca1de787cbc01c7068b12f146d6e6c5cf0d8217bbeddd42dd863ed4f4ec498892729a2fe22b8941d4ae144c8e4a608610aaab6319bcae443c46c66dd07d7a49cc6f61b5799fbf3bfe553f4b279c0ee2047ac2902bf5d84a5f3c7deb7de4a66a3b8eaf919433595db4fb992f2287a09f59b4b1a63e0552111c39e6205899d15aca991565c68b28e28b434ef236e465c77b89cff7aea57add1e7b56a93a51db334015ca1e6b2fa66fab2cccd64670fac59186ad3b1f82391406a927330651c9631c3e630f0e167556cb1545a303a2ee656ef03a00478f68b1d8c4d5867f90420b38886902a0ef2dbfd2c33cf9240345377a7b3c1cbd61d47c95dcccc10f091730ff515be1af704f03a46789b9a28aaa8f419322faa0293e45b77cbd1464901d27a03337c8516b962b7fb2a1f7b7249345571b64b88a525de88142525f96439e567eba62109c0f94a836895a9e1075861793fb5a1fb059435b6e59c189d36c79630aecabb6cd1ec5797a3767f9253aa19e85a1750fa8db1f20ecde983165fb33", "a6afbd806d8a5d4e29d253912a99b983f00ee7bf089536bcdc52b3c8553189b2c27c7ceb1a20f3b0a8a237eff6131ed6ac6690a72833ddfcfc12ae841de2a725f12742620e0cce8f7b21d73e269735bb8de9d0abc1dbbffd6877102fd72975c08128e8fa17198b017097fcc7e21c295698a4ac2cfc27817ba52e95d8b4d9b5f4cc9fbdda429c7ba5bb3c51ec7fa3c40025d443186fdf394572c59eaca61c290156aff44b86518fc292684aa166e6c43166ae3495a467e405865d0d042eae99d1ef1bc16166cca6692c83cea30c7a1e5c1e43ea35aeab403fdfdf76840648e41068e9ccc1c7f23710005075f96acb075b30d797eb118c10922364b12786953eb01ad9e4b6dac690467ff041f072eb72ed2784555ae4055dc53ec732665b838130543abc8b985113c2bb7a95370d964576081d84c7a8c4e19dbeb0e9844d0f563b84b5f3add3e29ec43c53c17ed0dd2eec3f8107e567c951dce625bff67258dbb85be33767c006581d0f72622852a22d7a5bed73cd9501c13f778c6ba30670f564167b4b9d6b1a3d0939694c797a88e717", "b19e7f2cbe31431513b456baf27aee8beb8e1ed3b27e08f687f19f4130db45782db7670d9c4792a8ec24b842911cf2abb4321cefdf8aad749632a42e801647162617997e2536edf7e66d11f57d1353a32732d0dd1b8a3ee5b2239818a225ee9e960d8b0c35d5ade32bc6e8ee8edc64b2ce18a176fbac84093824cf4ed846cb4aa00e36f69dc969de0e81e26a5a4c66be106275051a6367a24a24ad414eaa270f3374d85808fd7c6c4d6e2a254214e32f63a350e65780ec8ffde7400e50d9adbf369239d9c175bc1f0eeb67a6e2e1f2834b02a17d1b8debde26a516aa78bc17abb0afe4a82ba507665de32206e1005fac20d66a1a5d91c375f167deabf6735eb3ae6c96e89fd10375699a197abe5885464828bd2ab2fc50172cadbcb865604c7a4c4fe869bdc9bb74dd65777dd534e13e26e2e264dfb660a7ad931ca807d1ca948c2555578940cb920d7b3b0cd801cb410dfd35c2860f1c66b956945bf18edd3b16bfc64d9e7e6b24f1db61765a074962", "9544c0fe151c7b7e5ca6d8fbd621945bf39193e916279fc92787a725f44b01e2a98981464812679dca38f5d8bdb2babca8a37c85db77db1154e23c715d6a4fc1c7e3f1bcc9a1a1d54aa69cf5bbf477ec25250b21149d9da4a8d91f5f367c6da3aa9b69dee1a570b5fc28c0df1961a09b3e5acc24c3a06dadeb6b58c2c13bc76d0833e8def8290a28308883980e57a2062fd665e2a89648e647b1fc1d0fecd895d4c838ddb46ecc117303a5eccad570a40eb6735a888a0846f95241499587654dc66e28e7afc45ecabbd5db8bf75816a9251d63e3e9b77f0aef8ac32a9f6b980def56812f1ce21a2d12d24046928033081fc9da85d2ecc1e073d7afc7d18c52580af9ddbdf91c2dd161820a2644daa2bd4cc7209ffdafcbb1124c364f7b9777f83633c2b89206ccc665ae9ca3b63773772b9ec479eab30993da083649d48f458634c684786951fc5852d7d83e79634c73", "aa8e56525877dba347b7f46029af47728da7ebf9e55ccadaf5b9018a04f346a153d5478129a8d720baad0c16dbe5e0c492894071afd16f10faac88af39abb4387c28b6cb15247b4040a58cbc897e6b89e799ea3160a6c1168bd978670358bbac860e5716b57e6ef07821960062fc38413b2248ab2b8d3c087aec3530490d44435481e09164eaeea0f878abd82941e5e62a3113ef801d79f29afc652e378b0fa311bc79b0bcc271987a81fcb7f29916ee65a45a0dd0d2878593eefa5f917711747aaa6592013ac09286d4263843385f412c18d14ab9262fbd614313caf5c163a10278c2ef15e18f5ab7da093c1854dd8e38ceb262db9b2c84c4275b5243ef90eec1b85679ead9cacef2defe592e4d41ff0fc2fdb3fd974e39ac5a86091f8b2bf68004773b0fe05985d394551548575964"],
+    ["8e463a74f0fa830fd6f94e1f5df8e1df34b20b67cb254331db1031c68c5a9ec704bd655e1079acd76c2985f36759d4c5b9a4afb59b837aa13889e5c03251530b5f4e43ee1c8074aae8cb661009e2d6fd08ce01d90f806049c1123821e724f4ceb1dac672ef5e2b702cdfd7fb243a1d61a8e5ef076410a93b5456a5da72771aa5fbc3201b1d7b9e2c0eeb56deb98a84b72a5809454e84da8f0eb1380049afc5dc56bccc5c7d22ec032a794ee7e33ae3c914366e11ff2859888f8dd6c5782438d808ee4d6f357e35711d0e5dc2d70dfc80169962d0d039cc1aa34a6a892b80a893f3307916294f2b025bfd13803847b60e39037bea0a7861304e57b7effa5284fab25b144d3d21ef139b68c1477e13d53c4072528e65ae1a0e60f3f08dd46f6762ad0303daa490eccf28916930cdd09afd65fc303a6fd9809e5ceaa367bf751d07f3a98eac258a77345cb80dc57dad38e63a7109d167440a49e3538a0e42c0fe7b7502d114133c084aefe2470e5562fd3710d3ee4efa52365175112d72e5a8f20bf80ef310c6551aa1a108fffed04998f70f9e4e0b1ef11c25ab0336dee319d9c868699c67568c167b6d5202628aa426650f2e76d8e644a21669c3167ed786eaf13d56449d40a57fe46c6f6580fdf7fff3", "836aebeb345b2da47365bdb671a7c12ea6656b23976b3bfe239409957e13524b8d765d4a4a4433abe930711e2c478abab81161c3f4aa1366f05ffcdee9e247998e5617bc55d25e968d122de99817e8e9c0e80c8b7ef35930538d6137f9625fc48bd4ce1d70a8114f435ae513e78b693942d13e213ebbc66a69f01f1837de09f8f24bbe07bf8819598a92b51d771b64233e020ce135d8f7b1b11b863e7570d0d35f78a95a4b8b90d91311f95e69054fd23da508ae62432cded9229ba00a48a4922d051c56fdaee617010bc3d4551654780f45cdbd7876eb22288549ff17cdeb3e97b463502cfea9337d73c66e8a331d843b431e226147eeee4a4b651e4a046fe680e45f3eec2aedc2110eb954bd1a7cc132005ec8fc81937eaf79cb1084cfad7c77b960659a0b8245cc0f906d3d63925f535fd49db9ae18f633727a6db4dc9f41fcb924fe02140b16ed90c08db856e09525e7edc39b7c44afbcb5d8b4d5c7ed3772ce0b4fd52fb1dec2537f3c9a6b5ca732dd0030a810864160aff482924b29ac0a13b9309697642b307463bf02e8ec397171349683952c32776f727a2b80ec2e720c51ad6b163434b70e1e811bbf688f", "905c9bcfe7d3e5c7e3c8224563c0c4a7eb194ec55c7ba71a3bc4cf54f92cf13816255db94516cc91a23a2af8be0c10db9248578293d7dceb0788c8b930899446ee2af2083c8e7267fc5ee377fdc48f0d6bc495c5b99a0315da8da9f6f85ce1388643a84b61ee5e738680e7c9dde69c389a7713b0ede9926334e2b40f66d84822f8603820a7bedd3e6f3402836618bbbf62c0de87c7524c8d22fa87aa3c73922a1af89e54181ce7bb352bd3f9042dc8466f19128b7e6951af7552e83898112484b43b995ef56fb7837580fc8f1cae0bf51620115feced06a10dac7ffc1ce4b51eb9e346af0206ba465f902089fc0439362c8259ff6069ce1d6f11a5941573a5a18fd706ec256fac111b9011c18b91114c0d34e5ed46e9c5dbac351085af0aa9998e374e145707fb6bd922d3c7e3e8af3f43470b04f5221e18fda1a957efe6760ca87ea43de82a576515da1294a064f4fd4a10de4da8271f433c5323d5bd70397bcc3a8d36717456c8adc70a8f8872ba78333245f848b604f0d3b9f9e31b171eb292f52126006b571d73402c1e3500a545", "a1baf77a5756033b967aa1636cfaa0a97ef7a81ba4ebe951d4d0b50d178501f3932fb5aa3bba7354670de87692ac2c12b68e27caab60073653818467c1e2302aa30b810d3257ed0af1c5223939fee0c567380a46144f2363085d8d70f40a8c52b716b37e4b1771a0cf44578acff8401fc936457c03d400ecda823f9f3c0269638de0e9f8d52c60199546fac3ce05afcc3c627afc4522366ed7e2daceb9505a8ea7e9297beaf492395f1fed245abb13ff086cc479d1119aba5d46c72c03f71cdd8086214bc33b6f932fc7d6b9adf46cfb50926839422f97607056ae5993f11c0a7247a01d472f3565a468aa3d21b2b2cb5c03ee0154eaa01635b82b6e34e154873154bb46ec864de23487890b2c3ec00a57860769912db0f5bf756d620e7976cc38a727fd713862a048afaa739b998a0771efbc43a2d9acab99942cfbb06a71ec4d69f6173a9101ffaa7eaa570ed67f9067a2f47e77d63c525dd5a5e2eca6c5a5159d9d86889cdf1afbb198b0f8ed9949", "8b03997c3d1a1b9bd7a24b2692759c13119343197265a0690fa80c844009f75a3f440125a7f133eb664f7a733f190efcb5538afbf27f7bf13cf04e3bb18f79f386304a669bf53be9e73c2ba3774b149c84a279e4a4fc5b76ee619734570c7a7db42bb4513ec724f7ed1c13542e3ae9010f09cc967dcf06a2b250647b476d344db57d9de3495129fbac499807d81b18fc33632ad39d85ec9629117a066fd40e97e2975a3abdd8948e9803698d37a880100dc11afaaffdd2cddbd27bc8a62e7e9893aab7443b1b7297e50ee10874b9c2815f42c2d4aeadc73e2496f930fe724579ec89b0799e5dc3caf3543414d6b1e4cb654aa94dc7de9f7c598b8d058dd63daffd88576e28024fc8e562f87d3abc79513a5688bc08d6c7da46a9c8341b6262eaee6e30324ecb375a80020b4e0d86ba6d1c854101bb63e33d3db061114b0cd8dbc46de35f7b70e05abce74f523217c751", "84f9d33bba03f4d3362941e942de2da63c7e3f13d2df013b93c7a3a007c5ee1370a95989f61f126c4afbd21a81f690eea1c6795208bfca67ee4dd00b248ac9d43b896e00d3a1bd815244fe9488e849a7ab17996265fefa09e1272a0bb79e75ed92659a759e9c5f5e6745e01ebfa37c595123ed8366b198ed6128a3bfe2012a7496c6c2424d7e5c93176a56b2d2d4dff4695bce2403f9077310a3b0a4842484a4df168a4261872aea0c5f8a302179ef9b5651b16247b50a4d8111c81712d9e53591be28864ec70972444beb27433363642787fb29f2db83f60a066e587a3484025f771dd80053e56d71b347cc67b20f9d39d4f8519449b9f47c32e04305e06c519e41a0318e387afddba36d3ba9c067884d50e7a0907031094367002ab38d8bdd882bde239d05ad29a8a90682081c9ec9"],
+    ["8686c30b604dd37ade1184b2c8b194a55cb936f1bfa2cecd916b6143f44484ba41c08ad8b40d830f2fbfc99c170ad7d692df346c0b70d61527937e353558e93f9f4d7dbbd60edc841e314f2f07598b6cd3c97039aac7de30b5a88c3697c31e95a2e2c0bed22b814b8d5994ed7681b16984a4da602f49af4fbcff3f09fd3d4c271b93f54043dba6773301ef457e72e9c907dc8305aa3d89d2956318e94c1c27054bdf7e59e353672cce46e59bcf7d76aa173895d3c85ee3eb3845c897263946a9483428a2243f0ea75308eb9fc4447a6901fb54e61e89558fa9bd29a7071a374759bf4e635555a0a05cd91f1cbb838b6d0acca0e1be94230012e40168c3c1a09358e86cbc74fb1fce11e3aca8079e2906044654837c08a2f6ad7afd992131c7a4b936c9db60d19cf3156a3e094ad9736b2740fde4146e8b1a0d92e2363983aa36f0cea17f112100508ed670a3218c7c0a1858dd6043ada8a55fcad98265f6b1ea3457de864bb6981911a42cb88b5f4825688a154a945bbd10ebe056f95d915b0341c9aaf59817223a450188f016f9e1321c9a9800334ed34d3fbd62763c5fd4355f169633a33183e02be1e55066447a464e10edbfa47cfe08ae6e694804da1df2c8cd5a6ef2ddec63e11c980014d4815c", "845939eb59ad3f5d0ff2639b2b7979d91c36e2825e002567572299463228ebeca918d595dffbb49aad1644f75c0426a9b7a9051ccc59d40f46d6feaac215a2124c57775fd35351de0a70bd4f364c960186e8e125c621574b09bb3cc15ed68c2eaadb3587eaa801aff851b747ab604a3a01ae171f5be0fed56be1850995181ea0895191c950a2d9bcf1c933a371781a47664c4c1092139797624575eeaceb0d776aac457666210e45d20a71cd2f4f29c06320fedf6f336a0c578a0ec663ed0b1c9a597a0794bc6d6859b10436c16aef1e49ddeb5f59a8d4cb4ef4d957c8152f16b945fe0458205fe6108e8ca77045c1d762dbd360a20dfb6c61cb2a9bceb12f49f7403d6e1f97ffe4b3c862c6aa3aa5c5110bd7c92065455515054c1c6f906fd486d020241618659f82e50af53a9141da18d3d062d5e98516c19ba5457561d0072aefaba1ab6c261024cdfb03eef9effb30334146f8137b28a8f649f0f6b0b6c2291144a1701ece090a2b1c85c5d2d8162070c3a82fe2807d88104d409b8e8f2e29eebcee195c2035fb13e83252437adb0abbca236c5aa07f7a47696ac93567ec71f8820b4367da56dd8e182967ade1a8", "b4185a561205d7335a6af7987b59b8966fad8c9b65cfdf9d11ecebae79fb8a0bb9777bdbc833861a6ace69fb736c0010a5537b151208d2f3090fbb537416c31b93cf8bff1ab51090ef405fd3f4d19b5254e7f3e5fb5a67027466c57f6cf7a2cdaad3ce59c44b38e3d8a94c867c273fe2607f79d06d31b5b8b96b78a74d87b77a4d01d74a56bc37d31eed29275a88710f4c077e0fcb6a23f6c8ecff59063bf3fa932a7a269da0dbc26795ff65e768cd173b9877b9e665a11956e8d6e80d826d2a6e940bd904949fd21807c80b7aaf55f50fb6c137500eec60f8c8017224ce5a14f3d771ad8a4007f47a7c18fe3ccd16f3212408fab613b890539004b7ad90cc844eb4d16928d9f15ded850d46b1d47515634be2049cbafe56f9ea84ec32aef9e2b6699d44c17039b4c8d442621759f2ff07978410f43ca274ad45fc4edf02b2db0b7ac694a6911a8d50774674bbf560811be3811ac7cf23487f17b313d9cac84ce500b7fe1dcd23641e3b79ae30af7dc06b298f9364c8736da749f8aeff42d380c9ef59fd507acc0e94e9189191dc7b27", "ae63f86d750f370c0fd65dbade0b7a9bb1107204f3a82b50684ccba59bda94b16ba06c80f753f17b75825a957ef1b58091999b26b8caaf94033e9229cbe1bea9cef212de431932725b9a371c74c06a7fc24b4f91dcae39cd0a62fac4c0598e38a560645b0145c60f58f772aab2a1a1afecb167c5f729d4bec5eda61454fc3c648b0e40ac99e8707ec327584508b3e0a40c6364b04e2c90ab93d102e70e5d0df0880bc7f69c56021aefe6083395f78ac6534dbcc348e61d2fa0c62597e2b52c7745b4a3b889c3fe1ea93d10d0638c1ce62ea0e3e28fef470c4fdf038858d52acb0f2d7b3dbb28bf0e628a753f228901f303e947673afd8e3df3d790ee8fbcc5213871cee122f60a9c83d76ec0321056be10ea328c97075f567a56ecb2649f9cd53dee6f3eb566fb8e9b0f73e904de43762afeada417289d8ca4929312e0e0aa10d79fb216ff17bb182b3d46043c59824c4c072fd99ab1dfc3f2281c2fb4aa2ecc8f2db9b96a49b8cc9a3a6883d5098367", "b7e0bf681b9c6340bcf5427187e484a68e26436b79fbbf677f02e1d60734079110285f9709009238a5acb92fde55d0aa803a5ce6c0f867f1f90d3489e819adf108e87e760056162ceb8b2dbf82ea8615d39a2d40371324eb95adc51134fbfab997ac3b05bca76fe6fbf064c49a344f106f319458df30f2381318b3318d80f95e65eb43bf0d5be4789d3170e0d18f86ab40aa740c91195655a52f03902f3efe85aacf5984d260536d22772887a469ca1d6070e6899d726ccc18bf0128c9c3e18e2db48da9c83dc8cc47fdc20949d6e5c53ba640f7f6ec486df52a5d988318a176feb9064bd7de40b6200aaa0008586aef366a7787a4f4ff66014afb022a19e99474beabcf373b32af5545c86d6279deba4b751d9b6aafb71b6349e5220a5503dc5186bc97b93d0a3d06ada94fff17d0150b9c57910b1b098cba2a9aeb34671195e52785acc0b5bd7bc30d22b95d282078", "b53ff081274b3aa390ee3111987f014faeede0cd28f7d31f12808d7c1ce5617624f39bdfde855f4e056d905561a21ae7a49d86cf1399cace97ccfe8ac0c420df4e614380d7ef5fce391e577e545186175e5c1f7a1cf16884305123ae1bb698e38d72925941e6612853d1613aa15046b0f176c454e8af0a5a55aa729e55c5b2328c51ad5b7d5b4f7d41452de06b67c27810c14f8882943af63e7e3f2ae62339fcaa846e180ed7051a2678d2f66638a26251fa1d96b083140c58f5518d05d80d643bfab663558b60904da866a65815e10e1c6e83bb9d1a9f1ced749658019b1cc1931bd2ee4f9064a9fff6dfbf50b2bef10d3b6abdd96457fdf2361e5f6a05477fae0fab4175c1f7e03209c72f2f9d8ea856866a285320fce1982aef51b1685abf657551c8de397811041613523a090973"],
+    ["97e481662d4e408e389209bc65a62e759c67acd496d45670a6f969e939540ee2955272c6cb64e41b27a4207438c0f34fb6eaa4f95c9d8ad0c8bee84020f412f953988fbeaafae520a39d7bc9680505b59be556c5cb7b9e3294e5474b26fc124080ba16b1aed28793efdff5d40a276a227bf818cb0d8485a5a0ea17c41085ab17fe0c987bda2c5f1f28d21242ab903cab53b868bdf8d115bb97b196632accf35db3b31f2b98d422bde5e929ce42aa34da05d02c454b69026db230da46e4a32978ab030cb4f8e86d519ea5604daea1b48a1a733303a634016b2f9d9c9ac6d585c9a50423062a363da88c26ac32bb3025601cfd499a1da637e98802db6ad0e94e07f065f55af7293cf7959d3f327544eddf3820719c5bf86bf42e67ad8499f116ee42aa9b5af82f784870c34e6f6774e39e0ab94fce65ef8431190e1f845de8b717e99ab8835f7526f5a0a0143fc893d3c95da9cfa47cc11d0970f4985b6a8247589087f0b795e0bbdba780211aec0b15f7031cbff4acdca349cba560fd6b9da8962454a8cee7e2baa71337e86f6e65832e61b08c6c3b79b2a918a1afd5975beffce9f1e09575eb78f78cdc2f510b226d411410c474e1514b746435f906e7d527ea87473b2a548bb98fcc1e0cc31667fdb2", "a2050185ca0fb98fe906529f90d32081d870b420a2dc8a357eda137e7b8009af212ff95f7518909aa5417796ba7027aaa24902986dd120e1934e68d05fa55d07dee517c478c7c60dde335a065924828a90be2723c75ead505e03d2dfff6656b784b32e26dd5024c0398d202d7446f2dbf1cd896a52de82ee2e39e13726541ace0acb1145de1becc998dc07fbca4415cf54d98fbfa5409a298941298613a975bf9262abc2873c808d0ecf9057ceff5c862fa9fe149300f9f77995792397836773e83d65367abdc3662402908376899b263edcb38faaad4f7ab07529ebe539bb8fb44ed3ddf11f4a7d25284680ab9bdd01052bb60423fe4242aaaf115f4314174b9321678d23339eb58328786787f6f4022a5f9390a787a8b088c68b2818a8000922df2976c5108e2e2ea3959c5195ca8f53f5236ccb2a4f977f695e7e8f958ee4c6e9f34dec6b54dc480a27aae21f0ec216cf3380fc5669a51ced1fc0c73841a792ce9e2a793774b4b4f833dd42e3f0036c6a0f143850afbd38e3954c8074252bb0cf59769df20504eec21459be5aaa1618fa06b23ee59ed2e2f3a93489f6e7333f37b10fabd3111bde2d8b993073656b", "b40b6c1604f9357f16bf6866daa93982628ad4573aabf98dc0d41805f7d39cda1b0c07b6ab2f9351dea01ab04d16b17793296c5149e43f1390d511c4e1579898ebe53eec0ede3aa3757e7156216d985b7445657a8c4af70b96b9a406f4c4d1f282b80d96d01aa24d855483603b43649e69e89d12cf8091b2ab96c42bb5e751d7afe987d75a3039da2c7fb1e40ee7911f24d31693b301370fbace31e6a4315d4c3075a50a3b0c1b58a302a6a86054af611c3523d4d69431e6f3ab0ac835a36fb577f0c2ad44cdc25e031f468e79852e62257e38075c0562c1edafb7ef02b2065b39ed99b943048c1d6944113213ca80303d02bc9268b3faa47c089355950d029b9f09bb04dbe7eb6cf94a14b3109db0b44318ebd8f4e377e1b1a4e7655b6c70532ad0565a987190f612c907e3c7a653262fb498fb0865591b1b1e6f27d6e38e40a8335a32c2a4ee0c173ad1e438c6f90b4f4f5874280c356dda17e48ed693e18513a4091f4b443abe0fc00fb5600ceee66359fb7c24401d9eac04eb9baa0b21e5d4cd9d49250e490b6dac50118ce94690", "8f5edeae0fdb6b1d86195d46b115b444a945110300a46af205a3bc2370e22fcb5f8244b9b6f06d21915dae6d4f6f71dfab76eac58635fe1aac3802bc1dab560ebcb349a7cc9826876ce7c9297d6af1462f08c49b4db3f32342413b1148ae44c193f48c86dc4758cc29343bfa5dc79bcd4480bd2b45d4dea8379cf881a2d72a57f47292f1cc12240a55cc05d2ca86c8af03db5dca33d690413b9d999fc2d4a843f7a05097f4e10752039d30e3d59666922bf49265836d894bfdb4cecc7bf261f945fde5d8787d226a7ee7aff9acceb4ff73b2c7d908593ea08f85a4ff79c1aad6a2e018b90903b1402f3be6503c3ffd997235095d8f320a76ac679521e7077efd5d24ada19d8074bf892ce555a2c0ac6d16db3a11ac61db1524ab61e693aa9f3425a61c500cdba9e526a6f9385a5da8ef21b99859a4249f491ac2b0f8d625d97c6ae512e0272f37f1a7fda5ea2f7bd04e6ae3fbce09c0bdcceec111563678c1eb86638d3eaf1c0738825807d5802a6a22", "a0cfd7b2376d65ab6d1aee0e5e4e0c74e22d577b14ad0c279a09b4b0184a73b71fdf70bfb942d0fbc6fd7015aad16d7c973cd5f9769c24c048bed04455e796d7313c3a342a3acddcb7f7d2f62d21abb70136fd7e77e518397a7ae07ffba49b8db6325fcee227bf31bbb9e1e2bacac46d7279f618c6376e470b3b09ddf3c9280ea219a0665ed6dda3ec41032f1aaf9e6c1503c8d34286bc8e59b6d351317cee1a7cbb4a6db0e200f3a1cbd3ea05e9852c3fef6f713aaa9fcce943033d98637bde31dac1e5d6190eae451b5c20fd21469f28c1b5d7ef2b2eeaf8fffdd47b93482d0ddf99ece555cd628fc949dec4d7bfff6aeac685b09e93b56173d1c760bc9449ecfdb9d94c18782a6ec0b60ad6020e353f37f1c183e4dc47cfa4cf68300014fb38ccd2a684a1e9f42a05baafb28bb7422c99fe23f46775b8acc2a21f0faa8419ea4c9dea53a9bd3cc7bfe1fef8dafdca", "af67e733f0096b8a560348d9e619f8fc4671977e39ba500c01bd35f07dba9f6130f10b6f751655137f3a6ce9837ff9a7882b7c4a280412423702af0dad7203fa33c1545f660327fdf4416c0e35e7b08fc97b6f4288c3961b3455337e190b8a5ba6e69a0d4147f770863244b24a4f701182f537d86eab72298bc518b856d2825b9a15812f968c516df6f58c46e0aafcb37068b63eabfd56fee2d788c6e142565ab1e1e0d8a167b8cbe2217683e5feda4e17d6d63b0ff3271f137257fa7537e06a7fd0538dc89b8a89466c5e6dc0d8559573a372407242e13c892d042001994a0cbd333e6ddca333532a84add6fe1eda4b4a4e624da5cb9935c698b5e3751d7b4b8ca0f2ae7e91ebc899c8908ee69dccce165ae68803acbb150a9d33ffd645593e914852d2d4f679a7ff484f60011a23eb"],
+    ["b8b01ffad0c984f2e03171480b1ef5f6bc539bc3d731617062b8dad6de6ed8cf99321db72446ab13a8829860f9d84188a7a76e704b3e00d358abec6d03ec7da07e04c681e2ddc4a20d78e5b4c4d795247c5fe9cb876955c31e2282edfb04f067a6b6281d92f9f3f16108c88edf33e829cfd1a0ab60fa92d21e0a7d5d197d1551f5fcae5e0493be242d527228a60f69fb42f002bbd1b683961ce565fb8134fc35ff1ddde9c6e52ac0dfebb10613d61f7e567b4494b96798aefb604544c88557402a36d728023ec169865f1e8c9451af524bba75d6d0d42d2f0c949ef9089b7ff98abb8a68107e25f7363e76e62ee7e73a56d32493461a2cf514dfba062172a3db8cfb8dd95318bca9d928fdbe3423688264d282bd7366ee0529c5eb0f613b14c4a3e8ca227ba8db23967cf1087ea3058155529ac89bd9905375640f373c33512b4cad3947e1951c58c63361748880e39214475fdb9a496520fd42252ad348a8f1026e4c1dea14fdbd4b51bb066425f98e5bbee1bbd517807d5ca6fa567d9c2760589da4e548dfc002b312b9bde1e4a368179a4f1a9df7b49a8347dd8a21aca0fb660d4bd8a9061f3f29433bc1843a6a5c3e254a217b3a0d647aa7c990294e8e679e8f34a5994bdebf1c1b288bb34db326", "a46fdcd0a09c417cacb913e5a1b8dcf9d4144d2344a74cfe07037dbf88bd1dd47a05397a15da3183088e22636e94dd90863f5738fdead552604cd98c2ad9e6411c6b97030f74aaf0b0420d6d99218802432db3f605c52da6398380dd6a9b2f87b6391ad244c86c0b244e86768c42c53263db6d5573084d4ec19a6819e8aa12bb05400576a628c20549320d9fa2f9055370ca12e9ae9179ec17820a1eb9c634f5d9c8ca7e05b1b1d343e770ab5a4a6bea43cc2c8d21d45728089d98a6317e4c15a1f034e5a66437769f90009ae83f1fd1339c42dda60b57e3af2c4bac2e4905ef8400c217751ba1a12f1afec1cc1511805ee1d65e2d9bce9fe889c7750b78ec0a54a3e26392328fb59aae1118d1edd60157dfefe321c1a715aaaf00d60da3751770298e2e56fe906b846279fdc434f383668dbfe684686c248882802a90f8cba0c94b71abcbbc511b190b6a473b96112d0d9019bc0dbe31ef28b37c1e0e8c203f340a94c1f147bd64a6dd524dd313e5ae0e76deb1f4ec97e4986ed90cfab6ac0a008b34d01cec34453793f4e1ac697a6749b316cd159127326a3726abac73b74172de3b4f6eedad6877f1434064213981", "a5b4e87931d0a668670e6bbb1d9690e9f27e86e26b638bc12306fbd1fca4e846a25d0c7c3267f0faba2df63ccf98663fafd60dbff783175dba35f3d907a9fa1271294db7ab6d36792938ed99568a2a2666126a3508332de8a1c064d55ae74e35ba0007736c8c0d1ad4ccacf8b5c86b1de98942f2b9474a27b4979e31fa7c598ec0b186dc8cd4078fdd30693e32067a9a46b58f886a948c52813b2e83ca84d659e73fff04ad0cae0c870c5f2beea9708c37e0dc4b370f24f5db56cb6175349557463853c4c7dd11ccabbed9f578e33d6470bcd57a346f9fb95f7c9524d186658babfefd9ab94e139933be459e5b4ce3f514e652ca3e65be052f6ab681281da74be976c737f66ec5e7486207efd13e03bc0c20aa26842d345c83255aa319a780805fdb417e73d1aa5bf2647448173fd97566984da2be2890728022d936129c5e7b07ec2eea2c53fcf3e92b5de9bc26f3930744c21e1f4b63485c9af1cebdd729e10cbad0f1703894416f3a0ec88491e7ac1ec1ef206a619f2da880bc136c1e27d79a578682d3028e2441a0b2002e6a0684", "857b723b96e2bba107a981a3759d79df5f485f067c814f5658ca8fe1afc94043412e8253854eab3f6a6ce2ec95fd8226a051abda75768b917aad9a787bf0fe3a40ef094a5d9f82ec1809783cfb9e8f02cddb91120b5818f2246263a2a69e6fdab85f0c67d8dfad01c5303b87f22a8c80398a7c40ba29f5882b6b0fe827b9d8b9a3285fa124257e55edf4a64900ec7b116569c029553f43e2316fd6098fe49bc38eb7213521b71af4660f9a22db54f4b95d169d2e00c57ae75360b1653878bc7e34aee0e6745f08f83f81ba7a9d71415d3acf422abcabab416c056e6c9261124893dfe91acad235c88748f3d91c16c1d60cbc05e607ddc7633526efcb2721c5364b8b47e93903517ad29f0f5366257dba3835b86e9a72098b64f04a06bed62894358b704f53ee1c55d0eccc5f85c75b7a03f011ff2d6a1d2d5120e0dcefda6c25f3d973ee33dc2bda542d7fe88b9093c54bf36a2346df169f2594d8aad333d4066c2b6950b11ee81b3c1665f7eaca328e", "a92fe1c77eacad2e3cac29ac915edb3a0b6fad154684429c7df0cd7e19e6bbece01a7b20d2d6d58cc670642e607f1274a51e70cb402aa0120483520524d3533f7536746b97a523b6cdbeb163ca2c03deb840a70b07732f19dc3f543f5e27d859b81c691c5812dc8321d6ffe39047ee0ca11eee6fab17659dcb6d6302339234e84c155759f98a89ff9edd180b0941114d211db1ea346d7edf331d571897c4475204774fc9b89eb1b4d7ea576c7557c19720c5389bfe83b4b2f05f853e9e91e9b72f95e58fa2f13fdd0ea2d3cb049ee3d72f32ba8f67bbf4a8b0ca8745cf6544e3e21aa45810c1834e9b41d1e8fc7fa0451e80de0c9de86839088bb3aec28a82bcc8b4bac6bdb2e5417ef7fbe1fe4da1e95a397f5514b5e2158ba7b0b65839387d99061797e535356cd63933ff3730d3b35e89f083cd247196fb29b61179484816c1f200a45bd8a91be1517c0a1426c99d", "8c7ced824462793bfacdb97f0287477998efee4184985d50ebed005aae2d7395b32866f93eb4f044d1d764c575db8ddeb4827a548ab60e106d3112ece38dc086224d31367430fbb8bfe9c33f437929dd32095c6d6c7b6e536a2215ae7324b0688b35d71308329b72a7f572a96e5745a3e65348ea2e4297f1d9d25b6befca481f44a680fd11cfbc352298c4620954dddb4fda9cd41eb71fca8eb872be9cad98d6b39a6fbe3ca151f8cd63a3d37f360dc441bbec6cebfe4fc9fdbaad8f76bdecacedc08d3083d100c3007674570bcfef045108faa00fcc7561779a97e5552225f4edacac871e0169a57a439b99ec13a9290ec3d1db18010695ce9c5a7507ddf6958e809196d71ae61e3f8339ad945d85aa37288ef8b66caf6ffc74d397e204162a0150a2d93bcf8a871accc7be16e073dc"],
+];
+
+// Expected draft-06 sha256 proof fixtures using MockRng.
+// These were generated with this implementation.
+const EXPECTED_PROOFS_SHA256_DRAFT_06: [[&str; 6]; 7] = [
+    ["88bc0f6a6e622aa7617dbca57871ce6c3cfe4dc79899683425d8a6a7c85b76156406ba0e594636033130aaf0a924e8f8a026be804f0f9bd3b35dfc6a5c05cf4ef183565bf05cf5c315a68164eb49135361ad03431c1005b5575ed9b9526f2be1b6afdde2506b8acbcb894ab46e24b88dc46b7a41a581c4b0d0f783fa577d898e766f4281f1e9e59b1d3f5f6bf2dccbe055fca6dae87b5a3a86c73c3942e7dbe8683cd5593c1b9fe3b51713756b01a1d82838fd2e8b7e456f5393e0c1cba670c93d2a42377d8a68f23858e7c167afa60b439382c0cb0cc3733636371f9c40239dae6f56bf8a922db9a3467316d62185d04c54af927620bde26aebd3fdaa6f825f660f805ffc3f45ff86b81082371965600ec0fb3e0dc93135d53e91f029f5033f25dc03fc1190f22a33aa2aae7aefa35b039abef7fd66695719df584fe44156b626166a5f75afb2a163027e487645966523a308e88b07359da0b5331a62cba47032e4b24b59989eb13739dcd672e606e253bc895c232d217e33e5ea187c0f94532d3ec43f5f618f74def2b04e66441e2b13bdc368706b1d4cb62ccba3e68769ccaba7c661d1a01309788ff3c9a42387494975be1a763d84e39e9a734da6a6b9020a3bd76034dc7bf27bdbc01386fbc24e", "8f411144ff7aab94eadb972c64ed25d78b6edc9940b861361767f1f19984d891594bb92c5b8706396342982913117d2bb58e6c2f248d21ae7711f56aa516b4a29727b3329135e8e459f6e1e50e0b53b8185407f2203fae827ca3c38b6e2579388d0976f83ece28ea08004334d7a5e37fbfaa55051c56634b2b537a91645badffb4c7923b4889d30974635851e58b2d3d3e18270b8ddf1aa4116e60209384a83c05c9e69ce763cc8b5d0317f36ba9813b0dfd5b1550edb7cda14613ea2d665656c195a72a2e05b8d66556b6d318fdd14c5f8da0fe40a636e006f670afa5f69d6990fbb8d677db641b26ac639f5ca49f2139795b30cce02e0d791db84e219430906837654ae01b12bf8fe34379c4c00d7d1b36ed608f1a3c208abfce818fadaf23c5a4927a142d2f366da811ede26c005f14a0c33226da897a721dffbdf5c157bf03c20e608f910d77ab14bf1f5ce265dc1e5165d9828375078bb27faa39515bfc615f2bd3a1944827bee137838139de6c3d33fc94d16562c7a87e469753567a66a17619bb20a09f3f17af3b69db4020911c360e73f93b7c6641105fbbbb8639e1bcdbb6568d83e21d2e10466e76230f36", "b12b3a42804d57cdc39a6b0fdd355b990408ba1a59f9fca9f400294545f7e328ce5394b426459b55037115de3df33500b87091b22e879035329df05d06b7a98ce53e846aeff5420c93b731388199068c8611c8582022811ac4901df9de6d6727b48b3142b75731565993d151af05118957e511bfdd4644501f1b30c61ffdc0abb1335e03ba45c5cdc2a9af5faab2bb6b4bc8f9edc5748c4466900ff5dea967795169a778aef05fd409ba24f19447963b3c974adc2277afeaebbd8c4d0348a1d9c46beea056921df11409d3a20849b5fe11ffbc5a1761fc42b68b39917aba8e37c3b6c838d7a56e9f9942e15f591041e04d2c342a704cb9d02a57a53e3b8974967bc9c778efa4fc96fc0e7fe067652857274af0281d2a4356bd3f87ad29f72a8d0ef7e12cb98a534555fdb6820573a70149a1a9376863a7f49de4ec1012dbee64322e9184ec5907450c65d5a88211fbe554251b3549be1459638d8e9a655624fb96dbe5a288fe5fdec2e10d935325a8bb28b6e64069685ff8b8dca8c0095cfa9e547676a4a27f09dbd36f228e147e95f2", "8c02f1bba6254fc30d71f1f82beef10cf937e5bf46a6c3b99fcdaacbe662625f68372a9ce0a29601ea266c269f75467582d25dff75fe6f3bc0da3c4184db8a130eee3be61c1b419ee5dc2d1686739966c93ebb9cda053db974ac88822509f672b18f8e6d5a07ec6f4519959ed67ddafa4c9655beb798f87bb301f6e1835712608a7aef15b27a75613d7eaf97b95118f11a5749e6046bd9cf3b7413a49960c6175624bbb35abfa61ac1a3509cfcdc5d80655f23fdb12d71249e390e3bd620c41eaf869dd5e878354478b1e57df6f92d3b1501109d1d2faaff71e920f9574787f35943fe762c4c908150e1373442bd1c7521011ae743ceb9fc783153563aea20e03c15ed1aac4c1e37ee458f6f0b7b1900459e8832c37f91ce96599f74af6535bd439b21ddea116aeafeb7f9c7bc15cea1695122d41c150d4748f353fc23464d69c67162469c4819bb9c46498f6828e6d52f3aaa7c013bdf370000078d6135ab9373be444bba393ca31f77e855d1005dca", "81acae44f9afd2cddff686d11983a3be88afcd8fb5046266d801bd9b293ab511b4f42d306f0653fdea9098d1e7702ce6a42cc1581101b703796f5d324937c1dff04090f66c0fa0bf484ddeb16e31261b6081ece430368370e539122a058cf6d9b1a955c1c510c927a9c9f397ee79ae5c8ac375743f15e79ac87d3eea33c60abdb230c3ddfda791f322bb319bc9976af662929bb9cc0a3fd1846189a9302ba66d64704fd1af7f720902b59002447d770f10ccb3c61b9a73c5b18e523caf2e19fd6a7e2da42c73ec34f52abb91bae5108003f38bfefe24a1b0152bb939b9f09a1bdc326ff44b18cbea346951323ba2b1d36f9fa5216ac16802b7f71f25477c22465d2a0ff9e26a1b8d68112636102f5cf328d4553ac5992a03d6c482ea52ccaab6761d9a65067e7b7c360c45bb82496f3f623fc2a03d2bbaba6b57b569a607f9e30399f4c7a0f65b7f0fd49a5d690b8d60", "858ca3a59e99d19bb1e71ebe3c741394ba2eaec1894a785ca6454c292cb5d4b31396141f03779bf1d7687080baaf4e95a4df60b7a1f464ca7bda6dedf9f292c0050dac9f101e382d1fecbabb76315efa69706bac400dc9fe51746c5df216c8aab90a1ce35e0878df83b11651e9bdf28329786c4d7ef1fcbb7dce25996d9135eb3bb3cede2303c0c611ec61f5e4795f291660c49057a656d56015e6b64a5593b043f41ec6cc654b7987d56b89649be2d3016279773e6f0640be32097d0f7eeccc0eea061b9079ca4c7c7ba1b8d5a3073f2e5c270c7e0ed5d6823df9c55f75be3b2aee4ea660c3a4d0d236223c3ae2d9eb0a6191d40345e3ac558906e152c69097c0f88cc21cacb18804ce47a9df07a69838494d178cd19d73bf078dbfc02f0718a1aad608667c6914fc5a50cf43cf74b5"],
+    ["86118c89593462c90f46f9ebdba17b5eab8a06556e99680c63807f8ecd9872fc225ba6fc7abf86fd3ed30daf7d8566658a0830b11b29538ea61b7a7dbfc6e0bed999937b237d3450c22dc3784b65e010b62f3f553f4b3b5db1e7d5699941927b868292da9aadb21f32ab127eea06b380f7e4b72ca83aa13f961098e33fe1fbf09ff52611d108b6eba87a54b1cd1ec0dc2562010fe0773f408316cc6c24e02938db9212b01c3ec3ee00e2e8cb6f60c5d05c24034a97bdd96f74cf922ee42915216b9d1bbf4f52942b0476127e45725cf64d0a48dfac936046c4d588f321fe73cee589f3e8e7bb2253f0b1b8d2dffd47bd4e0d090886219c859ccdb88026431cd57e36e7fc439dcf58b20ebb95be4b1cd96e12bb4b7180231319e2152a601d205a2a35a40429397007963fd86df6fa46085bdded3b30406b1d5794b09fb9678902bd73dbdfcdbb2c84103b9ad2bed7efdf3120a205b95f383bbabe251a1614e6f24a1de4e1f60ea08b1f75f682c3803ac1447e4fad34079d14a878cddc70fc7c21bbf4dab4b7e90c9000c34e6b40cb51bc4ba29ad0553bf7b157da8b6832171ab6b8f6127fd2bc8303ffde0b2fd19ea74a3b1270f4cc542e68e2348884ded11de3a1f65a60d3727855714a548b5051d029", "8e105e35cfb8375218af99b32c5591eddc1e84ba9e7c43d39fcea27dd54faff0f71d8e981c03534fb277cca16f00d1008d1f65d7864ce849fea5017b21a43b9559c9a5ea5e8c8f63389d6006ed290324a142924f9ec6d39bd9487c310e5f019f941dcf72f145b6d0d5cfb2dffadc6bcf388702f7cdd3530fcddf06a48829f38dc729562e55c1b58abe2215f418aa67a83225aa2edbe5d615312229c96c8c205081d484127f35ce7348935ad9dd20e663583ec62562fc3ed809c26b3ab675e052f0c024a4d7c5d3df3011323691c57f5724746db4013c038f894da017a2418b2c9aa8e3956063ca064dd4ae5481368bf0685f13ec85ec59ed2e77f99e38b37465e47ede113025b7b2bc2e41fc9330ece948fbb18c4bdfb2b5ba268d88a73458e86e803e3e93b282e72b26f7d0a76e725f52656c1d0b489912891f0194467be5a364ee7c9123ba9988e0bc954ecca4fb1d63a662a4a73a75f92ef236751ab985e0b41909a5575cb99240332afda3376e2251ba038731dfecfdafdf567c4f2aebac3b8c38cbabf805cde7eeeed5cdbd4a721d5be53c22aa7748c7c7c8f17c4cb6ec39f487ffe1444d9fbcff82b36936b393", "a11296b61dc05234d2e6d4b53899fc28a4df8281e9bc87683e962d777aec3f26a112837a46d285046a42966cac894f6186cf30b3b6f78805279155b5b9a3cbf83f0a6dc7e580276201050064bdf28cfe575904cc5cc338735720c9ec0bf35cd0b5fe683138bdc1f398026a4e2f77bd0fee350664b31fdf02d849262d37aa24d4a85af6d293c2220675ffea67e3a48d9c4c1fd4dfc1c400e7ee875c77acffb51e7c7e980463595733b387d7306adc2cbf063d51837e118de2e36d4d166c3afea99af04a34604ded4c21917d4467abedca573bfcc7d398f7ee7ce2e42b8b2201a8dec55c62f1e9831b6d752cbd1bcbabd8687b7c34aaa8704e9b2bd2d24c57b82632115d8f3f01c418122cd66b1dba03f13177cb02b3bcaef6a4ddf111c06426b562bcfd93c65c27b10c9e6ffcace4bb2f4cf6c829badd85655c70b4e4e975702674f10bfec561ac13547808f4dd2d0b6664b140144671a5a76ad8dd951edb69bbac645e7220fcd0714ad4c4b764df6d3e3793a210bb35718cc9a47c3d4b7aa4e873fd84891e57052f9b3d4ff27c0a5de8", "86b57e290159e7d49505d7c7b4334e11edc4b4acc75e414b0793644d3b3eea36fb9c4061a8a82ee4e4054987237e82efab8dca44b8911e7519317fadce8396eed5b6e6a42a0659adeb2248f2886398270941c3b313797ece96c84b6feb532cd6b8b2ead294ca8bc83c3d04fce10e830bbe93e71f699eb2a0bc64cda362d7c8230d5e1576901b75393803925af1343fa406db51bc90300f8328309a0851aacb6320ea056bccedf81d65cd0f0274470db9419098c8037a9e75b053d6f3aeead7422daf49f372ee64d7ba69a9b87f978a055caa865a6caf75c223cef73afd43c89aff4c6a3f53b3a6fad4ed59ca0c71129663d52aa0257bd5b7a21d3ed598288540f92267cf055d322c9123235376fc2fdc66956b42329e48689109611fa1e16d087b9c4b13438f40f8275e6f2e225298606e8c36f935e1b0b707f19cc1dabcff429e1c4a7400ccdb142d694442acbdfb04324e5598666f550d940bed882748b002a3d9a766030ba2356b3af6f46d0063a4", "b2615b48f345dc264caca28c6c72c6d5ee53101641f06f6efd94d9d2e09626c53551dfa78b22394bdc2ee91dcfd217d98dcfa4061c074be3d6e08af444ef604f3a86d6955464d70d003819edc26adb7141f7fbe3ccb4419a83d7ed98d2bb57ec9196a7a59418ffa495015647a7a0917a0d2274047b1f31ead2d761a10ca5e9c0f60038999d6d1cc0930f4d4a79cc9a4643d64e87f001a7ad759677d0bb692962fe4a4540425538ca4cc344c9bdbc045b44ca4127cc80ef962781749b08638cf5a240a8e2de7d5749bdbf48073b3ff2313ef41076c66de3537a35cf950803a331171c588c71388eed5be6cff8e9b2939860db1a4c8272343db09a6a3dfb5d4dfd27d8634096e572ae28f66acad207c7b21effc1c40aad9d49fa2c0337e7162c15116d586674919ec61ff48e22d689749731d678edc8dfef1c0dff9b81e1702295e20c6f2be0279603b700da3213c1652e", "9765f29a89fa098aabd6bc4744ab847ad225763f778166e423ddc1dcec5b1ec153f06880906481687fbd5d9d3e369cf0ae7fbd3dac2d254a9eee79917c566af9f6f958e47a777c7337dcd1af3e41cd3d1b280937f3284345955425850a570c22a06309c9e214057398d0a11d91096701e7de5e9d0522cd05b15450ed2a40cfe2b02b3d75cc443e2b0bb1cac8720149c62db34d25852116dd1a708253f785179fb03339e41400efdb01ecbcf100b3d3ef03925de98715030beb0b9f0cad86e18b9f0dda26ef232423febfdf78b3b19fb662bb7c6a06efd6f054a3b88515f97d4a69730466fb6fc1cc91b76f2fad7629166fe178ef6ed012376b5522e5120972849b9e858f817fcc66002728ab2f73132747ed1fc460ce8049727d1c3fbf68deb4d21a37b42c9ddfe8aa90c9aa300229b5"],
+    ["8749ef14810045b35dae42b2e8892e8fd46b4c50017f7f2afd6834809c228bf35dc27c9f6a333eb954478dd7e09a746e830043e7f4344b8f8e1455fc6a3d6338cdf5d7312d83833244d95447cbccec0567670125b10f1080f57a7ca64a91d693b00c3b11eb7c92802e0497db9353347c94e52d6b199bfbc9b599d2feaf9dea20b389378c957ae50750cdd9a9e8888ea24d583ed68ab4dc61029eae4eb185543e74c840284c46cdc58d4582e92d0c3fd84ae6d8754c46ca77939962e3024ffd86c8b08be6917da90a4cdc4551ca5560df4a01bdb257b8507b380863ff31b84fd1431dcc4910e2e323c2cfc49f16947e0c41c93fe39d3a33f29437b2efd3408dc7020f009baf2a9615c1d2d1152982746043a2b9436cd1e76f325abcd931f6cbdcc7bcf9ff19b4a931cf1ceb2c663df28f380e2db887b9eb182a6c364b8e558cae3e67d9b8c1e9bbff27601a8375f240554c81e68e2ec965c3973e8e73bc2a98d2a9328a0bd65f91a2c379385cd5f4d4c430b7d2547bbfcb429d418fea462c641968a6e92a0568e2e7ccdfe029e2e38b4f4f42d380c67f7d1b7dffa515e8f1faad82a05d67615a12198db24453cd31fb360684ad4f1060dc039d36812a39626e21e1de576b284c722ff190d394ebfaeeff", "b638b1471bc6ff4684525f17ece7728f9996c29ff3c53497cb55d9ddacc0e6a5d03899348a98d5024ef71d0fe618ec26817568e9402630daaf955816d9eeabbea38d7499b738499f37882f57e8ebd4b19ec0d15f5a3fbe987447f521c77f8e318bff0c0d730afaa66d18b889f3a41c3e43a36fa8f6126a564c3df220111533186b53ee3622d5552166ca6c7fe5d43acf0f6b87134c46af8cb81200a30caa2082720d21cdf709758367ee038d81abef693d1b9c36b7fd75ba2178fbb5410c5453e05353a23953b350a2acc8d2568c0c1e05282bea61ecdbc6e5d99cf65c686dee701ffef965d59ae3b3a87fe0991ab33e6c5f2146613ff54384b7fe39c52e42fa75161535d117851931da5f525c4086aa0bdc0324764c823b1b7d62552fb940ad57a164f9f5d164062847d1cfebb4eae42292b8eb9f22194c60039083e79c44d9d4e227ea1250da40978740d37253c1ef11e8483b28b13debfeb975d8d7e3012e1fd89391df97c0256927997d694e5cec6f6817dfc546d0dd98f8257b21aeb2132886c4ddd678258f66e3ba70d899aca81c5bd4a722795b9dfb488a4898b83e66428a82fdb56e5f17e8e07e82799b5e15", "8beabd7b2af3c9b1aed5bb1ccc6fcba189136d4fccd7d58404d5227fbcc08d2bbd98e3379349e64fcd4fa9d84659e71eb063c3cdcaae6ba50422c790e693a2b5c03a730fa2990c1c9bd73198f9ccdf661c5e9e8bca9905478baf45aaf975b14aa1f30a4bee3f739fd88632f172333b7a158c65140dec9ec089443fc5fc8da93069e921abaa1778be68746ed8150ed6892f9fb325e55687ef97bc282868d92ed999fa9014f3ae5e9ed2e000abf6e2090766c0ec941803d2989c13759ae53f76c2dad72dd89183e69a7f64243207cbb8d5629f1ffe96ff404449bc0079a9076b279e184663c01e82b53cd5769cf6774f6846a0981288900927415d6372c4455c0aadd7ecde5873121b75d91ac57c1a80da145dbb2f883f70d3d29fbaf66960e7d03b6180c6dc9cd1d9a624ffa37d90648c2c22c9c9fa9060e4b9597006a060f1399026bd3fb99639ab2afef1bf81e95e385b3d70c6a1fb5c5103d67fc324fafb6159cee78db21610e27d95a2c376877bad08a7dcd33857b9fd75d8cb6be28f045cfc496d32a5b42dc4af3807b36eb8d3df", "b66fa39e98602b0b92f7c2127888e3daf023098edfcccbf019b2a8fb1befbd518ab450d85678d52547d1bfb7d80c63cd8dc9b11709f0bc0c47e5e52a3f3a2f43cc914a5646760d275fe9405757c4e99d2fdb03a6f8e6d2f8371d67460d439a7dacccbe6bc3bf30ab3478e41cac7cb93c7a93d5ad380eb37a1b8c9eed5497c6f0fd48505ee625c8b0b4d028b06719b4e06755b3df772d1f65f853c087fa7da0b7e51cc837b524c0511f77ccd15fa09d8170b15086779200670b14db53c55bd7c66324b9a54f5cdfc6291a358c2e6055ab2ad0b6aef561c118118e051b3aa5775b677e1409bcda779502af51c10d0dc18d4a4704e01b4046fc25eef582104b8d265cb97c553cb9e743a7dea4fd56736a333fc00629bf756f8449ceb143db448b0005eff97708d45312a1466299464932a23ce8b4af2eb46ad179a939dda9c57f5fc87fe6b773ebd6821cfe59ae4473038a133e75c40e6aa2755ee4a5d4a9ea93720861488ab4cf18f24827f7b86868236c", "b91bfdab6d021bd16d0de314f4e7e293d49105356440d11dc01bc41d230d4626b2bfdc2bf6616c980056195cef35473aa707339a2ad8afdd6c404bceebad31d8ecb249a6c283eefe1731effa12781b4b2b63d6705f2ceb9e28c7c0f4d16f91668db0b422bd15629d022463b1d438a9d68f1519672b8b5bf2fa5ec88e2445dbf24ff0ef42b023741675e0ce7ab0c609266fba950e32467ee3c90abc2b52216d2616a542af44b92f0fec78e52234f239e31cfe91f0d25a6a56ef06e0521be605609bcc0c00cba67d6e626f1714dd2af2c9249c786df1b12b6f169f96cb6648f2e0ac975ba9467d162a07d2212543740e9361b89aa8dbb4a4d6a654f2600bca7376b2e1d72122bf4fe1c1cb750bb82e49655f5d06db760c5a1f8f9ec0678288af52783a4418d1cce3401a725f4a052769621fb62236c30ba31f5026718a6d6a40793f0b92615a1e0716b246dd7863585291", "a1960efb36cd519fb7c96b22c66b3ad53aa5815ca7b81aacdd2a504d205ec7b9c54640601e8bbf7181d4e7fa6a619d98b1cec69715911a8051a9b0cc5e26fcafc85c185f6b669c0f05e4c7b0ffcbbee7eac3a4d3a82db664cc08b9020af4b147a0a0abf64681d46518eb524d416ac18ef2e6b1c8982452cdad7c600cb8c5a1224b81df7af8aeec8d9ec6dc834182629752cce17a3b1266afac1c8825fb03bdff9cb5fb3e1741040482b698c5f48e0caf397fc63649c72065686bd676612898021160ef731e70e2aeed34e8e20672fa64612982dc2d6641041d5569ea96f48766141dc4020c930a77e58ecf851647064f5363aa4f509f4b12f0bcd5bf8d42077661f61d2ac833362e047e83a55fab49a86bf3aa7f1ef2e0482e72719e1d7e6ae7a0aae3dc27b5a8b069a29ec7d163aecc"],
+    ["8635d1e45fdd880931c9a8e2f5cff9134e7bb6ca7f0b59d125e3930a82e79b34242b4f6d7ec315f33d0226854640a937a0dcac19414009c6fc65a7ea083374465fbf5668b53e5324b17ac5951228d32e0b45b4c518b6fea25d79aa3fe0cebe7bb2a1d0b8f681a0e3d77baac038fe59accd3f2752a7a7b6c1ee63bd41256a9899c5f9995213637f638c72955f2276843912c77491a6a9b5b0b219eabf27dcb3e1cb2c4f4608738f6c97fc11f5241304924cdad14ea08bf4db320e3be23b2f10e7d7f50af296824a6ed42a909385efb45039732dc6555c3d8462981297a5e50b8b641072e7fe9a4a055905275153488ebe3bf47febc34b58cdf533939a608b542ca16b80ca79907ba2e6466ee9105cb7c5627e1098a2c630ec85350d8a8d3f90a81479f1bca1b2a0811e6e16cd51bae81b62f4a098d98ad0e504c8731f5ff62669e127e8d93f7a94980b4c3e5929fbd907282c3b45267c70ec5534cdc95a90ce616232b9adebddfc4bf44980481d5eee763103cfe878b04ff6383cf9b35c09414c6158c971226c47f2e380be977316226748c31af10e442828b596ecb8c3d008c64589cafda7b1a95ce5a0cbb2960175df60ab7bf5e3048dc696a3b140b955fb5ec31d72b38f3d37ef8da4b41547f5064d", "88011964df862ea29ecd41007295982b085915925ebb2614b036e955d1466c3ec6dce69dc811337319c9a090bd33dcbe88dedf6e22552d31008c0227b2c143ec46ee74883dd6d0d254388eef97cc2c3a26556d4c54e63079c71068f8cac669ba836ea9b685751cd3fa1c7014c35782ce8be1fc86f239015f9bfee41b710eb8609396d40b38b35a7cc856cef554bca3002df251314c882f5595839d42a81c8b583d2a7cd7e9a3d4a6c9eea6c75c2897bb1fa7f6a07fe98be3804af9a106d75650eb32552a3b784d291a1706f70cfbb10c6f2f3dd2527c90242f4b81c73a02cf3a1b332bce7b25836382a2788a9d046fc73503d91af1ff5113ecd9e6d38b5522e15dfe4623effbcf9da3dc15c8d4c5c3ed252394f0fa18547ce01da86263e8bf93b65cc8e09a9643c4d68b51813e5ada302952192b978a94951bc876995315b890cdd3b8e44835fbc20d09d5a5d3cbd9f34752c422d2159c34c64a9da13611a5f6a7ed61fa2b4682f5d3940119695e21c64a66357c0df5a5c3aec79a0ff89a37364f6f521445c59068eb408098f11c9ced6d784982b5e839164802f0a168099d0473f9956fd152353113d49f412e36869c", "81115a3d42054b7baddd8d0ec8f34de107fdeb5a18826a207f490f165b3829ffebfa42507be8764610f03464fc33588b89507c4d3a83855e5a4926b6a8460a35edd6c0be693ae29b875d0f6a3d161d38645f35b1ef2ec2a196a2f10d51db33578495892dc1a7d7e0daddca34e02ac9813f6c4d1d964ae8f60a8275197937c1aa602145f054a652086cfbf8211271323b2c5547ab9b88c84dc2efa0d056a7291e39511bd28b2d941140539da364f94b562d1940e421d2ee4cad12f957ec719bbad06b034b24e9338414ba55884166c8572519e2a8f146a7cd5dec1c3800311ce511f50e9494dbf69c350915bc7bc80303343e6192cc6664ed1fd616097446510594419fda00af13f672eca0ed87ffb84901cbd3663a5242e76d01976a54c96136e8d651526d22f84a5ddcd67ae37d01ec653d4a2af65a0080936708ae6567ed265ada1378886a6bb77112bbbfa0c9c1b05ccb06c7a737896ee92e099f102633289ff4a02623d43f2e1d776be253147f8e494348b5e5e0e8e1fcc623ca420f2a719abf3a3c18380a5781053a22f6cff49a", "928e682ce3d8aa8b9e54a8c8efc0274491ef08560f735ce3edf5ce87ba4406f1f161e5cb196a86ee44f9028f42cd4e2e87400b6f611b41e6ef9ea2576df0ebd5a9d540359dbf2be1d2476d8ae70bdf5f69aea4be0bad7e86c49e10fb4d559450abf16cd1015141e4c13c90ed4bd51bd464c4e6c4a5bc2c3476c74f2306ce30139f2d6f4a45197c489e0801ff77e4dff50120cc6bca9049d85643671391e6e47caa4c99dd5476fda8805e6c691a115b011f9abdb21a75044027a39c855bd7654c498ee61b6d6ed8177e78bfe8e3e44df75174c04d4e29d8838996d7e3939a736640b0b9573a269dea72204363c465cb322e831321fa49fd05bd368a9cccebfbba55305398386a7fc41a5cf8d08726340a456e7bdfebb2b0d0ba93f68a8207f8d3eecbe813522bc805c8344a78e480133c33da09b415dfbe920058b06ef4ccc03cd447d38541c5777a7246c0a4da3cd2c8525f0bc1de99245d9ac75b30e8b3f307692c160610ea576553c484dc53be9a6e", "913069dd67a1820607098cf5ea88e441f973cc1d8d01ea78ad2422598d5fabee2e323729d92ce19c50ff7483cf7ed4f2b466f7845799fa1af6bd985e131beb6170cadddf01371cdf61852cd4cecc3bc10b4bd724337c1a55944ae3773e7f11d88040d8bc0e27e116ff4570c8c0306fcbb90e14b308d6891e9330a7934430bc250bdafd58baf00f96fcdf32255b5115840c660f46965e247d4d1ffc4e43c180092f4547a1283f6b3b9c38ae606990c4ee48cf3c21c020f53b0887e8618a862a8bf5115bb79cd811144bd0df2ca05503cd47335c7c5595c6ee90b1894f4368b1053e66c622078e86b661aa178ea81ce7c9213aab65e288e3aaaa1a7f6aee418928c573fd9835df345dde7ec4dae65f899a23279544ba3201dfb9fd01dfae82a5cbf13ce2289f64019662b1931a1643e4be5af2a6e1475cc08c53e7c2c1ad128ca73c96581336938a9873012f36dcf9c138", "b81ae520045e8becf30d5a705f30009212bc24345dc0cdeb54c95ed3addb6025c3e2c5e064790e47e66eabcdd76b9cd586c805346546c3e15549e2d8456a72c148bb06052755c3cf5e1966085541f36b0ccdf986fc73b9f2bd322110012a63f18f7e2d32924bd675ecf64dca384c92cd7e4cb1e1485eb072a8cfe3ac095ced459abc5044775a7d106137a82b4c7639de733493d181eb54b9a22d22ed7f97e590d141fa42a49ad620de5eead2683c519a71f9e19384aa052a377da0628164d210afdf72043ef5d49aa9181f2cc791df081833a83ced784e6fe81c3ed8da6cc187ef939da983145a212ee9ddcc2a51d2fc25ca7e298903c603ccd8ae9f2b25b267e8bc5a4341a9af384f2e47337b9826e1291eaa8cc30c3074109ce0201b6fc320a370b33ef2306521b7ab943115d68a80"],
+    ["852056ab6cba7271f420bcac6306d8c8dd8985693074ab154e644973cd894e76770f44c7b52c93ea4ef06d4f1b730ce2acac7948ad6d45e5abea01d6752b5e17f9da30d297a2bc0b686f38f7a47e158e317addbcf8336bf098922e062ffa5350b49f58904ec5e4bd4d6a9a605ae842e2f2f54be42458061872191fb9554ab47410e6d5912115b8c0cad1e2cd96a3bb1804a8907cf876c49d1b9561e13086569cfb05d4d8c0af000c4e4d14ef31bdb40a2054a715597870c2103cb73dab6ba2ab81ec3396e0d91014da6d1544dcc471065e41642f67b1cbca7e964989ee498d8ef3df87efa072f5cfca17d9343413141409bb926c29c42573c6477c9ceb8a0c4653a5e2dbeea21834c33dd08feabc2ad169f370789f0cf54376821dc6e1ac3b3bcf0d47fbebf96272c0337dfef2f844b702031cf054eb7e935ac05a10e4f2e5324fb6487160d153727413fa6add324637600af7a8c7de7a67dd005edccf0e54d375a7f1f94a5945f321792ecc8e719ca002f76f19444d8303a7bf37b052b185270a96697fb6ee2f30ed141894c20944af0ccdffe6a7926c178f5c90031da4b9b5785fd9415792dcba4b63a6feaad1d5330c04dd05cb99fe1129018085816f90e5f9583673df17330651ae71a19aab7159", "8b734c27995fc2d7237655e69b843bd7067b1a99249c7047b0e1ef590866cee90815cc1c3f6e64e02382158860524139a4999652d8fa06f80055277209a4af6cf82f8a4c9dab810f377b54521d19a610696b680f619c27e3d72d7b2dc91537b591a518b74d1c517c59d8e961383f4e534c5365d51c546b1fcec2ff8f41ac931b1d3211259d577291046ceb7ddf09d230409d05264a91333487bcb9e454872b8e2e44df746b013853709154eec4484a650a5eef726fb661390f95af4fda6bdd653f4a01ff005db6b433c12a916a115bfc62494694c99f2bcac3a706cc87761794849cbebd2b0612e83a7aac2969aa5e722afb825188dc145e683a7a53dcbc4ba3c5306456c787dcbebb1b9f484fb479a723210d5e7be4d3df2227c1c8105fcfd1c1d9173b9d67cea95aa1f5808d73fb4f71e04c2eedcca43b4ab95b8b0cd1237d2d14bdf9b32ee67ced3786ee999abe6d18d30d83ebe6ced5c09f91034553f26350e6cd1e651d3617089ef0a70d4c65dc476d4f9b0b0b0867b691dcf36deda56b2a42b7a0934ca018efcee995f007671b5b3c0ae2f13fba91f5fac6574a6e0ba03bf40d9ddd926dcaf1f18987ca6b4f96", "986465c10fbfff8e29062c9147cc7235e9754d3431e905b9762b84f01ee9a5f1ac784e0c876062b09118557f3a89432aa7d19f0e6adc5fced292200ba56fc181d5ac7202f351fc057fb30a05164a8858f7d202a1129152c015fb25dbe0420bff8c2477778dbc0180a20b090276cbaed56c43e4c2e7e62de65c090a33fe1ea0a1cb5438fb305de396c8552a57ab14a3a273c1a3f7bee97dbd9bfc5c2f6e02845a4f3cfff3bb2f4409073ed2641d3ab8a03783ac76333cb3b635c9079bfeebf71a524a4c843c289c4065e8b7a4ad43fc563239c12b10f36c7b38126445f2955fc6197bf4188feba1b9baaba89145ea63950da9815076527959c3c0c93e2662a590d8124edb52b0b8dd7f142232b864084419e49bc09b360e316ba371010e1cb7ca9688f575d84441a317d1f2401d6184935e1fad8b01c01a551495f2a5551cf4af51dddf6e12cd1f216ad66d136c5489d13c33e1fc36079b7fae2bc4fd9af11d92491f2936a41ac91f7b66806b4bd1923c3ba6217b07e806e6b2edbd66a7c096eb64164acdf4f3e7748ace89f05d786c35", "a50f7ea73f21b83297c122c41f036e4224785cc5bd8d5051d5d32daf8a5fac8084f2f4be0fc7d3dc4351e89c8d562221a3f5234dce10652ed467d440620b625f44a15831e49f57fc8ab7f24b85bfb74e0d705c68b771fee9b57a09c5be7ba9fba5d5fcacca756170d2636a4666c02dfd58d32009fb8d605142d23b3281cdd8442ed2868e80b9617455074c8ad07baa166ce663634350e1900d66eb34d5f6389b458c7e68ac25f8d8f3223fb144a7979a08adb100181065009c33fef12de7c5674bc41f05446dde08f4897a1e7a64465a723b42c549164b5107c7e97a4c5c916b6233f66d110a647249b6040057697b4b6e3194df0fa09408dbcdf611caca1059d7cc077f1224036e634173330c72d23903b18f5899800fb9162988e8e28f60791e2400900e01244ddc2196a8ebe60f4c54fe84af5b165ff7998e56685608f626daa4a845d1a462f6c1e7781e373ab06e52f45228577fa20b648ad480d4482b35ab2fa67f9b01d76e39298205a2b2e7ab", "b25c77f4bafc4ff9daa0df704f8ab95e10a7a458ec3d17f3488fad551cba85c290ec5141103ffbf1d3b65d95f23bba87a9e6f16038acde98fd731a11029771d1130f746e44434e1a81613d5606940d57bea9a90753e54dd1248450a9ea1b7c46890380fd998faadf86da485131acfc09fc023f01d90a49fb365256815a383efef70f91f4a337234b805ef366c53f737605ceef5d189deabe77a28e57f317866f6bcf0c09889e34b35ff11ce3190a994c40dfb9b04dc4a8795ec4a1a4067e42d23807473590b1a03fa646bd19131ee66e429debe6b2caa1bb5548222a157cf5649487834f88e8ad2aba3d8fc1bb2c6946529cae169a37e70bd4ff2a9721e25197b099c48ac486bda8ad198b39dfca52a15840de2d2a421d2de3fa10e905976d2cbb2ab569dbc1588036efada15431a5744e48fd60bb2c83541bcb738f46474dbed8b208daaff6ebeab033e2991c0b6f7a", "af60eb6c4224ed4d0f8e5799eef9587fd47134ed53d88b3fbbeb7aacceb34378526ae3d35a1fc9d69968d6aae7f9e63caa4a35c0717ac5d4fdf8191068dc45e5f467f428bf28f9e8bf91a3632cc8c48b771c3d61aadc39265b3aec619c03a42bb84ee01220a772cfce824b9c76db7e1dcfe1ec6e402671642d452173280a0f87a91264891ac9cb2ddd212c75cfae4ff110c163f13a89df49289a2a69f38ebd5f2c5f9a671cab0adefd430ed97ee4b6b41a634b8f6541b2cbb1f1b61f7d5bbf34cd2abe29c6e9d3fdc9f6d1ab304821a75dac9c75cb9a98b5ba0ef25d2df3c3b5eb045a43680f725347368225bb91fe5e70a48458f4e1a8c0f465c209dea963917068da4747d6b4171f83b803483256f932a7449cc4ec80521dcf13d4d9643924633942a0f4b6fbef95b6c5634209ae90"],
+    ["966218ec334d72326a78137c706a84d95c4336f072f1242dd413001e358bf8e19b13dee8c2d00b051b81dbd7f8014ee7aff3967fef2bfc7e4e0769ef6bbd3ddb5297c675a795a622882dc24c5f90e8b8a9bdf2844e352098cffaeb1284f43fb9802e6d12a6132b29215e7cff6b3ccbde231b70a49b45eff8254ab2a8382237a1e1140bc6a2406a8e0a37e59e47bf33ac37ffe3395d1b17220e42cb3408f0811819e60d6cb4f0e39c9ce01e3516653d6e320fc01b81063a4d9e992dbb5f63d709df5fadc019633f15aa5c06a84b34b2900da0b9dcd2be75f83ebd5f0914ab79f7af76f74d4f868d4ada4cd03b625b2ca83a8eb705473895b89e37969ca304e0cc18c33f639564311d419eb664f283e3d301ec21e41254f4ccfe8dd9c076bc15b6752093a1fdda53ad2fc842a7b09cf8ba276c4f2f8813d0554fd52b5a8efaf1f8a67de07cf883f3ea6f14f6916d1ed1254811a4c50609e7d2db5ee57536489a25ab387f41000245440ad8c6f901c5b7856aad796580928cb13b8e0828e2460c26fc916ad924b6b97eb5035d3e78e16bc96a56e5d2bbd473fc8dee782ffed8581be849514112d19c47f82abe24c54a43141ce7da732be25b035a9bb5963264b400735627524325855ce648eb424bfc74da", "818b6a4590fbe7c1b088085140548ec227660a6dc31555ddf3fd24058b2962b192251fc44da84c78dedce041b187b19d94a426800efbf1630437af2e31e1045ebe8bab50f8ce875001939ae0a566310aa4116b57d169020f201e11c66813771589116cbca9fb2afa62133cb68d6f72b3e1d7175825559cd511e806b60effea8d196db774bbac14ec43f136d39fbd144c4a604df90adea56f7ec029a3c113fcd30f8d296efa3d02a0c3a95dbfd4a15f2c4ae1845f698981cf9882f6a4f6ad8ddfcc02b1c94a6bedbe86339c7ff623e8720e1035a21b1067cd89c7c0408912cc9078d5876a0cd9e42d9919e721e948f99b63c3f023671f7b57718d55b1d988c2a6128d2680a5fe9b8cb930c845592ec986174eed05d60144f1be394feeb8516ab28634559974ff7d87bc43579fd05d50a01a9a6fee3c62904de24d3affdeb03abd6b9547ccba864a2082c45b1db0d7696e505fb1c198cba0d8b4729bcccdf4829bbc34bca69e26269a9312e5ef55dd5d350ba18540a686c0192e06cd57cf6a8ac98a15ae13d6ea4dad4eeb0ca59ab4df603b14aade6da4a5f84065a4a6f573178a4d3fa50a8733994cc230a05ab97f9614", "a608261b7f95ed4a19ee249956bd996fef384ad4cf280593ff331cca8c08b9bf42e32aeb8a81910e88ee2ab9b1a5dacba0cb1b01ad524e529ca3f526bcc70e7ec7acfde35394d4dfce61053c81e175c1dff31ab21d35cda99b1816e2c215cce99298993356a57385c9009793a30bca356ea29660ea3e741477c8912cd6a80da4556a38d6e10ddcbd56f0b204720308364be8e70b2a7ea44a760a2b4e83a0a65f006aab78268781cfe496e844677516f23784b6c7ee7ad8d159d67e6e2362001bf8c8401aa5548d817e839dc62ea1aa353c2d9806a78c18c297a598cf67bd5eb5cd5d229181baaf98df8779673cec8dae0a36aaab4b974be5d18edc58ff258cd56286064940e9377c8021e543c187cc9149d4891d6c3f42f0224fdbc146a7a0024e49c54d68de33b26ca447a78084d598336ce4302456abb29ba795b6508498ae4b8c2932a971ffb35d074024486be3e042516e052fd1be35c46c6e0280557015109b30897e736a9221bcec6e730519af6ae63c5bc7a1648c12845e2a2107424bf067816a5321e641a85d29dae1e605e5", "a36c13f45024f221142f3a27e11c4fb3a3a136da473a600a7122153e124bbd16ef39c360b3c144ca4053ce9cc0ede53498e52aebadee30b7b2cecf6e1be9f0b07cc4b2b7fedabadb0665ee2032f35cf58a494a2be913e2503284894ae56d5324b8466f4899c6daf2cf3c7fada7f61fd992bee3c98a2bdcd5f63ac52e79db05152d5d0462311bb4dc1841fe75cb70908a152d7bab82a6571543ca2b89e906cda6824e33eaf1313995364a6f26bb06265a00dfaa4955662629bae746e0332b9e2bfdc666be8dff347fc349f3e3d3b3dc0c6e870d43607ac54cf6bd68c5e0d848bcd1a2b7566e172e6f8478526b0964cc2c60fcf153538c134be685c3fb8c37eb1932f1ceb17f6290b13f820afe53402d710aae7b0c31a33b2b7d87b459a3882e12682807df7fb448262b162f6fc646553644b77ac001d7fecbc118d5f27824019326825022ac8da69ca941c8b45162885d31aac8dfab40bd608e638c57e0c2e7bebcbb66f759f1d586da8744bf394ac5b1", "8188f0381cecef5078232717398dae941920b93a872b3bb9d6b33f295f062c0b2dd452ea376982eef6bc2e7a58d290028585dfe99590d755df7776d2407f810f27515dff881eece9a6f7bc675d9ba9af939ddbbd6436f9f960ef1d5bcddd2d31b3283a7bb2ddf77f441bcc95ef4bdcb12e72dc73480a099cba9e197548e1620136bbc39247a562d50f930d974df86d1409d4aaab0de1a6f39655ca6fecaa3c8990951ea20a2dd85dff4ab255ce24662a1d5d3d49f7e6c0d1cd755d92e116d1a1a39d003e10c629060978c430ead8b95d5490bbfeb654d844bdd1222f0249d6a247e60327f354079754f74802d5e293c0317799959b678b444b8ebd0624c3bb4ef4b7c7318200305f6f0475b5e267973e1da3a0bc46365b4c357b321a41e6378499fce1508d9ce7d17b9e0da627991c466ce9bdb5348f8d6600b118b9049dc0a6e8ddaaae98cb249ce40ae821aa2f417a", "b0aa9d3bc455b7ae347cdc61afc36b1b1d227b552864e3ebf59018e5c3faf5ca8bd241f18e6b0dcadd9b0d425bfbe7088a475e6474cdd8709f070812fd8dc3fc6bd2e783f11c160baf871a9fdb4e242c5b46e6c0d668fd36097672ae306c212ca6f15bac799de9b08fbcbaf40b8616de07670a74a9a22d721a8304ee1d5673535c92a9fcb1a9cd91adeb85081cf87b74250176675846bbc01bdf4cad3274e98be26b15336c3e507cbd286948d39f74cb4f08c24915e95388d9bdb521ae1475a645032c3c29f8540eaa4dc50c7f157b5e4d15330e83f2b410ac2385a75f7f7bfa36483407953cfd734241e03282bd0d6942827b063e3b3f92985d2d9ce92378c2f6bdb5c3d60fbaad8822bd434b4234312299e066f6ffcaaeb3363b1bbf57930764386cbcb0dfcf3f2259bb2656ee9d0f"],
+    ["98a7c9b99d99ad76f874a13d3bcb6c39ffa87c8a1205cda537dee8703b7d9c0ab58db537ea56dee916d46919d570e1b39230da263563cb8d4030f98314a5ea7bbe93178ba4c616859e647f5be563341874ca334dce1e460284f405c40ee815f8880e428e7eabab194dfd35fe067c34d59151be8956e3774eb0a6491418138f52f05a0a4149296b7b83d61f3e90ea6af3381ab8b728c87c2f319ffa3967fc17d9f5fc2daa66814de3b1b2b9fea58300fe196128b4b520dce868e4daa5c5edd46b01670fe9b10383a7017805ebd196a75a16e6518bb8c62d56cb2f171c6d67351ce06027ea38fc784c10aaf31cfa75a8bd4ac794960c74fd52f4dd7fb9c942d9227daf0028aee8ac58f6c10f253a207ee81b807984902bed69b8341ce3b8016c2ee47559f9879ce19d9adefe4092a30f3e53c14c1391a0ce9122b09a5cff68c1c02988b885dc205f2e3ba722d53eaf627c4e721e8cfb16c92745f0e90af1995bc8a7b0ec3b69d4649d8daa6c26408148e5218c8c2747eaf9b3eac28f9f39b1d133b17d009425c8cd393aedb20d2deba92e619bcdb33c0d4165a6bd6a2fb905b60b508941f09264a6357dfbfa70ca25b8a408c509a9f8486ce1caaa1cbfc311339d3b4e0bdbd1a555c7fac71157fa9d1edd", "a78c99f72a673228f047f553554cd61383ce3bebf7af9eaca779e9bf30515f4604fb0e3d3770c9d5119aeb0151ea842c8a70412c50532fda5567d38b94ea8aa7d924c3e5c90d39d29ff44c456b771cb705d27daaceeb6295b6ded31174c66f13b0afe68f0f640496f07a20ccec77430462f4a5ea5f1d4cd633189dbd175547a9371836432c1292feb7b1cbfd094cb0c6370cda9d843f30c37f9b46c6ffa3c0c1d5bc614170fccdbf8dfb8e97cfa113f829a68d7e143c0c62903bfcf51e3c3c33f35bb6927e0db622b0609894f74fe6080fc4850369f5ef39c36e49ab9cc70f14309f0adb5787d7b31f95dccc8c815b303f9fa7119d643609f6ae62c49b2e0b6c99834a22e316e3dc09c62471685f7deb4e03bbf52cbd1a3a1f3d366afb0ef7cc1fbf93aa93046d3ff2a8b01fd8892d310c9861178897de75cceec7af7c760495d1f8c961161304e60a4615c3fbb464496aed573f3539e85f01e4b57a9dd0dfe8ce2dc43a9fd4012e1c8d5f901fc144c15b20e89019cee765dc554724d60ca181fa931add3b597a01bbc1bca5573cddb85113427ec14ef99ce5006c5d0a7f1fe81ba9e6a1841e6dd8854ec64b3915fac8", "a64b267c0a73cfe45b9dae8d43904c2579db47ab96440a5c2bd4b23036ad54528cd9f77ae0c842c5ada9c157fba95377a5b8ebe15e061f61dbf67e5b2c1d0490bc776339fefd22117d6cbac4259139d660cb547a16147b87cc1d8abbc9134b36822f5e5d820405e1c7f8d0d1b38bd8fe052b3260b1d28e9ddbcb30cc6fc2b4587e1a31d1d81efd55acbdbe5d7df6e87012e8ccce032e277ecbaf6bd78715fa4a4f31464460609b8969a804255dbfe473513592a300b0238cbbdc622e7a30666d2cc3b605b0f42caca90cf1ff014c3fb237e6e07cb9f894401ffbe5715247ef8568bc92a2967f36a421b6f8a58b66d5ac1a6850d5a135fa4aea9120d1e2f06e5cf6009b173e54e85de7a56d9b434d00923a13b480d933d102b6252a11622e58aa1585cbd0f1523ee769783bfcd896ee900c3508855c70fde7932785c7612499b646357cc8b3fe12205747a3a65a3d993423613a4c89016e9838ab67b587d0c1d963b9d5411d3c10fb5b542f5de6ae2a4c3aa9a0df3d0977609c67f902b31cb4bad31168f07bff2102f8231de23fb16b0b", "880ada2a90ddfa441a1935ccbc6708751dab0280c47e90291853a06169af2cbc48bf3e733a4c938ded07e20651317ec58199582b9154e5d86f84887f64cade7d09ce7e8607a02abfe1988e88c1e8e955f995e5060656153327e6d2b662b2066e8ae3231a8e5250d0e2e49fd769e41f335cfadadf3ddf5b69912e577eb6d85af332bc7faab731e3bc160e3d12325aacd930a590508a61d925da23c3188c9f536badec654dcbcd177343d24004e6407dce1b4b8fa36721ac6c2dcb4882d02c0d87349a29e1f08dcfce905cb202b88d8c5908310a9ab27a50383458b8991337a0550276e88b343f78745ccfcb2e20367d216149463d5664c0993eb4633c8bc4d7b5c5b233ec6eca9daf160eb800378fce3238444842a8e426967469433bcf1e4e5a7f457f50e027db0318a73f98146a1bd153816216e32dd87bdfe82ca5e1e9dc4560dd5a027d6fe5c0232d474e818b2e7510a75ca0304e23d829444f05f09a4fb6b5cdd09e4114a9c99244ba9b55abeecb", "878b49f7e7d39a69f4aa5f84ce18fb2862e9c385cd614617e6b3e1e0c43a5570d90ef32dee75179887b76bcaa29c14d192f6e1a24fd64628276c06e972559ca31f508e0e5a1aae205401a47e91a281e92fb436e18e5d29150dc0c199f1fc2fab896fb06443354bf96cb710182eab76f321c4cfcfabe1f8431edd9a7fbfafe2b33091a81fad368e6cdca485358b32ceff06aed5c0138135dbbc9e0de13a220b258b0255dab95a616422d4a7afeabcaaa00ff463878f45cfc27f8066b11ca9fc0a10b38174da65380b1617d915381fa0ef32ea742a344b100d06dea300afa678bb1a514ea6971b56b24c755ac4fbf2bff83bf92405fd694686b8f689e9cf6c822f3ea2f675df4c3d835ffe7f0ff2b7650105e947674245f7c6fd582de104d8f0662919acb2796bb38350cc5c19a7451bd84154a12838040520ab1d20ad71676f81c548b19f12c7f4626c8a0e70e8ca50b2", "88612019289547318d1b889aafbcc44c0f99654d6cf5020cb418ce63212e4bc388a3bf1503e547680b78a9dc92ff98d98a7099fa4b7f16f4c22123a2c6ba1224c86a36560b5054f0cc595e56c016e25356571faa41962183b8b42f4c0782a29b83128616a3789e1c2512af6589fe872dc720660730f0c2456bb5c809c00c9d2f19b847ca8f6cf0923224375b7d3f5fda3391e0ce9285b96780acbf4c55cd6326fe2e5b07bac123ad3aa895cab8af7a691e5726c20265b293b3606d6a3d80b7a1c26e0640472bb03969a4e3f3f00d5b9a6e259e9490ac5edfabf0e9e6d32db7204ae5379aec1616866ef09a4c247782ea12311153254d3a7fb96385e82be6d6d7f701d876edd6219cee74823a43d9c4862e2567c7796a9d432185d14a918a50189568f5f9628b654e9647415d99ebc284"],
+];
+
 // dst and seed for the mocked rng used for proof generation.
 const MOCKED_RNG_SEED: &str = "3.141592653589793238462643383279";
 const MOCKED_RNG_DST: &str = "MOCK_RANDOM_SCALARS_DST_";
@@ -169,6 +432,7 @@ macro_rules! proof_gen_verify_e2e_nominal {
         $proof_verify_fn:ident,
         $ciphersuite_id:ident,
         $expander:ty,
+        $proof_random_scalars_no:expr,
         $expected_proofs:ident
     ) => {
         let header = TEST_HEADER.as_ref();
@@ -224,7 +488,8 @@ macro_rules! proof_gen_verify_e2e_nominal {
                 // number of random scalars to return from the mockRng. Note
                 // that, this value will affect the value of the
                 // mocked random scalars
-                let rand_scalars_no = proof_messages.len() - j + 3;
+                let rand_scalars_no =
+                    proof_messages.len() - j + $proof_random_scalars_no;
                 // mocked rng computation
                 let mocked_rng = MockRng::<'_, $expander>::new(
                     MOCKED_RNG_SEED.as_bytes(),
@@ -284,6 +549,7 @@ fn proof_gen_verify_e2e_nominal() {
         bls12_381_g1_shake_256_proof_verify,
         bls12_381_g1_shake_256_ciphersuite_id,
         ExpandMsgXof<Shake256>,
+        3,
         EXPECTED_PROOFS_SHAKE256
     );
 
@@ -296,8 +562,32 @@ fn proof_gen_verify_e2e_nominal() {
         bls12_381_g1_sha_256_proof_verify,
         bls12_381_g1_sha_256_ciphersuite_id,
         ExpandMsgXmd<Sha256>,
+        3,
         EXPECTED_PROOFS_SHA256
     );
+
+    // The draft-06 `ProofGen` draws 5 + U random scalars, the mocked ones are
+    // seeded with the `api_id`.
+    proof_gen_verify_e2e_nominal!(
+        bls12_381_g1_shake_256_draft_06_sign,
+        bls12_381_g1_shake_256_draft_06_verify,
+        bls12_381_g1_shake_256_draft_06_proof_gen_with_rng,
+        bls12_381_g1_shake_256_draft_06_proof_verify,
+        bls12_381_g1_shake_256_draft_06_api_id,
+        ExpandMsgXof<Shake256>,
+        5,
+        EXPECTED_PROOFS_SHAKE256_DRAFT_06
+    );
+    proof_gen_verify_e2e_nominal!(
+        bls12_381_g1_sha_256_draft_06_sign,
+        bls12_381_g1_sha_256_draft_06_verify,
+        bls12_381_g1_sha_256_draft_06_proof_gen_with_rng,
+        bls12_381_g1_sha_256_draft_06_proof_verify,
+        bls12_381_g1_sha_256_draft_06_api_id,
+        ExpandMsgXmd<Sha256>,
+        5,
+        EXPECTED_PROOFS_SHA256_DRAFT_06
+    );
 }
 
 macro_rules! proof_gen_failure_message_modified {
@@ -394,3 +684,877 @@ fn proof_gen_failure_message_modified() {
         bls12_381_g1_sha_256_proof_gen
     );
 }
+
+macro_rules! draft_06_e2e_nominal {
+    (
+        $sign_fn:ident,
+        $verify_fn:ident,
+        $proof_gen_fn:ident,
+        $proof_verify_fn:ident,
+        $legacy_verify_fn:ident
+    ) => {
+        let header = TEST_HEADER.as_ref();
+        let presentation_header = TEST_PRESENTATION_HEADER.as_ref();
+        let messages = &TEST_CLAIMS;
+
+        let (secret_key, public_key) = KeyPair::random(&mut OsRng, b"")
+            .map(|key_pair| {
+                (
+                    key_pair.secret_key.to_bytes(),
+                    key_pair.public_key.to_octets(),
+                )
+            })
+            .expect("key generation failed");
+
+        let signature = $sign_fn(&BbsSignRequest {
+            secret_key: &secret_key,
+            public_key: &public_key,
+            header: Some(header),
+            messages: Some(messages),
+        })
+        .expect("signature generation failed");
+
+        let verify_request = BbsVerifyRequest {
+            public_key: &public_key,
+            header: Some(header),
+            messages: Some(messages),
+            signature: &signature,
+        };
+        assert!($verify_fn(&verify_request)
+            .expect("error during signature verification"));
+        // `e` is derived differently, the verification equation is not.
+        assert!($legacy_verify_fn(&verify_request)
+            .expect("error during signature verification"));
+
+        // Reveal every other message
+        let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+            .iter()
+            .enumerate()
+            .map(|(i, value)| BbsProofGenRevealMessageRequest {
+                reveal: i % 2 == 0,
+                value: *value,
+            })
+            .collect();
+        let revealed_messages = messages
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(i, m)| (i, *m))
+            .collect::<Vec<(usize, &[u8])>>();
+
+        let proof = $proof_gen_fn(&BbsProofGenRequest {
+            public_key: &public_key,
+            header: Some(header),
+            messages: Some(&proof_messages),
+            signature: &signature,
+            presentation_header: Some(presentation_header),
+            verify_signature: None,
+        })
+        .expect("proof generation failed");
+        assert_eq!(
+            proof.len(),
+            get_proof_size_draft_06(messages.len() - revealed_messages.len())
+        );
+
+        assert!($proof_verify_fn(&BbsProofVerifyRequest {
+            public_key: &public_key,
+            header: Some(header),
+            presentation_header: Some(presentation_header),
+            proof: &proof,
+            messages: Some(&revealed_messages),
+        })
+        .expect("proof verification failed"));
+
+        assert!(!$proof_verify_fn(&BbsProofVerifyRequest {
+            public_key: &public_key,
+            header: Some(header),
+            presentation_header: Some(b"another-presentation-header".as_ref()),
+            proof: &proof,
+            messages: Some(&revealed_messages),
+        })
+        .unwrap_or(false));
+    };
+}
+
+#[test]
+fn draft_06_e2e_nominal() {
+    draft_06_e2e_nominal!(
+        bls12_381_g1_shake_256_draft_06_sign,
+        bls12_381_g1_shake_256_draft_06_verify,
+        bls12_381_g1_shake_256_draft_06_proof_gen,
+        bls12_381_g1_shake_256_draft_06_proof_verify,
+        bls12_381_g1_shake_256_verify
+    );
+
+    draft_06_e2e_nominal!(
+        bls12_381_g1_sha_256_draft_06_sign,
+        bls12_381_g1_sha_256_draft_06_verify,
+        bls12_381_g1_sha_256_draft_06_proof_gen,
+        bls12_381_g1_sha_256_draft_06_proof_verify,
+        bls12_381_g1_sha_256_verify
+    );
+}
+
+#[test]
+fn ciphersuite_dispatch_e2e_nominal() {
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS;
+    let (secret_key, public_key) =
+        KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+            .map(|key_pair| {
+                (
+                    key_pair.secret_key.to_bytes(),
+                    key_pair.public_key.to_octets(),
+                )
+            })
+            .expect("key generation failed");
+    let sign_request = BbsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    };
+
+    for (ciphersuite, ciphersuite_id) in [
+        (
+            BbsCiphersuite::Bls12381G1Sha256,
+            bls12_381_g1_sha_256_ciphersuite_id(),
+        ),
+        (
+            BbsCiphersuite::Bls12381G1Shake256,
+            bls12_381_g1_shake_256_ciphersuite_id(),
+        ),
+        (
+            BbsCiphersuite::Bls12381G1Sha256Draft06,
+            bls12_381_g1_sha_256_draft_06_ciphersuite_id(),
+        ),
+        (
+            BbsCiphersuite::Bls12381G1Shake256Draft06,
+            bls12_381_g1_shake_256_draft_06_ciphersuite_id(),
+        ),
+    ] {
+        assert_eq!(ciphersuite.as_octets(), ciphersuite_id);
+        assert_eq!(
+            BbsCiphersuite::from_octets(&ciphersuite_id),
+            Ok(ciphersuite)
+        );
+        assert_eq!(
+            ciphersuite.to_string().parse::<BbsCiphersuite>(),
+            Ok(ciphersuite)
+        );
+
+        let signature =
+            ciphersuite.sign(&sign_request).expect("signing failed");
+        assert!(ciphersuite
+            .verify(&BbsVerifyRequest {
+                public_key: &public_key,
+                header: Some(header),
+                messages: Some(messages),
+                signature: &signature,
+            })
+            .expect("signature verification failed"));
+
+        let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+            .iter()
+            .map(|value| BbsProofGenRevealMessageRequest {
+                reveal: false,
+                value: *value,
+            })
+            .collect();
+        let proof = ciphersuite
+            .proof_gen(&BbsProofGenRequest {
+                public_key: &public_key,
+                header: Some(header),
+                messages: Some(&proof_messages),
+                signature: &signature,
+                presentation_header: None,
+                verify_signature: None,
+            })
+            .expect("proof generation failed");
+        assert!(ciphersuite
+            .proof_verify(&BbsProofVerifyRequest {
+                public_key: &public_key,
+                header: Some(header),
+                presentation_header: None,
+                proof: &proof,
+                messages: Some(&[]),
+            })
+            .expect("proof verification failed"));
+    }
+
+    assert_eq!(
+        BbsCiphersuite::Bls12381G1Sha256.sign(&sign_request),
+        bls12_381_g1_sha_256_sign(&sign_request)
+    );
+    assert_eq!(
+        BbsCiphersuite::Bls12381G1Sha256Draft06.sign(&sign_request),
+        bls12_381_g1_sha_256_draft_06_sign(&sign_request)
+    );
+    assert!(BbsCiphersuite::from_octets(b"BBS_UNKNOWN_").is_err());
+}
+
+#[derive(Debug, Clone)]
+struct Bls12381G1Sha512;
+
+impl BbsCustomCiphersuite for Bls12381G1Sha512 {
+    const ID: &'static [u8] = b"BBS_BLS12381G1_XMD:SHA-512_SSWU_RO_";
+    type ExpandMessage = XmdSha512;
+}
+
+#[derive(Debug, Clone)]
+struct ProvidedIdCiphersuite;
+
+impl BbsCustomCiphersuite for ProvidedIdCiphersuite {
+    const ID: &'static [u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_";
+    type ExpandMessage = XmdSha256;
+}
+
+#[derive(Debug, Clone)]
+struct NonAsciiIdCiphersuite;
+
+impl BbsCustomCiphersuite for NonAsciiIdCiphersuite {
+    const ID: &'static [u8] =
+        "BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_\u{e9}_".as_bytes();
+    type ExpandMessage = XmdSha256;
+}
+
+#[test]
+fn custom_ciphersuite_e2e_nominal() {
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS;
+    let (secret_key, public_key) =
+        KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+            .map(|key_pair| {
+                (
+                    key_pair.secret_key.to_bytes(),
+                    key_pair.public_key.to_octets(),
+                )
+            })
+            .expect("key generation failed");
+    let sign_request = BbsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    };
+
+    let signature = custom_sign::<Bls12381G1Sha512, _>(&sign_request)
+        .expect("signing failed");
+    let verify_request = BbsVerifyRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+        signature: &signature,
+    };
+    assert!(custom_verify::<Bls12381G1Sha512, _>(&verify_request)
+        .expect("signature verification failed"));
+    assert!(!bls12_381_g1_sha_256_verify(&verify_request)
+        .expect("signature verification failed"));
+
+    let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, value)| BbsProofGenRevealMessageRequest {
+            reveal: i == 0,
+            value: *value,
+        })
+        .collect();
+    let proof = custom_proof_gen::<Bls12381G1Sha512, _>(&BbsProofGenRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(&proof_messages),
+        signature: &signature,
+        presentation_header: None,
+        verify_signature: None,
+    })
+    .expect("proof generation failed");
+    assert!(custom_proof_verify::<Bls12381G1Sha512, _>(
+        &BbsProofVerifyRequest {
+            public_key: &public_key,
+            header: Some(header),
+            presentation_header: None,
+            proof: &proof,
+            messages: Some(&[(0, messages[0])]),
+        }
+    )
+    .expect("proof verification failed"));
+}
+
+#[test]
+fn custom_ciphersuite_invalid_id() {
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let secret_key = key_pair.secret_key.to_bytes();
+    let public_key = key_pair.public_key.to_octets();
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS;
+    let sign_request = BbsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    };
+    let signature =
+        bls12_381_g1_sha_256_sign(&sign_request).expect("signing failed");
+    let verify_request = BbsVerifyRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+        signature: &signature,
+    };
+    let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, value)| BbsProofGenRevealMessageRequest {
+            reveal: i == 0,
+            value: *value,
+        })
+        .collect();
+    let proof_request = BbsProofGenRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(&proof_messages),
+        signature: &signature,
+        presentation_header: None,
+        verify_signature: None,
+    };
+    let proof = bls12_381_g1_sha_256_proof_gen(&proof_request)
+        .expect("proof generation failed");
+    let pop = bls12_381_g1_sha_256_pop_prove(&key_pair.secret_key)
+        .expect("proof of possession generation failed");
+
+    macro_rules! assert_rejected {
+        ($suite:ty) => {
+            assert!(matches!(
+                custom_key_gen::<$suite>(KEY_GEN_SEED, TEST_KEY_INFOS[0], None),
+                Err(Error::BadParams { .. })
+            ));
+            assert!(matches!(
+                custom_pop_verify::<$suite>(&key_pair.public_key, &pop),
+                Err(Error::BadParams { .. })
+            ));
+            assert!(matches!(
+                custom_sign::<$suite, _>(&sign_request),
+                Err(Error::BadParams { .. })
+            ));
+            assert!(matches!(
+                custom_sign_with_signer::<$suite, _, _>(
+                    &key_pair.secret_key,
+                    &BbsSignWithSignerRequest {
+                        header: Some(header),
+                        messages: Some(messages),
+                    }
+                ),
+                Err(Error::BadParams { .. })
+            ));
+            assert!(matches!(
+                custom_verify::<$suite, _>(&verify_request),
+                Err(Error::BadParams { .. })
+            ));
+            assert!(matches!(
+                custom_proof_gen::<$suite, _>(&proof_request),
+                Err(Error::BadParams { .. })
+            ));
+            assert!(matches!(
+                custom_proof_verify::<$suite, _>(&BbsProofVerifyRequest {
+                    public_key: &public_key,
+                    header: Some(header),
+                    presentation_header: None,
+                    proof: &proof,
+                    messages: Some(&[(0, messages[0])]),
+                }),
+                Err(Error::BadParams { .. })
+            ));
+        };
+    }
+
+    assert_rejected!(ProvidedIdCiphersuite);
+    assert_rejected!(NonAsciiIdCiphersuite);
+}
+
+#[test]
+fn key_gen_nominal() {
+    let key_info = TEST_KEY_INFOS[1];
+    let secret_key = bls12_381_g1_sha_256_key_gen(KEY_GEN_SEED, key_info, None)
+        .expect("key generation failed");
+
+    assert_eq!(
+        BbsCiphersuite::Bls12381G1Sha256.key_gen(KEY_GEN_SEED, key_info, None),
+        Ok(secret_key.clone())
+    );
+    assert_eq!(
+        bls12_381_g1_sha_256_key_gen(
+            KEY_GEN_SEED,
+            key_info,
+            Some(b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_KEYGEN_DST_"),
+        ),
+        Ok(secret_key.clone())
+    );
+    // The `KeyGen` DST of draft-06 is built from its `api_id`, which is the
+    // identifier of the legacy ciphersuite.
+    assert_eq!(
+        bls12_381_g1_sha_256_draft_06_key_gen(KEY_GEN_SEED, key_info, None),
+        Ok(secret_key.clone())
+    );
+
+    // The key depends on the key info, the DST and the ciphersuite.
+    for other_secret_key in [
+        bls12_381_g1_sha_256_key_gen(KEY_GEN_SEED, TEST_KEY_INFOS[2], None),
+        bls12_381_g1_sha_256_key_gen(KEY_GEN_SEED, key_info, Some(b"dst")),
+        bls12_381_g1_shake_256_key_gen(KEY_GEN_SEED, key_info, None),
+        custom_key_gen::<Bls12381G1Sha512>(KEY_GEN_SEED, key_info, None),
+    ] {
+        assert_ne!(
+            other_secret_key.expect("key generation failed"),
+            secret_key
+        );
+    }
+
+    // The legacy derivation is still available, and differs.
+    let legacy_secret_key = SecretKey::legacy_key_gen(KEY_GEN_SEED, key_info)
+        .expect("key generation failed");
+    assert_eq!(
+        Some(legacy_secret_key.clone()),
+        SecretKey::new(KEY_GEN_SEED, key_info)
+    );
+    assert_ne!(legacy_secret_key, secret_key);
+
+    assert!(matches!(
+        bls12_381_g1_sha_256_key_gen(&KEY_GEN_SEED[1..], key_info, None),
+        Err(Error::BadParams { .. })
+    ));
+    assert!(matches!(
+        bls12_381_g1_sha_256_key_gen(KEY_GEN_SEED, &[0u8; 65536], None),
+        Err(Error::BadParams { .. })
+    ));
+}
+
+#[test]
+fn key_pop_nominal() {
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[1])
+        .expect("key generation failed");
+    let other_key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[2])
+        .expect("key generation failed");
+
+    let proof = bls12_381_g1_sha_256_pop_prove(&key_pair.secret_key)
+        .expect("proof of possession generation failed");
+    assert_eq!(
+        bls12_381_g1_sha_256_pop_verify(&key_pair.public_key, &proof),
+        Ok(true)
+    );
+    assert_eq!(
+        BbsCiphersuite::Bls12381G1Sha256
+            .pop_verify(&key_pair.public_key, &proof),
+        Ok(true)
+    );
+    assert_eq!(
+        custom_pop_verify::<Bls12381G1Sha512>(&key_pair.public_key, &proof),
+        Ok(false)
+    );
+
+    // The proof is randomized.
+    assert_ne!(
+        BbsCiphersuite::Bls12381G1Sha256.pop_prove(&key_pair.secret_key),
+        Ok(proof)
+    );
+
+    // The proof is bound to the public key and to the ciphersuite.
+    assert_eq!(
+        bls12_381_g1_sha_256_pop_verify(&other_key_pair.public_key, &proof),
+        Ok(false)
+    );
+    assert_eq!(
+        bls12_381_g1_shake_256_pop_verify(&key_pair.public_key, &proof),
+        Ok(false)
+    );
+    for prover in BbsCiphersuite::ALL {
+        let proof = prover
+            .pop_prove(&key_pair.secret_key)
+            .expect("proof of possession generation failed");
+        for verifier in BbsCiphersuite::ALL {
+            assert_eq!(
+                verifier.pop_verify(&key_pair.public_key, &proof),
+                Ok(prover == verifier),
+                "proof of {prover:?} verified with {verifier:?}"
+            );
+        }
+    }
+
+    let mut tampered_proof = proof;
+    tampered_proof[proof.len() - 1] ^= 1;
+    assert_eq!(
+        bls12_381_g1_sha_256_pop_verify(&key_pair.public_key, &tampered_proof),
+        Ok(false)
+    );
+
+    // Scalars of the proof must be canonical.
+    assert_eq!(
+        bls12_381_g1_sha_256_pop_verify(&key_pair.public_key, &[0xffu8; 64]),
+        Err(Error::BadEncoding)
+    );
+
+    // The public key must not be the identity.
+    let mut identity = [0u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH];
+    identity[0] = 0xc0;
+    let identity =
+        PublicKey::from_octets(&identity).expect("public key decoding failed");
+    assert_eq!(
+        bls12_381_g1_sha_256_pop_verify(&identity, &proof),
+        Err(Error::InvalidPublicKey)
+    );
+}
+
+#[test]
+fn typed_signature_and_proof_e2e_nominal() {
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS;
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let secret_key = key_pair.secret_key.to_bytes();
+    let public_key = key_pair.public_key.to_octets();
+    let sign_request = BbsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    };
+
+    let signature = BbsSignature::from_octets(
+        &bls12_381_g1_sha_256_sign(&sign_request).expect("signing failed"),
+    )
+    .expect("signature parsing failed");
+
+    // Typed values are accepted in place of octets.
+    assert_eq!(
+        bls12_381_g1_sha_256_verify(&BbsVerifyRequest {
+            public_key: &public_key,
+            header: Some(header),
+            messages: Some(messages),
+            signature: &signature,
+        }),
+        Ok(true)
+    );
+
+    // Human-readable formats use base64url.
+    let json = serde_json::to_string(&signature).expect("serialization failed");
+    assert_eq!(
+        json,
+        format!(
+            "\"{}\"",
+            base64::encode_config(
+                signature.to_octets(),
+                base64::URL_SAFE_NO_PAD
+            )
+        )
+    );
+    assert_eq!(
+        serde_json::from_str::<BbsSignature>(&json)
+            .expect("deserialization failed"),
+        signature
+    );
+
+    // Invalid values are rejected on parsing.
+    assert!(
+        BbsSignature::from_octets(&[0u8; BbsSignature::SIZE_BYTES]).is_err()
+    );
+    assert!(BbsSignature::try_from(&signature[1..]).is_err());
+    assert!(serde_json::from_str::<BbsSignature>("\"AAAA\"").is_err());
+    assert!(serde_json::from_str::<BbsSignature>("\"b64url?\"").is_err());
+
+    let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, value)| BbsProofGenRevealMessageRequest {
+            reveal: i == 0,
+            value: *value,
+        })
+        .collect();
+    let proof_request = BbsProofGenRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(&proof_messages),
+        signature: &signature,
+        presentation_header: None,
+        verify_signature: None,
+    };
+    let proof = BbsProof::from_octets(
+        &bls12_381_g1_sha_256_proof_gen(&proof_request)
+            .expect("proof generation failed"),
+    )
+    .expect("proof parsing failed");
+    assert_eq!(
+        bls12_381_g1_sha_256_proof_verify(&BbsProofVerifyRequest {
+            public_key: &public_key,
+            header: Some(header),
+            presentation_header: None,
+            proof: &proof,
+            messages: Some(&[(0, messages[0])]),
+        }),
+        Ok(true)
+    );
+
+    let json = serde_json::to_string(&proof).expect("serialization failed");
+    assert_eq!(
+        serde_json::from_str::<BbsProof>(&json)
+            .expect("deserialization failed"),
+        proof
+    );
+
+    // Hex is available with `serde_hex`, and values are still validated.
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Presentation {
+        #[serde(with = "pairing_crypto::serde_hex")]
+        signature: BbsSignature,
+        #[serde(with = "pairing_crypto::serde_hex")]
+        proof: BbsProof,
+    }
+    let presentation = Presentation {
+        signature,
+        proof: proof.clone(),
+    };
+    let json =
+        serde_json::to_string(&presentation).expect("serialization failed");
+    assert_eq!(
+        json,
+        format!(
+            r#"{{"signature":"{}","proof":"{}"}}"#,
+            hex::encode(signature),
+            hex::encode(&proof)
+        )
+    );
+    assert_eq!(
+        serde_json::from_str::<Presentation>(&json)
+            .expect("deserialization failed"),
+        presentation
+    );
+    let invalid = json.replacen(&hex::encode(signature), &"00".repeat(80), 1);
+    assert!(serde_json::from_str::<Presentation>(&invalid).is_err());
+
+    // The proof formats of the drafts are told apart.
+    let proof_draft_06 =
+        bls12_381_g1_sha_256_draft_06_proof_gen(&proof_request)
+            .expect("proof generation failed");
+    assert!(BbsProofDraft06::from_octets(&proof_draft_06).is_ok());
+    assert!(BbsProof::from_octets(&proof_draft_06).is_err());
+    assert!(BbsProofDraft06::from_octets(&proof).is_err());
+}
+
+#[test]
+fn inspect_proof_nominal() {
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS[..3];
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let public_key = key_pair.public_key.to_octets();
+    let signature = bls12_381_g1_sha_256_sign(&BbsSignRequest {
+        secret_key: &key_pair.secret_key.to_bytes(),
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    })
+    .expect("signing failed");
+    let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, value)| BbsProofGenRevealMessageRequest {
+            reveal: i == 0,
+            value: *value,
+        })
+        .collect();
+    let proof = bls12_381_g1_sha_256_proof_gen(&BbsProofGenRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(&proof_messages),
+        signature: &signature,
+        presentation_header: None,
+        verify_signature: None,
+    })
+    .expect("proof generation failed");
+
+    // proof = (Abar, Bbar, r2^, z^, (m^_1, m^_2), c)
+    let inspection = inspect_proof(&proof).expect("proof inspection failed");
+    assert_eq!(inspection.undisclosed_message_count, 2);
+    assert_eq!(inspection.a_bar[..], proof[..48]);
+    assert_eq!(inspection.b_bar[..], proof[48..96]);
+    assert_eq!(inspection.d, None);
+    assert_eq!(inspection.m_hat_list.len(), 2);
+    assert_eq!(inspection.m_hat_list[0][..], proof[160..192]);
+    assert_eq!(inspection.m_hat_list[1][..], proof[192..224]);
+    assert_eq!(inspection.challenge[..], proof[224..]);
+
+    let mut identity_a_bar = proof.clone();
+    identity_a_bar[..48].copy_from_slice(&[0u8; 48]);
+    identity_a_bar[0] = 0xc0;
+    let mut zero_m_hat = proof.clone();
+    zero_m_hat[192..224].copy_from_slice(&[0u8; 32]);
+    let mut zero_c = proof.clone();
+    zero_c[224..].copy_from_slice(&[0u8; 32]);
+
+    for (malformed, expected_component, expected_offset, expected_cause) in [
+        (&identity_a_bar[..], "A_bar", 0, Error::PointIsIdentity),
+        (&zero_m_hat[..], "m^_2", 192, Error::UnexpectedZeroValue),
+        (&zero_c[..], "c", 224, Error::UnexpectedZeroValue),
+    ] {
+        assert_eq!(
+            inspect_proof(malformed),
+            Err(Error::MalformedProofComponent {
+                component: expected_component.to_owned(),
+                offset: expected_offset,
+                cause: expected_cause.to_string(),
+            })
+        );
+    }
+
+    assert!(matches!(
+        inspect_proof(&proof[..proof.len() - 1]),
+        Err(Error::MalformedProofComponent {
+            ref component,
+            offset: 224,
+            ..
+        }) if component == "proof"
+    ));
+}
+
+#[test]
+fn inspect_proof_draft_06_nominal() {
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS[..3];
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let public_key = key_pair.public_key.to_octets();
+    let signature = bls12_381_g1_sha_256_draft_06_sign(&BbsSignRequest {
+        secret_key: &key_pair.secret_key.to_bytes(),
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(messages),
+    })
+    .expect("signing failed");
+    let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, value)| BbsProofGenRevealMessageRequest {
+            reveal: i == 0,
+            value: *value,
+        })
+        .collect();
+    let proof = bls12_381_g1_sha_256_draft_06_proof_gen(&BbsProofGenRequest {
+        public_key: &public_key,
+        header: Some(header),
+        messages: Some(&proof_messages),
+        signature: &signature,
+        presentation_header: None,
+        verify_signature: None,
+    })
+    .expect("proof generation failed");
+
+    // proof = (Abar, Bbar, D, e^, r1^, r3^, (m^_1, m^_2), c)
+    let inspection =
+        inspect_proof_draft_06(&proof).expect("proof inspection failed");
+    assert_eq!(inspection.undisclosed_message_count, 2);
+    assert_eq!(inspection.a_bar[..], proof[..48]);
+    assert_eq!(inspection.b_bar[..], proof[48..96]);
+    assert_eq!(inspection.d.expect("missing `D`")[..], proof[96..144]);
+    assert_eq!(inspection.m_hat_list.len(), 2);
+    assert_eq!(inspection.m_hat_list[0][..], proof[240..272]);
+    assert_eq!(inspection.m_hat_list[1][..], proof[272..304]);
+    assert_eq!(inspection.challenge[..], proof[304..]);
+
+    // The ciphersuite selects the proof layout.
+    for ciphersuite in [
+        BbsCiphersuite::Bls12381G1Sha256Draft06,
+        BbsCiphersuite::Bls12381G1Shake256Draft06,
+    ] {
+        assert_eq!(ciphersuite.inspect_proof(&proof), Ok(inspection.clone()));
+    }
+    assert!(BbsCiphersuite::Bls12381G1Sha256
+        .inspect_proof(&proof)
+        .is_err());
+    assert!(inspect_proof(&proof).is_err());
+
+    let mut identity_d = proof.clone();
+    identity_d[96..144].copy_from_slice(&[0u8; 48]);
+    identity_d[96] = 0xc0;
+    let mut zero_r1_hat = proof.clone();
+    zero_r1_hat[176..208].copy_from_slice(&[0u8; 32]);
+    let mut zero_m_hat = proof.clone();
+    zero_m_hat[272..304].copy_from_slice(&[0u8; 32]);
+    let mut zero_c = proof.clone();
+    zero_c[304..].copy_from_slice(&[0u8; 32]);
+
+    for (malformed, expected_component, expected_offset, expected_cause) in [
+        (&identity_d[..], "D", 96, Error::PointIsIdentity),
+        (&zero_r1_hat[..], "r1^", 176, Error::UnexpectedZeroValue),
+        (&zero_m_hat[..], "m^_2", 272, Error::UnexpectedZeroValue),
+        (&zero_c[..], "c", 304, Error::UnexpectedZeroValue),
+    ] {
+        assert_eq!(
+            inspect_proof_draft_06(malformed),
+            Err(Error::MalformedProofComponent {
+                component: expected_component.to_owned(),
+                offset: expected_offset,
+                cause: expected_cause.to_string(),
+            })
+        );
+    }
+
+    assert!(matches!(
+        inspect_proof_draft_06(&proof[..proof.len() - 1]),
+        Err(Error::MalformedProofComponent {
+            ref component,
+            offset: 304,
+            ..
+        }) if component == "proof"
+    ));
+}
+
+#[test]
+fn proof_verify_duplicate_revealed_index() {
+    let header = TEST_HEADER.as_ref();
+    let messages = &TEST_CLAIMS[..3];
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFOS[0])
+        .expect("key generation failed");
+    let secret_key = key_pair.secret_key.to_bytes();
+    let public_key = key_pair.public_key.to_octets();
+    let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+        .iter()
+        .enumerate()
+        .map(|(i, value)| BbsProofGenRevealMessageRequest {
+            reveal: i == 0,
+            value: *value,
+        })
+        .collect();
+
+    for ciphersuite in BbsCiphersuite::ALL {
+        let signature = ciphersuite
+            .sign(&BbsSignRequest {
+                secret_key: &secret_key,
+                public_key: &public_key,
+                header: Some(header),
+                messages: Some(messages),
+            })
+            .expect("signing failed");
+        let proof = ciphersuite
+            .proof_gen(&BbsProofGenRequest {
+                public_key: &public_key,
+                header: Some(header),
+                messages: Some(&proof_messages),
+                signature: &signature,
+                presentation_header: None,
+                verify_signature: None,
+            })
+            .expect("proof generation failed");
+
+        // Revealing the same message twice must not be counted as revealing
+        // two messages.
+        assert!(matches!(
+            ciphersuite.proof_verify(&BbsProofVerifyRequest {
+                public_key: &public_key,
+                header: Some(header),
+                presentation_header: None,
+                proof: &proof,
+                messages: Some(&[(0, messages[0]), (0, messages[0])]),
+            }),
+            Err(Error::BadParams { .. })
+        ));
+    }
+}
@@ -1,36 +1,131 @@
 use bbs_fixtures_generator::{
+    mock_rng::{MockRng, MOCKED_RNG_DST, MOCKED_RNG_SEED},
     validate_proof_fixture,
     validate_signature_fixture,
+    FixtureH2s,
+    FixtureKeyGen,
+    FixtureMockedRng,
     FixtureProof,
     FixtureSignature,
 };
+use blstrs::{
+    hash_to_curve::{ExpandMsgXmd, ExpandMsgXof},
+    Scalar,
+};
 use pairing_crypto::bbs::{
     ciphersuites::{
-        bls12_381::BBS_BLS12381G1_SIGNATURE_LENGTH,
+        bls12_381::{
+            PublicKey,
+            BBS_BLS12381G1_EXPAND_LEN,
+            BBS_BLS12381G1_PUBLIC_KEY_LENGTH,
+            BBS_BLS12381G1_SECRET_KEY_LENGTH,
+            BBS_BLS12381G1_SIGNATURE_LENGTH,
+        },
         bls12_381_g1_sha_256::{
+            key_gen as bls12_381_sha_256_key_gen,
             proof_gen as bls12_381_sha_256_proof_gen,
             proof_verify as bls12_381_sha_256_proof_verify,
             verify as bls12_381_sha_256_verify,
         },
+        bls12_381_g1_sha_256_draft_06::{
+            api_id as bls12_381_sha_256_draft_06_api_id,
+            create_generators as bls12_381_sha_256_draft_06_create_generators,
+            default_hash_to_scalar_dst as bls12_381_sha_256_draft_06_default_hash_to_scalar_dst,
+            hash_to_scalar as bls12_381_sha_256_draft_06_hash_to_scalar,
+            key_gen as bls12_381_sha_256_draft_06_key_gen,
+            proof_verify as bls12_381_sha_256_draft_06_proof_verify,
+            sign as bls12_381_sha_256_draft_06_sign,
+            verify as bls12_381_sha_256_draft_06_verify,
+        },
         bls12_381_g1_shake_256::{
+            key_gen as bls12_381_shake_256_key_gen,
             proof_gen as bls12_381_shake_256_proof_gen,
             proof_verify as bls12_381_shake_256_proof_verify,
             verify as bls12_381_shake_256_verify,
         },
+        bls12_381_g1_shake_256_draft_06::{
+            api_id as bls12_381_shake_256_draft_06_api_id,
+            create_generators as bls12_381_shake_256_draft_06_create_generators,
+            default_hash_to_scalar_dst as bls12_381_shake_256_draft_06_default_hash_to_scalar_dst,
+            hash_to_scalar as bls12_381_shake_256_draft_06_hash_to_scalar,
+            key_gen as bls12_381_shake_256_draft_06_key_gen,
+            proof_verify as bls12_381_shake_256_draft_06_proof_verify,
+            sign as bls12_381_shake_256_draft_06_sign,
+            verify as bls12_381_shake_256_draft_06_verify,
+        },
     },
     BbsProofGenRequest,
     BbsProofGenRevealMessageRequest,
     BbsProofVerifyRequest,
+    BbsSignRequest,
     BbsVerifyRequest,
 };
 
+use serde::Deserialize;
+use sha2::Sha256;
+use sha3::Shake256;
 use std::{convert::TryFrom, path::Path};
 
 static FIXTURES_DIR: &str =
     concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bbs");
 
+// Test vectors of `draft-irtf-cfrg-bbs-signatures-06`, in the JSON layout of
+// the `fixtures/` directory of the draft repository. The draft derives its
+// `KeyGen` DST, generators, `hash_to_scalar` DST and mocked random scalars from
+// its `api_id`, which is the identifier of the legacy ciphersuite, so these
+// vectors are the ones of `FIXTURES_DIR`. The signature and proof vectors were
+// generated with this implementation.
+static DRAFT_06_FIXTURES_DIR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/bbs_draft_06");
+
 const TEST_PRESENTATION_HEADER: &[u8; 24] = b"test-presentation-header";
 
+macro_rules! key_gen_fixture {
+    ($key_gen_fn:ident, $key_pair_fixture_file:expr) => {
+        let fixture = {
+            let text = std::fs::read_to_string($key_pair_fixture_file).unwrap();
+            serde_json::from_str::<FixtureKeyGen>(&text).unwrap()
+        };
+
+        let secret_key = $key_gen_fn(
+            &hex::decode(&fixture.key_material).unwrap(),
+            &hex::decode(&fixture.key_info).unwrap(),
+            None,
+        )
+        .expect("key generation failed");
+        assert_eq!(secret_key, fixture.key_pair.secret_key);
+        assert_eq!(PublicKey::from(&secret_key), fixture.key_pair.public_key);
+    };
+}
+
+#[test]
+fn key_gen_fixtures() {
+    key_gen_fixture!(
+        bls12_381_sha_256_key_gen,
+        Path::new(FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("keypair.json")
+    );
+    key_gen_fixture!(
+        bls12_381_shake_256_key_gen,
+        Path::new(FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("keypair.json")
+    );
+    key_gen_fixture!(
+        bls12_381_sha_256_draft_06_key_gen,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("keypair.json")
+    );
+    key_gen_fixture!(
+        bls12_381_shake_256_draft_06_key_gen,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("keypair.json")
+    );
+}
+
 macro_rules! sign_verify_fixtures {
     ($verify_fn:ident, $signature_fixtures_dir:expr) => {
         let fixtures_paths =
@@ -227,3 +322,318 @@ fn proof_verify_fixtures() {
             .join("proof")
     );
 }
+
+// The draft vectors use their own JSON layout with hex encoded values.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Draft06KeyPair {
+    secret_key: String,
+    public_key: String,
+}
+
+#[derive(Deserialize)]
+struct Draft06Result {
+    valid: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Draft06FixtureSignature {
+    case_name: String,
+    signer_key_pair: Draft06KeyPair,
+    header: String,
+    messages: Vec<String>,
+    signature: String,
+    result: Draft06Result,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Draft06FixtureProof {
+    case_name: String,
+    signer_public_key: String,
+    header: String,
+    presentation_header: String,
+    messages: Vec<String>,
+    disclosed_indexes: Vec<usize>,
+    proof: String,
+    result: Draft06Result,
+}
+
+fn decode_hex(value: &str) -> Vec<u8> {
+    hex::decode(value).expect("hex decoding failed")
+}
+
+macro_rules! draft_06_sign_verify_fixtures {
+    ($sign_fn:ident, $verify_fn:ident, $signature_fixtures_dir:expr) => {
+        let fixtures_paths =
+            std::fs::read_dir($signature_fixtures_dir).unwrap();
+
+        for fixture_path in fixtures_paths {
+            let fixture = {
+                let text =
+                    std::fs::read_to_string(fixture_path.unwrap().path())
+                        .unwrap();
+                serde_json::from_str::<Draft06FixtureSignature>(&text).unwrap()
+            };
+
+            let messages = fixture
+                .messages
+                .iter()
+                .map(|m| decode_hex(m))
+                .collect::<Vec<Vec<u8>>>();
+            let public_key =
+                <[u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH]>::try_from(decode_hex(
+                    &fixture.signer_key_pair.public_key,
+                ))
+                .unwrap();
+
+            // Signing is deterministic, a valid signature is reproduced from
+            // the signer secret key.
+            if fixture.result.valid {
+                let signature = $sign_fn(&BbsSignRequest {
+                    secret_key:
+                        &<[u8; BBS_BLS12381G1_SECRET_KEY_LENGTH]>::try_from(
+                            decode_hex(&fixture.signer_key_pair.secret_key),
+                        )
+                        .unwrap(),
+                    public_key: &public_key,
+                    header: Some(decode_hex(&fixture.header)),
+                    messages: Some(&messages),
+                })
+                .expect("signature generation failed");
+                assert_eq!(
+                    hex::encode(signature),
+                    fixture.signature,
+                    "signature generation mismatch, case: {}",
+                    fixture.case_name
+                );
+            }
+
+            let result = $verify_fn(&BbsVerifyRequest {
+                public_key: &public_key,
+                header: Some(decode_hex(&fixture.header)),
+                messages: Some(&messages),
+                signature: &<[u8; BBS_BLS12381G1_SIGNATURE_LENGTH]>::try_from(
+                    decode_hex(&fixture.signature),
+                )
+                .unwrap(),
+            })
+            .unwrap_or(false);
+
+            assert_eq!(
+                result, fixture.result.valid,
+                "signature verification mismatch, case: {}",
+                fixture.case_name
+            );
+        }
+    };
+}
+
+#[test]
+fn draft_06_sign_verify_fixtures() {
+    draft_06_sign_verify_fixtures!(
+        bls12_381_sha_256_draft_06_sign,
+        bls12_381_sha_256_draft_06_verify,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("signature")
+    );
+    draft_06_sign_verify_fixtures!(
+        bls12_381_shake_256_draft_06_sign,
+        bls12_381_shake_256_draft_06_verify,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("signature")
+    );
+}
+
+macro_rules! draft_06_proof_verify_fixtures {
+    ($proof_verify_fn:ident, $proof_fixtures_dir:expr) => {
+        let fixtures_paths = std::fs::read_dir($proof_fixtures_dir).unwrap();
+
+        for fixture_path in fixtures_paths {
+            let fixture = {
+                let text =
+                    std::fs::read_to_string(fixture_path.unwrap().path())
+                        .unwrap();
+                serde_json::from_str::<Draft06FixtureProof>(&text).unwrap()
+            };
+
+            let disclosed_messages = fixture
+                .disclosed_indexes
+                .iter()
+                .map(|&i| (i, decode_hex(&fixture.messages[i])))
+                .collect::<Vec<(usize, Vec<u8>)>>();
+            let result = $proof_verify_fn(&BbsProofVerifyRequest {
+                public_key:
+                    &<[u8; BBS_BLS12381G1_PUBLIC_KEY_LENGTH]>::try_from(
+                        decode_hex(&fixture.signer_public_key),
+                    )
+                    .unwrap(),
+                header: Some(decode_hex(&fixture.header)),
+                presentation_header: Some(decode_hex(
+                    &fixture.presentation_header,
+                )),
+                proof: &decode_hex(&fixture.proof),
+                messages: Some(&disclosed_messages),
+            })
+            .unwrap_or(false);
+
+            assert_eq!(
+                result, fixture.result.valid,
+                "proof verification mismatch, case: {}",
+                fixture.case_name
+            );
+        }
+    };
+}
+
+#[test]
+fn draft_06_proof_verify_fixtures() {
+    draft_06_proof_verify_fixtures!(
+        bls12_381_sha_256_draft_06_proof_verify,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("proof")
+    );
+    draft_06_proof_verify_fixtures!(
+        bls12_381_shake_256_draft_06_proof_verify,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("proof")
+    );
+}
+
+#[derive(Deserialize)]
+struct Draft06FixtureGenerators {
+    #[serde(rename = "BP")]
+    bp: String,
+    #[serde(rename = "Q1")]
+    q1: String,
+    #[serde(rename = "MsgGenerators")]
+    msg_generators: Vec<String>,
+}
+
+macro_rules! draft_06_generators_fixture {
+    ($create_generators_fn:ident, $generators_fixture_file:expr) => {
+        let fixture = {
+            let text =
+                std::fs::read_to_string($generators_fixture_file).unwrap();
+            serde_json::from_str::<Draft06FixtureGenerators>(&text).unwrap()
+        };
+
+        let generators =
+            $create_generators_fn(fixture.msg_generators.len() + 2, None)
+                .expect("generators creation failed");
+        let expected_generators = [fixture.bp, fixture.q1]
+            .into_iter()
+            .chain(fixture.msg_generators)
+            .map(|g| decode_hex(&g))
+            .collect::<Vec<Vec<u8>>>();
+        assert_eq!(generators, expected_generators);
+    };
+}
+
+#[test]
+fn draft_06_generators_fixtures() {
+    draft_06_generators_fixture!(
+        bls12_381_sha_256_draft_06_create_generators,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("generators.json")
+    );
+    draft_06_generators_fixture!(
+        bls12_381_shake_256_draft_06_create_generators,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("generators.json")
+    );
+}
+
+macro_rules! draft_06_h2s_fixture {
+    (
+        $hash_to_scalar_fn:ident,
+        $default_hash_to_scalar_dst_fn:ident,
+        $h2s_fixture_file:expr
+    ) => {
+        let fixture = {
+            let text = std::fs::read_to_string($h2s_fixture_file).unwrap();
+            serde_json::from_str::<FixtureH2s>(&text).unwrap()
+        };
+
+        assert_eq!($default_hash_to_scalar_dst_fn(), fixture.dst);
+        let scalar = $hash_to_scalar_fn(&fixture.message, None)
+            .expect("hash to scalar failed");
+        assert_eq!(scalar.to_vec(), fixture.scalar);
+    };
+}
+
+#[test]
+fn draft_06_h2s_fixtures() {
+    draft_06_h2s_fixture!(
+        bls12_381_sha_256_draft_06_hash_to_scalar,
+        bls12_381_sha_256_draft_06_default_hash_to_scalar_dst,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("h2s.json")
+    );
+    draft_06_h2s_fixture!(
+        bls12_381_shake_256_draft_06_hash_to_scalar,
+        bls12_381_shake_256_draft_06_default_hash_to_scalar_dst,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("h2s.json")
+    );
+}
+
+// The mocked random scalars are the output of `seeded_random_scalars`, the
+// randomness the draft uses to make its proof vectors deterministic.
+macro_rules! draft_06_mocked_rng_fixture {
+    ($api_id_fn:ident, $expander:ty, $mocked_rng_fixture_file:expr) => {
+        let fixture = {
+            let text =
+                std::fs::read_to_string($mocked_rng_fixture_file).unwrap();
+            serde_json::from_str::<FixtureMockedRng>(&text).unwrap()
+        };
+
+        let dst = [$api_id_fn().as_slice(), MOCKED_RNG_DST.as_bytes()].concat();
+        assert_eq!(fixture.seed, MOCKED_RNG_SEED.as_bytes());
+        assert_eq!(fixture.dst, dst);
+
+        let mut mocked_rng = MockRng::<'_, $expander>::new(
+            &fixture.seed,
+            &dst,
+            fixture.count,
+            Some(BBS_BLS12381G1_EXPAND_LEN),
+        );
+        let mocked_scalars = (0..fixture.count)
+            .map(|_| {
+                let mut buffer = [0u8; 64];
+                mocked_rng.fill(&mut buffer[64 - BBS_BLS12381G1_EXPAND_LEN..]);
+                hex::encode(
+                    Scalar::from_wide_bytes_be_mod_r(&buffer).to_bytes_be(),
+                )
+            })
+            .collect::<Vec<String>>();
+        assert_eq!(mocked_scalars, fixture.mocked_scalars);
+    };
+}
+
+#[test]
+fn draft_06_mocked_rng_fixtures() {
+    draft_06_mocked_rng_fixture!(
+        bls12_381_sha_256_draft_06_api_id,
+        ExpandMsgXmd<Sha256>,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_sha_256")
+            .join("mockedRng.json")
+    );
+    draft_06_mocked_rng_fixture!(
+        bls12_381_shake_256_draft_06_api_id,
+        ExpandMsgXof<Shake256>,
+        Path::new(DRAFT_06_FIXTURES_DIR)
+            .join("bls12_381_shake_256")
+            .join("mockedRng.json")
+    );
+}
@@ -3,6 +3,7 @@ use pairing_crypto::{
         ciphersuites::bls12_381_bbs_g1_bls_sig_g2_sha_256::{
             bls_key_pop as bls12_381_bbs_g1_bls_sig_g2_sha_256_bls_key_pop,
             bls_key_pop_verify as bls12_381_bbs_g1_bls_sig_g2_sha_256_bls_key_pop_verify,
+            inspect_proof as bls12_381_bbs_g1_bls_sig_g2_sha_256_inspect_proof,
             proof_gen as bls12_381_bbs_g1_bls_sig_g2_sha_256_proof_gen,
             proof_verify as bls12_381_bbs_g1_bls_sig_g2_sha_256_proof_verify,
             sign as bls12_381_bbs_g1_bls_sig_g2_sha_256_sign,
@@ -150,7 +151,7 @@ fn bound_sign_verify_e2e_nominal() {
 }
 
 macro_rules! bound_proof_gen_verify_e2e_nominal {
-    ($key_pop_gen_fn:ident, $key_pop_verify_fn:ident, $sign_fn:ident, $verify_fn:ident, $proof_gen_fn:ident, $proof_verify_fn:ident) => {
+    ($key_pop_gen_fn:ident, $key_pop_verify_fn:ident, $sign_fn:ident, $verify_fn:ident, $proof_gen_fn:ident, $proof_verify_fn:ident, $inspect_proof_fn:ident) => {
         let header = TEST_HEADER.as_ref();
         let presentation_header = TEST_PRESENTATION_HEADER.as_ref();
         let messages = &TEST_CLAIMS;
@@ -236,6 +237,17 @@ macro_rules! bound_proof_gen_verify_e2e_nominal {
             })
             .expect("proof generation failed");
 
+            // The BLS secret key is an additional undisclosed message
+            let inspection =
+                $inspect_proof_fn(proof).expect("proof inspection failed");
+            assert_eq!(
+                inspection.undisclosed_message_count,
+                messages.len() - j + 1
+            );
+            assert_eq!(inspection.m_hat_list.len(), messages.len() - j + 1);
+            assert_eq!(inspection.a_bar[..], proof[..48]);
+            assert_eq!(inspection.challenge[..], proof[proof.len() - 32..]);
+
             let mut revealed_msgs = Vec::new();
             for k in 0..j {
                 revealed_msgs.push((k as usize, TEST_CLAIMS[k]));
@@ -265,6 +277,7 @@ fn bound_proof_gen_verify_e2e_nominal() {
         bls12_381_bbs_g1_bls_sig_g2_sha_256_sign,
         bls12_381_bbs_g1_bls_sig_g2_sha_256_verify,
         bls12_381_bbs_g1_bls_sig_g2_sha_256_proof_gen,
-        bls12_381_bbs_g1_bls_sig_g2_sha_256_proof_verify
+        bls12_381_bbs_g1_bls_sig_g2_sha_256_proof_verify,
+        bls12_381_bbs_g1_bls_sig_g2_sha_256_inspect_proof
     );
 }
@@ -1,20 +1,69 @@
-use pairing_crypto::bls::ciphersuites::{
-    bls12_381::KeyPair,
-    bls12_381_g2_sha_256_aug::{
-        sign as bls12_381_g2_shake_256_aug_sign,
-        verify as bls12_381_g2_shake_256_aug_verify,
-    },
-    bls12_381_g2_sha_256_nul::{
-        sign as bls12_381_g2_shake_256_nul_sign,
-        verify as bls12_381_g2_shake_256_nul_verify,
-    },
-    bls12_381_g2_sha_256_pop::{
-        pop_prove as bls12_381_g2_shake_256_pop_pop_prove,
-        pop_verify as bls12_381_g2_shake_256_pop_pop_verify,
-        sign as bls12_381_g2_shake_256_pop_sign,
-        verify as bls12_381_g2_shake_256_pop_verify,
+use blstrs::G2Affine;
+use pairing_crypto::{
+    bls::{
+        blind::{blind, blind_sign, unblind},
+        ciphersuites::{
+            bls12_381::{
+                round_message,
+                BlsSignature,
+                BlsSigner,
+                KeyPair,
+                PublicKey,
+                SecretKey,
+            },
+            bls12_381_g1::{
+                BlsSignature as MinSigBlsSignature,
+                BlsSigner as MinSigBlsSigner,
+                KeyPair as MinSigKeyPair,
+                PublicKey as MinSigPublicKey,
+            },
+            bls12_381_g1_sha_256_aug::{
+                sign as bls12_381_g1_sha_256_aug_sign,
+                sign_with_signer as bls12_381_g1_sha_256_aug_sign_with_signer,
+                verify as bls12_381_g1_sha_256_aug_verify,
+            },
+            bls12_381_g1_sha_256_nul::{
+                sign as bls12_381_g1_sha_256_nul_sign,
+                sign_with_signer as bls12_381_g1_sha_256_nul_sign_with_signer,
+                verify as bls12_381_g1_sha_256_nul_verify,
+            },
+            bls12_381_g1_sha_256_pop::{
+                pop_prove as bls12_381_g1_sha_256_pop_pop_prove,
+                pop_verify as bls12_381_g1_sha_256_pop_pop_verify,
+                sign as bls12_381_g1_sha_256_pop_sign,
+                sign_with_signer as bls12_381_g1_sha_256_pop_sign_with_signer,
+                verify as bls12_381_g1_sha_256_pop_verify,
+            },
+            bls12_381_g2_sha_256_aug::{
+                sign as bls12_381_g2_shake_256_aug_sign,
+                sign_with_signer as bls12_381_g2_shake_256_aug_sign_with_signer,
+                verify as bls12_381_g2_shake_256_aug_verify,
+            },
+            bls12_381_g2_sha_256_nul::{
+                sign as bls12_381_g2_shake_256_nul_sign,
+                sign_with_signer as bls12_381_g2_shake_256_nul_sign_with_signer,
+                timelock_decrypt as bls12_381_g2_shake_256_nul_timelock_decrypt,
+                timelock_encrypt as bls12_381_g2_shake_256_nul_timelock_encrypt,
+                verify as bls12_381_g2_shake_256_nul_verify,
+            },
+            bls12_381_g2_sha_256_pop::{
+                aggregate_signatures as bls12_381_g2_shake_256_pop_aggregate_signatures,
+                pop_prove as bls12_381_g2_shake_256_pop_pop_prove,
+                pop_verify as bls12_381_g2_shake_256_pop_pop_verify,
+                sign as bls12_381_g2_shake_256_pop_sign,
+                sign_with_signer as bls12_381_g2_shake_256_pop_sign_with_signer,
+                timelock_decrypt as bls12_381_g2_shake_256_pop_timelock_decrypt,
+                timelock_encrypt as bls12_381_g2_shake_256_pop_timelock_encrypt,
+                verify as bls12_381_g2_shake_256_pop_verify,
+                MultiSig,
+            },
+            BlsCiphersuite,
+        },
+        vrf::{prove as vrf_prove, verify as vrf_verify},
     },
+    Error,
 };
+use rand_core::OsRng;
 
 const TEST_KEY_GEN_SEED: &[u8] = b"not_A_random_seed_at_Allllllllll";
 const TEST_KEY_INFO: &[u8] = b"test-key-info";
@@ -22,7 +71,10 @@ const TEST_MESSAGE: &[u8] = b"test-message";
 
 macro_rules! sign_verify_e2e_nominal {
     ($sign_fn:ident, $verify_fn:ident) => {
-        let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        sign_verify_e2e_nominal!(KeyPair, $sign_fn, $verify_fn);
+    };
+    ($key_pair:ident, $sign_fn:ident, $verify_fn:ident) => {
+        let key_pair = $key_pair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
             .expect("key generation must succeed");
 
         let signature = $sign_fn(&key_pair.secret_key, &TEST_MESSAGE)
@@ -48,6 +100,198 @@ fn sign_verify_e2e_nominal() {
         bls12_381_g2_shake_256_pop_sign,
         bls12_381_g2_shake_256_pop_verify
     );
+
+    sign_verify_e2e_nominal!(
+        MinSigKeyPair,
+        bls12_381_g1_sha_256_nul_sign,
+        bls12_381_g1_sha_256_nul_verify
+    );
+
+    sign_verify_e2e_nominal!(
+        MinSigKeyPair,
+        bls12_381_g1_sha_256_aug_sign,
+        bls12_381_g1_sha_256_aug_verify
+    );
+
+    sign_verify_e2e_nominal!(
+        MinSigKeyPair,
+        bls12_381_g1_sha_256_pop_sign,
+        bls12_381_g1_sha_256_pop_verify
+    );
+}
+
+// A signer which only exchanges octets with the application, as a key
+// management service would.
+struct RemoteSigner([u8; 32]);
+
+impl BlsSigner for RemoteSigner {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        SecretKey::from_bytes(&self.0)?.public_key()
+    }
+
+    fn sign_point(&self, point: &[u8; 96]) -> Result<[u8; 96], Error> {
+        SecretKey::from_bytes(&self.0)?.sign_point(point)
+    }
+}
+
+// A signer which does not hold the secret key of its public key.
+struct FaultySigner(PublicKey);
+
+impl BlsSigner for FaultySigner {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.0)
+    }
+
+    fn sign_point(&self, point: &[u8; 96]) -> Result<[u8; 96], Error> {
+        Ok(*point)
+    }
+}
+
+// The minimal-signature-size counterpart of `FaultySigner`.
+struct FaultyMinSigSigner(MinSigPublicKey);
+
+impl MinSigBlsSigner for FaultyMinSigSigner {
+    fn public_key(&self) -> Result<MinSigPublicKey, Error> {
+        Ok(self.0)
+    }
+
+    fn sign_point(&self, point: &[u8; 48]) -> Result<[u8; 48], Error> {
+        Ok(*point)
+    }
+}
+
+macro_rules! sign_with_signer_e2e_nominal {
+    (
+        $key_pair:ident,
+        $sign_fn:ident,
+        $sign_with_signer_fn:ident,
+        $verify_fn:ident
+    ) => {
+        let key_pair = $key_pair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+            .expect("key generation must succeed");
+
+        let signature = $sign_fn(&key_pair.secret_key, &TEST_MESSAGE)
+            .expect("siging must succeed");
+        assert_eq!(
+            $sign_with_signer_fn(&key_pair.secret_key, &TEST_MESSAGE),
+            Ok(signature)
+        );
+        assert!($verify_fn(&key_pair.public_key, &TEST_MESSAGE, &signature)
+            .expect("signature verification must succeed"));
+    };
+}
+
+#[test]
+fn sign_with_signer_e2e_nominal() {
+    sign_with_signer_e2e_nominal!(
+        KeyPair,
+        bls12_381_g2_shake_256_nul_sign,
+        bls12_381_g2_shake_256_nul_sign_with_signer,
+        bls12_381_g2_shake_256_nul_verify
+    );
+
+    sign_with_signer_e2e_nominal!(
+        KeyPair,
+        bls12_381_g2_shake_256_aug_sign,
+        bls12_381_g2_shake_256_aug_sign_with_signer,
+        bls12_381_g2_shake_256_aug_verify
+    );
+
+    sign_with_signer_e2e_nominal!(
+        KeyPair,
+        bls12_381_g2_shake_256_pop_sign,
+        bls12_381_g2_shake_256_pop_sign_with_signer,
+        bls12_381_g2_shake_256_pop_verify
+    );
+
+    sign_with_signer_e2e_nominal!(
+        MinSigKeyPair,
+        bls12_381_g1_sha_256_nul_sign,
+        bls12_381_g1_sha_256_nul_sign_with_signer,
+        bls12_381_g1_sha_256_nul_verify
+    );
+
+    sign_with_signer_e2e_nominal!(
+        MinSigKeyPair,
+        bls12_381_g1_sha_256_aug_sign,
+        bls12_381_g1_sha_256_aug_sign_with_signer,
+        bls12_381_g1_sha_256_aug_verify
+    );
+
+    sign_with_signer_e2e_nominal!(
+        MinSigKeyPair,
+        bls12_381_g1_sha_256_pop_sign,
+        bls12_381_g1_sha_256_pop_sign_with_signer,
+        bls12_381_g1_sha_256_pop_verify
+    );
+}
+
+#[test]
+fn sign_with_remote_signer() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    let signer = RemoteSigner(key_pair.secret_key.to_bytes());
+
+    assert_eq!(
+        bls12_381_g2_shake_256_aug_sign_with_signer(&signer, &TEST_MESSAGE),
+        bls12_381_g2_shake_256_aug_sign(&key_pair.secret_key, &TEST_MESSAGE)
+    );
+
+    let signature =
+        bls12_381_g2_shake_256_nul_sign_with_signer(&signer, &TEST_MESSAGE)
+            .expect("siging must succeed");
+    assert!(bls12_381_g2_shake_256_nul_verify(
+        &key_pair.public_key,
+        &TEST_MESSAGE,
+        &signature
+    )
+    .expect("signature verification must succeed"));
+
+    // The output of a faulty signer is rejected.
+    assert!(matches!(
+        bls12_381_g2_shake_256_nul_sign_with_signer(
+            &FaultySigner(key_pair.public_key),
+            &TEST_MESSAGE,
+        ),
+        Err(Error::CryptoOps { .. })
+    ));
+
+    // The minimal-signature-size ciphersuites use their own signer trait.
+    let min_sig_key_pair = MinSigKeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    assert_eq!(
+        MinSigBlsSigner::public_key(&min_sig_key_pair.secret_key),
+        Ok(min_sig_key_pair.public_key)
+    );
+    assert!(matches!(
+        bls12_381_g1_sha_256_nul_sign_with_signer(
+            &FaultyMinSigSigner(min_sig_key_pair.public_key),
+            &TEST_MESSAGE,
+        ),
+        Err(Error::CryptoOps { .. })
+    ));
+}
+
+#[test]
+fn sign_verify_e2e_cross_ciphersuite_rejected() {
+    let key_pair = MinSigKeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    let signature =
+        bls12_381_g1_sha_256_nul_sign(&key_pair.secret_key, &TEST_MESSAGE)
+            .expect("siging must succeed");
+    assert!(!bls12_381_g1_sha_256_aug_verify(
+        &key_pair.public_key,
+        &TEST_MESSAGE,
+        &signature
+    )
+    .expect("signature verification must succeed"));
+    assert!(!bls12_381_g1_sha_256_nul_verify(
+        &key_pair.public_key,
+        b"another-message",
+        &signature
+    )
+    .expect("signature verification must succeed"));
 }
 
 #[test]
@@ -63,3 +307,475 @@ fn pop_prove_verify_e2e_nominal() {
     )
     .expect("PoP verification must succeed"));
 }
+
+#[test]
+fn min_sig_pop_prove_verify_e2e_nominal() {
+    let key_pair = MinSigKeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    let proof = bls12_381_g1_sha_256_pop_pop_prove(&key_pair.secret_key)
+        .expect("PoP generation must succeed");
+    assert!(
+        bls12_381_g1_sha_256_pop_pop_verify(&key_pair.public_key, &proof)
+            .expect("PoP verification must succeed")
+    );
+}
+
+#[test]
+fn multi_sig_e2e_nominal() {
+    let key_pairs = (0..10u8)
+        .map(|i| {
+            KeyPair::new(TEST_KEY_GEN_SEED, &[TEST_KEY_INFO, &[i]].concat())
+                .expect("key generation must succeed")
+        })
+        .collect::<Vec<_>>();
+
+    let mut multi_sig = MultiSig::new();
+    for (i, key_pair) in key_pairs.iter().enumerate() {
+        let proof = bls12_381_g2_shake_256_pop_pop_prove(&key_pair.secret_key)
+            .expect("PoP generation must succeed");
+        assert_eq!(
+            multi_sig
+                .register(&key_pair.public_key, &proof)
+                .expect("registration must succeed"),
+            i
+        );
+    }
+    assert_eq!(multi_sig.len(), key_pairs.len());
+
+    let signers = [0, 3, 8, 9];
+    let signatures = signers
+        .iter()
+        .map(|&i| {
+            bls12_381_g2_shake_256_pop_sign(
+                &key_pairs[i].secret_key,
+                &TEST_MESSAGE,
+            )
+            .expect("siging must succeed")
+        })
+        .collect::<Vec<_>>();
+    let signature =
+        bls12_381_g2_shake_256_pop_aggregate_signatures(&signatures)
+            .expect("aggregation must succeed");
+    let bitmap = multi_sig
+        .signers_to_bitmap(&signers)
+        .expect("bitmap creation must succeed");
+    assert_eq!(bitmap, vec![0b0000_1001, 0b0000_0011]);
+
+    assert!(multi_sig
+        .verify(&TEST_MESSAGE, &signature, &bitmap)
+        .expect("signature verification must succeed"));
+
+    // Wrong signer set.
+    let bitmap = multi_sig
+        .signers_to_bitmap(&[0, 3, 8])
+        .expect("bitmap creation must succeed");
+    assert!(!multi_sig
+        .verify(&TEST_MESSAGE, &signature, &bitmap)
+        .expect("signature verification must succeed"));
+
+    // Wrong message.
+    let bitmap = multi_sig
+        .signers_to_bitmap(&signers)
+        .expect("bitmap creation must succeed");
+    assert!(!multi_sig
+        .verify(b"another-message", &signature, &bitmap)
+        .expect("signature verification must succeed"));
+}
+
+#[test]
+fn multi_sig_invalid_parameters() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    let other_key_pair =
+        KeyPair::new(TEST_KEY_GEN_SEED, b"another-test-key-info")
+            .expect("key generation must succeed");
+    let proof = bls12_381_g2_shake_256_pop_pop_prove(&key_pair.secret_key)
+        .expect("PoP generation must succeed");
+
+    let mut multi_sig = MultiSig::new();
+    // Proof of possession of another key.
+    assert!(multi_sig
+        .register(&other_key_pair.public_key, &proof)
+        .is_err());
+    assert!(multi_sig.is_empty());
+
+    multi_sig
+        .register(&key_pair.public_key, &proof)
+        .expect("registration must succeed");
+    // Duplicate registration.
+    assert!(multi_sig.register(&key_pair.public_key, &proof).is_err());
+
+    // Empty, oversized and out of range signer selections.
+    assert!(multi_sig.aggregate_public_key(&[0]).is_err());
+    assert!(multi_sig.aggregate_public_key(&[1, 0]).is_err());
+    assert!(multi_sig.aggregate_public_key(&[0b10]).is_err());
+    assert!(multi_sig.signers_to_bitmap(&[1]).is_err());
+    assert_eq!(
+        multi_sig
+            .aggregate_public_key(&[1])
+            .expect("aggregation must succeed"),
+        key_pair.public_key
+    );
+}
+
+#[test]
+fn vrf_prove_verify_e2e_nominal() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    let (output, proof) = vrf_prove(&key_pair.secret_key, &TEST_MESSAGE)
+        .expect("VRF evaluation must succeed");
+    assert_eq!(
+        vrf_verify(&key_pair.public_key, &TEST_MESSAGE, &proof)
+            .expect("VRF verification must succeed"),
+        output
+    );
+
+    // The VRF is deterministic.
+    assert_eq!(
+        vrf_prove(&key_pair.secret_key, &TEST_MESSAGE)
+            .expect("VRF evaluation must succeed"),
+        (output, proof)
+    );
+
+    // Different inputs give different outputs.
+    let (other_output, _) = vrf_prove(&key_pair.secret_key, b"another-input")
+        .expect("VRF evaluation must succeed");
+    assert_ne!(other_output, output);
+
+    // A proof for one input does not verify for another one.
+    let result = vrf_verify(&key_pair.public_key, b"another-input", &proof);
+    assert!(result.is_err());
+
+    // A VRF proof is not a valid signature of the `nul` ciphersuite.
+    assert!(!bls12_381_g2_shake_256_nul_verify(
+        &key_pair.public_key,
+        &TEST_MESSAGE,
+        &proof
+    )
+    .expect("signature verification must succeed"));
+}
+
+#[test]
+fn blind_sign_e2e_nominal() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    let (blinded_message, blinding_factor) =
+        blind(&TEST_MESSAGE, &mut OsRng).expect("blinding must succeed");
+    let blinded_signature = blind_sign(&key_pair.secret_key, &blinded_message)
+        .expect("blind signing must succeed");
+    let signature = unblind(&blinded_signature, &blinding_factor)
+        .expect("unblinding must succeed");
+
+    assert!(bls12_381_g2_shake_256_nul_verify(
+        &key_pair.public_key,
+        &TEST_MESSAGE,
+        &signature
+    )
+    .expect("signature verification must succeed"));
+    assert_eq!(
+        signature,
+        bls12_381_g2_shake_256_nul_sign(&key_pair.secret_key, &TEST_MESSAGE)
+            .expect("siging must succeed")
+    );
+
+    // Two blindings of the same message are unlinkable.
+    let (other_blinded_message, _) =
+        blind(&TEST_MESSAGE, &mut OsRng).expect("blinding must succeed");
+    assert_ne!(other_blinded_message, blinded_message);
+}
+
+#[test]
+fn blind_sign_invalid_blinded_message() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    // Identity point.
+    let mut identity = [0u8; 96];
+    identity[0] = 0xc0;
+    assert!(blind_sign(&key_pair.secret_key, &identity).is_err());
+
+    // Not a valid encoding.
+    assert!(blind_sign(&key_pair.secret_key, &[0u8; 96]).is_err());
+
+    // A point on the curve but outside of the prime order subgroup.
+    let (blinded_message, _) =
+        blind(&TEST_MESSAGE, &mut OsRng).expect("blinding must succeed");
+    let mut candidate = blinded_message;
+    let mut rejected = false;
+    for i in 0..=255u8 {
+        candidate[95] = i;
+        let point = G2Affine::from_compressed_unchecked(&candidate);
+        if point.is_some().unwrap_u8() == 1
+            && point.unwrap().is_torsion_free().unwrap_u8() == 0
+        {
+            assert!(blind_sign(&key_pair.secret_key, &candidate).is_err());
+            rejected = true;
+            break;
+        }
+    }
+    assert!(rejected);
+}
+
+const TEST_ROUND: u64 = 1000;
+const TEST_PLAINTEXT: &[u8] = b"sealed bid: 42";
+
+macro_rules! timelock_e2e_nominal {
+    ($sign_fn:ident, $encrypt_fn:ident, $decrypt_fn:ident) => {
+        // Local beacon key.
+        let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+            .expect("key generation must succeed");
+
+        let ciphertext = $encrypt_fn(
+            &key_pair.public_key,
+            TEST_ROUND,
+            TEST_PLAINTEXT,
+            &mut OsRng,
+        )
+        .expect("encryption must succeed");
+
+        let round_signature =
+            $sign_fn(&key_pair.secret_key, &round_message(TEST_ROUND))
+                .expect("siging must succeed");
+        assert_eq!(
+            $decrypt_fn(&round_signature, &ciphertext)
+                .expect("decryption must succeed"),
+            TEST_PLAINTEXT
+        );
+
+        // Signature of another round.
+        let other_round_signature =
+            $sign_fn(&key_pair.secret_key, &round_message(TEST_ROUND + 1))
+                .expect("siging must succeed");
+        assert!($decrypt_fn(&other_round_signature, &ciphertext).is_err());
+
+        // Tampered ciphertext.
+        let mut tampered_ciphertext = ciphertext.clone();
+        *tampered_ciphertext.last_mut().unwrap() ^= 1;
+        assert!($decrypt_fn(&round_signature, &tampered_ciphertext).is_err());
+
+        // Truncated ciphertext.
+        assert!($decrypt_fn(&round_signature, &ciphertext[..80]).is_err());
+    };
+}
+
+#[test]
+fn timelock_e2e_nominal() {
+    timelock_e2e_nominal!(
+        bls12_381_g2_shake_256_nul_sign,
+        bls12_381_g2_shake_256_nul_timelock_encrypt,
+        bls12_381_g2_shake_256_nul_timelock_decrypt
+    );
+
+    timelock_e2e_nominal!(
+        bls12_381_g2_shake_256_pop_sign,
+        bls12_381_g2_shake_256_pop_timelock_encrypt,
+        bls12_381_g2_shake_256_pop_timelock_decrypt
+    );
+}
+
+#[test]
+fn timelock_invalid_parameters() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    // Empty message.
+    assert!(bls12_381_g2_shake_256_nul_timelock_encrypt(
+        &key_pair.public_key,
+        TEST_ROUND,
+        b"",
+        &mut OsRng,
+    )
+    .is_err());
+
+    // Ciphertexts of one ciphersuite do not decrypt with the other one.
+    let ciphertext = bls12_381_g2_shake_256_nul_timelock_encrypt(
+        &key_pair.public_key,
+        TEST_ROUND,
+        TEST_PLAINTEXT,
+        &mut OsRng,
+    )
+    .expect("encryption must succeed");
+    let round_signature = bls12_381_g2_shake_256_pop_sign(
+        &key_pair.secret_key,
+        &round_message(TEST_ROUND),
+    )
+    .expect("siging must succeed");
+    assert!(bls12_381_g2_shake_256_pop_timelock_decrypt(
+        &round_signature,
+        &ciphertext
+    )
+    .is_err());
+}
+
+// RNG returning a constant byte, to get deterministic ciphertexts.
+struct FixedRng;
+
+impl rand_core::CryptoRng for FixedRng {}
+
+impl rand_core::RngCore for FixedRng {
+    fn next_u32(&mut self) -> u32 {
+        0x42424242
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        0x4242424242424242
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.fill(0x42)
+    }
+
+    fn try_fill_bytes(
+        &mut self,
+        dest: &mut [u8],
+    ) -> Result<(), rand_core::Error> {
+        dest.fill(0x42);
+        Ok(())
+    }
+}
+
+#[test]
+fn timelock_ciphertext_format() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    // Ciphertexts produced since timelock encryption was introduced must not
+    // change, or existing ciphertexts would no longer decrypt.
+    assert_eq!(
+        hex::encode(
+            bls12_381_g2_shake_256_nul_timelock_encrypt(
+                &key_pair.public_key,
+                TEST_ROUND,
+                TEST_MESSAGE,
+                &mut FixedRng,
+            )
+            .expect("encryption must succeed")
+        ),
+        "b959d8386057e5e8e252cf905897690db38b5f0b5c1d545c96d5bd9a3472ae2b5e40\
+         23cf2f2c90b4b2adfe57857d0ae7369247a6d967c6ab59758e1fb644214dde9e2f02\
+         e49910e5e882a0fb1be7c8617b30288d9babeaabfac89336"
+    );
+    assert_eq!(
+        hex::encode(
+            bls12_381_g2_shake_256_pop_timelock_encrypt(
+                &key_pair.public_key,
+                TEST_ROUND,
+                TEST_MESSAGE,
+                &mut FixedRng,
+            )
+            .expect("encryption must succeed")
+        ),
+        "a6c1a1dae83410fa2b11190eb371157c8e81399f5c6a4e349c0c076094c42e04190d\
+         8f6c1f1086a5171e12a9e057919ec51d982c3df716dfcced60c6bb15e27814a25bf7\
+         1e7324fdc247ceb64361a8f43341b5eeec7c56b6f9195e2f"
+    );
+}
+
+#[test]
+fn ciphersuite_dispatch_e2e_nominal() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    let min_sig_key_pair = MinSigKeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    for ciphersuite in BlsCiphersuite::ALL {
+        assert_eq!(
+            ciphersuite.as_str().parse::<BlsCiphersuite>(),
+            Ok(ciphersuite)
+        );
+        assert_eq!(
+            BlsCiphersuite::from_octets(ciphersuite.as_octets()),
+            Ok(ciphersuite)
+        );
+
+        let (secret_key, public_key) =
+            if ciphersuite.as_str().starts_with("BLS_SIG_BLS12381G2") {
+                (
+                    key_pair.secret_key.to_bytes(),
+                    key_pair.public_key.to_octets().to_vec(),
+                )
+            } else {
+                (
+                    min_sig_key_pair.secret_key.to_bytes(),
+                    min_sig_key_pair.public_key.to_octets().to_vec(),
+                )
+            };
+
+        let signature = ciphersuite
+            .sign(&secret_key, TEST_MESSAGE)
+            .expect("siging must succeed");
+        assert!(ciphersuite
+            .verify(&public_key, TEST_MESSAGE, &signature)
+            .expect("signature verification must succeed"));
+        assert!(!ciphersuite
+            .verify(&public_key, b"another-message", &signature)
+            .expect("signature verification must succeed"));
+    }
+
+    assert_eq!(
+        BlsCiphersuite::Bls12381G2Sha256Nul
+            .sign(&key_pair.secret_key.to_bytes(), TEST_MESSAGE)
+            .expect("siging must succeed"),
+        bls12_381_g2_shake_256_nul_sign(&key_pair.secret_key, TEST_MESSAGE)
+            .expect("siging must succeed")
+            .to_vec()
+    );
+    assert!("BLS_SIG_UNKNOWN_".parse::<BlsCiphersuite>().is_err());
+}
+
+#[test]
+fn typed_signature_e2e_nominal() {
+    let key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    let signature = BlsSignature::from_octets(
+        &bls12_381_g2_shake_256_pop_sign(&key_pair.secret_key, &TEST_MESSAGE)
+            .expect("siging must succeed"),
+    )
+    .expect("signature parsing must succeed");
+
+    // Typed values are accepted in place of octets.
+    assert_eq!(
+        bls12_381_g2_shake_256_pop_verify(
+            &key_pair.public_key,
+            &TEST_MESSAGE,
+            &signature
+        ),
+        Ok(true)
+    );
+
+    let json =
+        serde_json::to_string(&signature).expect("serialization must succeed");
+    assert_eq!(
+        serde_json::from_str::<BlsSignature>(&json)
+            .expect("deserialization must succeed"),
+        signature
+    );
+    assert!(
+        BlsSignature::from_octets(&[0u8; BlsSignature::SIZE_BYTES]).is_err()
+    );
+
+    let min_sig_key_pair = MinSigKeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    let signature = bls12_381_g1_sha_256_pop_sign(
+        &min_sig_key_pair.secret_key,
+        &TEST_MESSAGE,
+    )
+    .expect("siging must succeed");
+    let signature = MinSigBlsSignature::try_from(&signature[..])
+        .expect("signature parsing must succeed");
+    assert_eq!(
+        bls12_381_g1_sha_256_pop_verify(
+            &min_sig_key_pair.public_key,
+            &TEST_MESSAGE,
+            &signature
+        ),
+        Ok(true)
+    );
+
+    // A signature of the other group is rejected.
+    assert!(serde_json::from_str::<MinSigBlsSignature>(&json).is_err());
+}
@@ -0,0 +1,314 @@
+use blstrs::Scalar;
+use ff::Field;
+use pairing_crypto::dkg::{DkgParameters, ParticipantIndex};
+use rand_core::OsRng;
+
+const TEST_THRESHOLD: u32 = 3;
+const TEST_PARTICIPANTS: u32 = 5;
+
+// Lagrange interpolation at zero of the shares held by `indices`.
+fn interpolate_at_zero(
+    indices: &[ParticipantIndex],
+    shares: &[Scalar],
+) -> Scalar {
+    indices
+        .iter()
+        .zip(shares.iter())
+        .fold(Scalar::zero(), |acc, (i, share)| {
+            let xi = Scalar::from(*i as u64);
+            let lambda = indices.iter().filter(|j| *j != i).fold(
+                Scalar::one(),
+                |lambda, j| {
+                    let xj = Scalar::from(*j as u64);
+                    lambda * xj * (xj - xi).invert().unwrap()
+                },
+            );
+            acc + lambda * share
+        })
+}
+
+// Run all the DKG rounds in memory. `tamper` may alter the private shares
+// before delivery and `withhold_responses_of` silences a dealer in round 3.
+macro_rules! run_dkg {
+    ($dkg:ident, $tamper:expr, $withhold_responses_of:expr) => {{
+        let parameters =
+            DkgParameters::new(TEST_THRESHOLD, TEST_PARTICIPANTS).unwrap();
+        let mut participants = (1..=TEST_PARTICIPANTS)
+            .map(|i| {
+                pairing_crypto::dkg::$dkg::Participant::new(
+                    i, parameters, &mut OsRng,
+                )
+                .expect("participant creation failed")
+            })
+            .collect::<Vec<_>>();
+
+        let mut commitments = vec![];
+        let mut shares = vec![];
+        for p in participants.iter_mut() {
+            let (commitment, dealt_shares) = p.deal();
+            commitments.push(commitment);
+            shares.extend(dealt_shares);
+        }
+        ($tamper)(&mut shares);
+
+        let mut complaints = vec![];
+        for p in participants.iter_mut() {
+            let received = shares
+                .iter()
+                .filter(|s| s.recipient == p.index())
+                .cloned()
+                .collect::<Vec<_>>();
+            complaints.extend(
+                p.process_shares(&commitments, &received)
+                    .expect("round 2 failed"),
+            );
+        }
+
+        let mut responses = vec![];
+        for p in participants.iter_mut() {
+            let r = p.process_complaints(&complaints).expect("round 3 failed");
+            if Some(p.index()) != $withhold_responses_of {
+                responses.extend(r);
+            }
+        }
+
+        for p in participants.iter_mut() {
+            p.process_complaint_responses(&responses)
+                .expect("round 4 failed");
+        }
+
+        (
+            complaints,
+            participants
+                .iter()
+                .map(|p| p.finalize().expect("finalization failed"))
+                .collect::<Vec<_>>(),
+        )
+    }};
+}
+
+macro_rules! check_outputs {
+    ($dkg:ident, $outputs:expr, $expected_qualified:expr) => {
+        let outputs = $outputs;
+        let group_public_key = outputs[0].public_key;
+        for output in &outputs {
+            assert_eq!(output.qualified, $expected_qualified);
+            assert_eq!(output.public_key, group_public_key);
+            assert_eq!(
+                output.share_public_keys[&output.index],
+                pairing_crypto::dkg::$dkg::PublicKey::from(&output.secret_key)
+            );
+        }
+
+        // Any `threshold` key shares recover the group secret key.
+        for indices in [[1, 2, 3], [1, 3, 5], [2, 4, 5]] {
+            let shares = indices
+                .iter()
+                .map(|i| *outputs[*i as usize - 1].secret_key.0)
+                .collect::<Vec<Scalar>>();
+            let secret_key = pairing_crypto::dkg::$dkg::SecretKey(Box::new(
+                interpolate_at_zero(&indices, &shares),
+            ));
+            assert_eq!(
+                pairing_crypto::dkg::$dkg::PublicKey::from(&secret_key),
+                group_public_key
+            );
+        }
+    };
+}
+
+#[test]
+fn dkg_nominal() {
+    let (complaints, outputs) = run_dkg!(bls, |_| {}, None);
+    assert!(complaints.is_empty());
+    check_outputs!(bls, outputs, vec![1, 2, 3, 4, 5]);
+
+    let (complaints, outputs) = run_dkg!(bbs, |_| {}, None);
+    assert!(complaints.is_empty());
+    check_outputs!(bbs, outputs, vec![1, 2, 3, 4, 5]);
+}
+
+// Dealer 2 sends participant 4 the share meant for participant 5.
+macro_rules! corrupt_share_of_dealer_2 {
+    ($dkg:ident) => {
+        |shares: &mut Vec<pairing_crypto::dkg::$dkg::SecretShare>| {
+            let mut wrong_share = shares
+                .iter()
+                .find(|s| s.dealer == 2 && s.recipient == 5)
+                .unwrap()
+                .clone();
+            wrong_share.recipient = 4;
+            shares.retain(|s| !(s.dealer == 2 && s.recipient == 4));
+            shares.push(wrong_share);
+        }
+    };
+}
+
+#[test]
+fn dkg_complaint_answered() {
+    let (complaints, outputs) =
+        run_dkg!(bls, corrupt_share_of_dealer_2!(bls), None);
+    assert_eq!(complaints.len(), 1);
+    assert_eq!((complaints[0].complainer, complaints[0].dealer), (4, 2));
+    check_outputs!(bls, outputs, vec![1, 2, 3, 4, 5]);
+
+    let (complaints, outputs) =
+        run_dkg!(bbs, corrupt_share_of_dealer_2!(bbs), None);
+    assert_eq!(complaints.len(), 1);
+    check_outputs!(bbs, outputs, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn dkg_cheating_dealer_disqualified() {
+    let (complaints, outputs) =
+        run_dkg!(bls, corrupt_share_of_dealer_2!(bls), Some(2));
+    assert_eq!(complaints.len(), 1);
+    check_outputs!(bls, outputs, vec![1, 3, 4, 5]);
+
+    // Dealer 2 withholding the share of participant 1 altogether.
+    let (complaints, outputs) = run_dkg!(
+        bbs,
+        |shares: &mut Vec<pairing_crypto::dkg::bbs::SecretShare>| {
+            shares.retain(|s| !(s.dealer == 2 && s.recipient == 1))
+        },
+        Some(2)
+    );
+    assert_eq!((complaints[0].complainer, complaints[0].dealer), (1, 2));
+    check_outputs!(bbs, outputs, vec![1, 3, 4, 5]);
+}
+
+// Run all the DKG rounds in memory with dealer 3 misbehaving in every round:
+// it broadcasts commitments under an unknown index, sends participant 1 the
+// share addressed to participant 2, and complains about itself. The honest
+// participants are expected to carry on and disqualify dealer 3.
+macro_rules! run_dkg_with_bad_dealer {
+    ($dkg:ident) => {{
+        let parameters =
+            DkgParameters::new(TEST_THRESHOLD, TEST_PARTICIPANTS).unwrap();
+        let mut participants = (1..=TEST_PARTICIPANTS)
+            .map(|i| {
+                pairing_crypto::dkg::$dkg::Participant::new(
+                    i, parameters, &mut OsRng,
+                )
+                .expect("participant creation failed")
+            })
+            .collect::<Vec<_>>();
+
+        let mut commitments = vec![];
+        let mut shares = vec![];
+        for p in participants.iter_mut() {
+            let (commitment, dealt_shares) = p.deal();
+            if commitment.dealer == 3 {
+                let mut unknown_dealer = commitment.clone();
+                unknown_dealer.dealer = TEST_PARTICIPANTS + 1;
+                commitments.push(unknown_dealer);
+            }
+            commitments.push(commitment);
+            shares.extend(dealt_shares);
+        }
+
+        let mut complaints = vec![];
+        for p in participants.iter_mut() {
+            let received = shares
+                .iter()
+                .filter(|s| {
+                    if s.dealer == 3 && p.index() == 1 {
+                        s.recipient == 2
+                    } else {
+                        s.recipient == p.index()
+                    }
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            complaints.extend(
+                p.process_shares(&commitments, &received)
+                    .expect("round 2 failed"),
+            );
+        }
+        complaints.push(pairing_crypto::dkg::$dkg::Complaint {
+            complainer: 3,
+            dealer: 3,
+        });
+        complaints.push(pairing_crypto::dkg::$dkg::Complaint {
+            complainer: 0,
+            dealer: 3,
+        });
+
+        let mut responses = vec![];
+        for p in participants.iter_mut() {
+            responses.extend(
+                p.process_complaints(&complaints).expect("round 3 failed"),
+            );
+        }
+
+        for p in participants.iter_mut() {
+            p.process_complaint_responses(&responses)
+                .expect("round 4 failed");
+            assert_eq!(p.disqualified(), vec![3]);
+        }
+
+        (
+            complaints,
+            participants
+                .iter()
+                .map(|p| p.finalize().expect("finalization failed"))
+                .collect::<Vec<_>>(),
+        )
+    }};
+}
+
+#[test]
+fn dkg_bad_dealer_disqualified() {
+    let (complaints, outputs) = run_dkg_with_bad_dealer!(bls);
+    assert_eq!((complaints[0].complainer, complaints[0].dealer), (1, 3));
+    check_outputs!(bls, outputs, vec![1, 2, 4, 5]);
+
+    let (complaints, outputs) = run_dkg_with_bad_dealer!(bbs);
+    assert_eq!((complaints[0].complainer, complaints[0].dealer), (1, 3));
+    check_outputs!(bbs, outputs, vec![1, 2, 4, 5]);
+}
+
+#[test]
+fn dkg_invalid_parameters() {
+    assert!(DkgParameters::new(0, 5).is_err());
+    assert!(DkgParameters::new(6, 5).is_err());
+
+    let parameters = DkgParameters::new(2, 3).unwrap();
+    assert!(pairing_crypto::dkg::bls::Participant::new(
+        0, parameters, &mut OsRng
+    )
+    .is_err());
+    assert!(pairing_crypto::dkg::bbs::Participant::new(
+        4, parameters, &mut OsRng
+    )
+    .is_err());
+}
+
+#[test]
+fn dkg_messages_serde() {
+    let parameters = DkgParameters::new(2, 3).unwrap();
+    let mut participant =
+        pairing_crypto::dkg::bbs::Participant::new(1, parameters, &mut OsRng)
+            .unwrap();
+    let (commitment, shares) = participant.deal();
+
+    let serialized = serde_json::to_string(&commitment).unwrap();
+    assert_eq!(
+        serde_json::from_str::<pairing_crypto::dkg::bbs::DealerCommitment>(
+            &serialized
+        )
+        .unwrap(),
+        commitment
+    );
+
+    let serialized = serde_json::to_string(&shares[0]).unwrap();
+    let deserialized = serde_json::from_str::<
+        pairing_crypto::dkg::bbs::SecretShare,
+    >(&serialized)
+    .unwrap();
+    assert_eq!(
+        (deserialized.dealer, deserialized.recipient),
+        (shares[0].dealer, shares[0].recipient)
+    );
+    assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+}
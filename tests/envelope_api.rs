@@ -0,0 +1,242 @@
+use pairing_crypto::{
+    bbs::{
+        ciphersuites::{bls12_381::KeyPair as BbsKeyPair, BbsCiphersuite},
+        BbsProofGenRequest,
+        BbsProofGenRevealMessageRequest,
+        BbsSignRequest,
+    },
+    bls::ciphersuites::{
+        bls12_381::KeyPair as BlsKeyPair,
+        bls12_381_g1::KeyPair as MinSigBlsKeyPair,
+        BlsCiphersuite,
+    },
+    envelope::{Envelope, EnvelopeCiphersuite, EnvelopeKind, ENVELOPE_VERSION},
+    Error,
+};
+
+const KEY_GEN_SEED: &[u8; 32] = b"not_A_random_seed_at_Allllllllll";
+const KEY_INFO: &[u8; 16] = b"test-key-info-00";
+const TEST_HEADER: &[u8] = b"some_app_context";
+const TEST_MESSAGES: [&[u8]; 3] = [b"message-0", b"message-1", b"message-2"];
+
+#[test]
+fn bbs_envelope_e2e_nominal() {
+    let key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, KEY_INFO).expect("key generation failed");
+    let secret_key = key_pair.secret_key.to_bytes();
+    let public_key = key_pair.public_key.to_octets();
+    let messages: &[&[u8]] = &TEST_MESSAGES;
+
+    for ciphersuite in BbsCiphersuite::ALL {
+        let signature = ciphersuite
+            .sign(&BbsSignRequest {
+                secret_key: &secret_key,
+                public_key: &public_key,
+                header: Some(TEST_HEADER),
+                messages: Some(messages),
+            })
+            .expect("signing failed");
+
+        let envelope = Envelope::new(
+            EnvelopeCiphersuite::Bbs(ciphersuite),
+            EnvelopeKind::Signature,
+            signature,
+        )
+        .expect("envelope creation failed");
+        let octets = envelope.to_octets();
+        let id = ciphersuite.as_octets();
+        assert_eq!(octets[..4], [ENVELOPE_VERSION, 1, 1, id.len() as u8]);
+        assert_eq!(&octets[4..4 + id.len()], id);
+        assert_eq!(&octets[4 + id.len()..], signature);
+
+        let decoded =
+            Envelope::from_octets(&octets).expect("envelope parsing failed");
+        assert_eq!(decoded, envelope);
+        assert_eq!(
+            decoded.verify_bbs_signature(
+                &public_key,
+                Some(TEST_HEADER),
+                Some(messages),
+            ),
+            Ok(true)
+        );
+        assert_eq!(
+            decoded.verify_bbs_signature(&public_key, None, Some(messages),),
+            Ok(false)
+        );
+        assert!(matches!(
+            decoded.verify_bbs_proof(
+                &public_key,
+                Some(TEST_HEADER),
+                None,
+                None,
+            ),
+            Err(Error::BadParams { .. })
+        ));
+
+        let proof_messages: Vec<BbsProofGenRevealMessageRequest<_>> = messages
+            .iter()
+            .enumerate()
+            .map(|(i, value)| BbsProofGenRevealMessageRequest {
+                reveal: i == 0,
+                value: *value,
+            })
+            .collect();
+        let proof = ciphersuite
+            .proof_gen(&BbsProofGenRequest {
+                public_key: &public_key,
+                header: Some(TEST_HEADER),
+                messages: Some(&proof_messages),
+                signature: &signature,
+                presentation_header: None,
+                verify_signature: None,
+            })
+            .expect("proof generation failed");
+
+        let decoded = Envelope::from_octets(
+            &Envelope::new(
+                EnvelopeCiphersuite::Bbs(ciphersuite),
+                EnvelopeKind::Proof,
+                &proof,
+            )
+            .expect("envelope creation failed")
+            .to_octets(),
+        )
+        .expect("envelope parsing failed");
+        assert_eq!(decoded.kind(), EnvelopeKind::Proof);
+        assert_eq!(decoded.payload(), proof);
+        assert_eq!(
+            decoded.verify_bbs_proof(
+                &public_key,
+                Some(TEST_HEADER),
+                None,
+                Some(&[(0, messages[0])]),
+            ),
+            Ok(true)
+        );
+
+        // The proof layout of the draft-06 ciphersuites differs from the one
+        // of the other ciphersuites.
+        let other_ciphersuite = match ciphersuite {
+            BbsCiphersuite::Bls12381G1Sha256 => {
+                BbsCiphersuite::Bls12381G1Sha256Draft06
+            }
+            BbsCiphersuite::Bls12381G1Shake256 => {
+                BbsCiphersuite::Bls12381G1Shake256Draft06
+            }
+            BbsCiphersuite::Bls12381G1Sha256Draft06 => {
+                BbsCiphersuite::Bls12381G1Sha256
+            }
+            BbsCiphersuite::Bls12381G1Shake256Draft06 => {
+                BbsCiphersuite::Bls12381G1Shake256
+            }
+        };
+        assert!(Envelope::new(
+            EnvelopeCiphersuite::Bbs(other_ciphersuite),
+            EnvelopeKind::Proof,
+            &proof,
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn bls_envelope_e2e_nominal() {
+    let key_pair =
+        BlsKeyPair::new(KEY_GEN_SEED, KEY_INFO).expect("key generation failed");
+    let min_sig_key_pair = MinSigBlsKeyPair::new(KEY_GEN_SEED, KEY_INFO)
+        .expect("key generation failed");
+
+    for ciphersuite in BlsCiphersuite::ALL {
+        let (secret_key, public_key) =
+            if ciphersuite.as_str().starts_with("BLS_SIG_BLS12381G2") {
+                (
+                    key_pair.secret_key.to_bytes(),
+                    key_pair.public_key.to_octets().to_vec(),
+                )
+            } else {
+                (
+                    min_sig_key_pair.secret_key.to_bytes(),
+                    min_sig_key_pair.public_key.to_octets().to_vec(),
+                )
+            };
+        let signature = ciphersuite
+            .sign(&secret_key, TEST_MESSAGES[0])
+            .expect("signing failed");
+
+        let envelope = Envelope::new(
+            EnvelopeCiphersuite::Bls(ciphersuite),
+            EnvelopeKind::Signature,
+            &signature,
+        )
+        .expect("envelope creation failed");
+        let decoded = Envelope::from_octets(&envelope.to_octets())
+            .expect("envelope parsing failed");
+        assert_eq!(
+            decoded.ciphersuite(),
+            EnvelopeCiphersuite::Bls(ciphersuite)
+        );
+        assert_eq!(
+            decoded.verify_bls_signature(&public_key, TEST_MESSAGES[0]),
+            Ok(true)
+        );
+        assert_eq!(
+            decoded.verify_bls_signature(&public_key, TEST_MESSAGES[1]),
+            Ok(false)
+        );
+
+        assert!(Envelope::new(
+            EnvelopeCiphersuite::Bls(ciphersuite),
+            EnvelopeKind::Proof,
+            &signature,
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn envelope_invalid_octets() {
+    let key_pair =
+        BlsKeyPair::new(KEY_GEN_SEED, KEY_INFO).expect("key generation failed");
+    let ciphersuite = BlsCiphersuite::Bls12381G2Sha256Nul;
+    let signature = ciphersuite
+        .sign(&key_pair.secret_key.to_bytes(), TEST_MESSAGES[0])
+        .expect("signing failed");
+    let octets = Envelope::new(
+        EnvelopeCiphersuite::Bls(ciphersuite),
+        EnvelopeKind::Signature,
+        &signature,
+    )
+    .expect("envelope creation failed")
+    .to_octets();
+    let id_len = ciphersuite.as_octets().len();
+
+    // Truncated header or ciphersuite identifier.
+    assert_eq!(Envelope::from_octets(&octets[..3]), Err(Error::BadEncoding));
+    assert_eq!(
+        Envelope::from_octets(&octets[..4 + id_len - 1]),
+        Err(Error::BadEncoding)
+    );
+
+    // Unsupported version, unknown scheme, kind or ciphersuite.
+    for (index, value) in [(0, ENVELOPE_VERSION + 1), (1, 0), (1, 1), (2, 3)] {
+        let mut invalid = octets.clone();
+        invalid[index] = value;
+        assert!(matches!(
+            Envelope::from_octets(&invalid),
+            Err(Error::BadParams { .. })
+        ));
+    }
+    let mut invalid = octets.clone();
+    invalid[4] ^= 1;
+    assert!(matches!(
+        Envelope::from_octets(&invalid),
+        Err(Error::BadParams { .. })
+    ));
+
+    // Truncated or invalid payload.
+    assert!(Envelope::from_octets(&octets[..octets.len() - 1]).is_err());
+    let mut invalid = octets[..4 + id_len].to_vec();
+    invalid.extend_from_slice(&[0u8; 96]);
+    assert!(Envelope::from_octets(&invalid).is_err());
+}
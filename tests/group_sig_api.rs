@@ -0,0 +1,165 @@
+use pairing_crypto::{
+    group_sig::ciphersuites::{
+        bls12_381::{
+            GroupKeys,
+            GroupPublicKey,
+            IssuerSecretKey,
+            MemberSecretKey,
+            OpenerSecretKey,
+            RevocationList,
+            GROUP_SIG_BBS04_BLS12381_REVOCATION_ENTRY_LENGTH,
+        },
+        bls12_381_sha_256::{open, sign, verify},
+    },
+    Error,
+};
+use rand_core::OsRng;
+
+const TEST_MESSAGE: &[u8] = b"test-message";
+
+#[test]
+fn sign_verify_open_e2e_nominal() {
+    let group = GroupKeys::random(&mut OsRng).expect("group setup failed");
+    let gpk = group.public_key;
+    let members = (0..3)
+        .map(|_| group.issuer_key.issue(&gpk, &mut OsRng))
+        .collect::<Result<Vec<_>, _>>()
+        .expect("member key issuance failed");
+
+    for member in &members {
+        assert!(member.verify(&gpk));
+        let signature = sign(&gpk, member, TEST_MESSAGE, &mut OsRng)
+            .expect("signing failed");
+        assert!(verify(&gpk, TEST_MESSAGE, &signature)
+            .expect("error during signature verification"));
+        assert!(!verify(&gpk, b"another-message", &signature)
+            .expect("error during signature verification"));
+
+        // Two signatures of the same member are unlinkable
+        let other_signature = sign(&gpk, member, TEST_MESSAGE, &mut OsRng)
+            .expect("signing failed");
+        assert_ne!(signature, other_signature);
+
+        let tag = open(&gpk, &group.opener_key, TEST_MESSAGE, &signature)
+            .expect("opening failed");
+        assert_eq!(tag, member.tag());
+        assert_eq!(members.iter().filter(|m| m.tag() == tag).count(), 1usize);
+    }
+}
+
+#[test]
+fn key_serialization() {
+    let group = GroupKeys::random(&mut OsRng).expect("group setup failed");
+    let member = group
+        .issuer_key
+        .issue(&group.public_key, &mut OsRng)
+        .expect("member key issuance failed");
+
+    assert_eq!(
+        GroupPublicKey::from_octets(&group.public_key.to_octets()),
+        Ok(group.public_key)
+    );
+    assert_eq!(
+        IssuerSecretKey::from_bytes(&group.issuer_key.to_bytes()),
+        Ok(group.issuer_key.clone())
+    );
+    assert_eq!(
+        OpenerSecretKey::from_bytes(&group.opener_key.to_bytes()),
+        Ok(group.opener_key.clone())
+    );
+    assert_eq!(
+        MemberSecretKey::from_octets(&member.to_octets()),
+        Ok(member.clone())
+    );
+}
+
+#[test]
+fn revocation_e2e_nominal() {
+    let group = GroupKeys::random(&mut OsRng).expect("group setup failed");
+    let gpk = group.public_key;
+    let members = (0..3)
+        .map(|_| group.issuer_key.issue(&gpk, &mut OsRng))
+        .collect::<Result<Vec<_>, _>>()
+        .expect("member key issuance failed");
+
+    let mut revocation_list = RevocationList::new();
+    assert!(revocation_list.is_empty());
+    revocation_list
+        .revoke(&group.issuer_key, &gpk, &members[0])
+        .expect("revocation failed");
+    revocation_list
+        .revoke(&group.issuer_key, &gpk, &members[1])
+        .expect("revocation failed");
+    assert_eq!(revocation_list.len(), 2);
+    assert!(revocation_list
+        .revoke(&group.issuer_key, &gpk, &members[1])
+        .is_err());
+
+    let octets = revocation_list.to_octets();
+    assert_eq!(
+        octets.len(),
+        2 * GROUP_SIG_BBS04_BLS12381_REVOCATION_ENTRY_LENGTH
+    );
+    assert_eq!(
+        RevocationList::from_octets(&octets),
+        Ok(revocation_list.clone())
+    );
+
+    let current_gpk = revocation_list.public_key(&gpk);
+    assert_ne!(current_gpk, gpk);
+
+    // Revoked members can not update their keys
+    for member in &members[..2] {
+        assert!(revocation_list.update_member_key(member).is_err());
+        // Nor sign with their outdated keys
+        assert!(sign(&current_gpk, member, TEST_MESSAGE, &mut OsRng).is_err());
+    }
+
+    // Remaining members update their keys and keep signing
+    let member = revocation_list
+        .update_member_key(&members[2])
+        .expect("member key update failed");
+    assert!(member.verify(&current_gpk));
+    let signature = sign(&current_gpk, &member, TEST_MESSAGE, &mut OsRng)
+        .expect("signing failed");
+    assert!(verify(&current_gpk, TEST_MESSAGE, &signature)
+        .expect("error during signature verification"));
+    assert!(!verify(&gpk, TEST_MESSAGE, &signature)
+        .expect("error during signature verification"));
+    let tag = open(&current_gpk, &group.opener_key, TEST_MESSAGE, &signature)
+        .expect("opening failed");
+    assert_eq!(tag, member.tag());
+
+    // Signatures made before the revocation do not verify anymore
+    let signature = sign(&gpk, &members[0], TEST_MESSAGE, &mut OsRng)
+        .expect("signing failed");
+    assert!(!verify(&current_gpk, TEST_MESSAGE, &signature)
+        .expect("error during signature verification"));
+}
+
+#[test]
+fn open_invalid_signature() {
+    let group = GroupKeys::random(&mut OsRng).expect("group setup failed");
+    let gpk = group.public_key;
+    let member = group
+        .issuer_key
+        .issue(&gpk, &mut OsRng)
+        .expect("member key issuance failed");
+    let signature =
+        sign(&gpk, &member, TEST_MESSAGE, &mut OsRng).expect("signing failed");
+
+    assert_eq!(
+        open(&gpk, &group.opener_key, b"another-message", &signature),
+        Err(Error::SignatureVerification)
+    );
+
+    // Key of another group
+    let other_group =
+        GroupKeys::random(&mut OsRng).expect("group setup failed");
+    assert!(!verify(&other_group.public_key, TEST_MESSAGE, &signature)
+        .expect("error during signature verification"));
+    assert!(
+        sign(&other_group.public_key, &member, TEST_MESSAGE, &mut OsRng)
+            .is_err()
+    );
+}
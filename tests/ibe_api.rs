@@ -0,0 +1,137 @@
+use pairing_crypto::ibe::ciphersuites::{
+    bls12_381::{IdentitySecretKey, KeyPair},
+    bls12_381_g2_sha_256::{decrypt, encrypt, extract, verify_identity_key},
+};
+use rand_core::OsRng;
+
+const TEST_KEY_GEN_SEED: &[u8] = b"not_A_random_seed_at_Allllllllll";
+const TEST_KEY_INFO: &[u8] = b"test-key-info";
+const TEST_IDENTITY: &[u8] = b"alice@example.com";
+const TEST_MESSAGE: &[u8] = b"test-message";
+
+#[test]
+fn encrypt_decrypt_e2e_nominal() {
+    let master_key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    let identity_key = extract(&master_key_pair.secret_key, TEST_IDENTITY)
+        .expect("identity key extraction must succeed");
+    assert!(verify_identity_key(
+        &master_key_pair.public_key,
+        TEST_IDENTITY,
+        &identity_key
+    )
+    .expect("identity key verification must succeed"));
+
+    let ciphertext = encrypt(
+        &master_key_pair.public_key,
+        TEST_IDENTITY,
+        TEST_MESSAGE,
+        &mut OsRng,
+    )
+    .expect("encryption must succeed");
+    assert_eq!(
+        decrypt(&identity_key, &ciphertext).expect("decryption must succeed"),
+        TEST_MESSAGE
+    );
+
+    // Encryption is randomized.
+    assert_ne!(
+        encrypt(
+            &master_key_pair.public_key,
+            TEST_IDENTITY,
+            TEST_MESSAGE,
+            &mut OsRng,
+        )
+        .expect("encryption must succeed"),
+        ciphertext
+    );
+
+    // Identity keys survive serialization.
+    let identity_key =
+        IdentitySecretKey::from_octets(&identity_key.to_octets())
+            .expect("identity key deserialization must succeed");
+    assert_eq!(
+        decrypt(&identity_key, &ciphertext).expect("decryption must succeed"),
+        TEST_MESSAGE
+    );
+}
+
+#[test]
+fn decrypt_with_wrong_identity_key() {
+    let master_key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    let ciphertext = encrypt(
+        &master_key_pair.public_key,
+        TEST_IDENTITY,
+        TEST_MESSAGE,
+        &mut OsRng,
+    )
+    .expect("encryption must succeed");
+
+    let other_identity_key =
+        extract(&master_key_pair.secret_key, b"bob@example.com")
+            .expect("identity key extraction must succeed");
+    assert!(decrypt(&other_identity_key, &ciphertext).is_err());
+    assert!(!verify_identity_key(
+        &master_key_pair.public_key,
+        TEST_IDENTITY,
+        &other_identity_key
+    )
+    .expect("identity key verification must succeed"));
+
+    // Identity key of another private key generator.
+    let other_master_key_pair =
+        KeyPair::new(TEST_KEY_GEN_SEED, b"another-test-key-info")
+            .expect("key generation must succeed");
+    let other_identity_key =
+        extract(&other_master_key_pair.secret_key, TEST_IDENTITY)
+            .expect("identity key extraction must succeed");
+    assert!(decrypt(&other_identity_key, &ciphertext).is_err());
+}
+
+#[test]
+fn decrypt_tampered_ciphertext() {
+    let master_key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+    let identity_key = extract(&master_key_pair.secret_key, TEST_IDENTITY)
+        .expect("identity key extraction must succeed");
+    let ciphertext = encrypt(
+        &master_key_pair.public_key,
+        TEST_IDENTITY,
+        TEST_MESSAGE,
+        &mut OsRng,
+    )
+    .expect("encryption must succeed");
+
+    // Flip one bit of each of the `U`, `V` and `W` components.
+    for i in [47, 48 + 31, ciphertext.len() - 1] {
+        let mut tampered_ciphertext = ciphertext.clone();
+        tampered_ciphertext[i] ^= 1;
+        assert!(decrypt(&identity_key, &tampered_ciphertext).is_err());
+    }
+    assert!(decrypt(&identity_key, &ciphertext[..80]).is_err());
+}
+
+#[test]
+fn invalid_parameters() {
+    let master_key_pair = KeyPair::new(TEST_KEY_GEN_SEED, TEST_KEY_INFO)
+        .expect("key generation must succeed");
+
+    assert!(extract(&master_key_pair.secret_key, b"").is_err());
+    assert!(encrypt(
+        &master_key_pair.public_key,
+        b"",
+        TEST_MESSAGE,
+        &mut OsRng
+    )
+    .is_err());
+    assert!(encrypt(
+        &master_key_pair.public_key,
+        TEST_IDENTITY,
+        b"",
+        &mut OsRng
+    )
+    .is_err());
+    assert!(IdentitySecretKey::from_octets(&[0u8; 96]).is_err());
+}
@@ -0,0 +1,133 @@
+use pairing_crypto::{
+    bbs::ciphersuites::bls12_381::SecretKey as BbsSecretKey,
+    bls::ciphersuites::{
+        bls12_381::SecretKey as BlsSecretKey,
+        bls12_381_g1::SecretKey as BlsMinSigSecretKey,
+    },
+    Error,
+};
+
+struct TestVector {
+    seed: &'static str,
+    master_sk: &'static str,
+    child_index: u32,
+    child_sk: &'static str,
+}
+
+// Test vectors of <https://eips.ethereum.org/EIPS/eip-2333#test-cases>, with
+// the secret keys converted from decimal to big-endian hex.
+const EIP_2333_TEST_VECTORS: [TestVector; 4] = [
+    TestVector {
+        seed: "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+        master_sk: "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070",
+        child_index: 0,
+        child_sk: "2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e",
+    },
+    TestVector {
+        seed: "3141592653589793238462643383279502884197169399375105820974944592",
+        master_sk: "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7",
+        child_index: 3141592653,
+        child_sk: "384843fad5f3d777ea39de3e47a8f999ae91f89e42bffa993d91d9782d152a0f",
+    },
+    TestVector {
+        seed: "0099ff991111002299dd7744ee3355bbdd8844115566cc55663355668888cc00",
+        master_sk: "3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80",
+        child_index: 4294967295,
+        child_sk: "40e86285582f35b28821340f6a53b448588efa575bc4d88c32ef8567b8d9479b",
+    },
+    TestVector {
+        seed: "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+        master_sk: "2a0e28ffa5fbbe2f8e7aad4ed94f745d6bf755c51182e119bb1694fe61d3afca",
+        child_index: 42,
+        child_sk: "455c0dc9fccb3395825d92a60d2672d69416be1c2578a87a7a3d3ced11ebb88d",
+    },
+];
+
+macro_rules! eip_2333_test_vectors {
+    ($secret_key_type:ident) => {
+        for test_vector in EIP_2333_TEST_VECTORS {
+            let seed = hex::decode(test_vector.seed).unwrap();
+
+            let master_sk = $secret_key_type::derive_master(&seed)
+                .expect("master key derivation failed");
+            assert_eq!(
+                hex::encode(master_sk.to_bytes()),
+                test_vector.master_sk
+            );
+
+            let child_sk = master_sk
+                .derive_child(test_vector.child_index)
+                .expect("child key derivation failed");
+            assert_eq!(hex::encode(child_sk.to_bytes()), test_vector.child_sk);
+
+            assert_eq!(
+                $secret_key_type::derive_path(
+                    &seed,
+                    &format!("m/{}", test_vector.child_index)
+                ),
+                Ok(child_sk)
+            );
+        }
+    };
+}
+
+#[test]
+fn eip_2333_test_vectors() {
+    eip_2333_test_vectors!(BbsSecretKey);
+    eip_2333_test_vectors!(BlsSecretKey);
+    eip_2333_test_vectors!(BlsMinSigSecretKey);
+}
+
+#[test]
+fn derive_path_nominal() {
+    let seed = hex::decode(EIP_2333_TEST_VECTORS[0].seed).unwrap();
+
+    assert_eq!(
+        BbsSecretKey::derive_path(&seed, "m"),
+        BbsSecretKey::derive_master(&seed)
+    );
+    assert_eq!(
+        BbsSecretKey::derive_path(&seed, "m/12381/3600/0/0"),
+        BbsSecretKey::derive_master(&seed)
+            .and_then(|sk| sk.derive_child(12381))
+            .and_then(|sk| sk.derive_child(3600))
+            .and_then(|sk| sk.derive_child(0))
+            .and_then(|sk| sk.derive_child(0))
+    );
+    assert_ne!(
+        BbsSecretKey::derive_path(&seed, "m/12381/3600/0/0"),
+        BbsSecretKey::derive_path(&seed, "m/12381/3600/1/0")
+    );
+}
+
+#[test]
+fn derive_path_invalid_parameters() {
+    let seed = hex::decode(EIP_2333_TEST_VECTORS[0].seed).unwrap();
+
+    for path in [
+        "",
+        "/0",
+        "n/0",
+        "M/0",
+        "m/",
+        "m//0",
+        "m/0/",
+        "m/+1",
+        "m/-1",
+        "m/0x10",
+        "m/4294967296",
+        "m/12381/3600/0/0'",
+    ] {
+        assert!(
+            matches!(
+                BbsSecretKey::derive_path(&seed, path),
+                Err(Error::BadParams { .. })
+            ),
+            "derivation path {path:?} should be rejected"
+        );
+    }
+    assert!(matches!(
+        BbsSecretKey::derive_master(&seed[..31]),
+        Err(Error::BadParams { .. })
+    ));
+}
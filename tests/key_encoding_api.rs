@@ -0,0 +1,318 @@
+#[cfg(feature = "jwk")]
+use pairing_crypto::key_encoding::{Jwk, JwkKeyType};
+use pairing_crypto::{
+    bbs::ciphersuites::bls12_381::{
+        KeyPair as BbsKeyPair,
+        PublicKey as BbsPublicKey,
+        SecretKey as BbsSecretKey,
+    },
+    bls::ciphersuites::{
+        bls12_381::{
+            KeyPair as BlsKeyPair,
+            PublicKey as BlsPublicKey,
+            SecretKey as BlsSecretKey,
+        },
+        bls12_381_g1::{
+            KeyPair as BlsMinSigKeyPair,
+            PublicKey as BlsMinSigPublicKey,
+            SecretKey as BlsMinSigSecretKey,
+        },
+    },
+    Error,
+};
+
+const KEY_GEN_SEED: &[u8; 32] = b"not_A_random_seed_at_Allllllllll";
+
+macro_rules! key_encoding_round_trip {
+    (
+        $key_pair_type:ident,
+        $public_key_type:ident,
+        $secret_key_type:ident,
+        $multikey_prefix:expr
+    ) => {
+        let key_pair = $key_pair_type::new(KEY_GEN_SEED, b"")
+            .expect("key generation failed");
+        let (secret_key, public_key) =
+            (&key_pair.secret_key, &key_pair.public_key);
+
+        let multikey = public_key.to_multikey();
+        assert!(multikey.starts_with($multikey_prefix));
+        assert_eq!($public_key_type::from_multikey(&multikey), Ok(*public_key));
+        let did = public_key.to_did_key();
+        assert_eq!(did, format!("did:key:{multikey}"));
+        assert_eq!($public_key_type::from_did_key(&did), Ok(*public_key));
+        assert_eq!(
+            $public_key_type::from_did_key(&format!("{did}#{multikey}")),
+            Ok(*public_key)
+        );
+
+        let private_multikey = secret_key.to_multikey();
+        assert_eq!(
+            $secret_key_type::from_multikey(&private_multikey),
+            Ok(secret_key.clone())
+        );
+        // A secret key Multikey is not a public key Multikey.
+        assert!(matches!(
+            $public_key_type::from_multikey(&private_multikey),
+            Err(Error::BadParams { .. })
+        ));
+    };
+}
+
+#[cfg(feature = "jwk")]
+macro_rules! jwk_round_trip {
+    (
+        $key_pair_type:ident,
+        $public_key_type:ident,
+        $secret_key_type:ident,
+        $crv:expr
+    ) => {
+        let key_pair = $key_pair_type::new(KEY_GEN_SEED, b"")
+            .expect("key generation failed");
+        let (secret_key, public_key) =
+            (&key_pair.secret_key, &key_pair.public_key);
+
+        for kty in [JwkKeyType::Okp, JwkKeyType::Ec] {
+            let jwk = public_key.to_jwk(kty);
+            assert_eq!(jwk.crv, $crv);
+            assert_eq!(jwk.d, None);
+            assert_eq!(jwk.y.is_some(), kty == JwkKeyType::Ec);
+            assert_eq!(Jwk::from_json(&jwk.to_json()), Ok(jwk.clone()));
+            assert_eq!($public_key_type::from_jwk(&jwk), Ok(*public_key));
+
+            let private_jwk = secret_key.to_jwk(kty);
+            assert!(private_jwk.d.is_some());
+            assert_eq!(private_jwk.thumbprint(), jwk.thumbprint());
+            assert_eq!(
+                $secret_key_type::from_jwk(&private_jwk),
+                Ok(secret_key.clone())
+            );
+        }
+        assert_ne!(
+            public_key.to_jwk(JwkKeyType::Okp).thumbprint(),
+            public_key.to_jwk(JwkKeyType::Ec).thumbprint()
+        );
+    };
+}
+
+#[cfg(feature = "nonstandard_der")]
+macro_rules! der_round_trip {
+    (
+        $key_pair_type:ident,
+        $public_key_type:ident,
+        $secret_key_type:ident
+    ) => {
+        let key_pair = $key_pair_type::new(KEY_GEN_SEED, b"")
+            .expect("key generation failed");
+        let (secret_key, public_key) =
+            (&key_pair.secret_key, &key_pair.public_key);
+
+        let spki = public_key.to_spki_der();
+        assert_eq!($public_key_type::from_spki_der(&spki), Ok(*public_key));
+        let pkcs8 = secret_key.to_pkcs8_der();
+        assert_eq!(
+            $secret_key_type::from_pkcs8_der(&pkcs8),
+            Ok(secret_key.clone())
+        );
+        // Trailing bytes.
+        assert_eq!(
+            $public_key_type::from_spki_der(&[spki.as_slice(), &[0]].concat()),
+            Err(Error::BadEncoding)
+        );
+        assert_eq!(
+            $secret_key_type::from_pkcs8_der(
+                &[pkcs8.as_slice(), &[0]].concat()
+            ),
+            Err(Error::BadEncoding)
+        );
+    };
+}
+
+#[test]
+fn key_encoding_round_trip() {
+    key_encoding_round_trip!(BbsKeyPair, BbsPublicKey, BbsSecretKey, "zUC7");
+    key_encoding_round_trip!(BlsKeyPair, BlsPublicKey, BlsSecretKey, "z3tE");
+    key_encoding_round_trip!(
+        BlsMinSigKeyPair,
+        BlsMinSigPublicKey,
+        BlsMinSigSecretKey,
+        "zUC7"
+    );
+}
+
+#[cfg(feature = "jwk")]
+#[test]
+fn jwk_round_trip() {
+    jwk_round_trip!(BbsKeyPair, BbsPublicKey, BbsSecretKey, "BLS12381G2");
+    jwk_round_trip!(BlsKeyPair, BlsPublicKey, BlsSecretKey, "BLS12381G1");
+    jwk_round_trip!(
+        BlsMinSigKeyPair,
+        BlsMinSigPublicKey,
+        BlsMinSigSecretKey,
+        "BLS12381G2"
+    );
+}
+
+#[cfg(feature = "nonstandard_der")]
+#[test]
+fn der_round_trip() {
+    der_round_trip!(BbsKeyPair, BbsPublicKey, BbsSecretKey);
+    der_round_trip!(BlsKeyPair, BlsPublicKey, BlsSecretKey);
+    der_round_trip!(BlsMinSigKeyPair, BlsMinSigPublicKey, BlsMinSigSecretKey);
+}
+
+#[cfg(feature = "jwk")]
+#[test]
+fn jwk_thumbprint() {
+    let jwk = Jwk::from_json(r#"{"kty":"OKP","crv":"BLS12381G2","x":"abc"}"#)
+        .expect("JWK decoding failed");
+    assert_eq!(
+        jwk.thumbprint(),
+        Ok("dyT5odpFQYvxPvOCwr05hNVpqLTjUiCn1F-MDLTyfpM".to_owned())
+    );
+
+    // Non required members are not part of the thumbprint.
+    let jwk = Jwk::from_json(
+        r#"{"y":"def","crv":"BLS12381G1","x":"abc","kty":"EC","d":"ghi"}"#,
+    )
+    .expect("JWK decoding failed");
+    assert_eq!(
+        jwk.thumbprint(),
+        Ok("BNUXBpGDofUBXggIgxQj1id6RuV5EJOOA12rTgIrgF8".to_owned())
+    );
+
+    let jwk = Jwk::from_json(r#"{"kty":"RSA","crv":"BLS12381G2","x":"abc"}"#)
+        .expect("JWK decoding failed");
+    assert!(jwk.thumbprint().is_err());
+
+    // Unsupported curves, including names which would need JSON escaping.
+    for crv in ["P-256", "BLS12381G2\",\"a\":\"b"] {
+        let jwk = Jwk {
+            kty: "OKP".to_owned(),
+            crv: crv.to_owned(),
+            x: "abc".to_owned(),
+            y: None,
+            d: None,
+        };
+        assert!(matches!(jwk.thumbprint(), Err(Error::BadParams { .. })));
+    }
+}
+
+#[test]
+fn key_import_validation() {
+    let key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"").expect("key generation failed");
+
+    // The identity point.
+    assert_eq!(
+        BbsPublicKey::from_multikey(
+            "zUC7Nyd4gVLT161bpuGj3HCuZSf2MBus2X3kbzCTzeQRWKDxPR7fdCcsZ76SyuLqK3xAEimkGDUa6XsYodwn1hNgHMX8oyezfvqm2PcnieBghfwyGhEfbd4W1ScNN9aiPv9mmZH"
+        ),
+        Err(Error::InvalidPublicKey)
+    );
+
+    // A point of the curve out of the prime order subgroup, (4, sqrt(68)).
+    assert_eq!(
+        BlsPublicKey::from_multikey(
+            "z3tEEbgifFi1eYnj7yEx7zA3R9Mj9GwHnHoGxBonF8FsswxX7sNHt3y6ojvjCjUr36EacT"
+        ),
+        Err(Error::BadEncoding)
+    );
+
+    // Non-minimal varint of the multicodec.
+    let multikey = key_pair.public_key.to_multikey();
+    let bytes = bs58::decode(&multikey[1..])
+        .into_vec()
+        .expect("base58 decoding failed");
+    assert_eq!(bytes[..2], [0xeb, 0x01]);
+    let non_minimal = format!(
+        "z{}",
+        bs58::encode([&[0xeb, 0x81, 0x00], &bytes[2..]].concat()).into_string()
+    );
+    assert_eq!(
+        BbsPublicKey::from_multikey(&non_minimal),
+        Err(Error::BadEncoding)
+    );
+
+    for invalid in ["", "z", "uAOsB", "z3tE0OIl", "did:web:example.com"] {
+        assert!(BlsPublicKey::from_multikey(invalid).is_err());
+        assert!(BlsPublicKey::from_did_key(invalid).is_err());
+    }
+}
+
+#[cfg(feature = "jwk")]
+#[test]
+fn jwk_import_validation() {
+    let key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"").expect("key generation failed");
+
+    // Curve mismatch.
+    let mut jwk = key_pair.public_key.to_jwk(JwkKeyType::Okp);
+    jwk.crv = "BLS12381G1".to_owned();
+    assert!(matches!(
+        BbsPublicKey::from_jwk(&jwk),
+        Err(Error::BadParams { .. })
+    ));
+
+    // Public key of a different secret key.
+    let other_key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"other").expect("key generation failed");
+    let other_jwk = other_key_pair.public_key.to_jwk(JwkKeyType::Okp);
+    let mut private_jwk = key_pair.secret_key.to_jwk(JwkKeyType::Okp);
+    private_jwk.x = other_jwk.x.clone();
+    assert_eq!(
+        BbsSecretKey::from_jwk(&private_jwk),
+        Err(Error::InvalidSecretKey)
+    );
+}
+
+#[cfg(feature = "nonstandard_der")]
+#[test]
+fn der_import_validation() {
+    let key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"").expect("key generation failed");
+    let other_key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"other").expect("key generation failed");
+
+    // SubjectPublicKeyInfo and PKCS#8 of a key of the other group.
+    let bls_key_pair =
+        BlsKeyPair::new(KEY_GEN_SEED, b"").expect("key generation failed");
+    assert!(matches!(
+        BbsPublicKey::from_spki_der(&bls_key_pair.public_key.to_spki_der()),
+        Err(Error::BadParams { .. })
+    ));
+    assert!(matches!(
+        BbsSecretKey::from_pkcs8_der(&bls_key_pair.secret_key.to_pkcs8_der()),
+        Err(Error::BadParams { .. })
+    ));
+
+    // PKCS#8 with the public key of a different secret key.
+    let pkcs8 = key_pair.secret_key.to_pkcs8_der();
+    let other_pkcs8 = other_key_pair.secret_key.to_pkcs8_der();
+    let (point_start, point_end) = (pkcs8.len() - 96, pkcs8.len());
+    let mismatched =
+        [&pkcs8[..point_start], &other_pkcs8[point_start..point_end]].concat();
+    assert_eq!(
+        BbsSecretKey::from_pkcs8_der(&mismatched),
+        Err(Error::InvalidSecretKey)
+    );
+
+    // SubjectPublicKeyInfo of the identity point.
+    let spki = key_pair.public_key.to_spki_der();
+    let mut identity = spki[..spki.len() - 96].to_vec();
+    identity.push(0xc0);
+    identity.extend_from_slice(&[0u8; 95]);
+    assert_eq!(
+        BbsPublicKey::from_spki_der(&identity),
+        Err(Error::InvalidPublicKey)
+    );
+
+    // Non-minimal DER length.
+    let spki = bls_key_pair.public_key.to_spki_der();
+    assert!(spki[1] < 0x80);
+    let long_length = [&[0x30, 0x81, spki[1]], &spki[2..]].concat();
+    assert_eq!(
+        BlsPublicKey::from_spki_der(&long_length),
+        Err(Error::BadEncoding)
+    );
+}
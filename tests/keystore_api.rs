@@ -0,0 +1,261 @@
+use pairing_crypto::{
+    bbs::ciphersuites::bls12_381::{
+        KeyPair as BbsKeyPair,
+        SecretKey as BbsSecretKey,
+    },
+    bls::ciphersuites::bls12_381::SecretKey as BlsSecretKey,
+    keystore::{Keystore, KeystoreKdf},
+    Error,
+};
+use rand_core::OsRng;
+
+const KEY_GEN_SEED: &[u8; 32] = b"not_A_random_seed_at_Allllllllll";
+
+// Cheap KDF parameters, for tests only.
+const TEST_KDFS: [KeystoreKdf; 2] = [
+    KeystoreKdf::Scrypt {
+        n: 1024,
+        r: 8,
+        p: 1,
+    },
+    KeystoreKdf::Pbkdf2 { c: 1000 },
+];
+
+// Test vectors of <https://eips.ethereum.org/EIPS/eip-2335#test-cases>.
+const EIP_2335_PASSWORD: &str = "𝔱𝔢𝔰𝔱𝔭𝔞𝔰𝔰𝔴𝔬𝔯𝔡🔑";
+const EIP_2335_SECRET: &str =
+    "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+const EIP_2335_SCRYPT_KEYSTORE: &str = r#"{
+    "crypto": {
+        "kdf": {
+            "function": "scrypt",
+            "params": {
+                "dklen": 32,
+                "n": 262144,
+                "p": 1,
+                "r": 8,
+                "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+            },
+            "message": ""
+        },
+        "checksum": {
+            "function": "sha256",
+            "params": {},
+            "message": "d2217fe5f3e9a1e34581ef8a78f7c9928e436d36dacc5e846690a5581e8ea484"
+        },
+        "cipher": {
+            "function": "aes-128-ctr",
+            "params": {
+                "iv": "264daa3f303d7259501c93d997d84fe6"
+            },
+            "message": "06ae90d55fe0a6e9c5c3bc5b170827b2e5cce3929ed3f116c2811e6366dfe20f"
+        }
+    },
+    "description": "This is a test keystore that uses scrypt to secure the secret.",
+    "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+    "path": "m/12381/60/3141592653/589793238",
+    "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+    "version": 4
+}"#;
+
+const EIP_2335_PBKDF2_KEYSTORE: &str = r#"{
+    "crypto": {
+        "kdf": {
+            "function": "pbkdf2",
+            "params": {
+                "dklen": 32,
+                "c": 262144,
+                "prf": "hmac-sha256",
+                "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+            },
+            "message": ""
+        },
+        "checksum": {
+            "function": "sha256",
+            "params": {},
+            "message": "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1"
+        },
+        "cipher": {
+            "function": "aes-128-ctr",
+            "params": {
+                "iv": "264daa3f303d7259501c93d997d84fe6"
+            },
+            "message": "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad"
+        }
+    },
+    "description": "This is a test keystore that uses PBKDF2 to secure the secret.",
+    "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+    "path": "m/12381/60/0/0",
+    "uuid": "64625def-3331-4eea-ab6f-782f3ed16a83",
+    "version": 4
+}"#;
+
+const SHORT_SALT_SCRYPT_KEYSTORE: &str = r#"{
+    "crypto": {
+        "kdf": {
+            "function": "scrypt",
+            "params": {
+                "dklen": 32,
+                "n": 1024,
+                "p": 1,
+                "r": 8,
+                "salt": "d4e56740f876aef8c010b86a40d5f567"
+            },
+            "message": ""
+        },
+        "checksum": {
+            "function": "sha256",
+            "params": {},
+            "message": "b703a8bc23050a7dcc849c3948e54c6bd44bd35390d7bb8cead61b955510be39"
+        },
+        "cipher": {
+            "function": "aes-128-ctr",
+            "params": {
+                "iv": "264daa3f303d7259501c93d997d84fe6"
+            },
+            "message": "5b584f1ec8281087bec9d7d340a2eca370f7961e4604e016c7a072e147a608c0"
+        }
+    },
+    "description": "",
+    "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+    "path": "m/12381/60/0/0",
+    "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+    "version": 4
+}"#;
+
+const SHORT_SALT_PBKDF2_KEYSTORE: &str = r#"{
+    "crypto": {
+        "kdf": {
+            "function": "pbkdf2",
+            "params": {
+                "dklen": 32,
+                "c": 1000,
+                "prf": "hmac-sha256",
+                "salt": "d4e56740f876aef8c010b86a40d5f567"
+            },
+            "message": ""
+        },
+        "checksum": {
+            "function": "sha256",
+            "params": {},
+            "message": "0c75bb716bf902aa7be0b5b8ba9d46a7d28225d280e6e14b358b58e2150d6f3e"
+        },
+        "cipher": {
+            "function": "aes-128-ctr",
+            "params": {
+                "iv": "264daa3f303d7259501c93d997d84fe6"
+            },
+            "message": "3d6e76c339553d21ffac960a9a4a212015c5985a03a9070c233cb4c60bedd9fa"
+        }
+    },
+    "description": "",
+    "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+    "path": "m/12381/60/0/0",
+    "uuid": "64625def-3331-4eea-ab6f-782f3ed16a83",
+    "version": 4
+}"#;
+
+fn eip_2335_test_vector(keystore_json: &str) {
+    let keystore =
+        Keystore::from_json(keystore_json).expect("keystore decoding failed");
+    let secret_key = BlsSecretKey::from_keystore(&keystore, EIP_2335_PASSWORD)
+        .expect("keystore decryption failed");
+    assert_eq!(hex::encode(secret_key.to_bytes()), EIP_2335_SECRET);
+    assert_eq!(Keystore::from_json(&keystore.to_json()), Ok(keystore));
+}
+
+#[test]
+fn eip_2335_pbkdf2_test_vector() {
+    eip_2335_test_vector(EIP_2335_PBKDF2_KEYSTORE);
+}
+
+#[test]
+fn eip_2335_scrypt_test_vector() {
+    eip_2335_test_vector(EIP_2335_SCRYPT_KEYSTORE);
+}
+
+// The EIP-2335 test vectors with 16 bytes salts and cheap KDF parameters,
+// EIP-2335 does not mandate a salt length.
+#[test]
+fn eip_2335_short_salt() {
+    for keystore_json in
+        [SHORT_SALT_SCRYPT_KEYSTORE, SHORT_SALT_PBKDF2_KEYSTORE]
+    {
+        eip_2335_test_vector(keystore_json);
+    }
+}
+
+#[test]
+fn keystore_round_trip() {
+    let key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"").expect("key generation failed");
+
+    for kdf in TEST_KDFS {
+        let keystore = key_pair
+            .secret_key
+            .to_keystore("password", kdf, "m/12381/3600/0/0", &mut OsRng)
+            .expect("keystore encryption failed");
+        assert_eq!(keystore.version, 4);
+        assert_eq!(keystore.path, "m/12381/3600/0/0");
+        assert_eq!(
+            keystore.pubkey,
+            hex::encode(key_pair.public_key.to_octets())
+        );
+
+        let keystore = Keystore::from_json(&keystore.to_json())
+            .expect("keystore decoding failed");
+        assert_eq!(
+            BbsSecretKey::from_keystore(&keystore, "password"),
+            Ok(key_pair.secret_key.clone())
+        );
+
+        // Control codes are removed from the password.
+        assert_eq!(
+            BbsSecretKey::from_keystore(&keystore, "pass\u{7f}word\u{0}"),
+            Ok(key_pair.secret_key.clone())
+        );
+
+        assert!(matches!(
+            BbsSecretKey::from_keystore(&keystore, "Password"),
+            Err(Error::CryptoOps { .. })
+        ));
+    }
+}
+
+#[test]
+fn keystore_invalid_parameters() {
+    let key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"").expect("key generation failed");
+    let keystore = key_pair
+        .secret_key
+        .to_keystore("password", TEST_KDFS[1], "", &mut OsRng)
+        .expect("keystore encryption failed");
+
+    // Public key of a different secret key.
+    let other_key_pair =
+        BbsKeyPair::new(KEY_GEN_SEED, b"other").expect("key generation failed");
+    let mut other_keystore = keystore.clone();
+    other_keystore.pubkey = hex::encode(other_key_pair.public_key.to_octets());
+    assert_eq!(
+        BbsSecretKey::from_keystore(&other_keystore, "password"),
+        Err(Error::InvalidSecretKey)
+    );
+
+    let json = keystore.to_json();
+    for (from, to) in [
+        ("\"aes-128-ctr\"", "\"aes-256-ctr\""),
+        ("\"hmac-sha256\"", "\"hmac-sha512\""),
+        ("\"sha256\"", "\"sha512\""),
+        ("\"version\":4", "\"version\":3"),
+        ("\"dklen\":32", "\"dklen\":16"),
+    ] {
+        let keystore = Keystore::from_json(&json.replace(from, to))
+            .expect("keystore decoding failed");
+        assert!(matches!(
+            BbsSecretKey::from_keystore(&keystore, "password"),
+            Err(Error::BadParams { .. })
+        ));
+    }
+    assert_eq!(Keystore::from_json("{}"), Err(Error::Serde));
+}
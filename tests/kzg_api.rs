@@ -0,0 +1,373 @@
+use blstrs::{G1Affine, G1Projective, Scalar};
+use group::{Curve, Group};
+use pairing_crypto::kzg::eip4844::{
+    blob_to_kzg_commitment,
+    compute_blob_kzg_proof,
+    compute_kzg_multi_proof,
+    compute_kzg_proof,
+    verify_blob_kzg_proof,
+    verify_blob_kzg_proof_batch,
+    verify_kzg_multi_proof,
+    verify_kzg_proof,
+    TrustedSetup,
+    BYTES_PER_BLOB,
+    BYTES_PER_FIELD_ELEMENT,
+    FIELD_ELEMENTS_PER_BLOB,
+};
+use rand_core::{OsRng, RngCore};
+use std::path::Path;
+
+// Secret of the insecure trusted setup used in tests.
+const TEST_SETUP_SECRET: [u8; 32] = [0x2a; 32];
+
+// Mainnet trusted setup of EIP-4844, in the text format of the reference
+// implementations.
+const MAINNET_SETUP_FILE: &str = "tests/fixtures/kzg/trusted_setup.txt";
+
+fn random_blob() -> Vec<u8> {
+    let mut blob = vec![0u8; BYTES_PER_BLOB];
+    OsRng.fill_bytes(&mut blob);
+    // Clear the top byte of every field element to keep it canonical.
+    blob.iter_mut()
+        .step_by(BYTES_PER_FIELD_ELEMENT)
+        .for_each(|b| *b = 0);
+    blob
+}
+
+fn random_field_element() -> [u8; BYTES_PER_FIELD_ELEMENT] {
+    let mut z = [0u8; BYTES_PER_FIELD_ELEMENT];
+    OsRng.fill_bytes(&mut z[1..]);
+    z
+}
+
+// Deterministic blob, with field elements from a linear congruential
+// generator seeded with `seed`.
+fn test_blob(seed: u64) -> Vec<u8> {
+    let mut state = seed;
+    let mut blob = (0..BYTES_PER_BLOB)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 56) as u8
+        })
+        .collect::<Vec<u8>>();
+    // Clear the top byte of every field element to keep it canonical.
+    blob.iter_mut()
+        .step_by(BYTES_PER_FIELD_ELEMENT)
+        .for_each(|b| *b = 0);
+    blob
+}
+
+fn decode_hex<const N: usize>(hex: &str) -> [u8; N] {
+    hex::decode(hex).unwrap().try_into().unwrap()
+}
+
+fn mainnet_setup() -> TrustedSetup {
+    TrustedSetup::from_text(
+        &std::fs::read_to_string(Path::new(MAINNET_SETUP_FILE))
+            .expect("trusted setup file must be readable"),
+    )
+    .expect("trusted setup loading must succeed")
+}
+
+// Expected values below are computed with the c-kzg-4844 reference
+// implementation and the mainnet trusted setup.
+const MAINNET_COMMITMENTS: [&str; 3] = [
+    "a9032d9ec7e485e0e4c510725a769e65b28d3925af7ecb82bac0c6559a81444a6ab17393\
+     f838ffff2ad0494b391e930f",
+    "a102d6cd536480c17883134ecbc3b3a3cc648a8cb1a0f63c275d316b7c65af8904649a72\
+     3006316cddeaaae46008d3cb",
+    "8a2a503b7dc687ae6dd3ece85e2b96b85037df19fd4acf4bfd0e618f021eef668983510f\
+     570f1a9adfbd3334ab4afef0",
+];
+const MAINNET_BLOB_PROOFS: [&str; 3] = [
+    "ae65c3e4bb740d67ba0eb004eddbf6ba72af76fbf0ee554654db0308cdfd1278d15c51aa\
+     b60daa67e0874a4da2977ead",
+    "a2b28f2f8ee6b137f2bd4bd1368fba5e0a2ade9904c04a8c247421c39a2f4f20ea550d13\
+     f6128af3358383b92661c17b",
+    "8c1eabbf91d46b7957b5bcec3cf983b16ae2064269c2f74d3de7bcec5c925ee2d013a939\
+     23fde47f26c837be25545cdc",
+];
+
+#[test]
+fn mainnet_setup_blob_to_kzg_commitment() {
+    let setup = mainnet_setup();
+
+    let mut infinity = [0u8; 48];
+    infinity[0] = 0xc0;
+    assert_eq!(
+        blob_to_kzg_commitment(&vec![0u8; BYTES_PER_BLOB], &setup)
+            .expect("commitment must succeed"),
+        infinity
+    );
+    for (seed, expected) in (1..).zip(MAINNET_COMMITMENTS) {
+        assert_eq!(
+            blob_to_kzg_commitment(&test_blob(seed), &setup)
+                .expect("commitment must succeed"),
+            decode_hex::<48>(expected)
+        );
+    }
+}
+
+#[test]
+fn mainnet_setup_compute_verify_kzg_proof() {
+    let setup = mainnet_setup();
+    let blob = test_blob(1);
+    let commitment = decode_hex::<48>(MAINNET_COMMITMENTS[0]);
+
+    for (z, expected_proof, expected_y) in [
+        (
+            "0077dd9527961697d1e1c440d1daa3debb1fc6e5bc9125fbd49f16d617ec722b",
+            "8e5a1347a167c5311cba7339712033d0ced1d2c6b54607c473781513e2759d4e\
+             709b4abe56e6e60ba25a2e4d2126c66e",
+            "426563ca00d10f8f083b4a4ca7dc05b10545bf5ae957f31eda64a1e017c5b3d1",
+        ),
+        // Evaluation point in the domain.
+        (
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "b23085026196b5a3d9b159f55c7b13878b60236571272f3f8061735875070580\
+             cac084751b933535bfa859303cdd10f9",
+            "0082a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3",
+        ),
+    ] {
+        let z = decode_hex::<32>(z);
+        let (proof, y) =
+            compute_kzg_proof(&blob, &z, &setup).expect("proof must succeed");
+        assert_eq!(proof, decode_hex::<48>(expected_proof));
+        assert_eq!(y, decode_hex::<32>(expected_y));
+
+        assert!(verify_kzg_proof(&commitment, &z, &y, &proof, &setup)
+            .expect("verification must succeed"));
+        let mut wrong_y = y;
+        wrong_y[31] ^= 1;
+        assert!(!verify_kzg_proof(&commitment, &z, &wrong_y, &proof, &setup)
+            .expect("verification must succeed"));
+    }
+}
+
+#[test]
+fn mainnet_setup_verify_blob_kzg_proof_batch() {
+    let setup = mainnet_setup();
+    let blobs = (1..=3).map(test_blob).collect::<Vec<_>>();
+    let commitments = MAINNET_COMMITMENTS.map(decode_hex::<48>);
+    let proofs = MAINNET_BLOB_PROOFS.map(decode_hex::<48>);
+
+    for ((blob, commitment), proof) in
+        blobs.iter().zip(commitments.iter()).zip(proofs.iter())
+    {
+        assert_eq!(
+            &compute_blob_kzg_proof(blob, commitment, &setup)
+                .expect("proof must succeed"),
+            proof
+        );
+    }
+    assert!(
+        verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &setup)
+            .expect("verification must succeed")
+    );
+    assert!(!verify_blob_kzg_proof_batch(
+        &blobs,
+        &commitments,
+        &[proofs[1], proofs[0], proofs[2]],
+        &setup
+    )
+    .expect("verification must succeed"));
+    assert!(
+        verify_blob_kzg_proof_batch::<Vec<u8>>(&[], &[], &[], &setup)
+            .expect("verification must succeed")
+    );
+}
+
+#[test]
+fn blob_to_kzg_commitment_known_values() {
+    let setup = TrustedSetup::insecure_from_secret(&TEST_SETUP_SECRET)
+        .expect("setup generation must succeed");
+
+    // The commitment to the zero polynomial is the point at infinity.
+    let mut infinity = [0u8; 48];
+    infinity[0] = 0xc0;
+    assert_eq!(
+        blob_to_kzg_commitment(&vec![0u8; BYTES_PER_BLOB], &setup)
+            .expect("commitment must succeed"),
+        infinity
+    );
+
+    // The commitment to a constant polynomial is independent of the setup.
+    let c = Scalar::from(0x1234u64);
+    let blob = c.to_bytes_be().repeat(FIELD_ELEMENTS_PER_BLOB);
+    assert_eq!(
+        blob_to_kzg_commitment(&blob, &setup).expect("commitment must succeed"),
+        (G1Projective::generator() * c).to_affine().to_compressed()
+    );
+}
+
+#[test]
+fn kzg_proof_e2e_nominal() {
+    let setup = TrustedSetup::insecure_from_secret(&TEST_SETUP_SECRET)
+        .expect("setup generation must succeed");
+    let blob = random_blob();
+    let commitment =
+        blob_to_kzg_commitment(&blob, &setup).expect("commitment must succeed");
+
+    // Evaluation point outside of the domain.
+    let z = random_field_element();
+    let (proof, y) =
+        compute_kzg_proof(&blob, &z, &setup).expect("proof must succeed");
+    assert!(verify_kzg_proof(&commitment, &z, &y, &proof, &setup)
+        .expect("verification must succeed"));
+
+    let wrong_y = random_field_element();
+    assert!(!verify_kzg_proof(&commitment, &z, &wrong_y, &proof, &setup)
+        .expect("verification must succeed"));
+
+    // Evaluation point in the domain; 1 is the first root of unity in
+    // bit-reversal permutation order.
+    let one = Scalar::from(1u64).to_bytes_be();
+    let (proof, y) =
+        compute_kzg_proof(&blob, &one, &setup).expect("proof must succeed");
+    assert_eq!(y, blob[..BYTES_PER_FIELD_ELEMENT]);
+    assert!(verify_kzg_proof(&commitment, &one, &y, &proof, &setup)
+        .expect("verification must succeed"));
+}
+
+#[test]
+fn blob_kzg_proof_e2e_nominal() {
+    let setup = TrustedSetup::insecure_from_secret(&TEST_SETUP_SECRET)
+        .expect("setup generation must succeed");
+
+    let blobs = (0..3).map(|_| random_blob()).collect::<Vec<_>>();
+    let commitments = blobs
+        .iter()
+        .map(|blob| {
+            blob_to_kzg_commitment(blob, &setup)
+                .expect("commitment must succeed")
+        })
+        .collect::<Vec<_>>();
+    let proofs = blobs
+        .iter()
+        .zip(commitments.iter())
+        .map(|(blob, commitment)| {
+            compute_blob_kzg_proof(blob, commitment, &setup)
+                .expect("proof must succeed")
+        })
+        .collect::<Vec<_>>();
+
+    for ((blob, commitment), proof) in
+        blobs.iter().zip(commitments.iter()).zip(proofs.iter())
+    {
+        assert!(verify_blob_kzg_proof(blob, commitment, proof, &setup)
+            .expect("verification must succeed"));
+    }
+    assert!(
+        verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs, &setup)
+            .expect("verification must succeed")
+    );
+
+    // Swapped proofs.
+    let swapped_proofs = [proofs[1], proofs[0], proofs[2]];
+    assert!(!verify_blob_kzg_proof(
+        &blobs[0],
+        &commitments[0],
+        &swapped_proofs[0],
+        &setup
+    )
+    .expect("verification must succeed"));
+    assert!(!verify_blob_kzg_proof_batch(
+        &blobs,
+        &commitments,
+        &swapped_proofs,
+        &setup
+    )
+    .expect("verification must succeed"));
+
+    // Length mismatch.
+    assert!(verify_blob_kzg_proof_batch(
+        &blobs,
+        &commitments[..2],
+        &proofs,
+        &setup
+    )
+    .is_err());
+}
+
+#[test]
+fn kzg_multi_proof_e2e_nominal() {
+    let setup = TrustedSetup::insecure_from_secret(&TEST_SETUP_SECRET)
+        .expect("setup generation must succeed");
+    let blob = random_blob();
+    let commitment =
+        blob_to_kzg_commitment(&blob, &setup).expect("commitment must succeed");
+
+    let mut zs = (0..7).map(|_| random_field_element()).collect::<Vec<_>>();
+    // Include a point of the domain.
+    zs.push(Scalar::from(1u64).to_bytes_be());
+
+    let (proof, ys) = compute_kzg_multi_proof(&blob, &zs, &setup)
+        .expect("proof must succeed");
+    assert_eq!(ys[7], blob[..BYTES_PER_FIELD_ELEMENT]);
+    assert!(
+        verify_kzg_multi_proof(&commitment, &zs, &ys, &proof, &setup)
+            .expect("verification must succeed")
+    );
+
+    // Evaluations match single-point openings.
+    let (_, y) =
+        compute_kzg_proof(&blob, &zs[0], &setup).expect("proof must succeed");
+    assert_eq!(ys[0], y);
+
+    // Wrong evaluation.
+    let mut wrong_ys = ys.clone();
+    wrong_ys[3] = random_field_element();
+    assert!(!verify_kzg_multi_proof(
+        &commitment,
+        &zs,
+        &wrong_ys,
+        &proof,
+        &setup
+    )
+    .expect("verification must succeed"));
+
+    // Subset of the points.
+    assert!(!verify_kzg_multi_proof(
+        &commitment,
+        &zs[..7],
+        &ys[..7],
+        &proof,
+        &setup
+    )
+    .expect("verification must succeed"));
+
+    // Duplicate and too many points.
+    assert!(compute_kzg_multi_proof(&blob, &[zs[0], zs[0]], &setup).is_err());
+    let zs = (0..65).map(|_| random_field_element()).collect::<Vec<_>>();
+    assert!(compute_kzg_multi_proof(&blob, &zs, &setup).is_err());
+}
+
+#[test]
+fn invalid_inputs() {
+    let setup = TrustedSetup::insecure_from_secret(&TEST_SETUP_SECRET)
+        .expect("setup generation must succeed");
+
+    // Wrong blob length.
+    assert!(blob_to_kzg_commitment(&[0u8; 32], &setup).is_err());
+
+    // Non canonical field element.
+    let mut blob = random_blob();
+    blob[..BYTES_PER_FIELD_ELEMENT].copy_from_slice(&[0xff; 32]);
+    assert!(blob_to_kzg_commitment(&blob, &setup).is_err());
+    assert!(compute_kzg_proof(&random_blob(), &[0xff; 32], &setup).is_err());
+
+    // Invalid commitment encoding.
+    let blob = random_blob();
+    assert!(compute_blob_kzg_proof(&blob, &[0u8; 48], &setup).is_err());
+
+    // Trusted setups of the wrong size or format.
+    assert!(TrustedSetup::from_octets(
+        &[G1Affine::generator().to_compressed()],
+        &[]
+    )
+    .is_err());
+    assert!(TrustedSetup::from_text("4096\n65\n").is_err());
+    assert!(TrustedSetup::from_text("not a setup").is_err());
+}
@@ -0,0 +1,137 @@
+use pairing_crypto::pre::ciphersuites::{
+    bls12_381::{
+        KeyPair,
+        PublicKey,
+        ReEncryptionKey,
+        SecretKey,
+        PRE_AFGH_BLS12381_FIRST_LEVEL_CIPHERTEXT_OVERHEAD,
+        PRE_AFGH_BLS12381_MAX_MESSAGE_LENGTH,
+        PRE_AFGH_BLS12381_SECOND_LEVEL_CIPHERTEXT_OVERHEAD,
+    },
+    bls12_381_sha_256::{
+        decrypt,
+        decrypt_first_level,
+        encrypt,
+        encrypt_first_level,
+        re_encrypt,
+    },
+};
+use rand_core::OsRng;
+
+const TEST_KEY_INFO: &[u8] = b"test-key-info";
+
+const TEST_MESSAGES: [&[u8]; 3] = [
+    b"a",
+    b"a document shared through a proxy",
+    &[0xAB; PRE_AFGH_BLS12381_MAX_MESSAGE_LENGTH],
+];
+
+#[test]
+fn re_encrypt_e2e_nominal() {
+    let alice = KeyPair::random(&mut OsRng, TEST_KEY_INFO)
+        .expect("key pair generation failed");
+    let bob = KeyPair::random(&mut OsRng, TEST_KEY_INFO)
+        .expect("key pair generation failed");
+    let rk = ReEncryptionKey::new(&alice.secret_key, &bob.public_key)
+        .expect("re-encryption key generation failed");
+
+    for message in TEST_MESSAGES {
+        let ciphertext = encrypt(&alice.public_key, message, &mut OsRng)
+            .expect("encryption failed");
+        assert_eq!(
+            ciphertext.len(),
+            PRE_AFGH_BLS12381_SECOND_LEVEL_CIPHERTEXT_OVERHEAD + message.len()
+        );
+        assert_eq!(
+            decrypt(&alice.secret_key, &ciphertext).expect("decryption failed"),
+            message
+        );
+        assert!(decrypt(&bob.secret_key, &ciphertext).is_err());
+
+        let re_encrypted =
+            re_encrypt(&rk, &ciphertext).expect("re-encryption failed");
+        assert_eq!(
+            re_encrypted.len(),
+            PRE_AFGH_BLS12381_FIRST_LEVEL_CIPHERTEXT_OVERHEAD + message.len()
+        );
+        assert_eq!(
+            decrypt_first_level(&bob.secret_key, &re_encrypted)
+                .expect("decryption failed"),
+            message
+        );
+        assert!(decrypt_first_level(&alice.secret_key, &re_encrypted).is_err());
+
+        // First-level ciphertexts can not be re-encrypted
+        assert!(re_encrypt(&rk, &re_encrypted).is_err());
+    }
+}
+
+#[test]
+fn first_level_e2e_nominal() {
+    let bob = KeyPair::random(&mut OsRng, TEST_KEY_INFO)
+        .expect("key pair generation failed");
+
+    for message in TEST_MESSAGES {
+        let ciphertext =
+            encrypt_first_level(&bob.public_key, message, &mut OsRng)
+                .expect("encryption failed");
+        assert_eq!(
+            decrypt_first_level(&bob.secret_key, &ciphertext)
+                .expect("decryption failed"),
+            message
+        );
+    }
+}
+
+#[test]
+fn key_serialization() {
+    let alice =
+        KeyPair::new(b"not_A_random_seed_at_Allllllllll", TEST_KEY_INFO)
+            .expect("key pair generation failed");
+    let bob = KeyPair::random(&mut OsRng, TEST_KEY_INFO)
+        .expect("key pair generation failed");
+    let rk = ReEncryptionKey::new(&alice.secret_key, &bob.public_key)
+        .expect("re-encryption key generation failed");
+
+    assert_eq!(
+        SecretKey::from_bytes(&alice.secret_key.to_bytes()),
+        Ok(alice.secret_key.clone())
+    );
+    assert_eq!(
+        PublicKey::from_octets(&alice.public_key.to_octets()),
+        Ok(alice.public_key)
+    );
+    assert_eq!(ReEncryptionKey::from_octets(&rk.to_octets()), Ok(rk));
+
+    // Public key components with different discrete logarithms
+    let mut mixed = alice.public_key.to_octets();
+    mixed[48..].copy_from_slice(&bob.public_key.to_octets()[48..]);
+    assert!(PublicKey::from_octets(&mixed).is_err());
+}
+
+#[test]
+fn tampered_ciphertext() {
+    let alice = KeyPair::random(&mut OsRng, TEST_KEY_INFO)
+        .expect("key pair generation failed");
+    let bob = KeyPair::random(&mut OsRng, TEST_KEY_INFO)
+        .expect("key pair generation failed");
+    let rk = ReEncryptionKey::new(&alice.secret_key, &bob.public_key)
+        .expect("re-encryption key generation failed");
+
+    let mut ciphertext =
+        encrypt(&alice.public_key, TEST_MESSAGES[1], &mut OsRng)
+            .expect("encryption failed");
+    let last = ciphertext.len() - 1;
+    ciphertext[last] ^= 1;
+    assert!(decrypt(&alice.secret_key, &ciphertext).is_err());
+    let re_encrypted =
+        re_encrypt(&rk, &ciphertext).expect("re-encryption failed");
+    assert!(decrypt_first_level(&bob.secret_key, &re_encrypted).is_err());
+
+    assert!(encrypt(&alice.public_key, b"", &mut OsRng).is_err());
+    assert!(decrypt(
+        &alice.secret_key,
+        &ciphertext[..PRE_AFGH_BLS12381_SECOND_LEVEL_CIPHERTEXT_OVERHEAD]
+    )
+    .is_err());
+}
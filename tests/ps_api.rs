@@ -0,0 +1,299 @@
+use pairing_crypto::{
+    ps::{
+        ciphersuites::{
+            bls12_381::{
+                get_proof_size,
+                ps_bls12381g1_public_key_length,
+                ps_bls12381g1_secret_key_length,
+                KeyPair,
+                PublicKey,
+                SecretKey,
+            },
+            bls12_381_g1_sha_256::{
+                proof_gen,
+                proof_verify,
+                rerandomize,
+                sign,
+                verify,
+            },
+        },
+        PsProofGenRequest,
+        PsProofGenRevealMessageRequest,
+        PsProofVerifyRequest,
+        PsSignRequest,
+        PsVerifyRequest,
+    },
+    Error,
+};
+use rand_core::OsRng;
+
+const KEY_GEN_SEED: &[u8; 32] = b"not_A_random_seed_at_Allllllllll";
+
+const TEST_KEY_INFO: &[u8] = b"test-key-info";
+
+const TEST_CLAIMS: [&[u8]; 6] = [
+    b"first_name",
+    b"surname",
+    b"date_of_birth",
+    b"father",
+    b"mother",
+    b"credential_id",
+];
+
+const TEST_HEADER: &[u8; 16] = b"some_app_context";
+
+const TEST_PRESENTATION_HEADER: &[u8; 24] = b"test-presentation-header";
+
+fn test_key_pair() -> (Vec<u8>, Vec<u8>) {
+    let key_pair = KeyPair::new(KEY_GEN_SEED, TEST_KEY_INFO, TEST_CLAIMS.len())
+        .expect("key generation failed");
+    (
+        key_pair.secret_key.to_bytes(),
+        key_pair.public_key.to_octets(),
+    )
+}
+
+#[test]
+fn key_pair_serialization() {
+    let key_pair =
+        KeyPair::random(&mut OsRng, TEST_KEY_INFO, TEST_CLAIMS.len())
+            .expect("key generation failed");
+    let sk_bytes = key_pair.secret_key.to_bytes();
+    let pk_bytes = key_pair.public_key.to_octets();
+    assert_eq!(
+        sk_bytes.len(),
+        ps_bls12381g1_secret_key_length(TEST_CLAIMS.len())
+    );
+    assert_eq!(
+        pk_bytes.len(),
+        ps_bls12381g1_public_key_length(TEST_CLAIMS.len())
+    );
+
+    let sk = SecretKey::from_bytes(&sk_bytes).expect("secret key decoding");
+    let pk = PublicKey::from_octets(&pk_bytes).expect("public key decoding");
+    assert_eq!(sk, key_pair.secret_key);
+    assert_eq!(pk, key_pair.public_key);
+    assert_eq!(pk.message_count(), TEST_CLAIMS.len());
+
+    assert!(PublicKey::from_octets(&pk_bytes[1..]).is_err());
+    assert!(SecretKey::from_bytes(&sk_bytes[1..]).is_err());
+
+    // Same IKM and key info gives the same key pair
+    let (sk_1, pk_1) = test_key_pair();
+    let (sk_2, pk_2) = test_key_pair();
+    assert_eq!(sk_1, sk_2);
+    assert_eq!(pk_1, pk_2);
+}
+
+#[test]
+fn sign_verify_e2e_nominal() {
+    let (secret_key, public_key) = test_key_pair();
+    let messages = TEST_CLAIMS.to_vec();
+
+    let signature = sign(&PsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages),
+    })
+    .expect("signature generation failed");
+
+    assert!(verify(&PsVerifyRequest {
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages),
+        signature: &signature,
+    })
+    .expect("error during signature verification"));
+
+    // Tampered message
+    let mut tampered_messages = messages.clone();
+    tampered_messages[2] = b"tampered".as_ref();
+    assert!(!verify(&PsVerifyRequest {
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&tampered_messages),
+        signature: &signature,
+    })
+    .expect("error during signature verification"));
+
+    // Different header
+    assert!(!verify(&PsVerifyRequest {
+        public_key: &public_key,
+        header: Some(b"another_context".as_ref()),
+        messages: Some(&messages),
+        signature: &signature,
+    })
+    .expect("error during signature verification"));
+}
+
+#[test]
+fn rerandomize_e2e_nominal() {
+    let (secret_key, public_key) = test_key_pair();
+    let messages = TEST_CLAIMS.to_vec();
+
+    let signature = sign(&PsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages),
+    })
+    .expect("signature generation failed");
+
+    let rerandomized =
+        rerandomize(&signature).expect("signature rerandomization failed");
+    assert_ne!(signature, rerandomized);
+    assert!(verify(&PsVerifyRequest {
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages),
+        signature: &rerandomized,
+    })
+    .expect("error during signature verification"));
+}
+
+#[test]
+fn proof_gen_verify_e2e_nominal() {
+    let (secret_key, public_key) = test_key_pair();
+    let messages = TEST_CLAIMS.to_vec();
+
+    let signature = sign(&PsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages),
+    })
+    .expect("signature generation failed");
+
+    // Reveal none, every other message, and all messages
+    let reveal_patterns: [fn(usize) -> bool; 3] =
+        [|_| false, |i| i % 2 == 0, |_| true];
+    for reveal in reveal_patterns {
+        let proof_messages: Vec<PsProofGenRevealMessageRequest<&[u8]>> =
+            messages
+                .iter()
+                .enumerate()
+                .map(|(i, value)| PsProofGenRevealMessageRequest {
+                    reveal: reveal(i),
+                    value: *value,
+                })
+                .collect();
+        let revealed_messages: Vec<(usize, &[u8])> = messages
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| reveal(*i))
+            .map(|(i, m)| (i, *m))
+            .collect();
+
+        let proof = proof_gen(&PsProofGenRequest {
+            public_key: &public_key,
+            header: Some(TEST_HEADER.as_ref()),
+            messages: Some(&proof_messages),
+            signature: &signature,
+            presentation_header: Some(TEST_PRESENTATION_HEADER.as_ref()),
+            verify_signature: None,
+        })
+        .expect("proof generation failed");
+        assert_eq!(
+            proof.len(),
+            get_proof_size(messages.len() - revealed_messages.len())
+        );
+
+        assert!(proof_verify(&PsProofVerifyRequest {
+            public_key: &public_key,
+            header: Some(TEST_HEADER.as_ref()),
+            presentation_header: Some(TEST_PRESENTATION_HEADER.as_ref()),
+            proof: &proof,
+            messages: Some(&revealed_messages),
+        })
+        .expect("error during proof verification"));
+
+        // Different presentation header
+        assert!(!proof_verify(&PsProofVerifyRequest {
+            public_key: &public_key,
+            header: Some(TEST_HEADER.as_ref()),
+            presentation_header: Some(b"another-presentation".as_ref()),
+            proof: &proof,
+            messages: Some(&revealed_messages),
+        })
+        .expect("error during proof verification"));
+
+        // Tampered revealed message
+        if let Some((_, m)) = revealed_messages.first().copied() {
+            let mut tampered = revealed_messages.clone();
+            tampered[0].1 = &m[1..];
+            assert!(!proof_verify(&PsProofVerifyRequest {
+                public_key: &public_key,
+                header: Some(TEST_HEADER.as_ref()),
+                presentation_header: Some(TEST_PRESENTATION_HEADER.as_ref()),
+                proof: &proof,
+                messages: Some(&tampered),
+            })
+            .expect("error during proof verification"));
+        }
+    }
+}
+
+#[test]
+fn sign_verify_invalid_parameters() {
+    let (secret_key, public_key) = test_key_pair();
+    let messages = TEST_CLAIMS.to_vec();
+
+    // Message count does not match the key
+    let result = sign(&PsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages[1..]),
+    });
+    assert_eq!(
+        result,
+        Err(Error::MessageGeneratorsLengthMismatch {
+            generators: messages.len(),
+            messages: messages.len() - 1,
+        })
+    );
+
+    // Empty keys
+    let result = sign(&PsSignRequest {
+        messages: Some(&messages),
+        ..Default::default()
+    });
+    assert!(result.is_err());
+
+    // Signature over a different message count
+    let signature = sign(&PsSignRequest {
+        secret_key: &secret_key,
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages),
+    })
+    .expect("signature generation failed");
+    let result = verify(&PsVerifyRequest {
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&messages[1..]),
+        signature: &signature,
+    });
+    assert!(result.is_err());
+
+    // Proof over a message that was not signed
+    let mut proof_messages: Vec<PsProofGenRevealMessageRequest<&[u8]>> =
+        messages
+            .iter()
+            .map(|value| PsProofGenRevealMessageRequest {
+                reveal: false,
+                value: *value,
+            })
+            .collect();
+    proof_messages[0].value = b"tampered".as_ref();
+    let result = proof_gen(&PsProofGenRequest {
+        public_key: &public_key,
+        header: Some(TEST_HEADER.as_ref()),
+        messages: Some(&proof_messages),
+        signature: &signature,
+        presentation_header: None,
+        verify_signature: None,
+    });
+    assert_eq!(result, Err(Error::SignatureVerification));
+}
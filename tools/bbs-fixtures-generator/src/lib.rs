@@ -11,6 +11,9 @@ pub use generators::{
 pub use model::{
     ExpectedResult,
     FixtureGenInput,
+    FixtureH2s,
+    FixtureKeyGen,
+    FixtureMockedRng,
     FixtureProof,
     FixtureSignature,
     TestAsset,